@@ -0,0 +1,239 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use audit_service::models::{AuditEntry, AuditFilter};
+use audit_service::ports::audit_log_repository::{AuditLogRepository, RepositoryFailure};
+use tokio::sync::RwLock;
+
+#[derive(Clone, Debug)]
+pub struct InMemoryAuditLogRepository {
+    pub entries: Arc<RwLock<Vec<AuditEntry>>>,
+}
+
+impl InMemoryAuditLogRepository {
+    pub fn empty() -> Self {
+        Self {
+            entries: Arc::new(RwLock::new(vec![])),
+        }
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for InMemoryAuditLogRepository {
+    async fn append(&self, entry: AuditEntry) -> Result<(), RepositoryFailure> {
+        let mut entries = self.entries.write().await;
+        entries.push(entry);
+        Ok(())
+    }
+
+    async fn find(&self, filter: AuditFilter) -> Result<Vec<AuditEntry>, RepositoryFailure> {
+        let entries = self.entries.read().await;
+        Ok(entries
+            .iter()
+            .rev()
+            .filter(|entry| filter.matches(entry))
+            .skip(filter.offset)
+            .take(filter.limit)
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use audit_service::{
+        actor::with_actor,
+        audit_event_publisher::AuditEventPublisher,
+        get_audit_entries::GetAuditEntriesInput,
+        models::{AuditChange, AuditFilter},
+        service::AuditService,
+    };
+    use auth_service::{
+        change_password::ChangePasswordInput, create_user::CreateUserInput,
+        models::OidcRoleMapping, service::AuthService,
+    };
+    use in_memory_user_repository::InMemoryUserRepository;
+    use in_memory_worksite_repository::InMemoryWorksiteRepository;
+    use worksite_service::{
+        models::{Employment, Worker, Worksite},
+        service::WorksiteService,
+        update_worker::UpdateWorkerInput,
+    };
+
+    use super::InMemoryAuditLogRepository;
+
+    fn setup() -> (AuditService, AuditEventPublisher) {
+        let audit_service = AuditService::new(Arc::new(InMemoryAuditLogRepository::empty()));
+        let publisher = AuditEventPublisher {
+            audit_service: Arc::new(audit_service.clone()),
+        };
+
+        (audit_service, publisher)
+    }
+
+    async fn entries(audit_service: &AuditService, filter: AuditFilter) -> Vec<String> {
+        audit_service
+            .get_audit_entries(GetAuditEntriesInput { filter })
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|entry| format!("{} {}", entry.entity, entry.action))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn records_worksite_changes_by_the_actor() {
+        let (audit_service, publisher) = setup();
+        let worker = Worker::new("Jim".into(), "Halpert".into(), "jim@example.com".into());
+        let worksite = Worksite::new("Scranton".into()).add_worker(worker.clone());
+        let worksite_service = WorksiteService::new(
+            Arc::new(InMemoryWorksiteRepository::with(vec![worksite.clone()])),
+            Arc::new(publisher),
+        );
+
+        with_actor(
+            Some("organizer-1".into()),
+            worksite_service.update_worker(UpdateWorkerInput {
+                worksite_id: worksite.id.clone(),
+                worker_id: worker.id.clone(),
+                first_name: "James".into(),
+                last_name: "Halpert".into(),
+                email: "jim@example.com".into(),
+                phone: "".into(),
+                street_address: "".into(),
+                city: "".into(),
+                region: "".into(),
+                postal_code: "".into(),
+                employment: Employment::default(),
+                custom_values: vec![],
+            }),
+        )
+        .await
+        .unwrap();
+
+        let entry = audit_service
+            .get_audit_entries(GetAuditEntriesInput {
+                filter: AuditFilter::default(),
+            })
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        assert_eq!(entry.actor_id, Some("organizer-1".into()));
+        assert_eq!(entry.worksite_id, Some(worksite.id));
+        assert_eq!(entry.entity, "Worker");
+        assert_eq!(entry.entity_id, worker.id);
+        assert_eq!(entry.action, "Updated");
+        assert!(entry.changes().contains(&AuditChange {
+            field: "first_name".into(),
+            before: Some("Jim".into()),
+            after: Some("James".into()),
+        }));
+    }
+
+    #[tokio::test]
+    async fn records_auth_changes_without_secrets() {
+        let (audit_service, publisher) = setup();
+        let auth_service = AuthService::new(
+            Arc::new(InMemoryUserRepository::empty()),
+            Arc::new(publisher),
+            vec![],
+            None,
+            OidcRoleMapping::default(),
+        );
+
+        let user = auth_service
+            .create_user(CreateUserInput {
+                email: "pam@example.com".into(),
+                password: "password".into(),
+                role: "Organizer".into(),
+            })
+            .await
+            .unwrap();
+        auth_service
+            .change_password(ChangePasswordInput {
+                user_id: user.id.clone(),
+                current_password: "password".into(),
+                new_password: "beesly".into(),
+                new_password_confirmation: "beesly".into(),
+            })
+            .await
+            .unwrap();
+
+        let recorded = audit_service
+            .get_audit_entries(GetAuditEntriesInput {
+                filter: AuditFilter {
+                    actor_id: Some(user.id.clone()),
+                    ..AuditFilter::default()
+                },
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(recorded.len(), 2);
+        assert_eq!(recorded[0].action, "Password changed");
+        assert_eq!(recorded[1].action, "Created");
+        for entry in recorded {
+            let json = serde_json::to_string(&entry.after).unwrap();
+            assert!(!json.contains("hashed_password"));
+            assert!(!json.contains(&user.hashed_password));
+        }
+    }
+
+    #[tokio::test]
+    async fn finds_newest_first_a_page_at_a_time() {
+        let (audit_service, publisher) = setup();
+        let worksite_service = WorksiteService::new(
+            Arc::new(InMemoryWorksiteRepository::empty()),
+            Arc::new(publisher),
+        );
+
+        let worksite = worksite_service
+            .create_worksite(worksite_service::create_worksite::CreateWorksiteInput {
+                worksite_name: "Scranton".into(),
+            })
+            .await
+            .unwrap();
+        for location_name in ["Warehouse", "Annex"] {
+            worksite_service
+                .add_location(worksite_service::add_location::AddLocationInput {
+                    worksite_id: worksite.id.clone(),
+                    location_name: location_name.into(),
+                })
+                .await
+                .unwrap();
+        }
+
+        let all = AuditFilter::default();
+        assert_eq!(
+            entries(&audit_service, all.clone()).await,
+            vec!["Location Added", "Location Added", "Worksite Created"]
+        );
+        assert_eq!(
+            entries(
+                &audit_service,
+                AuditFilter {
+                    offset: 1,
+                    limit: 1,
+                    ..all.clone()
+                }
+            )
+            .await,
+            vec!["Location Added"]
+        );
+        assert_eq!(
+            entries(
+                &audit_service,
+                AuditFilter {
+                    entity: Some("Worksite".into()),
+                    ..all
+                }
+            )
+            .await,
+            vec!["Worksite Created"]
+        );
+    }
+}
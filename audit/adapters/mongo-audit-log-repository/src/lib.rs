@@ -0,0 +1,218 @@
+use async_trait::async_trait;
+use chrono::{serde::ts_milliseconds, DateTime, Utc};
+use futures::stream::TryStreamExt;
+use mongodb::bson::{doc, Document};
+use serde::{Deserialize, Serialize};
+
+use audit_service::models::{AuditEntry, AuditFilter};
+use audit_service::ports::audit_log_repository::{AuditLogRepository, RepositoryFailure};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct AuditEntryRecord {
+    pub id: String,
+    pub actor_id: Option<String>,
+    #[serde(with = "ts_milliseconds")]
+    pub occurred_at: DateTime<Utc>,
+    pub worksite_id: Option<String>,
+    pub entity: String,
+    pub entity_id: String,
+    pub action: String,
+    // Kept as json text so snapshots round trip exactly
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl AuditEntryRecord {
+    pub fn to_audit_entry(&self) -> Result<AuditEntry, RepositoryFailure> {
+        let parse = |value: &Option<String>| {
+            value
+                .as_ref()
+                .map(|value| serde_json::from_str(value))
+                .transpose()
+                .map_err(|e| RepositoryFailure::Unknown(e.to_string()))
+        };
+
+        Ok(AuditEntry {
+            id: self.id.clone(),
+            actor_id: self.actor_id.clone(),
+            occurred_at: self.occurred_at,
+            worksite_id: self.worksite_id.clone(),
+            entity: self.entity.clone(),
+            entity_id: self.entity_id.clone(),
+            action: self.action.clone(),
+            before: parse(&self.before)?,
+            after: parse(&self.after)?,
+        })
+    }
+}
+
+fn to_audit_entry_record(entry: &AuditEntry) -> AuditEntryRecord {
+    AuditEntryRecord {
+        id: entry.id.clone(),
+        actor_id: entry.actor_id.clone(),
+        occurred_at: entry.occurred_at,
+        worksite_id: entry.worksite_id.clone(),
+        entity: entry.entity.clone(),
+        entity_id: entry.entity_id.clone(),
+        action: entry.action.clone(),
+        before: entry.before.as_ref().map(|value| value.to_string()),
+        after: entry.after.as_ref().map(|value| value.to_string()),
+    }
+}
+
+fn to_filter_document(filter: &AuditFilter) -> Document {
+    let mut document = Document::new();
+    let fields = [
+        ("actor_id", &filter.actor_id),
+        ("worksite_id", &filter.worksite_id),
+        ("entity", &filter.entity),
+        ("entity_id", &filter.entity_id),
+        ("action", &filter.action),
+    ];
+    for (field, value) in fields {
+        if let Some(value) = value {
+            document.insert(field, value.clone());
+        }
+    }
+    document
+}
+
+#[derive(Clone, Debug)]
+pub struct MongoAuditLogRepository {
+    collection: mongodb::Collection<AuditEntryRecord>,
+}
+
+impl MongoAuditLogRepository {
+    pub async fn new(url: &String) -> Result<Self, mongodb::error::Error> {
+        let client = mongodb::Client::with_uri_str(url).await?;
+        Self::from_client(&client)
+    }
+    pub fn from_client(client: &mongodb::Client) -> Result<Self, mongodb::error::Error> {
+        let db = client.database("audit");
+        Ok(Self {
+            collection: db.collection::<AuditEntryRecord>("audit_log"),
+        })
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for MongoAuditLogRepository {
+    async fn append(&self, entry: AuditEntry) -> Result<(), RepositoryFailure> {
+        self.collection
+            .insert_one(to_audit_entry_record(&entry), None)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn find(&self, filter: AuditFilter) -> Result<Vec<AuditEntry>, RepositoryFailure> {
+        // Entries recorded in the same millisecond stay in the order they were inserted
+        let options = mongodb::options::FindOptions::builder()
+            .sort(doc! { "occurred_at": -1, "_id": -1 })
+            .skip(filter.offset as u64)
+            .limit(filter.limit as i64)
+            .build();
+        let cursor = self
+            .collection
+            .find(to_filter_document(&filter), options)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        let entries: Vec<AuditEntryRecord> = cursor
+            .try_collect()
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        entries.iter().map(|e| e.to_audit_entry()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use audit_service::models::{AuditEntry, AuditFilter};
+    use audit_service::ports::audit_log_repository::AuditLogRepository;
+    use chrono::{TimeZone, Utc};
+    use mongo_testcontainer::Mongo;
+    use mongodb::Client;
+    use pretty_assertions::assert_eq;
+    use testcontainers::clients;
+
+    use crate::MongoAuditLogRepository;
+
+    fn make_entry(entity_id: &str, action: &str, minute: u32) -> AuditEntry {
+        AuditEntry {
+            actor_id: Some("user-1".into()),
+            occurred_at: Utc.with_ymd_and_hms(2024, 1, 1, 12, minute, 0).unwrap(),
+            worksite_id: Some("worksite-1".into()),
+            ..AuditEntry::new(
+                "Worker",
+                entity_id.into(),
+                action,
+                Some(serde_json::json!({ "first_name": "Jim" })),
+                Some(serde_json::json!({ "first_name": "James" })),
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn tests() {
+        let docker_cli = clients::Cli::default();
+        let container = docker_cli.run(Mongo);
+        let host_port = container.get_host_port_ipv4(27017);
+        let url = format!("mongodb://127.0.0.1:{host_port}/");
+        let mongo_client: Client = Client::with_uri_str(&url).await.unwrap();
+        let repo = MongoAuditLogRepository::from_client(&mongo_client).unwrap();
+
+        test_append_and_find(&repo).await;
+        test_find_filtered_and_paged(&repo).await;
+    }
+
+    async fn test_append_and_find(repo: &MongoAuditLogRepository) {
+        let entry = make_entry("worker-1", "Updated", 0);
+
+        repo.append(entry.clone()).await.unwrap();
+
+        let result = repo
+            .find(AuditFilter {
+                entity_id: Some("worker-1".into()),
+                ..AuditFilter::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(result, vec![entry]);
+    }
+
+    async fn test_find_filtered_and_paged(repo: &MongoAuditLogRepository) {
+        let older = make_entry("worker-2", "Added", 1);
+        let newer = make_entry("worker-2", "Updated", 2);
+        repo.append(older.clone()).await.unwrap();
+        repo.append(newer.clone()).await.unwrap();
+
+        let filter = AuditFilter {
+            entity_id: Some("worker-2".into()),
+            ..AuditFilter::default()
+        };
+        assert_eq!(
+            repo.find(filter.clone()).await.unwrap(),
+            vec![newer.clone(), older.clone()]
+        );
+        assert_eq!(
+            repo.find(AuditFilter {
+                offset: 1,
+                ..filter.clone()
+            })
+            .await
+            .unwrap(),
+            vec![older]
+        );
+        assert_eq!(
+            repo.find(AuditFilter {
+                action: Some("Updated".into()),
+                ..filter
+            })
+            .await
+            .unwrap(),
+            vec![newer]
+        );
+    }
+}
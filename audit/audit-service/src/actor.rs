@@ -0,0 +1,21 @@
+use std::future::Future;
+
+tokio::task_local! {
+    static ACTOR_ID: Option<String>;
+}
+
+/**
+* Runs a request with the user making it as the actor of any changes it records. The web
+* layers wrap their handlers with this, so the services don't need the user passed in.
+*/
+pub async fn with_actor<F: Future>(actor_id: Option<String>, f: F) -> F::Output {
+    ACTOR_ID.scope(actor_id, f).await
+}
+
+// None outside of a request, or when nobody is logged in yet, e.g. while accepting an invite
+pub fn current_actor() -> Option<String> {
+    ACTOR_ID
+        .try_with(|actor_id| actor_id.clone())
+        .ok()
+        .flatten()
+}
@@ -0,0 +1,496 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde_json::{json, Value};
+
+use auth_service::{
+    events::{AuthEvent, DomainEvent as AuthDomainEvent},
+    models::{ApiToken, User},
+    ports::event_publisher::EventPublisher as AuthEventPublisher,
+};
+use worksite_service::{
+    events::{DomainEvent as WorksiteDomainEvent, WorksiteEvent},
+    models::Eligibility,
+    ports::event_publisher::EventPublisher as WorksiteEventPublisher,
+};
+
+use crate::{
+    actor::current_actor, models::AuditEntry, record_audit_entry::RecordAuditEntryInput,
+    service::AuditService,
+};
+
+/**
+* Records the events from the worksite and auth services in the audit log. Plug it into
+* both services alongside any other publishers.
+*/
+#[derive(Clone)]
+pub struct AuditEventPublisher {
+    pub audit_service: Arc<AuditService>,
+}
+
+impl AuditEventPublisher {
+    async fn record(&self, entry: AuditEntry) {
+        // The change is already saved, so a failure here can only be logged
+        if let Err(e) = self
+            .audit_service
+            .record_audit_entry(RecordAuditEntryInput { entry })
+            .await
+        {
+            tracing::error!("Failed to record audit entry: {}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl WorksiteEventPublisher for AuditEventPublisher {
+    async fn publish(&self, event: WorksiteDomainEvent) {
+        let entry = worksite_audit_entry(event.worksite_id.clone(), event.event);
+
+        self.record(AuditEntry {
+            actor_id: current_actor(),
+            occurred_at: event.occurred_at,
+            worksite_id: Some(event.worksite_id),
+            ..entry
+        })
+        .await;
+    }
+}
+
+#[async_trait]
+impl AuthEventPublisher for AuditEventPublisher {
+    async fn publish(&self, event: AuthDomainEvent) {
+        let entry = auth_audit_entry(event.user_id.clone(), event.event);
+
+        self.record(AuditEntry {
+            // Users act on their own account when nobody is logged in yet, e.g. accepting an invite
+            actor_id: current_actor().or(Some(event.user_id)),
+            occurred_at: event.occurred_at,
+            ..entry
+        })
+        .await;
+    }
+}
+
+fn snapshot<T: serde::Serialize>(value: &T) -> Option<Value> {
+    serde_json::to_value(value).ok()
+}
+
+// Worker centric where possible, so a worker's history can be found by their id
+fn worksite_audit_entry(worksite_id: String, event: WorksiteEvent) -> AuditEntry {
+    match event {
+        WorksiteEvent::WorksiteCreated { name } => AuditEntry::new(
+            "Worksite",
+            worksite_id,
+            "Created",
+            None,
+            Some(json!({ "name": name })),
+        ),
+        WorksiteEvent::WorksiteRenamed {
+            previous_name,
+            name,
+        } => AuditEntry::new(
+            "Worksite",
+            worksite_id,
+            "Renamed",
+            Some(json!({ "name": previous_name })),
+            Some(json!({ "name": name })),
+        ),
+        WorksiteEvent::LocationAdded { location } => AuditEntry::new(
+            "Location",
+            location.id.clone(),
+            "Added",
+            None,
+            Some(json!({ "name": location.name })),
+        ),
+        WorksiteEvent::ShiftAdded { location_id, shift } => AuditEntry::new(
+            "Shift",
+            shift.id.clone(),
+            "Added",
+            None,
+            Some(json!({ "location_id": location_id, "name": shift.name })),
+        ),
+        WorksiteEvent::WorkerAdded { worker } => AuditEntry::new(
+            "Worker",
+            worker.id.clone(),
+            "Added",
+            None,
+            snapshot(&worker),
+        ),
+        WorksiteEvent::WorkerUpdated { previous, worker } => AuditEntry::new(
+            "Worker",
+            worker.id.clone(),
+            "Updated",
+            snapshot(&previous),
+            snapshot(&worker),
+        ),
+        WorksiteEvent::WorkersImported { worker_ids } => AuditEntry::new(
+            "Worksite",
+            worksite_id,
+            "Workers imported",
+            None,
+            Some(json!({ "worker_ids": worker_ids })),
+        ),
+        WorksiteEvent::WorkerAssignedToShift {
+            location_id,
+            shift_id,
+            worker_id,
+        } => AuditEntry::new(
+            "Worker",
+            worker_id,
+            "Assigned to shift",
+            None,
+            Some(json!({ "location_id": location_id, "shift_id": shift_id })),
+        ),
+        WorksiteEvent::WorkerRemovedFromShift {
+            shift_id,
+            worker_id,
+        } => AuditEntry::new(
+            "Worker",
+            worker_id,
+            "Removed from shift",
+            Some(json!({ "shift_id": shift_id })),
+            None,
+        ),
+        WorksiteEvent::TagAdded { tag } => {
+            AuditEntry::new("Tag", tag.id.clone(), "Added", None, snapshot(&tag))
+        }
+        WorksiteEvent::TagUpdated { previous, tag } => AuditEntry::new(
+            "Tag",
+            tag.id.clone(),
+            "Updated",
+            snapshot(&previous),
+            snapshot(&tag),
+        ),
+        WorksiteEvent::TagRemoved { tag } => {
+            AuditEntry::new("Tag", tag.id.clone(), "Removed", snapshot(&tag), None)
+        }
+        WorksiteEvent::TagAssigned { worker_id, tag_id } => AuditEntry::new(
+            "Worker",
+            worker_id,
+            "Tag assigned",
+            None,
+            Some(json!({ "tag_id": tag_id })),
+        ),
+        WorksiteEvent::TagUnassigned { worker_id, tag_id } => AuditEntry::new(
+            "Worker",
+            worker_id,
+            "Tag unassigned",
+            Some(json!({ "tag_id": tag_id })),
+            None,
+        ),
+        WorksiteEvent::WorkerAssessed {
+            worker_id,
+            assessment,
+        } => AuditEntry::new("Worker", worker_id, "Assessed", None, snapshot(&assessment)),
+        WorksiteEvent::AssessmentUpdated {
+            worker_id,
+            previous,
+            assessment,
+        } => AuditEntry::new(
+            "Worker",
+            worker_id,
+            "Assessment updated",
+            snapshot(&previous),
+            snapshot(&assessment),
+        ),
+        WorksiteEvent::AssessmentRemoved {
+            worker_id,
+            assessment,
+        } => AuditEntry::new(
+            "Worker",
+            worker_id,
+            "Assessment removed",
+            snapshot(&assessment),
+            None,
+        ),
+        WorksiteEvent::ConversationLogged {
+            worker_id,
+            conversation,
+        } => AuditEntry::new(
+            "Worker",
+            worker_id,
+            "Conversation logged",
+            None,
+            snapshot(&conversation),
+        ),
+        WorksiteEvent::ConversationUpdated {
+            worker_id,
+            previous,
+            conversation,
+        } => AuditEntry::new(
+            "Worker",
+            worker_id,
+            "Conversation updated",
+            snapshot(&previous),
+            snapshot(&conversation),
+        ),
+        WorksiteEvent::ConversationRemoved {
+            worker_id,
+            conversation,
+        } => AuditEntry::new(
+            "Worker",
+            worker_id,
+            "Conversation removed",
+            snapshot(&conversation),
+            None,
+        ),
+        WorksiteEvent::CommitteeMemberAdded { worker_id } => {
+            AuditEntry::new("Worker", worker_id, "Joined committee", None, None)
+        }
+        WorksiteEvent::CommitteeMemberRemoved { worker_id } => {
+            AuditEntry::new("Worker", worker_id, "Left committee", None, None)
+        }
+        WorksiteEvent::LeaderAssigned {
+            worker_id,
+            previous_leader_id,
+            leader_id,
+        } => AuditEntry::new(
+            "Worker",
+            worker_id,
+            "Leader assigned",
+            Some(json!({ "leader_id": previous_leader_id })),
+            Some(json!({ "leader_id": leader_id })),
+        ),
+        WorksiteEvent::SupervisorChanged {
+            worker_id,
+            previous_supervisor_id,
+            supervisor_id,
+        } => AuditEntry::new(
+            "Worker",
+            worker_id,
+            "Supervisor changed",
+            Some(json!({ "supervisor_id": previous_supervisor_id })),
+            Some(json!({ "supervisor_id": supervisor_id })),
+        ),
+        WorksiteEvent::TurfAssigned { assignment } => AuditEntry::new(
+            "Turf",
+            assignment.id.clone(),
+            "Assigned",
+            None,
+            snapshot(&assignment),
+        ),
+        WorksiteEvent::TurfUnassigned { assignment } => AuditEntry::new(
+            "Turf",
+            assignment.id.clone(),
+            "Unassigned",
+            snapshot(&assignment),
+            None,
+        ),
+        WorksiteEvent::ActionAdded { action } => AuditEntry::new(
+            "Action",
+            action.id.clone(),
+            "Added",
+            None,
+            snapshot(&action),
+        ),
+        WorksiteEvent::ActionUpdated { previous, action } => AuditEntry::new(
+            "Action",
+            action.id.clone(),
+            "Updated",
+            snapshot(&previous),
+            snapshot(&action),
+        ),
+        WorksiteEvent::ActionRemoved { action } => AuditEntry::new(
+            "Action",
+            action.id.clone(),
+            "Removed",
+            snapshot(&action),
+            None,
+        ),
+        // Keyed by worker, e.g. {"1": "DidIt", "2": null}
+        WorksiteEvent::ParticipationRecorded { action_id, changes } => AuditEntry::new(
+            "Action",
+            action_id,
+            "Participation recorded",
+            None,
+            Some(Value::Object(
+                changes
+                    .into_iter()
+                    .map(|(worker_id, status)| {
+                        (worker_id, json!(status.map(|status| status.to_string())))
+                    })
+                    .collect(),
+            )),
+        ),
+        WorksiteEvent::AuthorizationCardRecorded {
+            worker_id,
+            previous,
+            card,
+        } => AuditEntry::new(
+            "Worker",
+            worker_id,
+            "Card recorded",
+            previous.as_ref().and_then(snapshot),
+            card.as_ref().and_then(snapshot),
+        ),
+        WorksiteEvent::CardThresholdsUpdated {
+            previous,
+            thresholds,
+        } => AuditEntry::new(
+            "Worksite",
+            worksite_id,
+            "Card thresholds updated",
+            Some(json!({ "thresholds": previous })),
+            Some(json!({ "thresholds": thresholds })),
+        ),
+        WorksiteEvent::EligibilityChanged {
+            worker_id,
+            previous,
+            eligibility,
+        } => AuditEntry::new(
+            "Worker",
+            worker_id,
+            "Eligibility changed",
+            Some(eligibility_snapshot(previous)),
+            Some(eligibility_snapshot(eligibility)),
+        ),
+        WorksiteEvent::CustomFieldAdded { field } => AuditEntry::new(
+            "CustomField",
+            field.id.clone(),
+            "Added",
+            None,
+            snapshot(&field),
+        ),
+        WorksiteEvent::CustomFieldUpdated { previous, field } => AuditEntry::new(
+            "CustomField",
+            field.id.clone(),
+            "Updated",
+            snapshot(&previous),
+            snapshot(&field),
+        ),
+        WorksiteEvent::CustomFieldRemoved { field } => AuditEntry::new(
+            "CustomField",
+            field.id.clone(),
+            "Removed",
+            snapshot(&field),
+            None,
+        ),
+        WorksiteEvent::StatusChanged { worker_id, change } => AuditEntry::new(
+            "Worker",
+            worker_id,
+            "Status changed",
+            Some(json!({ "status": change.previous.to_string() })),
+            Some(json!({
+                "status": change.status.to_string(),
+                "changed_on": change.changed_on,
+                "note": change.note,
+            })),
+        ),
+        WorksiteEvent::RelationshipAdded { relationship } => AuditEntry::new(
+            "Relationship",
+            relationship.id.clone(),
+            "Added",
+            None,
+            snapshot(&relationship),
+        ),
+        WorksiteEvent::RelationshipRemoved { relationship } => AuditEntry::new(
+            "Relationship",
+            relationship.id.clone(),
+            "Removed",
+            snapshot(&relationship),
+            None,
+        ),
+        WorksiteEvent::RosterReconciled {
+            hired,
+            returned,
+            moved,
+            departed,
+        } => AuditEntry::new(
+            "Worksite",
+            worksite_id,
+            "Roster reconciled",
+            None,
+            Some(json!({
+                "hired": hired,
+                "returned": returned,
+                "moved": moved,
+                "departed": departed,
+            })),
+        ),
+        WorksiteEvent::WorkersMerged { duplicate, worker } => AuditEntry::new(
+            "Worker",
+            worker.id.clone(),
+            "Merged",
+            snapshot(&duplicate),
+            snapshot(&worker),
+        ),
+    }
+}
+
+// e.g. {"exclusion_reason": "Supervisor"}, or null for someone in the unit
+fn eligibility_snapshot(eligibility: Eligibility) -> Value {
+    json!({
+        "exclusion_reason": eligibility.exclusion_reason().map(|reason| reason.to_string())
+    })
+}
+
+// Leaves out the password hash, invite token and two-factor secrets
+fn user_snapshot(user: &User) -> Option<Value> {
+    Some(json!({
+        "email": user.email,
+        "role": user.role.to_string(),
+        "display_name": user.display_name,
+        "default_worksite_id": user.default_worksite_id,
+        "invite_pending": user.is_pending_invite(),
+        "two_factor": user.has_two_factor(),
+        "single_sign_on": user.oidc_subject.is_some(),
+    }))
+}
+
+fn api_token_snapshot(api_token: &ApiToken) -> Option<Value> {
+    Some(json!({
+        "name": api_token.name,
+        "created_at": api_token.created_at.to_rfc3339(),
+    }))
+}
+
+fn auth_audit_entry(user_id: String, event: AuthEvent) -> AuditEntry {
+    let user_entry = |action: &str, before: Option<&User>, after: Option<&User>| {
+        AuditEntry::new(
+            "User",
+            user_id.clone(),
+            action,
+            before.and_then(user_snapshot),
+            after.and_then(user_snapshot),
+        )
+    };
+
+    match event {
+        AuthEvent::UserCreated { user } => user_entry("Created", None, Some(&user)),
+        AuthEvent::UserUpdated { previous, user } => {
+            user_entry("Updated", Some(&previous), Some(&user))
+        }
+        AuthEvent::UserDeleted { user } => user_entry("Deleted", Some(&user), None),
+        AuthEvent::UserInvited { user } => user_entry("Invited", None, Some(&user)),
+        AuthEvent::InviteAccepted { user } => user_entry("Invite accepted", None, Some(&user)),
+        AuthEvent::InviteRevoked { user } => user_entry("Invite revoked", Some(&user), None),
+        AuthEvent::UserProvisioned { previous, user } => {
+            user_entry("Single sign-on", previous.as_ref(), Some(&user))
+        }
+        AuthEvent::ProfileUpdated { previous, user } => {
+            user_entry("Profile updated", Some(&previous), Some(&user))
+        }
+        AuthEvent::PasswordChanged => user_entry("Password changed", None, None),
+        AuthEvent::UserUnlocked => user_entry("Unlocked", None, None),
+        AuthEvent::TwoFactorEnrollmentStarted => {
+            user_entry("Two-factor enrollment started", None, None)
+        }
+        AuthEvent::TwoFactorEnabled => user_entry("Two-factor enabled", None, None),
+        AuthEvent::TwoFactorDisabled => user_entry("Two-factor disabled", None, None),
+        AuthEvent::BackupCodeUsed => user_entry("Backup code used", None, None),
+        AuthEvent::ApiTokenCreated { api_token } => AuditEntry::new(
+            "ApiToken",
+            api_token.id.clone(),
+            "Created",
+            None,
+            api_token_snapshot(&api_token),
+        ),
+        AuthEvent::ApiTokenRevoked { api_token } => AuditEntry::new(
+            "ApiToken",
+            api_token.id.clone(),
+            "Revoked",
+            api_token_snapshot(&api_token),
+            None,
+        ),
+    }
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    models::{AuditEntry, AuditFilter},
+    ports::audit_log_repository::AuditLogRepository,
+};
+
+#[derive(Clone)]
+pub struct GetAuditEntries {
+    pub audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetAuditEntriesInput {
+    pub filter: AuditFilter,
+}
+
+// Newest first
+pub type GetAuditEntriesOutput = Result<Vec<AuditEntry>, GetAuditEntriesFailure>;
+
+impl GetAuditEntries {
+    pub async fn get_audit_entries(&self, input: GetAuditEntriesInput) -> GetAuditEntriesOutput {
+        self.audit_log_repository
+            .find(input.filter)
+            .await
+            .map_err(|e| GetAuditEntriesFailure::Unknown(e.to_string()))
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetAuditEntriesFailure {
+    #[error("Something went wrong")]
+    Unknown(String),
+}
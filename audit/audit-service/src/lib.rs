@@ -0,0 +1,7 @@
+pub mod actor;
+pub mod audit_event_publisher;
+pub mod get_audit_entries;
+pub mod models;
+pub mod ports;
+pub mod record_audit_entry;
+pub mod service;
@@ -0,0 +1,137 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+// What the entries are about, used to filter the log
+pub const AUDITED_ENTITIES: [&str; 11] = [
+    "Worksite",
+    "Location",
+    "Shift",
+    "Worker",
+    "Relationship",
+    "Tag",
+    "CustomField",
+    "Turf",
+    "Action",
+    "User",
+    "ApiToken",
+];
+
+/**
+* One recorded change. Before and after hold the entity as json, with anything sensitive
+* like password hashes left out, and are None when the entity was created or removed.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEntry {
+    pub id: String,
+    // None for changes nobody was logged in for, e.g. the app creating the default worksite
+    pub actor_id: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+    // None for changes to users and their tokens
+    pub worksite_id: Option<String>,
+    pub entity: String,
+    pub entity_id: String,
+    // e.g. "Updated" or "Assigned to shift"
+    pub action: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+impl AuditEntry {
+    pub fn new(
+        entity: &str,
+        entity_id: String,
+        action: &str,
+        before: Option<Value>,
+        after: Option<Value>,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            actor_id: None,
+            occurred_at: Utc::now(),
+            worksite_id: None,
+            entity: entity.into(),
+            entity_id,
+            action: action.into(),
+            before,
+            after,
+        }
+    }
+
+    // Only the fields that differ between before and after, or everything for creates and removals
+    pub fn changes(&self) -> Vec<AuditChange> {
+        let fields = |value: &Option<Value>| match value {
+            Some(Value::Object(fields)) => fields.clone(),
+            _ => serde_json::Map::new(),
+        };
+        let (before, after) = (fields(&self.before), fields(&self.after));
+
+        let mut names: Vec<&String> = before.keys().chain(after.keys()).collect();
+        names.sort();
+        names.dedup();
+
+        names
+            .into_iter()
+            .filter(|name| before.get(*name) != after.get(*name))
+            .map(|name| AuditChange {
+                field: name.clone(),
+                before: before.get(name).map(display_value),
+                after: after.get(name).map(display_value),
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditChange {
+    pub field: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        Value::Null => "".into(),
+        value => value.to_string(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditFilter {
+    pub actor_id: Option<String>,
+    pub worksite_id: Option<String>,
+    pub entity: Option<String>,
+    pub entity_id: Option<String>,
+    pub action: Option<String>,
+    pub offset: usize,
+    pub limit: usize,
+}
+
+impl Default for AuditFilter {
+    fn default() -> Self {
+        Self {
+            actor_id: None,
+            worksite_id: None,
+            entity: None,
+            entity_id: None,
+            action: None,
+            offset: 0,
+            limit: 50,
+        }
+    }
+}
+
+impl AuditFilter {
+    pub fn matches(&self, entry: &AuditEntry) -> bool {
+        let matches = |filter: &Option<String>, value: Option<&String>| match filter {
+            Some(filter) => value == Some(filter),
+            None => true,
+        };
+
+        matches(&self.actor_id, entry.actor_id.as_ref())
+            && matches(&self.worksite_id, entry.worksite_id.as_ref())
+            && matches(&self.entity, Some(&entry.entity))
+            && matches(&self.entity_id, Some(&entry.entity_id))
+            && matches(&self.action, Some(&entry.action))
+    }
+}
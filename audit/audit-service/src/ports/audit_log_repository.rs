@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::models::{AuditEntry, AuditFilter};
+
+// Append only, entries are never changed or removed once recorded
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync + 'static {
+    async fn append(&self, entry: AuditEntry) -> Result<(), RepositoryFailure>;
+    // Newest first
+    async fn find(&self, filter: AuditFilter) -> Result<Vec<AuditEntry>, RepositoryFailure>;
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RepositoryFailure {
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{models::AuditEntry, ports::audit_log_repository::AuditLogRepository};
+
+#[derive(Clone)]
+pub struct RecordAuditEntry {
+    pub audit_log_repository: Arc<dyn AuditLogRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RecordAuditEntryInput {
+    pub entry: AuditEntry,
+}
+
+pub type RecordAuditEntryOutput = Result<(), RecordAuditEntryFailure>;
+
+impl RecordAuditEntry {
+    pub async fn record_audit_entry(&self, input: RecordAuditEntryInput) -> RecordAuditEntryOutput {
+        self.audit_log_repository
+            .append(input.entry)
+            .await
+            .map_err(|e| RecordAuditEntryFailure::Unknown(e.to_string()))
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RecordAuditEntryFailure {
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use crate::{
+    get_audit_entries::{GetAuditEntries, GetAuditEntriesInput, GetAuditEntriesOutput},
+    ports::audit_log_repository::AuditLogRepository,
+    record_audit_entry::{RecordAuditEntry, RecordAuditEntryInput, RecordAuditEntryOutput},
+};
+
+#[derive(Clone)]
+pub struct AuditService {
+    pub record_audit_entry: RecordAuditEntry,
+    pub get_audit_entries: GetAuditEntries,
+}
+
+impl AuditService {
+    pub fn new(audit_log_repository: Arc<dyn AuditLogRepository>) -> Self {
+        Self {
+            record_audit_entry: RecordAuditEntry {
+                audit_log_repository: audit_log_repository.clone(),
+            },
+            get_audit_entries: GetAuditEntries {
+                audit_log_repository,
+            },
+        }
+    }
+
+    pub async fn record_audit_entry(&self, input: RecordAuditEntryInput) -> RecordAuditEntryOutput {
+        self.record_audit_entry.record_audit_entry(input).await
+    }
+
+    pub async fn get_audit_entries(&self, input: GetAuditEntriesInput) -> GetAuditEntriesOutput {
+        self.get_audit_entries.get_audit_entries(input).await
+    }
+}
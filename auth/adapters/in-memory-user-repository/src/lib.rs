@@ -1,26 +1,31 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use auth_service::models::User;
+use auth_service::models::{ApiToken, LockoutEvent, LoginAttempts, LoginThrottlePolicy, User};
 use auth_service::ports::user_repository::{RepositoryFailure, UserRepository};
 use axum_login::{AuthnBackend, UserId};
+use chrono::{DateTime, Utc};
 use tokio::sync::RwLock;
 
 #[derive(Clone, Debug)]
 pub struct InMemoryUserRepository {
     pub users: Arc<RwLock<Vec<User>>>,
+    pub login_attempts: Arc<RwLock<Vec<LoginAttempts>>>,
+    pub lockout_events: Arc<RwLock<Vec<LockoutEvent>>>,
+    pub api_tokens: Arc<RwLock<Vec<ApiToken>>>,
 }
 
 impl InMemoryUserRepository {
     pub fn empty() -> Self {
-        Self {
-            users: Arc::new(RwLock::new(vec![])),
-        }
+        Self::with(vec![])
     }
 
     pub fn with(users: Vec<User>) -> Self {
         Self {
             users: Arc::new(RwLock::new(users)),
+            login_attempts: Arc::new(RwLock::new(vec![])),
+            lockout_events: Arc::new(RwLock::new(vec![])),
+            api_tokens: Arc::new(RwLock::new(vec![])),
         }
     }
 }
@@ -45,6 +50,28 @@ impl UserRepository for InMemoryUserRepository {
             .map(|u| u.to_owned()))
     }
 
+    async fn find_by_invite_token(
+        &self,
+        invite_token: String,
+    ) -> Result<Option<User>, RepositoryFailure> {
+        let users = self.users.read().await;
+        Ok(users
+            .iter()
+            .find(|u| u.invite_token.as_ref() == Some(&invite_token))
+            .map(|u| u.to_owned()))
+    }
+
+    async fn find_by_oidc_subject(
+        &self,
+        oidc_subject: String,
+    ) -> Result<Option<User>, RepositoryFailure> {
+        let users = self.users.read().await;
+        Ok(users
+            .iter()
+            .find(|u| u.oidc_subject.as_ref() == Some(&oidc_subject))
+            .map(|u| u.to_owned()))
+    }
+
     async fn save(&self, user: User) -> Result<(), RepositoryFailure> {
         let mut users = self.users.write().await;
 
@@ -61,6 +88,113 @@ impl UserRepository for InMemoryUserRepository {
 
         Ok(())
     }
+
+    async fn find_login_attempts(
+        &self,
+        key: String,
+    ) -> Result<Option<LoginAttempts>, RepositoryFailure> {
+        let login_attempts = self.login_attempts.read().await;
+        Ok(login_attempts
+            .iter()
+            .find(|a| a.key == key)
+            .map(|a| a.to_owned()))
+    }
+
+    async fn get_login_attempts(&self) -> Result<Vec<LoginAttempts>, RepositoryFailure> {
+        let login_attempts = self.login_attempts.read().await;
+        Ok(login_attempts.to_vec())
+    }
+
+    async fn save_login_attempts(&self, attempts: LoginAttempts) -> Result<(), RepositoryFailure> {
+        let mut login_attempts = self.login_attempts.write().await;
+
+        login_attempts.retain(|a| a.key != attempts.key);
+        login_attempts.push(attempts);
+
+        Ok(())
+    }
+
+    async fn record_login_failure(
+        &self,
+        key: String,
+        max_failures: u32,
+        policy: &LoginThrottlePolicy,
+        now: DateTime<Utc>,
+    ) -> Result<LoginAttempts, RepositoryFailure> {
+        let mut login_attempts = self.login_attempts.write().await;
+
+        let attempts = login_attempts
+            .iter()
+            .find(|a| a.key == key)
+            .cloned()
+            .unwrap_or(LoginAttempts::new(key.clone()))
+            .record_failure(max_failures, policy, now);
+        login_attempts.retain(|a| a.key != key);
+        login_attempts.push(attempts.clone());
+
+        Ok(attempts)
+    }
+
+    async fn delete_login_attempts(&self, key: String) -> Result<(), RepositoryFailure> {
+        let mut login_attempts = self.login_attempts.write().await;
+
+        login_attempts.retain(|a| a.key != key);
+
+        Ok(())
+    }
+
+    async fn save_lockout_event(&self, event: LockoutEvent) -> Result<(), RepositoryFailure> {
+        let mut lockout_events = self.lockout_events.write().await;
+
+        lockout_events.push(event);
+
+        Ok(())
+    }
+
+    async fn get_lockout_events(&self) -> Result<Vec<LockoutEvent>, RepositoryFailure> {
+        let lockout_events = self.lockout_events.read().await;
+        Ok(lockout_events.to_vec())
+    }
+
+    async fn save_api_token(&self, api_token: ApiToken) -> Result<(), RepositoryFailure> {
+        let mut api_tokens = self.api_tokens.write().await;
+
+        api_tokens.retain(|t| t.id != api_token.id);
+        api_tokens.push(api_token);
+
+        Ok(())
+    }
+
+    async fn find_api_token_by_hash(
+        &self,
+        hashed_token: String,
+    ) -> Result<Option<ApiToken>, RepositoryFailure> {
+        let api_tokens = self.api_tokens.read().await;
+        Ok(api_tokens
+            .iter()
+            .find(|t| t.hashed_token == hashed_token)
+            .map(|t| t.to_owned()))
+    }
+
+    async fn get_api_tokens_for_user(
+        &self,
+        user_id: String,
+    ) -> Result<Vec<ApiToken>, RepositoryFailure> {
+        let api_tokens = self.api_tokens.read().await;
+        Ok(api_tokens
+            .iter()
+            .filter(|t| t.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_api_token(&self, id: String) -> Result<(), RepositoryFailure> {
+        let mut api_tokens = self.api_tokens.write().await;
+
+        api_tokens.retain(|t| t.id != id);
+
+        Ok(())
+    }
 }
 
 #[derive(Clone)]
@@ -95,3 +229,656 @@ impl AuthnBackend for InMemoryUserStore {
 }
 
 // pub type AuthContext = axum_login::extractors::AuthContext<String, User, InMemoryUserStore>;
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use auth_service::{
+        begin_two_factor_enrollment::BeginTwoFactorEnrollmentInput,
+        change_password::{ChangePasswordFailure, ChangePasswordInput},
+        complete_oidc_login::{CompleteOidcLoginFailure, CompleteOidcLoginInput},
+        confirm_two_factor_enrollment::ConfirmTwoFactorEnrollmentInput,
+        create_api_token::CreateApiTokenInput,
+        create_user::CreateUserInput,
+        disable_two_factor::{DisableTwoFactorFailure, DisableTwoFactorInput},
+        get_two_factor_status::GetTwoFactorStatusInput,
+        get_user_for_api_token::{GetUserForApiTokenFailure, GetUserForApiTokenInput},
+        get_user_for_login::{GetUserForLoginFailure, GetUserForLoginInput},
+        models::{
+            LoginAttempts, LoginThrottlePolicy, OidcAuthorization, OidcIdentity, OidcPendingLogin,
+            OidcRoleMapping, TwoFactorStatus, User, UserRole,
+        },
+        ports::{
+            event_publisher::EventPublishers,
+            identity_provider::{IdentityProvider, IdentityProviderFailure},
+            user_repository::UserRepository,
+        },
+        revoke_api_token::RevokeApiTokenInput,
+        service::AuthService,
+        unlock_user::UnlockUserInput,
+        update_profile::UpdateProfileInput,
+        verify_two_factor::{VerifyTwoFactorFailure, VerifyTwoFactorInput},
+    };
+    use chrono::Duration;
+    use totp_rs::TOTP;
+
+    use super::InMemoryUserRepository;
+
+    const EMAIL: &str = "user@yallchart.com";
+    const PASSWORD: &str = "password";
+
+    // Backoff is turned off unless a test is about backoff, so failures can be made back to back
+    async fn setup(policy: LoginThrottlePolicy) -> (AuthService, InMemoryUserRepository, User) {
+        setup_with_required_roles(policy, vec![]).await
+    }
+
+    async fn setup_with_required_roles(
+        policy: LoginThrottlePolicy,
+        two_factor_required_roles: Vec<UserRole>,
+    ) -> (AuthService, InMemoryUserRepository, User) {
+        let repo = InMemoryUserRepository::empty();
+        let mut service = AuthService::new(
+            Arc::new(repo.clone()),
+            Arc::new(EventPublishers(vec![])),
+            two_factor_required_roles,
+            None,
+            OidcRoleMapping::default(),
+        );
+        service.get_user_for_login.throttle_policy = policy.clone();
+        service.verify_two_factor.throttle_policy = policy;
+
+        let user = service
+            .create_user(CreateUserInput {
+                email: EMAIL.into(),
+                password: PASSWORD.into(),
+                role: "Organizer".into(),
+            })
+            .await
+            .unwrap();
+
+        (service, repo, user)
+    }
+
+    fn no_backoff() -> LoginThrottlePolicy {
+        LoginThrottlePolicy {
+            base_backoff: Duration::zero(),
+            ..LoginThrottlePolicy::default()
+        }
+    }
+
+    fn login(email: &str, password: &str, client_ip: Option<&str>) -> GetUserForLoginInput {
+        GetUserForLoginInput {
+            email: email.into(),
+            password: password.into(),
+            client_ip: client_ip.map(|ip| ip.into()),
+        }
+    }
+
+    #[tokio::test]
+    async fn locks_account_after_max_failures() {
+        let (service, repo, _) = setup(no_backoff()).await;
+
+        for _ in 0..5 {
+            let result = service.get_user_for_login(login(EMAIL, "nope", None)).await;
+            assert_eq!(result, Err(GetUserForLoginFailure::WrongPassword));
+        }
+
+        let result = service
+            .get_user_for_login(login(EMAIL, PASSWORD, None))
+            .await;
+        assert!(matches!(
+            result,
+            Err(GetUserForLoginFailure::AccountLocked(_))
+        ));
+
+        let events = repo.get_lockout_events().await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].key, LoginAttempts::account_key(EMAIL));
+        assert_eq!(events[0].failures, 5);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn counts_every_concurrent_failure() {
+        let (service, repo, _) = setup(no_backoff()).await;
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let service = service.clone();
+                tokio::spawn(
+                    async move { service.get_user_for_login(login(EMAIL, "nope", None)).await },
+                )
+            })
+            .collect();
+        for handle in handles {
+            let result = handle.await.unwrap();
+            assert_eq!(result, Err(GetUserForLoginFailure::WrongPassword));
+        }
+
+        let attempts = repo
+            .find_login_attempts(LoginAttempts::account_key(EMAIL))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(attempts.failures, 4);
+    }
+
+    #[tokio::test]
+    async fn backs_off_after_a_failure() {
+        let (service, _, _) = setup(LoginThrottlePolicy::default()).await;
+
+        let result = service.get_user_for_login(login(EMAIL, "nope", None)).await;
+        assert_eq!(result, Err(GetUserForLoginFailure::WrongPassword));
+
+        let result = service
+            .get_user_for_login(login(EMAIL, PASSWORD, None))
+            .await;
+        assert!(matches!(
+            result,
+            Err(GetUserForLoginFailure::TooManyAttempts(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn successful_login_clears_account_failures() {
+        let (service, repo, user) = setup(no_backoff()).await;
+
+        for _ in 0..3 {
+            let _ = service.get_user_for_login(login(EMAIL, "nope", None)).await;
+        }
+
+        let result = service
+            .get_user_for_login(login(EMAIL, PASSWORD, None))
+            .await;
+        assert_eq!(result, Ok(user));
+
+        let attempts = repo
+            .find_login_attempts(LoginAttempts::account_key(EMAIL))
+            .await
+            .unwrap();
+        assert_eq!(attempts, None);
+    }
+
+    #[tokio::test]
+    async fn locks_client_ip_across_accounts() {
+        let (service, _, user) = setup(LoginThrottlePolicy {
+            max_ip_failures: 3,
+            ..no_backoff()
+        })
+        .await;
+
+        for i in 0..3 {
+            let email = format!("guess{}@yallchart.com", i);
+            let result = service
+                .get_user_for_login(login(&email, "nope", Some("10.0.0.1")))
+                .await;
+            assert_eq!(result, Err(GetUserForLoginFailure::UserNotFound));
+        }
+
+        let result = service
+            .get_user_for_login(login(EMAIL, PASSWORD, Some("10.0.0.1")))
+            .await;
+        assert!(matches!(
+            result,
+            Err(GetUserForLoginFailure::AccountLocked(_))
+        ));
+
+        let result = service
+            .get_user_for_login(login(EMAIL, PASSWORD, Some("10.0.0.2")))
+            .await;
+        assert_eq!(result, Ok(user));
+    }
+
+    #[tokio::test]
+    async fn unlock_user_clears_lockout() {
+        let (service, _, user) = setup(no_backoff()).await;
+
+        for _ in 0..5 {
+            let _ = service.get_user_for_login(login(EMAIL, "nope", None)).await;
+        }
+
+        let locked = service.get_locked_accounts().await.unwrap();
+        assert_eq!(locked.len(), 1);
+        assert_eq!(locked[0].key, LoginAttempts::account_key(EMAIL));
+
+        service
+            .unlock_user(UnlockUserInput {
+                user_id: user.id.clone(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(service.get_locked_accounts().await.unwrap(), vec![]);
+
+        let result = service
+            .get_user_for_login(login(EMAIL, PASSWORD, None))
+            .await;
+        assert_eq!(result, Ok(user));
+    }
+
+    async fn enroll(service: &AuthService, user: &User) -> (TOTP, Vec<String>) {
+        let enrollment = service
+            .begin_two_factor_enrollment(BeginTwoFactorEnrollmentInput {
+                user_id: user.id.clone(),
+            })
+            .await
+            .unwrap();
+        let totp = TOTP::from_url(enrollment.otpauth_url).unwrap();
+
+        let backup_codes = service
+            .confirm_two_factor_enrollment(ConfirmTwoFactorEnrollmentInput {
+                user_id: user.id.clone(),
+                code: totp.generate_current().unwrap(),
+            })
+            .await
+            .unwrap();
+
+        (totp, backup_codes)
+    }
+
+    fn verify(user: &User, code: &str) -> VerifyTwoFactorInput {
+        VerifyTwoFactorInput {
+            user_id: user.id.clone(),
+            code: code.into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn enrolls_and_verifies_two_factor() {
+        let (service, _, user) = setup(no_backoff()).await;
+
+        let (totp, backup_codes) = enroll(&service, &user).await;
+        assert_eq!(backup_codes.len(), 10);
+
+        let status = service
+            .get_two_factor_status(GetTwoFactorStatusInput {
+                user_id: user.id.clone(),
+            })
+            .await;
+        assert_eq!(status, Ok(TwoFactorStatus::Enabled));
+
+        let result = service.verify_two_factor(verify(&user, "000000")).await;
+        assert_eq!(result, Err(VerifyTwoFactorFailure::InvalidCode));
+
+        let result = service
+            .verify_two_factor(verify(&user, &totp.generate_current().unwrap()))
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn backup_codes_only_work_once() {
+        let (service, _, user) = setup(no_backoff()).await;
+
+        let (_, backup_codes) = enroll(&service, &user).await;
+
+        let result = service
+            .verify_two_factor(verify(&user, &backup_codes[0]))
+            .await;
+        assert!(result.is_ok());
+
+        let result = service
+            .verify_two_factor(verify(&user, &backup_codes[0]))
+            .await;
+        assert_eq!(result, Err(VerifyTwoFactorFailure::InvalidCode));
+    }
+
+    #[tokio::test]
+    async fn wrong_two_factor_codes_lock_the_account() {
+        let (service, _, user) = setup(no_backoff()).await;
+
+        enroll(&service, &user).await;
+
+        for _ in 0..5 {
+            let _ = service.verify_two_factor(verify(&user, "000000")).await;
+        }
+
+        // Getting the password right again does not reset the two-factor failures
+        let result = service
+            .get_user_for_login(login(EMAIL, PASSWORD, None))
+            .await;
+        assert!(matches!(
+            result,
+            Err(GetUserForLoginFailure::AccountLocked(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn two_factor_can_be_required_by_role() {
+        let (service, _, user) =
+            setup_with_required_roles(no_backoff(), vec![UserRole::Organizer]).await;
+
+        let status = service
+            .get_two_factor_status(GetTwoFactorStatusInput {
+                user_id: user.id.clone(),
+            })
+            .await;
+        assert_eq!(status, Ok(TwoFactorStatus::Required));
+
+        enroll(&service, &user).await;
+
+        let result = service
+            .disable_two_factor(DisableTwoFactorInput {
+                user_id: user.id.clone(),
+                current_password: PASSWORD.into(),
+            })
+            .await;
+        assert_eq!(
+            result,
+            Err(DisableTwoFactorFailure::RequiredForRole("Organizer".into()))
+        );
+    }
+
+    // Stands in for the identity provider, signing in whoever it was built with
+    struct FakeIdentityProvider {
+        identity: OidcIdentity,
+    }
+
+    #[async_trait]
+    impl IdentityProvider for FakeIdentityProvider {
+        fn authorize(&self) -> OidcAuthorization {
+            OidcAuthorization {
+                url: "https://idp.example.com/authorize".into(),
+                pending: pending_login(),
+            }
+        }
+
+        async fn exchange_code(
+            &self,
+            code: String,
+            _pending: OidcPendingLogin,
+        ) -> Result<OidcIdentity, IdentityProviderFailure> {
+            match code.as_str() {
+                "good-code" => Ok(self.identity.clone()),
+                _ => Err(IdentityProviderFailure::InvalidCode(code)),
+            }
+        }
+    }
+
+    fn pending_login() -> OidcPendingLogin {
+        OidcPendingLogin {
+            csrf_state: "state".into(),
+            nonce: "nonce".into(),
+            pkce_verifier: "verifier".into(),
+        }
+    }
+
+    fn oidc_identity(email: &str, email_verified: bool, roles: Vec<&str>) -> OidcIdentity {
+        OidcIdentity {
+            subject: "subject-1".into(),
+            email: email.into(),
+            email_verified,
+            name: Some("Rosa Parks".into()),
+            roles: roles.into_iter().map(|r| r.into()).collect(),
+        }
+    }
+
+    async fn setup_oidc(identity: OidcIdentity) -> (AuthService, InMemoryUserRepository, User) {
+        let (mut service, repo, user) = setup(no_backoff()).await;
+        service.complete_oidc_login.identity_provider =
+            Some(Arc::new(FakeIdentityProvider { identity }));
+        service.complete_oidc_login.role_mapping = OidcRoleMapping {
+            default_role: UserRole::Organizer,
+            claim_roles: vec![
+                ("local-board".into(), UserRole::SuperAdmin),
+                ("stewards".into(), UserRole::Admin),
+            ],
+        };
+
+        (service, repo, user)
+    }
+
+    fn callback(code: &str, state: &str) -> CompleteOidcLoginInput {
+        CompleteOidcLoginInput {
+            code: code.into(),
+            state: state.into(),
+            pending: pending_login(),
+        }
+    }
+
+    #[tokio::test]
+    async fn provisions_new_oidc_users_with_mapped_role() {
+        let (service, repo, _) = setup_oidc(oidc_identity(
+            "rosa@local100.org",
+            true,
+            vec!["members", "stewards", "local-board"],
+        ))
+        .await;
+
+        let user = service
+            .complete_oidc_login(callback("good-code", "state"))
+            .await
+            .unwrap();
+        assert_eq!(user.email, "rosa@local100.org");
+        assert_eq!(user.role, UserRole::SuperAdmin);
+        assert_eq!(user.display_name, Some("Rosa Parks".into()));
+
+        // Signing in again finds the same user instead of provisioning another
+        let again = service
+            .complete_oidc_login(callback("good-code", "state"))
+            .await
+            .unwrap();
+        assert_eq!(again.id, user.id);
+        assert_eq!(repo.get_users().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn provisions_unmapped_oidc_users_with_default_role() {
+        let (service, _, _) =
+            setup_oidc(oidc_identity("rosa@local100.org", true, vec!["members"])).await;
+
+        let user = service
+            .complete_oidc_login(callback("good-code", "state"))
+            .await
+            .unwrap();
+        assert_eq!(user.role, UserRole::Organizer);
+    }
+
+    #[tokio::test]
+    async fn rejects_oidc_callback_with_wrong_state() {
+        let (service, _, _) = setup_oidc(oidc_identity("rosa@local100.org", true, vec![])).await;
+
+        let result = service
+            .complete_oidc_login(callback("good-code", "forged"))
+            .await;
+        assert_eq!(result, Err(CompleteOidcLoginFailure::StateMismatch));
+
+        let result = service
+            .complete_oidc_login(callback("bad-code", "state"))
+            .await;
+        assert!(matches!(
+            result,
+            Err(CompleteOidcLoginFailure::IdentityProvider(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn links_existing_user_only_with_verified_email() {
+        let (service, _, _) = setup_oidc(oidc_identity(EMAIL, false, vec![])).await;
+        let result = service
+            .complete_oidc_login(callback("good-code", "state"))
+            .await;
+        assert_eq!(
+            result,
+            Err(CompleteOidcLoginFailure::EmailInUse(EMAIL.into()))
+        );
+
+        let (service, repo, user) = setup_oidc(oidc_identity(EMAIL, true, vec![])).await;
+        let linked = service
+            .complete_oidc_login(callback("good-code", "state"))
+            .await
+            .unwrap();
+        assert_eq!(linked.id, user.id);
+        assert_eq!(linked.oidc_subject, Some("subject-1".into()));
+        assert_eq!(repo.get_users().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn api_tokens_authenticate_until_revoked() {
+        let (service, repo, user) = setup(no_backoff()).await;
+
+        let created = service
+            .create_api_token(CreateApiTokenInput {
+                user_id: user.id.clone(),
+                name: "Roster sync".into(),
+            })
+            .await
+            .unwrap();
+
+        // Only the hash is kept
+        let stored = repo.get_api_tokens_for_user(user.id.clone()).await.unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_ne!(stored[0].hashed_token, created.token);
+
+        let result = service
+            .get_user_for_api_token(GetUserForApiTokenInput {
+                token: created.token.clone(),
+            })
+            .await;
+        assert_eq!(result, Ok(user.clone()));
+
+        let stored = repo.get_api_tokens_for_user(user.id.clone()).await.unwrap();
+        assert!(stored[0].last_used_at.is_some());
+
+        service
+            .revoke_api_token(RevokeApiTokenInput {
+                user_id: user.id.clone(),
+                api_token_id: created.api_token.id.clone(),
+            })
+            .await
+            .unwrap();
+
+        let result = service
+            .get_user_for_api_token(GetUserForApiTokenInput {
+                token: created.token,
+            })
+            .await;
+        assert_eq!(result, Err(GetUserForApiTokenFailure::InvalidToken));
+    }
+
+    #[tokio::test]
+    async fn api_tokens_can_only_be_revoked_by_their_owner() {
+        let (service, _, user) = setup(no_backoff()).await;
+
+        let created = service
+            .create_api_token(CreateApiTokenInput {
+                user_id: user.id.clone(),
+                name: "Roster sync".into(),
+            })
+            .await
+            .unwrap();
+
+        let result = service
+            .revoke_api_token(RevokeApiTokenInput {
+                user_id: "someone-else".into(),
+                api_token_id: created.api_token.id,
+            })
+            .await;
+        assert!(result.is_err());
+
+        let result = service
+            .get_user_for_api_token(GetUserForApiTokenInput {
+                token: created.token,
+            })
+            .await;
+        assert_eq!(result, Ok(user));
+    }
+
+    fn change_password(current: &str, new: &str, confirmation: &str) -> ChangePasswordInput {
+        ChangePasswordInput {
+            user_id: String::new(),
+            current_password: current.into(),
+            new_password: new.into(),
+            new_password_confirmation: confirmation.into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn changes_password_only_with_the_current_password() {
+        let (service, _, user) = setup(no_backoff()).await;
+
+        let result = service
+            .change_password(ChangePasswordInput {
+                user_id: user.id.clone(),
+                ..change_password("nope", "beesly", "beesly")
+            })
+            .await;
+        assert_eq!(result, Err(ChangePasswordFailure::WrongPassword));
+
+        service
+            .change_password(ChangePasswordInput {
+                user_id: user.id.clone(),
+                ..change_password(PASSWORD, "beesly", "beesly")
+            })
+            .await
+            .unwrap();
+
+        let result = service
+            .get_user_for_login(login(EMAIL, PASSWORD, None))
+            .await;
+        assert_eq!(result, Err(GetUserForLoginFailure::WrongPassword));
+        let result = service
+            .get_user_for_login(login(EMAIL, "beesly", None))
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn new_password_must_be_given_and_confirmed() {
+        let (service, _, user) = setup(no_backoff()).await;
+
+        let result = service
+            .change_password(ChangePasswordInput {
+                user_id: user.id.clone(),
+                ..change_password(PASSWORD, "", "")
+            })
+            .await;
+        assert_eq!(result, Err(ChangePasswordFailure::EmptyPassword));
+
+        let result = service
+            .change_password(ChangePasswordInput {
+                user_id: user.id.clone(),
+                ..change_password(PASSWORD, "beesly", "beasly")
+            })
+            .await;
+        assert_eq!(result, Err(ChangePasswordFailure::PasswordMismatch));
+
+        // Neither attempt changed anything
+        let result = service
+            .get_user_for_login(login(EMAIL, PASSWORD, None))
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn blank_profile_fields_clear_the_value() {
+        let (service, repo, user) = setup(no_backoff()).await;
+
+        service
+            .update_profile(UpdateProfileInput {
+                user_id: user.id.clone(),
+                display_name: Some("Pam".into()),
+                default_worksite_id: Some("scranton".into()),
+            })
+            .await
+            .unwrap();
+
+        let saved = repo.find_by_id(user.id.clone()).await.unwrap().unwrap();
+        assert_eq!(saved.display_name, Some("Pam".into()));
+        assert_eq!(saved.default_worksite_id, Some("scranton".into()));
+
+        service
+            .update_profile(UpdateProfileInput {
+                user_id: user.id.clone(),
+                display_name: Some("  ".into()),
+                default_worksite_id: Some("".into()),
+            })
+            .await
+            .unwrap();
+
+        let saved = repo.find_by_id(user.id).await.unwrap().unwrap();
+        assert_eq!(saved.display_name, None);
+        assert_eq!(saved.default_worksite_id, None);
+    }
+}
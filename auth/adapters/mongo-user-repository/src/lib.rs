@@ -1,11 +1,21 @@
 use async_trait::async_trait;
 use axum_login::{AuthnBackend, AuthzBackend, UserId};
+use chrono::{
+    serde::{ts_seconds, ts_seconds_option},
+    DateTime, Utc,
+};
 use futures::stream::TryStreamExt;
 use mongodb::bson::doc;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
-use auth_service::models::{User, UserPermission, UserRole};
+use auth_service::complete_oidc_login::{
+    CompleteOidcLogin, CompleteOidcLoginFailure, CompleteOidcLoginInput,
+};
+use auth_service::models::{
+    ApiToken, LockoutEvent, LoginAttempts, LoginThrottlePolicy, TwoFactor, User, UserPermission,
+    UserRole,
+};
 use auth_service::ports::user_repository::{RepositoryFailure, UserRepository};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -14,6 +24,23 @@ struct UserRecord {
     pub email: String,
     pub hashed_password: String,
     pub role: String,
+    #[serde(default)]
+    pub invite_token: Option<String>,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub default_worksite_id: Option<String>,
+    #[serde(default)]
+    pub two_factor: Option<TwoFactorRecord>,
+    #[serde(default)]
+    pub oidc_subject: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TwoFactorRecord {
+    pub secret: String,
+    pub confirmed: bool,
+    pub backup_codes: Vec<String>,
 }
 
 impl UserRecord {
@@ -23,6 +50,15 @@ impl UserRecord {
             email: self.email.clone(),
             hashed_password: self.hashed_password.clone(),
             role: UserRole::new(self.role.clone()).ok_or(RepositoryFailure::UnknownUserRole)?,
+            invite_token: self.invite_token.clone(),
+            display_name: self.display_name.clone(),
+            default_worksite_id: self.default_worksite_id.clone(),
+            two_factor: self.two_factor.as_ref().map(|t| TwoFactor {
+                secret: t.secret.clone(),
+                confirmed: t.confirmed,
+                backup_codes: t.backup_codes.clone(),
+            }),
+            oidc_subject: self.oidc_subject.clone(),
         })
     }
 }
@@ -33,12 +69,123 @@ fn to_user_record(user: &User) -> UserRecord {
         email: user.email.clone(),
         hashed_password: user.hashed_password.clone(),
         role: user.role.to_string(),
+        invite_token: user.invite_token.clone(),
+        display_name: user.display_name.clone(),
+        default_worksite_id: user.default_worksite_id.clone(),
+        two_factor: user.two_factor.as_ref().map(|t| TwoFactorRecord {
+            secret: t.secret.clone(),
+            confirmed: t.confirmed,
+            backup_codes: t.backup_codes.clone(),
+        }),
+        oidc_subject: user.oidc_subject.clone(),
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LoginAttemptsRecord {
+    pub key: String,
+    pub failures: u32,
+    #[serde(with = "ts_seconds_option")]
+    pub last_failure_at: Option<DateTime<Utc>>,
+    #[serde(with = "ts_seconds_option")]
+    pub locked_until: Option<DateTime<Utc>>,
+}
+
+impl LoginAttemptsRecord {
+    pub fn to_login_attempts(&self) -> LoginAttempts {
+        LoginAttempts {
+            key: self.key.clone(),
+            failures: self.failures,
+            last_failure_at: self.last_failure_at,
+            locked_until: self.locked_until,
+        }
+    }
+}
+
+fn to_login_attempts_record(attempts: &LoginAttempts) -> LoginAttemptsRecord {
+    LoginAttemptsRecord {
+        key: attempts.key.clone(),
+        failures: attempts.failures,
+        last_failure_at: attempts.last_failure_at,
+        locked_until: attempts.locked_until,
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LockoutEventRecord {
+    pub id: String,
+    pub key: String,
+    pub failures: u32,
+    #[serde(with = "ts_seconds")]
+    pub locked_at: DateTime<Utc>,
+    #[serde(with = "ts_seconds")]
+    pub locked_until: DateTime<Utc>,
+}
+
+impl LockoutEventRecord {
+    pub fn to_lockout_event(&self) -> LockoutEvent {
+        LockoutEvent {
+            id: self.id.clone(),
+            key: self.key.clone(),
+            failures: self.failures,
+            locked_at: self.locked_at,
+            locked_until: self.locked_until,
+        }
+    }
+}
+
+fn to_lockout_event_record(event: &LockoutEvent) -> LockoutEventRecord {
+    LockoutEventRecord {
+        id: event.id.clone(),
+        key: event.key.clone(),
+        failures: event.failures,
+        locked_at: event.locked_at,
+        locked_until: event.locked_until,
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ApiTokenRecord {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub hashed_token: String,
+    #[serde(with = "ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "ts_seconds_option")]
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl ApiTokenRecord {
+    pub fn to_api_token(&self) -> ApiToken {
+        ApiToken {
+            id: self.id.clone(),
+            user_id: self.user_id.clone(),
+            name: self.name.clone(),
+            hashed_token: self.hashed_token.clone(),
+            created_at: self.created_at,
+            last_used_at: self.last_used_at,
+        }
+    }
+}
+
+fn to_api_token_record(api_token: &ApiToken) -> ApiTokenRecord {
+    ApiTokenRecord {
+        id: api_token.id.clone(),
+        user_id: api_token.user_id.clone(),
+        name: api_token.name.clone(),
+        hashed_token: api_token.hashed_token.clone(),
+        created_at: api_token.created_at,
+        last_used_at: api_token.last_used_at,
     }
 }
 
 #[derive(Clone, Debug)]
 pub struct MongoUserRepository {
     collection: mongodb::Collection<UserRecord>,
+    login_attempts: mongodb::Collection<LoginAttemptsRecord>,
+    lockout_events: mongodb::Collection<LockoutEventRecord>,
+    api_tokens: mongodb::Collection<ApiTokenRecord>,
 }
 
 // let url = format!("mongodb://127.0.0.1:{host_port}/");
@@ -47,16 +194,16 @@ pub struct MongoUserRepository {
 // let coll = db.collection("some-coll");
 impl MongoUserRepository {
     pub async fn new(url: &String) -> Result<Self, mongodb::error::Error> {
-        Ok(Self {
-            collection: mongodb::Client::with_uri_str(url)
-                .await?
-                .database("auth")
-                .collection::<UserRecord>("users"),
-        })
+        let client = mongodb::Client::with_uri_str(url).await?;
+        Self::from_client(&client)
     }
     pub fn from_client(client: &mongodb::Client) -> Result<Self, mongodb::error::Error> {
+        let db = client.database("auth");
         Ok(Self {
-            collection: client.database("auth").collection::<UserRecord>("users"),
+            collection: db.collection::<UserRecord>("users"),
+            login_attempts: db.collection::<LoginAttemptsRecord>("login_attempts"),
+            lockout_events: db.collection::<LockoutEventRecord>("lockout_events"),
+            api_tokens: db.collection::<ApiTokenRecord>("api_tokens"),
         })
     }
 }
@@ -109,6 +256,38 @@ impl UserRepository for MongoUserRepository {
         }
     }
 
+    async fn find_by_invite_token(
+        &self,
+        invite_token: String,
+    ) -> Result<Option<User>, RepositoryFailure> {
+        let filter = doc! { "invite_token": invite_token };
+        let maybe_user = self
+            .collection
+            .find_one(filter, None)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+        match maybe_user {
+            Some(u) => u.to_user().map(Some),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_by_oidc_subject(
+        &self,
+        oidc_subject: String,
+    ) -> Result<Option<User>, RepositoryFailure> {
+        let filter = doc! { "oidc_subject": oidc_subject };
+        let maybe_user = self
+            .collection
+            .find_one(filter, None)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+        match maybe_user {
+            Some(u) => u.to_user().map(Some),
+            None => Ok(None),
+        }
+    }
+
     async fn save(&self, user: User) -> Result<(), RepositoryFailure> {
         let filter = doc! {"id": user.id.clone()};
         let record = to_user_record(&user);
@@ -131,18 +310,225 @@ impl UserRepository for MongoUserRepository {
 
         Ok(())
     }
+
+    async fn find_login_attempts(
+        &self,
+        key: String,
+    ) -> Result<Option<LoginAttempts>, RepositoryFailure> {
+        let filter = doc! { "key": key };
+        let maybe_attempts = self
+            .login_attempts
+            .find_one(filter, None)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        Ok(maybe_attempts.map(|a| a.to_login_attempts()))
+    }
+
+    async fn get_login_attempts(&self) -> Result<Vec<LoginAttempts>, RepositoryFailure> {
+        let cursor = self
+            .login_attempts
+            .find(None, None)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        let attempts: Vec<LoginAttemptsRecord> = cursor
+            .try_collect()
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        Ok(attempts.iter().map(|a| a.to_login_attempts()).collect())
+    }
+
+    async fn save_login_attempts(&self, attempts: LoginAttempts) -> Result<(), RepositoryFailure> {
+        let filter = doc! {"key": attempts.key.clone()};
+        let record = to_login_attempts_record(&attempts);
+        let options = mongodb::options::ReplaceOptions::builder()
+            .upsert(true)
+            .build();
+        self.login_attempts
+            .replace_one(filter, record, options)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn record_login_failure(
+        &self,
+        key: String,
+        max_failures: u32,
+        policy: &LoginThrottlePolicy,
+        now: DateTime<Utc>,
+    ) -> Result<LoginAttempts, RepositoryFailure> {
+        let window_start = (now - policy.failure_window).timestamp();
+        let locked_until = (now + policy.lockout_duration).timestamp();
+        let now = now.timestamp();
+
+        // Mirrors LoginAttempts::record_failure as one update, so the count is read and
+        // written atomically. Failures start over once a lockout has expired or after a
+        // quiet period.
+        let lockout_not_expired = doc! {
+            "$or": [
+                { "$eq": [{ "$ifNull": ["$locked_until", null] }, null] },
+                { "$gt": ["$locked_until", now] },
+            ]
+        };
+        let counting = doc! {
+            "$and": [{ "$gt": ["$last_failure_at", window_start] }, lockout_not_expired]
+        };
+        let update = vec![
+            doc! {
+                "$set": {
+                    "failures": { "$cond": [counting.clone(), { "$add": ["$failures", 1] }, 1] },
+                    "last_failure_at": now,
+                    "locked_until": {
+                        "$cond": [counting, { "$ifNull": ["$locked_until", null] }, null]
+                    },
+                }
+            },
+            doc! {
+                "$set": {
+                    "locked_until": {
+                        "$cond": [
+                            {
+                                "$and": [
+                                    { "$gte": ["$failures", max_failures] },
+                                    { "$eq": ["$locked_until", null] },
+                                ]
+                            },
+                            locked_until,
+                            "$locked_until",
+                        ]
+                    },
+                }
+            },
+        ];
+
+        let filter = doc! { "key": key };
+        let options = mongodb::options::FindOneAndUpdateOptions::builder()
+            .upsert(true)
+            .return_document(mongodb::options::ReturnDocument::After)
+            .build();
+        let record = self
+            .login_attempts
+            .find_one_and_update(filter, update, options)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?
+            .ok_or(RepositoryFailure::Unknown(
+                "Failed to record a login failure".into(),
+            ))?;
+
+        Ok(record.to_login_attempts())
+    }
+
+    async fn delete_login_attempts(&self, key: String) -> Result<(), RepositoryFailure> {
+        let filter = doc! { "key": key };
+        self.login_attempts
+            .delete_one(filter, None)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn save_lockout_event(&self, event: LockoutEvent) -> Result<(), RepositoryFailure> {
+        self.lockout_events
+            .insert_one(to_lockout_event_record(&event), None)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn get_lockout_events(&self) -> Result<Vec<LockoutEvent>, RepositoryFailure> {
+        let cursor = self
+            .lockout_events
+            .find(None, None)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        let events: Vec<LockoutEventRecord> = cursor
+            .try_collect()
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        Ok(events.iter().map(|e| e.to_lockout_event()).collect())
+    }
+
+    async fn save_api_token(&self, api_token: ApiToken) -> Result<(), RepositoryFailure> {
+        let filter = doc! {"id": api_token.id.clone()};
+        let record = to_api_token_record(&api_token);
+        let options = mongodb::options::ReplaceOptions::builder()
+            .upsert(true)
+            .build();
+        self.api_tokens
+            .replace_one(filter, record, options)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn find_api_token_by_hash(
+        &self,
+        hashed_token: String,
+    ) -> Result<Option<ApiToken>, RepositoryFailure> {
+        let filter = doc! { "hashed_token": hashed_token };
+        let maybe_api_token = self
+            .api_tokens
+            .find_one(filter, None)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        Ok(maybe_api_token.map(|t| t.to_api_token()))
+    }
+
+    async fn get_api_tokens_for_user(
+        &self,
+        user_id: String,
+    ) -> Result<Vec<ApiToken>, RepositoryFailure> {
+        let filter = doc! { "user_id": user_id };
+        let cursor = self
+            .api_tokens
+            .find(filter, None)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        let api_tokens: Vec<ApiTokenRecord> = cursor
+            .try_collect()
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        Ok(api_tokens.iter().map(|t| t.to_api_token()).collect())
+    }
+
+    async fn delete_api_token(&self, id: String) -> Result<(), RepositoryFailure> {
+        let filter = doc! { "id": id };
+        self.api_tokens
+            .delete_one(filter, None)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        Ok(())
+    }
 }
 
 #[derive(Clone)]
 pub struct MongoUserStore {
     pub users: Arc<MongoUserRepository>,
+    // Signs users in through the identity provider, when single sign-on is configured
+    pub complete_oidc_login: CompleteOidcLogin,
 }
 
 #[derive(Clone)]
-pub struct Credentials {
-    pub user_id: String,
-    // Used to help redirect the user to the page they were trying to access
-    pub next: Option<String>,
+pub enum Credentials {
+    // A user that was already verified, e.g. by their password and two-factor code
+    User {
+        user_id: String,
+        // Used to help redirect the user to the page they were trying to access
+        next: Option<String>,
+    },
+    // The identity provider's callback after the user signed in there
+    Oidc(CompleteOidcLoginInput),
 }
 
 /**
@@ -156,9 +542,24 @@ impl AuthnBackend for MongoUserStore {
 
     async fn authenticate(
         &self,
-        Credentials { user_id, .. }: Self::Credentials,
+        credentials: Self::Credentials,
     ) -> Result<Option<Self::User>, Self::Error> {
-        self.get_user(&user_id).await
+        match credentials {
+            Credentials::User { user_id, .. } => self.get_user(&user_id).await,
+            Credentials::Oidc(input) => {
+                match self.complete_oidc_login.complete_oidc_login(input).await {
+                    Ok(user) => Ok(Some(user)),
+                    Err(CompleteOidcLoginFailure::Internal(e)) => {
+                        Err(RepositoryFailure::Unknown(e))
+                    }
+                    // Anything else means the login was not valid
+                    Err(e) => {
+                        tracing::warn!("Single sign-on failed: {}", e);
+                        Ok(None)
+                    }
+                }
+            }
+        }
     }
 
     async fn get_user(&self, user_id: &UserId<Self>) -> Result<Option<Self::User>, Self::Error> {
@@ -182,8 +583,12 @@ impl AuthzBackend for MongoUserStore {
 #[cfg(test)]
 mod tests {
     use auth_service::ports::user_repository::UserRepository;
+    use chrono::{TimeZone, Utc};
     use fake::{
-        faker::internet::en::{Password, SafeEmail},
+        faker::{
+            internet::en::{Password, SafeEmail},
+            name::en::Name,
+        },
         Fake,
     };
     use mongo_testcontainer::Mongo;
@@ -256,6 +661,13 @@ mod tests {
         test_create_and_find_by_email(&repo).await;
         test_create_and_delete_user(&repo).await;
         test_update_user(&repo).await;
+        test_invite_and_find_by_invite_token(&repo).await;
+        test_update_profile(&repo).await;
+        test_two_factor(&repo).await;
+        test_find_by_oidc_subject(&repo).await;
+        test_login_attempts(&repo).await;
+        test_lockout_events(&repo).await;
+        test_api_tokens(&repo).await;
     }
 
     async fn test_create_and_fetch(repo: &MongoUserRepository) {
@@ -310,6 +722,193 @@ mod tests {
         assert_eq!(result, Some(user));
     }
 
+    async fn test_invite_and_find_by_invite_token(repo: &MongoUserRepository) {
+        let user = auth_service::models::User::invite(
+            SafeEmail().fake::<String>(),
+            auth_service::models::UserRole::Organizer,
+        );
+
+        repo.save(user.clone()).await.unwrap();
+
+        let result = repo
+            .find_by_invite_token(user.invite_token.clone().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(result, Some(user.clone()));
+
+        let user =
+            user.accept_invite(Password(std::ops::Range { start: 10, end: 20 }).fake::<String>());
+
+        repo.save(user.clone()).await.unwrap();
+
+        let result = repo.find_by_id(user.id.clone()).await.unwrap();
+        assert_eq!(result, Some(user));
+    }
+
+    async fn test_update_profile(repo: &MongoUserRepository) {
+        let user = make_user();
+
+        repo.save(user.clone()).await.unwrap();
+
+        let user = user.update_profile(Some(Name().fake::<String>()), Some("1".into()));
+
+        repo.save(user.clone()).await.unwrap();
+
+        let result = repo.find_by_id(user.id.clone()).await.unwrap();
+        assert_eq!(result, Some(user));
+    }
+
+    async fn test_two_factor(repo: &MongoUserRepository) {
+        let user = make_user().begin_two_factor("JBSWY3DPEHPK3PXP".into());
+
+        repo.save(user.clone()).await.unwrap();
+
+        let result = repo.find_by_id(user.id.clone()).await.unwrap();
+        assert_eq!(result, Some(user.clone()));
+
+        let user = user.confirm_two_factor(vec!["hashed-backup-code".into()]);
+
+        repo.save(user.clone()).await.unwrap();
+
+        let result = repo.find_by_id(user.id.clone()).await.unwrap();
+        assert_eq!(result, Some(user));
+    }
+
+    async fn test_find_by_oidc_subject(repo: &MongoUserRepository) {
+        let user = make_user().link_oidc("oidc-subject-1".into());
+
+        repo.save(user.clone()).await.unwrap();
+
+        let result = repo
+            .find_by_oidc_subject("oidc-subject-1".into())
+            .await
+            .unwrap();
+        assert_eq!(result, Some(user));
+
+        let result = repo
+            .find_by_oidc_subject("oidc-subject-2".into())
+            .await
+            .unwrap();
+        assert_eq!(result, None);
+    }
+
+    async fn test_login_attempts(repo: &MongoUserRepository) {
+        let attempts = auth_service::models::LoginAttempts {
+            key: auth_service::models::LoginAttempts::account_key(&SafeEmail().fake::<String>()),
+            failures: 3,
+            last_failure_at: Some(Utc.with_ymd_and_hms(2023, 11, 1, 12, 0, 0).unwrap()),
+            locked_until: None,
+        };
+
+        repo.save_login_attempts(attempts.clone()).await.unwrap();
+
+        let result = repo
+            .find_login_attempts(attempts.key.clone())
+            .await
+            .unwrap();
+        assert_eq!(result, Some(attempts.clone()));
+
+        let attempts = auth_service::models::LoginAttempts {
+            failures: 5,
+            locked_until: Some(Utc.with_ymd_and_hms(2023, 11, 1, 12, 15, 0).unwrap()),
+            ..attempts
+        };
+
+        repo.save_login_attempts(attempts.clone()).await.unwrap();
+
+        let result = repo.get_login_attempts().await.unwrap();
+        assert!(result.contains(&attempts));
+
+        repo.delete_login_attempts(attempts.key.clone())
+            .await
+            .unwrap();
+
+        let result = repo
+            .find_login_attempts(attempts.key.clone())
+            .await
+            .unwrap();
+        assert_eq!(result, None);
+
+        let policy = auth_service::models::LoginThrottlePolicy::default();
+        let now = Utc.with_ymd_and_hms(2023, 11, 1, 12, 0, 0).unwrap();
+        for _ in 0..2 {
+            repo.record_login_failure(attempts.key.clone(), 3, &policy, now)
+                .await
+                .unwrap();
+        }
+        let result = repo
+            .record_login_failure(attempts.key.clone(), 3, &policy, now)
+            .await
+            .unwrap();
+        assert_eq!(
+            result,
+            auth_service::models::LoginAttempts {
+                key: attempts.key.clone(),
+                failures: 3,
+                last_failure_at: Some(now),
+                locked_until: Some(now + policy.lockout_duration),
+            }
+        );
+
+        // Failures start over once the lockout has expired
+        let later = now + policy.lockout_duration;
+        let result = repo
+            .record_login_failure(attempts.key.clone(), 3, &policy, later)
+            .await
+            .unwrap();
+        assert_eq!(result.failures, 1);
+        assert_eq!(result.locked_until, None);
+    }
+
+    async fn test_lockout_events(repo: &MongoUserRepository) {
+        let attempts = auth_service::models::LoginAttempts {
+            key: auth_service::models::LoginAttempts::ip_key("10.0.0.1"),
+            failures: 20,
+            last_failure_at: Some(Utc.with_ymd_and_hms(2023, 11, 1, 12, 0, 0).unwrap()),
+            locked_until: Some(Utc.with_ymd_and_hms(2023, 11, 1, 12, 15, 0).unwrap()),
+        };
+        let event = auth_service::models::LockoutEvent::new(
+            &attempts,
+            Utc.with_ymd_and_hms(2023, 11, 1, 12, 0, 0).unwrap(),
+        );
+
+        repo.save_lockout_event(event.clone()).await.unwrap();
+
+        let result = repo.get_lockout_events().await.unwrap();
+        assert!(result.contains(&event));
+    }
+
+    async fn test_api_tokens(repo: &MongoUserRepository) {
+        let user_id = make_user().id;
+        let api_token = auth_service::models::ApiToken {
+            id: "api-token-1".into(),
+            user_id: user_id.clone(),
+            name: "Roster sync".into(),
+            hashed_token: "hashed-token".into(),
+            created_at: Utc.with_ymd_and_hms(2023, 11, 1, 12, 0, 0).unwrap(),
+            last_used_at: None,
+        };
+
+        repo.save_api_token(api_token.clone()).await.unwrap();
+
+        let result = repo
+            .find_api_token_by_hash("hashed-token".into())
+            .await
+            .unwrap();
+        assert_eq!(result, Some(api_token.clone()));
+
+        let api_token = api_token.used(Utc.with_ymd_and_hms(2023, 11, 2, 12, 0, 0).unwrap());
+        repo.save_api_token(api_token.clone()).await.unwrap();
+
+        let result = repo.get_api_tokens_for_user(user_id.clone()).await.unwrap();
+        assert_eq!(result, vec![api_token.clone()]);
+
+        repo.delete_api_token(api_token.id.clone()).await.unwrap();
+
+        let result = repo.get_api_tokens_for_user(user_id).await.unwrap();
+        assert_eq!(result, vec![]);
+    }
+
     async fn test_get_users(repo: &MongoUserRepository) {
         // Note that this is a get all, but we are using a single db instance
         // so our assertions here are going to be weaker so the test is less flaky
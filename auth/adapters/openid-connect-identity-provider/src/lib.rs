@@ -0,0 +1,298 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use openidconnect::{
+    core::{CoreAuthenticationFlow, CoreClient, CoreGenderClaim, CoreProviderMetadata},
+    reqwest::async_http_client,
+    AccessToken, AdditionalClaims, AuthorizationCode, ClientId, ClientSecret, CsrfToken, IssuerUrl,
+    Nonce, OAuth2TokenResponse, PkceCodeChallenge, PkceCodeVerifier, RedirectUrl, Scope,
+    SubjectIdentifier, TokenResponse, UserInfoClaims,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use auth_service::{
+    models::{OidcAuthorization, OidcIdentity, OidcPendingLogin},
+    ports::identity_provider::{IdentityProvider, IdentityProviderFailure},
+};
+
+#[derive(Clone, Debug)]
+pub struct OpenIdConnectConfig {
+    pub issuer_url: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    // Where the identity provider sends the user back to, i.e. our callback route
+    pub redirect_url: String,
+    // The userinfo claim holding the values that get mapped to roles, e.g. "groups"
+    pub role_claim: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct OpenIdConnectIdentityProvider {
+    client: CoreClient,
+    role_claim: Option<String>,
+}
+
+// Every claim in the userinfo response, so the role claim can be configured
+#[derive(Debug, Deserialize, Serialize)]
+struct AllClaims {
+    #[serde(flatten)]
+    claims: HashMap<String, Value>,
+}
+impl AdditionalClaims for AllClaims {}
+
+impl OpenIdConnectIdentityProvider {
+    /**
+     * Fetches the issuer's discovery document, so the issuer needs to be reachable when the
+     * app starts.
+     */
+    pub async fn discover(config: OpenIdConnectConfig) -> Result<Self, IdentityProviderFailure> {
+        let issuer_url = IssuerUrl::new(config.issuer_url)
+            .map_err(|e| IdentityProviderFailure::Unknown(e.to_string()))?;
+        let redirect_url = RedirectUrl::new(config.redirect_url)
+            .map_err(|e| IdentityProviderFailure::Unknown(e.to_string()))?;
+
+        let provider_metadata = CoreProviderMetadata::discover_async(issuer_url, async_http_client)
+            .await
+            .map_err(|e| IdentityProviderFailure::Unknown(e.to_string()))?;
+
+        let client = CoreClient::from_provider_metadata(
+            provider_metadata,
+            ClientId::new(config.client_id),
+            config.client_secret.map(ClientSecret::new),
+        )
+        .set_redirect_uri(redirect_url);
+
+        Ok(Self {
+            client,
+            role_claim: config.role_claim,
+        })
+    }
+
+    async fn fetch_roles(
+        &self,
+        role_claim: &str,
+        access_token: AccessToken,
+        subject: SubjectIdentifier,
+    ) -> Result<Vec<String>, IdentityProviderFailure> {
+        let user_info: UserInfoClaims<AllClaims, CoreGenderClaim> = self
+            .client
+            .user_info(access_token, Some(subject))
+            .map_err(|e| IdentityProviderFailure::Unknown(e.to_string()))?
+            .request_async(async_http_client)
+            .await
+            .map_err(|e| IdentityProviderFailure::InvalidToken(e.to_string()))?;
+
+        // Providers send groups as either a list or a single value
+        let roles = match user_info.additional_claims().claims.get(role_claim) {
+            Some(Value::Array(values)) => values
+                .iter()
+                .filter_map(|value| value.as_str())
+                .map(|value| value.to_string())
+                .collect(),
+            Some(Value::String(value)) => vec![value.clone()],
+            _ => vec![],
+        };
+
+        Ok(roles)
+    }
+}
+
+#[async_trait]
+impl IdentityProvider for OpenIdConnectIdentityProvider {
+    fn authorize(&self) -> OidcAuthorization {
+        let (pkce_challenge, pkce_verifier) = PkceCodeChallenge::new_random_sha256();
+
+        let (url, csrf_state, nonce) = self
+            .client
+            .authorize_url(
+                CoreAuthenticationFlow::AuthorizationCode,
+                CsrfToken::new_random,
+                Nonce::new_random,
+            )
+            .add_scope(Scope::new("email".into()))
+            .add_scope(Scope::new("profile".into()))
+            .set_pkce_challenge(pkce_challenge)
+            .url();
+
+        OidcAuthorization {
+            url: url.to_string(),
+            pending: OidcPendingLogin {
+                csrf_state: csrf_state.secret().clone(),
+                nonce: nonce.secret().clone(),
+                pkce_verifier: pkce_verifier.secret().clone(),
+            },
+        }
+    }
+
+    async fn exchange_code(
+        &self,
+        code: String,
+        pending: OidcPendingLogin,
+    ) -> Result<OidcIdentity, IdentityProviderFailure> {
+        let token_response = self
+            .client
+            .exchange_code(AuthorizationCode::new(code))
+            .set_pkce_verifier(PkceCodeVerifier::new(pending.pkce_verifier))
+            .request_async(async_http_client)
+            .await
+            .map_err(|e| IdentityProviderFailure::InvalidCode(e.to_string()))?;
+
+        let id_token = token_response
+            .id_token()
+            .ok_or(IdentityProviderFailure::MissingClaim("id_token".into()))?;
+        let claims = id_token
+            .claims(&self.client.id_token_verifier(), &Nonce::new(pending.nonce))
+            .map_err(|e| IdentityProviderFailure::InvalidToken(e.to_string()))?;
+
+        let email = claims
+            .email()
+            .map(|email| email.to_string())
+            .ok_or(IdentityProviderFailure::MissingClaim("email".into()))?;
+
+        let roles = match &self.role_claim {
+            Some(role_claim) => {
+                self.fetch_roles(
+                    role_claim,
+                    token_response.access_token().clone(),
+                    claims.subject().clone(),
+                )
+                .await?
+            }
+            None => vec![],
+        };
+
+        Ok(OidcIdentity {
+            subject: claims.subject().to_string(),
+            email,
+            email_verified: claims.email_verified().unwrap_or(false),
+            name: claims
+                .name()
+                .and_then(|name| name.get(None))
+                .map(|name| name.to_string()),
+            roles,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use auth_service::ports::identity_provider::{IdentityProvider, IdentityProviderFailure};
+    use mock_oidc_testcontainer::{MockOidcIssuer, PORT};
+    use openidconnect::url::Url;
+    use testcontainers::clients;
+
+    use crate::{OpenIdConnectConfig, OpenIdConnectIdentityProvider};
+
+    const CLIENT_ID: &str = "yallchart";
+    const REDIRECT_URL: &str = "http://localhost:3000/login/oidc/callback";
+
+    // Every code the mock issuer hands out signs in the same steward
+    fn json_config() -> String {
+        format!(
+            r#"{{
+                "interactiveLogin": false,
+                "tokenCallbacks": [{{
+                    "issuerId": "default",
+                    "tokenExpiry": 120,
+                    "requestMappings": [{{
+                        "requestParam": "grant_type",
+                        "match": "authorization_code",
+                        "claims": {{
+                            "sub": "steward-1",
+                            "aud": ["{CLIENT_ID}"],
+                            "email": "steward@local100.org",
+                            "email_verified": true,
+                            "name": "Shop Steward",
+                            "groups": ["members", "stewards"]
+                        }}
+                    }}]
+                }}]
+            }}"#
+        )
+    }
+
+    // The issuer takes a moment to start listening after the container is up
+    async fn discover(issuer_url: String) -> OpenIdConnectIdentityProvider {
+        let config = OpenIdConnectConfig {
+            issuer_url,
+            client_id: CLIENT_ID.into(),
+            client_secret: Some("secret".into()),
+            redirect_url: REDIRECT_URL.into(),
+            role_claim: Some("groups".into()),
+        };
+
+        for _ in 0..60 {
+            if let Ok(provider) = OpenIdConnectIdentityProvider::discover(config.clone()).await {
+                return provider;
+            }
+            tokio::time::sleep(Duration::from_millis(500)).await;
+        }
+        panic!("Mock OIDC issuer never came up");
+    }
+
+    // Follows the authorization url the way a browser would, returning the callback's query
+    async fn sign_in(url: &str) -> (String, String) {
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .unwrap();
+        let response = client.get(url).send().await.unwrap();
+        let location = response.headers()["location"].to_str().unwrap().to_string();
+
+        let callback = Url::parse(&location).unwrap();
+        assert!(location.starts_with(REDIRECT_URL));
+        let param = |name: &str| {
+            callback
+                .query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.to_string())
+                .unwrap()
+        };
+
+        (param("code"), param("state"))
+    }
+
+    #[tokio::test]
+    async fn tests() {
+        let docker_cli = clients::Cli::default();
+        let container = docker_cli.run(MockOidcIssuer::with_json_config(json_config()));
+        let host_port = container.get_host_port_ipv4(PORT);
+        let provider = discover(format!("http://localhost:{host_port}/default")).await;
+
+        test_sign_in(&provider).await;
+        test_rejects_wrong_nonce(&provider).await;
+    }
+
+    async fn test_sign_in(provider: &OpenIdConnectIdentityProvider) {
+        let authorization = provider.authorize();
+        let (code, state) = sign_in(&authorization.url).await;
+        assert_eq!(state, authorization.pending.csrf_state);
+
+        let identity = provider
+            .exchange_code(code, authorization.pending)
+            .await
+            .unwrap();
+        assert_eq!(identity.subject, "steward-1");
+        assert_eq!(identity.email, "steward@local100.org");
+        assert!(identity.email_verified);
+        assert_eq!(identity.name, Some("Shop Steward".into()));
+        assert_eq!(identity.roles, vec!["members", "stewards"]);
+    }
+
+    async fn test_rejects_wrong_nonce(provider: &OpenIdConnectIdentityProvider) {
+        let authorization = provider.authorize();
+        let (code, _) = sign_in(&authorization.url).await;
+
+        let mut pending = authorization.pending;
+        pending.nonce = "replayed".into();
+
+        let result = provider.exchange_code(code, pending).await;
+        assert!(matches!(
+            result,
+            Err(IdentityProviderFailure::InvalidToken(_))
+        ));
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use password_auth::generate_hash;
+use thiserror::Error;
+
+use crate::{
+    events::{AuthEvent, DomainEvent},
+    models::User,
+    ports::{event_publisher::EventPublisher, user_repository::UserRepository},
+};
+
+#[derive(Clone)]
+pub struct AcceptInvite {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AcceptInviteInput {
+    pub invite_token: String,
+    pub password: String,
+}
+
+pub type AcceptInviteOutput = Result<User, AcceptInviteFailure>;
+
+impl AcceptInvite {
+    pub async fn accept_invite(&self, input: AcceptInviteInput) -> AcceptInviteOutput {
+        if input.password.is_empty() {
+            return Err(AcceptInviteFailure::EmptyPassword);
+        }
+
+        let user = self
+            .user_repository
+            .find_by_invite_token(input.invite_token)
+            .await
+            .map_err(|e| AcceptInviteFailure::Internal(e.to_string()))?
+            .ok_or(AcceptInviteFailure::NotFound)?;
+
+        // Salt and hash the pw
+        let user = user.accept_invite(generate_hash(input.password));
+
+        self.user_repository
+            .save(user.clone())
+            .await
+            .map_err(|e| AcceptInviteFailure::Internal(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                user.id.clone(),
+                AuthEvent::InviteAccepted { user: user.clone() },
+            ))
+            .await;
+
+        Ok(user)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum AcceptInviteFailure {
+    #[error("Invite does not exist or was already used")]
+    NotFound,
+    #[error("Password can not be empty")]
+    EmptyPassword,
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,20 @@
+use rand::{distributions::Alphanumeric, Rng};
+
+// Makes tokens easy to spot if one ends up somewhere it should not, e.g. a git repo
+const TOKEN_PREFIX: &str = "yct_";
+const TOKEN_LENGTH: usize = 40;
+
+pub fn generate_token() -> String {
+    let secret: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(TOKEN_LENGTH)
+        .map(char::from)
+        .collect();
+
+    format!("{}{}", TOKEN_PREFIX, secret)
+}
+
+// Tokens are long and random, so a fast unsalted hash is enough to look them up by
+pub fn hash_token(token: &str) -> String {
+    sha256::digest(token.trim())
+}
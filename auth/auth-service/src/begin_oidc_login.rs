@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{models::OidcAuthorization, ports::identity_provider::IdentityProvider};
+
+#[derive(Clone)]
+pub struct BeginOidcLogin {
+    // None when single sign-on is not configured
+    pub identity_provider: Option<Arc<dyn IdentityProvider>>,
+}
+
+pub type BeginOidcLoginOutput = Result<OidcAuthorization, BeginOidcLoginFailure>;
+
+impl BeginOidcLogin {
+    pub async fn begin_oidc_login(&self) -> BeginOidcLoginOutput {
+        let identity_provider = self
+            .identity_provider
+            .as_ref()
+            .ok_or(BeginOidcLoginFailure::NotConfigured)?;
+
+        Ok(identity_provider.authorize())
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.identity_provider.is_some()
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum BeginOidcLoginFailure {
+    #[error("Single sign-on is not configured")]
+    NotConfigured,
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{AuthEvent, DomainEvent},
+    models::TwoFactorEnrollment,
+    ports::{event_publisher::EventPublisher, user_repository::UserRepository},
+    two_factor,
+};
+
+#[derive(Clone)]
+pub struct BeginTwoFactorEnrollment {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct BeginTwoFactorEnrollmentInput {
+    pub user_id: String,
+}
+
+pub type BeginTwoFactorEnrollmentOutput =
+    Result<TwoFactorEnrollment, BeginTwoFactorEnrollmentFailure>;
+
+impl BeginTwoFactorEnrollment {
+    pub async fn begin_two_factor_enrollment(
+        &self,
+        input: BeginTwoFactorEnrollmentInput,
+    ) -> BeginTwoFactorEnrollmentOutput {
+        let user = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(|e| BeginTwoFactorEnrollmentFailure::Internal(e.to_string()))?
+            .ok_or(BeginTwoFactorEnrollmentFailure::NotFound)?;
+
+        if user.has_two_factor() {
+            return Err(BeginTwoFactorEnrollmentFailure::AlreadyEnabled);
+        }
+
+        // Starting over replaces any secret from an enrollment that was never confirmed
+        let secret = two_factor::generate_secret();
+        let otpauth_url = two_factor::build_totp(&secret, &user.email)
+            .ok_or(BeginTwoFactorEnrollmentFailure::Internal(
+                "Failed to build TOTP".into(),
+            ))?
+            .get_url();
+
+        self.user_repository
+            .save(user.begin_two_factor(secret.clone()))
+            .await
+            .map_err(|e| BeginTwoFactorEnrollmentFailure::Internal(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                user.id,
+                AuthEvent::TwoFactorEnrollmentStarted,
+            ))
+            .await;
+
+        Ok(TwoFactorEnrollment {
+            secret,
+            otpauth_url,
+        })
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum BeginTwoFactorEnrollmentFailure {
+    #[error("User does not exist!")]
+    NotFound,
+    #[error("Two-factor authentication is already enabled")]
+    AlreadyEnabled,
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use password_auth::{generate_hash, verify_password};
+use thiserror::Error;
+
+use crate::{
+    events::{AuthEvent, DomainEvent},
+    ports::{event_publisher::EventPublisher, user_repository::UserRepository},
+};
+
+#[derive(Clone)]
+pub struct ChangePassword {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ChangePasswordInput {
+    pub user_id: String,
+    pub current_password: String,
+    pub new_password: String,
+    pub new_password_confirmation: String,
+}
+
+pub type ChangePasswordOutput = Result<(), ChangePasswordFailure>;
+
+impl ChangePassword {
+    pub async fn change_password(&self, input: ChangePasswordInput) -> ChangePasswordOutput {
+        if input.new_password.is_empty() {
+            return Err(ChangePasswordFailure::EmptyPassword);
+        }
+
+        if input.new_password != input.new_password_confirmation {
+            return Err(ChangePasswordFailure::PasswordMismatch);
+        }
+
+        let user = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(|e| ChangePasswordFailure::Internal(e.to_string()))?
+            .ok_or(ChangePasswordFailure::NotFound)?;
+
+        verify_password(input.current_password, &user.hashed_password)
+            .map_err(|_| ChangePasswordFailure::WrongPassword)?;
+
+        // Salt and hash the pw
+        let user = user.change_password(generate_hash(input.new_password));
+        let user_id = user.id.clone();
+
+        self.user_repository
+            .save(user)
+            .await
+            .map_err(|e| ChangePasswordFailure::Internal(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(user_id, AuthEvent::PasswordChanged))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ChangePasswordFailure {
+    #[error("User does not exist!")]
+    NotFound,
+    #[error("Wrong password!")]
+    WrongPassword,
+    #[error("Password can not be empty")]
+    EmptyPassword,
+    #[error("Passwords do not match")]
+    PasswordMismatch,
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{AuthEvent, DomainEvent},
+    models::{OidcPendingLogin, OidcRoleMapping, User},
+    ports::{
+        event_publisher::EventPublisher, identity_provider::IdentityProvider,
+        user_repository::UserRepository,
+    },
+};
+
+#[derive(Clone)]
+pub struct CompleteOidcLogin {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+    // None when single sign-on is not configured
+    pub identity_provider: Option<Arc<dyn IdentityProvider>>,
+    pub role_mapping: OidcRoleMapping,
+}
+
+#[derive(Clone, Debug)]
+pub struct CompleteOidcLoginInput {
+    // From the query string the identity provider redirected back with
+    pub code: String,
+    pub state: String,
+    // From the session, saved when the login began
+    pub pending: OidcPendingLogin,
+}
+
+pub type CompleteOidcLoginOutput = Result<User, CompleteOidcLoginFailure>;
+
+impl CompleteOidcLogin {
+    pub async fn complete_oidc_login(
+        &self,
+        input: CompleteOidcLoginInput,
+    ) -> CompleteOidcLoginOutput {
+        let identity_provider = self
+            .identity_provider
+            .as_ref()
+            .ok_or(CompleteOidcLoginFailure::NotConfigured)?;
+
+        if input.state != input.pending.csrf_state {
+            return Err(CompleteOidcLoginFailure::StateMismatch);
+        }
+
+        let identity = identity_provider
+            .exchange_code(input.code, input.pending)
+            .await
+            .map_err(|e| CompleteOidcLoginFailure::IdentityProvider(e.to_string()))?;
+
+        let existing = match self
+            .user_repository
+            .find_by_oidc_subject(identity.subject.clone())
+            .await
+            .map_err(|e| CompleteOidcLoginFailure::Internal(e.to_string()))?
+        {
+            Some(user) => Some(user),
+            None => {
+                self.find_user_to_link(&identity.email, identity.email_verified)
+                    .await?
+            }
+        };
+
+        let mapped_role = self.role_mapping.role_for(&identity);
+
+        // Provision users the first time they sign in. Returning users keep the role
+        // an admin gave them unless the identity provider maps them to one.
+        let previous = existing.clone();
+        let user = match existing {
+            Some(user) => {
+                let user = user.link_oidc(identity.subject.clone());
+                match mapped_role {
+                    Some(role) => user.update(user.email.clone(), role),
+                    None => user,
+                }
+            }
+            None => User::provision_oidc(
+                &identity,
+                mapped_role.unwrap_or(self.role_mapping.default_role.clone()),
+            ),
+        };
+
+        self.user_repository
+            .save(user.clone())
+            .await
+            .map_err(|e| CompleteOidcLoginFailure::Internal(e.to_string()))?;
+
+        // Most sign-ins change nothing about the account
+        if previous.as_ref() != Some(&user) {
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    user.id.clone(),
+                    AuthEvent::UserProvisioned {
+                        previous,
+                        user: user.clone(),
+                    },
+                ))
+                .await;
+        }
+
+        Ok(user)
+    }
+
+    // An existing password or invited account is only linked when the identity provider
+    // vouches for the email, otherwise anyone could claim it.
+    async fn find_user_to_link(
+        &self,
+        email: &str,
+        email_verified: bool,
+    ) -> Result<Option<User>, CompleteOidcLoginFailure> {
+        let user = self
+            .user_repository
+            .find_by_email(email.to_string())
+            .await
+            .map_err(|e| CompleteOidcLoginFailure::Internal(e.to_string()))?;
+
+        match user {
+            Some(user) if user.oidc_subject.is_some() => {
+                Err(CompleteOidcLoginFailure::EmailInUse(email.to_string()))
+            }
+            Some(_) if !email_verified => {
+                Err(CompleteOidcLoginFailure::EmailInUse(email.to_string()))
+            }
+            user => Ok(user),
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum CompleteOidcLoginFailure {
+    #[error("Single sign-on is not configured")]
+    NotConfigured,
+    #[error("Login state did not match, the login may have been tampered with")]
+    StateMismatch,
+    #[error("Identity provider error: {0}")]
+    IdentityProvider(String),
+    #[error("{0} already belongs to another account")]
+    EmailInUse(String),
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
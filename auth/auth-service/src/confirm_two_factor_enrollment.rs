@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{AuthEvent, DomainEvent},
+    ports::{event_publisher::EventPublisher, user_repository::UserRepository},
+    two_factor,
+};
+
+#[derive(Clone)]
+pub struct ConfirmTwoFactorEnrollment {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ConfirmTwoFactorEnrollmentInput {
+    pub user_id: String,
+    pub code: String,
+}
+
+// Returns the plain backup codes. This is the only time they are available.
+pub type ConfirmTwoFactorEnrollmentOutput = Result<Vec<String>, ConfirmTwoFactorEnrollmentFailure>;
+
+impl ConfirmTwoFactorEnrollment {
+    pub async fn confirm_two_factor_enrollment(
+        &self,
+        input: ConfirmTwoFactorEnrollmentInput,
+    ) -> ConfirmTwoFactorEnrollmentOutput {
+        let user = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(|e| ConfirmTwoFactorEnrollmentFailure::Internal(e.to_string()))?
+            .ok_or(ConfirmTwoFactorEnrollmentFailure::NotFound)?;
+
+        let pending = match &user.two_factor {
+            Some(two_factor) if !two_factor.confirmed => two_factor.clone(),
+            _ => return Err(ConfirmTwoFactorEnrollmentFailure::NotStarted),
+        };
+
+        if !two_factor::check_code(&pending.secret, &user.email, &input.code) {
+            return Err(ConfirmTwoFactorEnrollmentFailure::InvalidCode);
+        }
+
+        let backup_codes = two_factor::generate_backup_codes();
+        let hashed_backup_codes = backup_codes
+            .iter()
+            .map(|code| two_factor::hash_backup_code(code))
+            .collect();
+
+        self.user_repository
+            .save(user.confirm_two_factor(hashed_backup_codes))
+            .await
+            .map_err(|e| ConfirmTwoFactorEnrollmentFailure::Internal(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(user.id, AuthEvent::TwoFactorEnabled))
+            .await;
+
+        Ok(backup_codes)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ConfirmTwoFactorEnrollmentFailure {
+    #[error("User does not exist!")]
+    NotFound,
+    #[error("Two-factor enrollment has not been started")]
+    NotStarted,
+    #[error("Invalid code")]
+    InvalidCode,
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
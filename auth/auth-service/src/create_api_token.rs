@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    api_token::{generate_token, hash_token},
+    events::{AuthEvent, DomainEvent},
+    models::ApiToken,
+    ports::{event_publisher::EventPublisher, user_repository::UserRepository},
+};
+
+#[derive(Clone)]
+pub struct CreateApiToken {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateApiTokenInput {
+    pub user_id: String,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreatedApiToken {
+    pub api_token: ApiToken,
+    // The plain token, this is the only time it is available
+    pub token: String,
+}
+
+pub type CreateApiTokenOutput = Result<CreatedApiToken, CreateApiTokenFailure>;
+
+impl CreateApiToken {
+    pub async fn create_api_token(&self, input: CreateApiTokenInput) -> CreateApiTokenOutput {
+        let name = input.name.trim().to_string();
+        if name.is_empty() {
+            return Err(CreateApiTokenFailure::EmptyName);
+        }
+
+        let user = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(|e| CreateApiTokenFailure::Internal(e.to_string()))?
+            .ok_or(CreateApiTokenFailure::NotFound)?;
+
+        let token = generate_token();
+        let api_token = ApiToken::new(user.id, name, hash_token(&token));
+
+        self.user_repository
+            .save_api_token(api_token.clone())
+            .await
+            .map_err(|e| CreateApiTokenFailure::Internal(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                api_token.user_id.clone(),
+                AuthEvent::ApiTokenCreated {
+                    api_token: api_token.clone(),
+                },
+            ))
+            .await;
+
+        Ok(CreatedApiToken { api_token, token })
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum CreateApiTokenFailure {
+    #[error("User does not exist")]
+    NotFound,
+    #[error("Token name can not be empty")]
+    EmptyName,
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -4,13 +4,15 @@ use password_auth::generate_hash;
 use thiserror::Error;
 
 use crate::{
+    events::{AuthEvent, DomainEvent},
     models::{User, UserRole},
-    ports::user_repository::UserRepository,
+    ports::{event_publisher::EventPublisher, user_repository::UserRepository},
 };
 
 #[derive(Clone)]
 pub struct CreateUser {
     pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -43,12 +45,26 @@ impl CreateUser {
             hashed_password,
             role: UserRole::new(&input.role)
                 .ok_or(CreateUserFailure::InvalidUserRole(input.role))?,
+            invite_token: None,
+            display_name: None,
+            default_worksite_id: None,
+            two_factor: None,
+            oidc_subject: None,
         };
         self.user_repository
             .save(new_user.clone())
             .await
             .map_err(|e| CreateUserFailure::Internal(e.to_string()))?;
 
+        self.event_publisher
+            .publish(DomainEvent::new(
+                new_user.id.clone(),
+                AuthEvent::UserCreated {
+                    user: new_user.clone(),
+                },
+            ))
+            .await;
+
         Ok(new_user)
     }
 }
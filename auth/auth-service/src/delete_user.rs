@@ -2,11 +2,15 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
-use crate::ports::user_repository::UserRepository;
+use crate::{
+    events::{AuthEvent, DomainEvent},
+    ports::{event_publisher::EventPublisher, user_repository::UserRepository},
+};
 
 #[derive(Clone)]
 pub struct DeleteUser {
     pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -19,11 +23,26 @@ pub type DeleteUserOutput = Result<(), DeleteUserFailure>;
 
 impl DeleteUser {
     pub async fn delete_user(&self, input: DeleteUserInput) -> DeleteUserOutput {
+        let user = self
+            .user_repository
+            .find_by_id(input.user_id.clone())
+            .await
+            .map_err(|e| DeleteUserFailure::Internal(e.to_string()))?;
+
         self.user_repository
             .delete_by_id(input.user_id)
             .await
             .map_err(|e| DeleteUserFailure::Internal(e.to_string()))?;
 
+        if let Some(user) = user {
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    user.id.clone(),
+                    AuthEvent::UserDeleted { user },
+                ))
+                .await;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use password_auth::verify_password;
+use thiserror::Error;
+
+use crate::{
+    events::{AuthEvent, DomainEvent},
+    models::UserRole,
+    ports::{event_publisher::EventPublisher, user_repository::UserRepository},
+};
+
+#[derive(Clone)]
+pub struct DisableTwoFactor {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+    pub required_roles: Vec<UserRole>,
+}
+
+#[derive(Clone, Debug)]
+pub struct DisableTwoFactorInput {
+    pub user_id: String,
+    pub current_password: String,
+}
+
+pub type DisableTwoFactorOutput = Result<(), DisableTwoFactorFailure>;
+
+impl DisableTwoFactor {
+    pub async fn disable_two_factor(&self, input: DisableTwoFactorInput) -> DisableTwoFactorOutput {
+        let user = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(|e| DisableTwoFactorFailure::Internal(e.to_string()))?
+            .ok_or(DisableTwoFactorFailure::NotFound)?;
+
+        if self.required_roles.contains(&user.role) {
+            return Err(DisableTwoFactorFailure::RequiredForRole(
+                user.role.to_string(),
+            ));
+        }
+
+        verify_password(input.current_password, &user.hashed_password)
+            .map_err(|_| DisableTwoFactorFailure::WrongPassword)?;
+
+        self.user_repository
+            .save(user.disable_two_factor())
+            .await
+            .map_err(|e| DisableTwoFactorFailure::Internal(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(user.id, AuthEvent::TwoFactorDisabled))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum DisableTwoFactorFailure {
+    #[error("User does not exist!")]
+    NotFound,
+    #[error("Wrong password!")]
+    WrongPassword,
+    #[error("Two-factor authentication is required for this role")]
+    RequiredForRole(String),
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+
+use crate::models::{ApiToken, User};
+
+/**
+* What happened to a user's account, published by the commands after they save.
+* Subscribers such as audit logging react to these instead of each command knowing about them.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct DomainEvent {
+    pub user_id: String,
+    pub occurred_at: DateTime<Utc>,
+    pub event: AuthEvent,
+}
+
+impl DomainEvent {
+    pub fn new(user_id: String, event: AuthEvent) -> Self {
+        Self {
+            user_id,
+            occurred_at: Utc::now(),
+            event,
+        }
+    }
+}
+
+// Changes carry the user before and after. Subscribers must not expose the password hash
+// or two-factor secrets that come along with them.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AuthEvent {
+    UserCreated { user: User },
+    UserUpdated { previous: User, user: User },
+    UserDeleted { user: User },
+    UserInvited { user: User },
+    InviteAccepted { user: User },
+    InviteRevoked { user: User },
+    // Signed in with OIDC for the first time, or the identity provider changed their account
+    UserProvisioned { previous: Option<User>, user: User },
+    ProfileUpdated { previous: User, user: User },
+    PasswordChanged,
+    UserUnlocked,
+    TwoFactorEnrollmentStarted,
+    TwoFactorEnabled,
+    TwoFactorDisabled,
+    BackupCodeUsed,
+    ApiTokenCreated { api_token: ApiToken },
+    ApiTokenRevoked { api_token: ApiToken },
+}
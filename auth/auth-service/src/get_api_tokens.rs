@@ -0,0 +1,39 @@
+use std::{cmp::Reverse, sync::Arc};
+
+use thiserror::Error;
+
+use crate::{models::ApiToken, ports::user_repository::UserRepository};
+
+#[derive(Clone)]
+pub struct GetApiTokens {
+    pub user_repository: Arc<dyn UserRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetApiTokensInput {
+    pub user_id: String,
+}
+
+pub type GetApiTokensOutput = Result<Vec<ApiToken>, GetApiTokensFailure>;
+
+impl GetApiTokens {
+    pub async fn get_api_tokens(&self, input: GetApiTokensInput) -> GetApiTokensOutput {
+        let mut api_tokens = self
+            .user_repository
+            .get_api_tokens_for_user(input.user_id)
+            .await
+            .map_err(|e| GetApiTokensFailure::Internal(e.to_string()))?;
+
+        api_tokens.sort_by_key(|t| Reverse(t.created_at));
+
+        Ok(api_tokens)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetApiTokensFailure {
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
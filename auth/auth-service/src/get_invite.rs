@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{models::User, ports::user_repository::UserRepository};
+
+#[derive(Clone)]
+pub struct GetInvite {
+    pub user_repository: Arc<dyn UserRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetInviteInput {
+    pub invite_token: String,
+}
+
+// Returns the pending user the invite was created for, if the invite is still open
+pub type GetInviteOutput = Result<Option<User>, GetInviteFailure>;
+
+impl GetInvite {
+    pub async fn get_invite(&self, input: GetInviteInput) -> GetInviteOutput {
+        self.user_repository
+            .find_by_invite_token(input.invite_token)
+            .await
+            .map_err(|e| GetInviteFailure::Internal(e.to_string()))
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetInviteFailure {
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use thiserror::Error;
+
+use crate::{models::LoginAttempts, ports::user_repository::UserRepository};
+
+#[derive(Clone)]
+pub struct GetLockedAccounts {
+    pub user_repository: Arc<dyn UserRepository>,
+}
+
+// Only the account lockouts that are still in effect, ip lockouts are left out
+pub type GetLockedAccountsOutput = Result<Vec<LoginAttempts>, GetLockedAccountsFailure>;
+
+impl GetLockedAccounts {
+    pub async fn get_locked_accounts(&self) -> GetLockedAccountsOutput {
+        let now = Utc::now();
+
+        let attempts = self
+            .user_repository
+            .get_login_attempts()
+            .await
+            .map_err(|e| GetLockedAccountsFailure::Unknown(e.to_string()))?;
+
+        Ok(attempts
+            .into_iter()
+            .filter(|a| a.is_account() && a.is_locked(now))
+            .collect())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetLockedAccountsFailure {
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    models::{TwoFactorStatus, UserRole},
+    ports::user_repository::UserRepository,
+};
+
+#[derive(Clone)]
+pub struct GetTwoFactorStatus {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub required_roles: Vec<UserRole>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetTwoFactorStatusInput {
+    pub user_id: String,
+}
+
+pub type GetTwoFactorStatusOutput = Result<TwoFactorStatus, GetTwoFactorStatusFailure>;
+
+impl GetTwoFactorStatus {
+    pub async fn get_two_factor_status(
+        &self,
+        input: GetTwoFactorStatusInput,
+    ) -> GetTwoFactorStatusOutput {
+        let user = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(|e| GetTwoFactorStatusFailure::Internal(e.to_string()))?
+            .ok_or(GetTwoFactorStatusFailure::NotFound)?;
+
+        if user.has_two_factor() {
+            Ok(TwoFactorStatus::Enabled)
+        } else if self.required_roles.contains(&user.role) {
+            Ok(TwoFactorStatus::Required)
+        } else {
+            Ok(TwoFactorStatus::Disabled)
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetTwoFactorStatusFailure {
+    #[error("User does not exist!")]
+    NotFound,
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
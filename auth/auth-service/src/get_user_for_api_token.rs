@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use thiserror::Error;
+
+use crate::{api_token::hash_token, models::User, ports::user_repository::UserRepository};
+
+#[derive(Clone)]
+pub struct GetUserForApiToken {
+    pub user_repository: Arc<dyn UserRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetUserForApiTokenInput {
+    pub token: String,
+}
+
+pub type GetUserForApiTokenOutput = Result<User, GetUserForApiTokenFailure>;
+
+impl GetUserForApiToken {
+    pub async fn get_user_for_api_token(
+        &self,
+        input: GetUserForApiTokenInput,
+    ) -> GetUserForApiTokenOutput {
+        let api_token = self
+            .user_repository
+            .find_api_token_by_hash(hash_token(&input.token))
+            .await
+            .map_err(|e| GetUserForApiTokenFailure::Internal(e.to_string()))?
+            .ok_or(GetUserForApiTokenFailure::InvalidToken)?;
+
+        // Tokens outlive deleted users in storage, so the user has to be checked every time
+        let user = self
+            .user_repository
+            .find_by_id(api_token.user_id.clone())
+            .await
+            .map_err(|e| GetUserForApiTokenFailure::Internal(e.to_string()))?
+            .filter(|user| !user.is_pending_invite())
+            .ok_or(GetUserForApiTokenFailure::InvalidToken)?;
+
+        self.user_repository
+            .save_api_token(api_token.used(Utc::now()))
+            .await
+            .map_err(|e| GetUserForApiTokenFailure::Internal(e.to_string()))?;
+
+        Ok(user)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetUserForApiTokenFailure {
+    #[error("Api token is not valid")]
+    InvalidToken,
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -1,19 +1,26 @@
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
 use password_auth::verify_password;
 use thiserror::Error;
 
-use crate::{models::User, ports::user_repository::UserRepository};
+use crate::{
+    models::{LockoutEvent, LoginAttempts, LoginThrottlePolicy, User},
+    ports::user_repository::UserRepository,
+};
 
 #[derive(Clone)]
 pub struct GetUserForLogin {
     pub user_repository: Arc<dyn UserRepository>,
+    pub throttle_policy: LoginThrottlePolicy,
 }
 
 #[derive(Clone, Debug)]
 pub struct GetUserForLoginInput {
     pub email: String,
     pub password: String,
+    // Used to throttle attempts per client in addition to per account
+    pub client_ip: Option<String>,
 }
 
 // Change the return type, if needed
@@ -21,18 +28,106 @@ pub type GetUserForLoginOutput = Result<User, GetUserForLoginFailure>;
 
 impl GetUserForLogin {
     pub async fn get_user_for_login(&self, input: GetUserForLoginInput) -> GetUserForLoginOutput {
+        let now = Utc::now();
+
+        let mut tracked = vec![(
+            LoginAttempts::account_key(&input.email),
+            self.throttle_policy.max_account_failures,
+        )];
+        if let Some(ip) = &input.client_ip {
+            tracked.push((
+                LoginAttempts::ip_key(ip),
+                self.throttle_policy.max_ip_failures,
+            ));
+        }
+
+        // Refuse to even check the password while the account or ip is throttled
+        let mut attempts = vec![];
+        for (key, max_failures) in tracked {
+            let existing = self
+                .user_repository
+                .find_login_attempts(key.clone())
+                .await
+                .map_err(|e| GetUserForLoginFailure::Internal(e.to_string()))?
+                .unwrap_or(LoginAttempts::new(key));
+
+            if existing.is_locked(now) {
+                return Err(GetUserForLoginFailure::AccountLocked(
+                    existing.locked_until.unwrap_or(now),
+                ));
+            }
+            if let Some(retry_at) = existing.blocked_until(&self.throttle_policy, now) {
+                return Err(GetUserForLoginFailure::TooManyAttempts(retry_at));
+            }
+
+            attempts.push((existing, max_failures));
+        }
+
         let user = self
             .user_repository
             .find_by_email(input.email)
             .await
             .map_err(|e| GetUserForLoginFailure::Internal(e.to_string()))?;
 
-        match user {
+        let result = match user {
+            Some(user) if user.is_pending_invite() => Err(GetUserForLoginFailure::InvitePending),
             Some(user) => verify_password(input.password, &user.hashed_password)
                 .map(|_| user)
                 .map_err(|_| GetUserForLoginFailure::WrongPassword),
             None => Err(GetUserForLoginFailure::UserNotFound),
+        };
+
+        match &result {
+            // Only the account is cleared, otherwise one good login from an ip
+            // would reset the failures from guessing at other accounts. Users with
+            // two-factor are cleared once their code is verified instead.
+            Ok(user) => {
+                let (account_attempts, _) = &attempts[0];
+                if account_attempts.failures > 0 && !user.has_two_factor() {
+                    self.user_repository
+                        .delete_login_attempts(account_attempts.key.clone())
+                        .await
+                        .map_err(|e| GetUserForLoginFailure::Internal(e.to_string()))?;
+                }
+            }
+            Err(GetUserForLoginFailure::WrongPassword)
+            | Err(GetUserForLoginFailure::UserNotFound) => {
+                for (existing, max_failures) in attempts {
+                    self.record_failure(existing.key, max_failures, now).await?;
+                }
+            }
+            Err(_) => {}
         }
+
+        result
+    }
+
+    async fn record_failure(
+        &self,
+        key: String,
+        max_failures: u32,
+        now: DateTime<Utc>,
+    ) -> Result<(), GetUserForLoginFailure> {
+        let attempts = self
+            .user_repository
+            .record_login_failure(key, max_failures, &self.throttle_policy, now)
+            .await
+            .map_err(|e| GetUserForLoginFailure::Internal(e.to_string()))?;
+
+        // The count goes up atomically, so only the failure that reached the limit logs it
+        if attempts.is_locked(now) && attempts.failures == max_failures {
+            tracing::warn!(
+                "Locking {} after {} failures",
+                attempts.key,
+                attempts.failures
+            );
+            self.user_repository
+                .save_lockout_event(LockoutEvent::new(&attempts, now))
+                .await
+                .map_err(|e| GetUserForLoginFailure::Internal(e.to_string()))?;
+        }
+
+        Ok(())
     }
 }
 
@@ -42,6 +137,12 @@ pub enum GetUserForLoginFailure {
     UserNotFound,
     #[error("Wrong password!")]
     WrongPassword,
+    #[error("User has not accepted their invite yet!")]
+    InvitePending,
+    #[error("Too many login attempts, try again later")]
+    TooManyAttempts(DateTime<Utc>),
+    #[error("Account is locked")]
+    AccountLocked(DateTime<Utc>),
     #[error("Internal Error")]
     Internal(String),
     #[error("Something went wrong")]
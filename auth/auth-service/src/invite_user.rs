@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{AuthEvent, DomainEvent},
+    models::{User, UserRole},
+    ports::{event_publisher::EventPublisher, user_repository::UserRepository},
+};
+
+#[derive(Clone)]
+pub struct InviteUser {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct InviteUserInput {
+    pub email: String,
+    pub role: String,
+}
+
+// Returns the pending user, which carries the invite token
+pub type InviteUserOutput = Result<User, InviteUserFailure>;
+
+impl InviteUser {
+    pub async fn invite_user(&self, input: InviteUserInput) -> InviteUserOutput {
+        // Fail if the email is already in use, whether by an active user or another invite
+        self.user_repository
+            .find_by_email(input.email.clone())
+            .await
+            .map_err(|e| InviteUserFailure::Internal(e.to_string()))
+            .and_then(|user| match user {
+                Some(_) => Err(InviteUserFailure::UserAlreadyExists(input.email.clone())),
+                None => Ok(()),
+            })?;
+
+        let role =
+            UserRole::new(&input.role).ok_or(InviteUserFailure::InvalidUserRole(input.role))?;
+
+        let invited_user = User::invite(input.email, role);
+
+        self.user_repository
+            .save(invited_user.clone())
+            .await
+            .map_err(|e| InviteUserFailure::Internal(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                invited_user.id.clone(),
+                AuthEvent::UserInvited {
+                    user: invited_user.clone(),
+                },
+            ))
+            .await;
+
+        Ok(invited_user)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum InviteUserFailure {
+    #[error("User already exists!")]
+    UserAlreadyExists(String),
+    #[error("Role not recognized")]
+    InvalidUserRole(String),
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -1,5 +1,6 @@
 pub mod create_user;
 pub mod delete_user;
+pub mod events;
 pub mod get_user;
 pub mod get_user_for_login;
 pub mod get_users;
@@ -7,4 +8,25 @@ pub mod models;
 pub mod ports;
 pub mod service;
 
-pub mod update_user;
\ No newline at end of file
+pub mod update_user;
+pub mod invite_user;
+pub mod get_invite;
+pub mod accept_invite;
+pub mod revoke_invite;
+pub mod change_password;
+pub mod update_profile;
+pub mod unlock_user;
+pub mod get_locked_accounts;
+pub mod get_two_factor_status;
+pub mod begin_two_factor_enrollment;
+pub mod confirm_two_factor_enrollment;
+pub mod disable_two_factor;
+pub mod verify_two_factor;
+pub mod two_factor;
+pub mod begin_oidc_login;
+pub mod complete_oidc_login;
+pub mod api_token;
+pub mod create_api_token;
+pub mod get_api_tokens;
+pub mod revoke_api_token;
+pub mod get_user_for_api_token;
@@ -1,4 +1,5 @@
 use axum_login::AuthUser;
+use chrono::{DateTime, Duration, Utc};
 use std::fmt::Display;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -7,6 +8,13 @@ pub struct User {
     pub email: String,
     pub hashed_password: String,
     pub role: UserRole,
+    // Set while the user has been invited but has not chosen a password yet
+    pub invite_token: Option<String>,
+    pub display_name: Option<String>,
+    pub default_worksite_id: Option<String>,
+    pub two_factor: Option<TwoFactor>,
+    // The subject claim from the identity provider, for users who sign in with OIDC
+    pub oidc_subject: Option<String>,
 }
 
 impl User {
@@ -16,21 +24,217 @@ impl User {
             email,
             hashed_password,
             role: UserRole::Organizer,
+            invite_token: None,
+            display_name: None,
+            default_worksite_id: None,
+            two_factor: None,
+            oidc_subject: None,
+        }
+    }
+    pub fn invite(email: String, role: UserRole) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            email,
+            hashed_password: "".into(),
+            role,
+            invite_token: Some(uuid::Uuid::new_v4().to_string()),
+            display_name: None,
+            default_worksite_id: None,
+            two_factor: None,
+            oidc_subject: None,
         }
     }
     pub fn update(&self, email: String, role: UserRole) -> Self {
         Self {
-            id: self.id.clone(),
             email,
-            hashed_password: self.hashed_password.clone(),
             role,
+            ..self.clone()
+        }
+    }
+    pub fn accept_invite(&self, hashed_password: String) -> Self {
+        Self {
+            hashed_password,
+            invite_token: None,
+            ..self.clone()
+        }
+    }
+    pub fn update_profile(
+        &self,
+        display_name: Option<String>,
+        default_worksite_id: Option<String>,
+    ) -> Self {
+        Self {
+            display_name,
+            default_worksite_id,
+            ..self.clone()
+        }
+    }
+    pub fn change_password(&self, hashed_password: String) -> Self {
+        Self {
+            hashed_password,
+            ..self.clone()
+        }
+    }
+    // Falls back to the email for users who have not set a display name
+    pub fn name(&self) -> String {
+        self.display_name.clone().unwrap_or(self.email.clone())
+    }
+    pub fn begin_two_factor(&self, secret: String) -> Self {
+        Self {
+            two_factor: Some(TwoFactor {
+                secret,
+                confirmed: false,
+                backup_codes: vec![],
+            }),
+            ..self.clone()
+        }
+    }
+    pub fn confirm_two_factor(&self, hashed_backup_codes: Vec<String>) -> Self {
+        Self {
+            two_factor: self.two_factor.clone().map(|two_factor| TwoFactor {
+                confirmed: true,
+                backup_codes: hashed_backup_codes,
+                ..two_factor
+            }),
+            ..self.clone()
+        }
+    }
+    pub fn disable_two_factor(&self) -> Self {
+        Self {
+            two_factor: None,
+            ..self.clone()
+        }
+    }
+    // Backup codes only work once, so a used code is removed
+    pub fn use_backup_code(&self, hashed_backup_code: &String) -> Option<Self> {
+        let two_factor = self.two_factor.clone()?;
+        if !two_factor.backup_codes.contains(hashed_backup_code) {
+            return None;
+        }
+
+        Some(Self {
+            two_factor: Some(TwoFactor {
+                backup_codes: two_factor
+                    .backup_codes
+                    .into_iter()
+                    .filter(|code| code != hashed_backup_code)
+                    .collect(),
+                ..two_factor
+            }),
+            ..self.clone()
+        })
+    }
+    // Users signing in through the identity provider never get a local password
+    pub fn provision_oidc(identity: &OidcIdentity, role: UserRole) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            email: identity.email.clone(),
+            hashed_password: "".into(),
+            role,
+            invite_token: None,
+            display_name: identity.name.clone(),
+            default_worksite_id: None,
+            two_factor: None,
+            oidc_subject: Some(identity.subject.clone()),
+        }
+    }
+    // Linking an invited user also accepts their invite
+    pub fn link_oidc(&self, subject: String) -> Self {
+        Self {
+            oidc_subject: Some(subject),
+            invite_token: None,
+            ..self.clone()
         }
     }
+    pub fn has_two_factor(&self) -> bool {
+        self.two_factor.as_ref().is_some_and(|t| t.confirmed)
+    }
+    pub fn is_pending_invite(&self) -> bool {
+        self.invite_token.is_some()
+    }
     pub fn has_perm(&self, permission: UserPermission) -> bool {
         self.role.has_perm(permission)
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct TwoFactor {
+    // Base32 encoded TOTP secret
+    pub secret: String,
+    // Stays false until the user proves their authenticator works by entering a code
+    pub confirmed: bool,
+    // Hashes of the unused backup codes
+    pub backup_codes: Vec<String>,
+}
+
+// What the user needs to add the account to their authenticator app
+#[derive(Debug, Clone, PartialEq)]
+pub struct TwoFactorEnrollment {
+    pub secret: String,
+    pub otpauth_url: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TwoFactorStatus {
+    Enabled,
+    Disabled,
+    // Not enrolled yet, but the user's role requires it
+    Required,
+}
+
+// Where to send the user to sign in with the identity provider
+#[derive(Debug, Clone, PartialEq)]
+pub struct OidcAuthorization {
+    pub url: String,
+    pub pending: OidcPendingLogin,
+}
+
+// Kept in the session until the identity provider redirects back
+#[derive(Debug, Clone, PartialEq)]
+pub struct OidcPendingLogin {
+    pub csrf_state: String,
+    pub nonce: String,
+    pub pkce_verifier: String,
+}
+
+// The verified claims from the identity provider's id token
+#[derive(Debug, Clone, PartialEq)]
+pub struct OidcIdentity {
+    pub subject: String,
+    pub email: String,
+    pub email_verified: bool,
+    pub name: Option<String>,
+    // The values of the configured role claim, e.g. the user's groups
+    pub roles: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OidcRoleMapping {
+    // Given to new users when none of their claim values are mapped
+    pub default_role: UserRole,
+    // Claim values and the role they grant. The first match wins, so list the most
+    // privileged roles first.
+    pub claim_roles: Vec<(String, UserRole)>,
+}
+
+impl OidcRoleMapping {
+    pub fn role_for(&self, identity: &OidcIdentity) -> Option<UserRole> {
+        self.claim_roles
+            .iter()
+            .find(|(value, _)| identity.roles.contains(value))
+            .map(|(_, role)| role.clone())
+    }
+}
+
+impl Default for OidcRoleMapping {
+    fn default() -> Self {
+        Self {
+            default_role: UserRole::Organizer,
+            claim_roles: vec![],
+        }
+    }
+}
+
 /**
 * Need to implement this for axum-login
 */
@@ -72,6 +276,15 @@ impl UserRole {
                 UserPermission::DeleteAssignedWorker => false,
                 UserPermission::DeleteTag => false,
                 UserPermission::DeleteAssessment => false,
+                UserPermission::ReadAuditLog => false,
+                UserPermission::ManageCustomFields => false,
+                UserPermission::DeleteConversation => false,
+                UserPermission::DeleteTask => false,
+                UserPermission::UnassignTurf => false,
+                UserPermission::DeleteAction => false,
+                UserPermission::RemoveRelationship => false,
+                UserPermission::ReconcileRoster => false,
+                UserPermission::MergeWorkers => false,
             },
             Self::Admin => match permission {
                 UserPermission::CreateUser => false,
@@ -81,6 +294,15 @@ impl UserRole {
                 UserPermission::DeleteAssignedWorker => true,
                 UserPermission::DeleteTag => true,
                 UserPermission::DeleteAssessment => true,
+                UserPermission::ReadAuditLog => false,
+                UserPermission::ManageCustomFields => true,
+                UserPermission::DeleteConversation => true,
+                UserPermission::DeleteTask => true,
+                UserPermission::UnassignTurf => true,
+                UserPermission::DeleteAction => true,
+                UserPermission::RemoveRelationship => true,
+                UserPermission::ReconcileRoster => true,
+                UserPermission::MergeWorkers => true,
             },
             Self::SuperAdmin => true,
         }
@@ -107,6 +329,15 @@ pub enum UserPermission {
     DeleteAssignedWorker,
     DeleteTag,
     DeleteAssessment,
+    ReadAuditLog,
+    ManageCustomFields,
+    DeleteConversation,
+    DeleteTask,
+    UnassignTurf,
+    DeleteAction,
+    RemoveRelationship,
+    ReconcileRoster,
+    MergeWorkers,
 }
 
 impl From<&str> for UserPermission {
@@ -119,7 +350,191 @@ impl From<&str> for UserPermission {
             "assigned_worker.delete" => Self::DeleteAssignedWorker,
             "tag.delete" => Self::DeleteTag,
             "assessment.delete" => Self::DeleteAssessment,
+            "audit_log.read" => Self::ReadAuditLog,
+            "custom_field.manage" => Self::ManageCustomFields,
+            "conversation.delete" => Self::DeleteConversation,
+            "task.delete" => Self::DeleteTask,
+            "turf.unassign" => Self::UnassignTurf,
+            "action.delete" => Self::DeleteAction,
+            "relationship.remove" => Self::RemoveRelationship,
+            "roster.reconcile" => Self::ReconcileRoster,
+            "worker.merge" => Self::MergeWorkers,
             _ => panic!("Permission does not exist"),
         }
     }
 }
+
+/**
+* Failed login tracking. Attempts are kept per account and per client ip,
+* see LoginAttempts::account_key and LoginAttempts::ip_key.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoginAttempts {
+    pub key: String,
+    pub failures: u32,
+    pub last_failure_at: Option<DateTime<Utc>>,
+    pub locked_until: Option<DateTime<Utc>>,
+}
+
+impl LoginAttempts {
+    pub fn new(key: String) -> Self {
+        Self {
+            key,
+            failures: 0,
+            last_failure_at: None,
+            locked_until: None,
+        }
+    }
+    pub fn account_key(email: &str) -> String {
+        format!("account:{}", email.to_lowercase())
+    }
+    pub fn ip_key(ip: &str) -> String {
+        format!("ip:{}", ip)
+    }
+    pub fn is_account(&self) -> bool {
+        self.key.starts_with("account:")
+    }
+    pub fn is_locked(&self, now: DateTime<Utc>) -> bool {
+        self.locked_until.is_some_and(|until| until > now)
+    }
+    // When the next attempt is allowed, if it is not allowed yet
+    pub fn blocked_until(
+        &self,
+        policy: &LoginThrottlePolicy,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        if self.is_locked(now) {
+            return self.locked_until;
+        }
+
+        let failures = self.current_failures(policy, now);
+        if failures == 0 {
+            return None;
+        }
+
+        self.last_failure_at
+            .map(|last| last + policy.backoff(failures))
+            .filter(|next_attempt_at| *next_attempt_at > now)
+    }
+    pub fn record_failure(
+        &self,
+        max_failures: u32,
+        policy: &LoginThrottlePolicy,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let failures = self.current_failures(policy, now) + 1;
+        let locked_until = if failures >= max_failures {
+            Some(now + policy.lockout_duration)
+        } else {
+            None
+        };
+
+        Self {
+            key: self.key.clone(),
+            failures,
+            last_failure_at: Some(now),
+            locked_until,
+        }
+    }
+    // Failures are forgotten once a lockout has expired or after a quiet period
+    fn current_failures(&self, policy: &LoginThrottlePolicy, now: DateTime<Utc>) -> u32 {
+        let lockout_expired = self.locked_until.is_some_and(|until| until <= now);
+        let window_expired = match self.last_failure_at {
+            Some(last) => last + policy.failure_window <= now,
+            None => true,
+        };
+
+        if lockout_expired || window_expired {
+            0
+        } else {
+            self.failures
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoginThrottlePolicy {
+    // Failures before an account is locked
+    pub max_account_failures: u32,
+    // Failures before a client ip is locked. Higher than the account limit
+    // since many users can share an ip.
+    pub max_ip_failures: u32,
+    pub base_backoff: Duration,
+    pub max_backoff: Duration,
+    pub lockout_duration: Duration,
+    pub failure_window: Duration,
+}
+
+impl LoginThrottlePolicy {
+    // Doubles with every failure: 1s, 2s, 4s, ... up to max_backoff
+    pub fn backoff(&self, failures: u32) -> Duration {
+        let exponent = failures.saturating_sub(1).min(16);
+        std::cmp::min(self.base_backoff * 2_i32.pow(exponent), self.max_backoff)
+    }
+}
+
+impl Default for LoginThrottlePolicy {
+    fn default() -> Self {
+        Self {
+            max_account_failures: 5,
+            max_ip_failures: 20,
+            base_backoff: Duration::seconds(1),
+            max_backoff: Duration::minutes(5),
+            lockout_duration: Duration::minutes(15),
+            failure_window: Duration::hours(1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LockoutEvent {
+    pub id: String,
+    pub key: String,
+    pub failures: u32,
+    pub locked_at: DateTime<Utc>,
+    pub locked_until: DateTime<Utc>,
+}
+
+impl LockoutEvent {
+    pub fn new(attempts: &LoginAttempts, locked_at: DateTime<Utc>) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            key: attempts.key.clone(),
+            failures: attempts.failures,
+            locked_at,
+            locked_until: attempts.locked_until.unwrap_or(locked_at),
+        }
+    }
+}
+
+// A personal token for scripts using the JSON API. It acts as the user it belongs to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiToken {
+    pub id: String,
+    pub user_id: String,
+    // So the user can tell their tokens apart, e.g. "Roster sync"
+    pub name: String,
+    // Only the hash is stored, the token itself is shown once when it is created
+    pub hashed_token: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl ApiToken {
+    pub fn new(user_id: String, name: String, hashed_token: String) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            user_id,
+            name,
+            hashed_token,
+            created_at: Utc::now(),
+            last_used_at: None,
+        }
+    }
+    pub fn used(&self, at: DateTime<Utc>) -> Self {
+        Self {
+            last_used_at: Some(at),
+            ..self.clone()
+        }
+    }
+}
@@ -1,2 +1,4 @@
 //##PLOP INSERT MOD HOOK##
+pub mod event_publisher;
+pub mod identity_provider;
 pub mod user_repository;
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::events::DomainEvent;
+
+/**
+* Where commands send their events once a change is saved. Publishing can't fail the
+* command, adapters are expected to log their own failures.
+*/
+#[async_trait]
+pub trait EventPublisher: Send + Sync + 'static {
+    async fn publish(&self, event: DomainEvent);
+}
+
+// Sends every event to each of the publishers in turn
+pub struct EventPublishers(pub Vec<Arc<dyn EventPublisher>>);
+
+#[async_trait]
+impl EventPublisher for EventPublishers {
+    async fn publish(&self, event: DomainEvent) {
+        for publisher in &self.0 {
+            publisher.publish(event.clone()).await;
+        }
+    }
+}
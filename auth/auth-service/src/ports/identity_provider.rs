@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::models::{OidcAuthorization, OidcIdentity, OidcPendingLogin};
+
+#[async_trait]
+pub trait IdentityProvider: Send + Sync + 'static {
+    fn authorize(&self) -> OidcAuthorization;
+    async fn exchange_code(
+        &self,
+        code: String,
+        pending: OidcPendingLogin,
+    ) -> Result<OidcIdentity, IdentityProviderFailure>;
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum IdentityProviderFailure {
+    #[error("Identity provider rejected the authorization code")]
+    InvalidCode(String),
+    #[error("Id token could not be verified")]
+    InvalidToken(String),
+    #[error("Id token is missing the {0} claim")]
+    MissingClaim(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
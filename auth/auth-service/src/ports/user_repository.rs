@@ -1,7 +1,8 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 
-use crate::models::User;
+use crate::models::{ApiToken, LockoutEvent, LoginAttempts, LoginThrottlePolicy, User};
 
 #[async_trait]
 pub trait UserRepository: Send + Sync + 'static {
@@ -9,7 +10,42 @@ pub trait UserRepository: Send + Sync + 'static {
     async fn get_users(&self) -> Result<Vec<User>, RepositoryFailure>;
     async fn save(&self, user: User) -> Result<(), RepositoryFailure>;
     async fn find_by_email(&self, email: String) -> Result<Option<User>, RepositoryFailure>;
+    async fn find_by_invite_token(
+        &self,
+        invite_token: String,
+    ) -> Result<Option<User>, RepositoryFailure>;
+    async fn find_by_oidc_subject(
+        &self,
+        oidc_subject: String,
+    ) -> Result<Option<User>, RepositoryFailure>;
     async fn delete_by_id(&self, id: String) -> Result<(), RepositoryFailure>;
+    async fn find_login_attempts(
+        &self,
+        key: String,
+    ) -> Result<Option<LoginAttempts>, RepositoryFailure>;
+    async fn get_login_attempts(&self) -> Result<Vec<LoginAttempts>, RepositoryFailure>;
+    async fn save_login_attempts(&self, attempts: LoginAttempts) -> Result<(), RepositoryFailure>;
+    // Counts a failure in a single step, so concurrent failures can't overwrite each other
+    async fn record_login_failure(
+        &self,
+        key: String,
+        max_failures: u32,
+        policy: &LoginThrottlePolicy,
+        now: DateTime<Utc>,
+    ) -> Result<LoginAttempts, RepositoryFailure>;
+    async fn delete_login_attempts(&self, key: String) -> Result<(), RepositoryFailure>;
+    async fn save_lockout_event(&self, event: LockoutEvent) -> Result<(), RepositoryFailure>;
+    async fn get_lockout_events(&self) -> Result<Vec<LockoutEvent>, RepositoryFailure>;
+    async fn save_api_token(&self, api_token: ApiToken) -> Result<(), RepositoryFailure>;
+    async fn find_api_token_by_hash(
+        &self,
+        hashed_token: String,
+    ) -> Result<Option<ApiToken>, RepositoryFailure>;
+    async fn get_api_tokens_for_user(
+        &self,
+        user_id: String,
+    ) -> Result<Vec<ApiToken>, RepositoryFailure>;
+    async fn delete_api_token(&self, id: String) -> Result<(), RepositoryFailure>;
 }
 
 #[derive(Error, Debug, PartialEq)]
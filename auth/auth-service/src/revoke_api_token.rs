@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{AuthEvent, DomainEvent},
+    ports::{event_publisher::EventPublisher, user_repository::UserRepository},
+};
+
+#[derive(Clone)]
+pub struct RevokeApiToken {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RevokeApiTokenInput {
+    // Users can only revoke their own tokens
+    pub user_id: String,
+    pub api_token_id: String,
+}
+
+pub type RevokeApiTokenOutput = Result<(), RevokeApiTokenFailure>;
+
+impl RevokeApiToken {
+    pub async fn revoke_api_token(&self, input: RevokeApiTokenInput) -> RevokeApiTokenOutput {
+        let api_tokens = self
+            .user_repository
+            .get_api_tokens_for_user(input.user_id)
+            .await
+            .map_err(|e| RevokeApiTokenFailure::Internal(e.to_string()))?;
+
+        let api_token = api_tokens
+            .into_iter()
+            .find(|t| t.id == input.api_token_id)
+            .ok_or(RevokeApiTokenFailure::NotFound)?;
+
+        self.user_repository
+            .delete_api_token(api_token.id.clone())
+            .await
+            .map_err(|e| RevokeApiTokenFailure::Internal(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                api_token.user_id.clone(),
+                AuthEvent::ApiTokenRevoked { api_token },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RevokeApiTokenFailure {
+    #[error("Api token does not exist")]
+    NotFound,
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
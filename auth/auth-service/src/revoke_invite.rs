@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{AuthEvent, DomainEvent},
+    ports::{event_publisher::EventPublisher, user_repository::UserRepository},
+};
+
+#[derive(Clone)]
+pub struct RevokeInvite {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RevokeInviteInput {
+    pub user_id: String,
+}
+
+pub type RevokeInviteOutput = Result<(), RevokeInviteFailure>;
+
+impl RevokeInvite {
+    pub async fn revoke_invite(&self, input: RevokeInviteInput) -> RevokeInviteOutput {
+        let user = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(|e| RevokeInviteFailure::Internal(e.to_string()))?
+            .ok_or(RevokeInviteFailure::NotFound)?;
+
+        // Only pending users can be revoked, active users have to be deleted instead
+        if !user.is_pending_invite() {
+            return Err(RevokeInviteFailure::AlreadyAccepted);
+        }
+
+        self.user_repository
+            .delete_by_id(user.id.clone())
+            .await
+            .map_err(|e| RevokeInviteFailure::Internal(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                user.id.clone(),
+                AuthEvent::InviteRevoked { user },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RevokeInviteFailure {
+    #[error("Invite does not exist")]
+    NotFound,
+    #[error("Invite was already accepted")]
+    AlreadyAccepted,
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -1,19 +1,70 @@
 use std::sync::Arc;
 
 use crate::{
+    accept_invite::{AcceptInvite, AcceptInviteInput, AcceptInviteOutput},
+    begin_oidc_login::{BeginOidcLogin, BeginOidcLoginOutput},
+    begin_two_factor_enrollment::{
+        BeginTwoFactorEnrollment, BeginTwoFactorEnrollmentInput, BeginTwoFactorEnrollmentOutput,
+    },
+    change_password::{ChangePassword, ChangePasswordInput, ChangePasswordOutput},
+    complete_oidc_login::{CompleteOidcLogin, CompleteOidcLoginInput, CompleteOidcLoginOutput},
+    confirm_two_factor_enrollment::{
+        ConfirmTwoFactorEnrollment, ConfirmTwoFactorEnrollmentInput,
+        ConfirmTwoFactorEnrollmentOutput,
+    },
+    create_api_token::{CreateApiToken, CreateApiTokenInput, CreateApiTokenOutput},
     create_user::{CreateUser, CreateUserInput, CreateUserOutput},
     delete_user::{DeleteUser, DeleteUserInput, DeleteUserOutput},
+    disable_two_factor::{DisableTwoFactor, DisableTwoFactorInput, DisableTwoFactorOutput},
+    get_api_tokens::{GetApiTokens, GetApiTokensInput, GetApiTokensOutput},
+    get_invite::{GetInvite, GetInviteInput, GetInviteOutput},
+    get_locked_accounts::{GetLockedAccounts, GetLockedAccountsOutput},
+    get_two_factor_status::{
+        GetTwoFactorStatus, GetTwoFactorStatusInput, GetTwoFactorStatusOutput,
+    },
     get_user::{GetUser, GetUserInput, GetUserOutput},
+    get_user_for_api_token::{
+        GetUserForApiToken, GetUserForApiTokenInput, GetUserForApiTokenOutput,
+    },
     get_user_for_login::{GetUserForLogin, GetUserForLoginInput, GetUserForLoginOutput},
     get_users::{GetUsers, GetUsersOutput},
-    ports::user_repository::UserRepository,
+    invite_user::{InviteUser, InviteUserInput, InviteUserOutput},
+    models::{LoginThrottlePolicy, OidcRoleMapping, UserRole},
+    ports::{
+        event_publisher::EventPublisher, identity_provider::IdentityProvider,
+        user_repository::UserRepository,
+    },
+    revoke_api_token::{RevokeApiToken, RevokeApiTokenInput, RevokeApiTokenOutput},
+    revoke_invite::{RevokeInvite, RevokeInviteInput, RevokeInviteOutput},
+    unlock_user::{UnlockUser, UnlockUserInput, UnlockUserOutput},
+    update_profile::{UpdateProfile, UpdateProfileInput, UpdateProfileOutput},
     //##PLOP INSERT COMMAND IMPORTS HOOK##
     update_user::{UpdateUser, UpdateUserInput, UpdateUserOutput},
+    verify_two_factor::{VerifyTwoFactor, VerifyTwoFactorInput, VerifyTwoFactorOutput},
 };
 
 #[derive(Clone)]
 pub struct AuthService {
     //##PLOP INSERT COMMAND HOOK##
+    pub get_user_for_api_token: GetUserForApiToken,
+    pub revoke_api_token: RevokeApiToken,
+    pub get_api_tokens: GetApiTokens,
+    pub create_api_token: CreateApiToken,
+    pub complete_oidc_login: CompleteOidcLogin,
+    pub begin_oidc_login: BeginOidcLogin,
+    pub verify_two_factor: VerifyTwoFactor,
+    pub disable_two_factor: DisableTwoFactor,
+    pub confirm_two_factor_enrollment: ConfirmTwoFactorEnrollment,
+    pub begin_two_factor_enrollment: BeginTwoFactorEnrollment,
+    pub get_two_factor_status: GetTwoFactorStatus,
+    pub unlock_user: UnlockUser,
+    pub get_locked_accounts: GetLockedAccounts,
+    pub change_password: ChangePassword,
+    pub update_profile: UpdateProfile,
+    pub revoke_invite: RevokeInvite,
+    pub accept_invite: AcceptInvite,
+    pub get_invite: GetInvite,
+    pub invite_user: InviteUser,
     pub update_user: UpdateUser,
     pub get_user: GetUser,
     pub delete_user: DeleteUser,
@@ -23,12 +74,109 @@ pub struct AuthService {
 }
 
 impl AuthService {
-    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+        two_factor_required_roles: Vec<UserRole>,
+        identity_provider: Option<Arc<dyn IdentityProvider>>,
+        oidc_role_mapping: OidcRoleMapping,
+    ) -> Self {
         Self {
             //##PLOP INSERT COMMAND INSTANTIATION HOOK##
+            get_user_for_api_token: GetUserForApiToken {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                user_repository: user_repository.clone(),
+            },
+            revoke_api_token: RevokeApiToken {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                user_repository: user_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            get_api_tokens: GetApiTokens {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                user_repository: user_repository.clone(),
+            },
+            create_api_token: CreateApiToken {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                user_repository: user_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            complete_oidc_login: CompleteOidcLogin {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                user_repository: user_repository.clone(),
+                event_publisher: event_publisher.clone(),
+                identity_provider: identity_provider.clone(),
+                role_mapping: oidc_role_mapping,
+            },
+            begin_oidc_login: BeginOidcLogin {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                identity_provider: identity_provider.clone(),
+            },
+            verify_two_factor: VerifyTwoFactor {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                user_repository: user_repository.clone(),
+                event_publisher: event_publisher.clone(),
+                throttle_policy: LoginThrottlePolicy::default(),
+            },
+            disable_two_factor: DisableTwoFactor {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                user_repository: user_repository.clone(),
+                event_publisher: event_publisher.clone(),
+                required_roles: two_factor_required_roles.clone(),
+            },
+            confirm_two_factor_enrollment: ConfirmTwoFactorEnrollment {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                user_repository: user_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            begin_two_factor_enrollment: BeginTwoFactorEnrollment {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                user_repository: user_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            get_two_factor_status: GetTwoFactorStatus {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                user_repository: user_repository.clone(),
+                required_roles: two_factor_required_roles,
+            },
+            unlock_user: UnlockUser {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                user_repository: user_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            get_locked_accounts: GetLockedAccounts {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                user_repository: user_repository.clone(),
+            },
+            change_password: ChangePassword {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                user_repository: user_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            update_profile: UpdateProfile {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                user_repository: user_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            revoke_invite: RevokeInvite {
+                user_repository: user_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            accept_invite: AcceptInvite {
+                user_repository: user_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            get_invite: GetInvite {
+                user_repository: user_repository.clone(),
+            },
+            invite_user: InviteUser {
+                user_repository: user_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
             update_user: UpdateUser {
                 // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
                 user_repository: user_repository.clone(),
+                event_publisher: event_publisher.clone(),
             },
             get_user: GetUser {
                 // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
@@ -36,18 +184,126 @@ impl AuthService {
             },
             delete_user: DeleteUser {
                 user_repository: user_repository.clone(),
+                event_publisher: event_publisher.clone(),
             },
             get_users: GetUsers {
                 user_repository: user_repository.clone(),
             },
             get_user_for_login: GetUserForLogin {
                 user_repository: user_repository.clone(),
+                throttle_policy: LoginThrottlePolicy::default(),
+            },
+            create_user: CreateUser {
+                user_repository,
+                event_publisher,
             },
-            create_user: CreateUser { user_repository },
         }
     }
 
     //##PLOP INSERT DELEGATE HOOK##
+    pub async fn get_user_for_api_token(
+        &self,
+        input: GetUserForApiTokenInput,
+    ) -> GetUserForApiTokenOutput {
+        self.get_user_for_api_token
+            .get_user_for_api_token(input)
+            .await
+    }
+
+    pub async fn revoke_api_token(&self, input: RevokeApiTokenInput) -> RevokeApiTokenOutput {
+        self.revoke_api_token.revoke_api_token(input).await
+    }
+
+    pub async fn get_api_tokens(&self, input: GetApiTokensInput) -> GetApiTokensOutput {
+        self.get_api_tokens.get_api_tokens(input).await
+    }
+
+    pub async fn create_api_token(&self, input: CreateApiTokenInput) -> CreateApiTokenOutput {
+        self.create_api_token.create_api_token(input).await
+    }
+
+    pub async fn complete_oidc_login(
+        &self,
+        input: CompleteOidcLoginInput,
+    ) -> CompleteOidcLoginOutput {
+        self.complete_oidc_login.complete_oidc_login(input).await
+    }
+
+    pub async fn begin_oidc_login(&self) -> BeginOidcLoginOutput {
+        self.begin_oidc_login.begin_oidc_login().await
+    }
+
+    pub fn is_oidc_enabled(&self) -> bool {
+        self.begin_oidc_login.is_enabled()
+    }
+
+    pub async fn verify_two_factor(&self, input: VerifyTwoFactorInput) -> VerifyTwoFactorOutput {
+        self.verify_two_factor.verify_two_factor(input).await
+    }
+
+    pub async fn disable_two_factor(&self, input: DisableTwoFactorInput) -> DisableTwoFactorOutput {
+        self.disable_two_factor.disable_two_factor(input).await
+    }
+
+    pub async fn confirm_two_factor_enrollment(
+        &self,
+        input: ConfirmTwoFactorEnrollmentInput,
+    ) -> ConfirmTwoFactorEnrollmentOutput {
+        self.confirm_two_factor_enrollment
+            .confirm_two_factor_enrollment(input)
+            .await
+    }
+
+    pub async fn begin_two_factor_enrollment(
+        &self,
+        input: BeginTwoFactorEnrollmentInput,
+    ) -> BeginTwoFactorEnrollmentOutput {
+        self.begin_two_factor_enrollment
+            .begin_two_factor_enrollment(input)
+            .await
+    }
+
+    pub async fn get_two_factor_status(
+        &self,
+        input: GetTwoFactorStatusInput,
+    ) -> GetTwoFactorStatusOutput {
+        self.get_two_factor_status
+            .get_two_factor_status(input)
+            .await
+    }
+
+    pub async fn unlock_user(&self, input: UnlockUserInput) -> UnlockUserOutput {
+        self.unlock_user.unlock_user(input).await
+    }
+
+    pub async fn get_locked_accounts(&self) -> GetLockedAccountsOutput {
+        self.get_locked_accounts.get_locked_accounts().await
+    }
+
+    pub async fn change_password(&self, input: ChangePasswordInput) -> ChangePasswordOutput {
+        self.change_password.change_password(input).await
+    }
+
+    pub async fn update_profile(&self, input: UpdateProfileInput) -> UpdateProfileOutput {
+        self.update_profile.update_profile(input).await
+    }
+
+    pub async fn revoke_invite(&self, input: RevokeInviteInput) -> RevokeInviteOutput {
+        self.revoke_invite.revoke_invite(input).await
+    }
+
+    pub async fn accept_invite(&self, input: AcceptInviteInput) -> AcceptInviteOutput {
+        self.accept_invite.accept_invite(input).await
+    }
+
+    pub async fn get_invite(&self, input: GetInviteInput) -> GetInviteOutput {
+        self.get_invite.get_invite(input).await
+    }
+
+    pub async fn invite_user(&self, input: InviteUserInput) -> InviteUserOutput {
+        self.invite_user.invite_user(input).await
+    }
+
     pub async fn update_user(&self, input: UpdateUserInput) -> UpdateUserOutput {
         self.update_user.update_user(input).await
     }
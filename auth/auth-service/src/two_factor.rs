@@ -0,0 +1,50 @@
+use rand::{distributions::Alphanumeric, Rng};
+use totp_rs::{Algorithm, Secret, TOTP};
+
+const ISSUER: &str = "Yallchart";
+const BACKUP_CODE_COUNT: usize = 10;
+const BACKUP_CODE_LENGTH: usize = 10;
+
+pub fn generate_secret() -> String {
+    Secret::generate_secret().to_encoded().to_string()
+}
+
+pub fn build_totp(secret: &str, email: &str) -> Option<TOTP> {
+    let secret = Secret::Encoded(secret.to_string()).to_bytes().ok()?;
+
+    // Skew of 1 accepts the codes on either side of the current one, for clock drift
+    TOTP::new(
+        Algorithm::SHA1,
+        6,
+        1,
+        30,
+        secret,
+        Some(ISSUER.into()),
+        email.to_string(),
+    )
+    .ok()
+}
+
+pub fn check_code(secret: &str, email: &str, code: &str) -> bool {
+    build_totp(secret, email)
+        .and_then(|totp| totp.check_current(code.trim()).ok())
+        .unwrap_or(false)
+}
+
+pub fn generate_backup_codes() -> Vec<String> {
+    (0..BACKUP_CODE_COUNT)
+        .map(|_| {
+            rand::thread_rng()
+                .sample_iter(&Alphanumeric)
+                .take(BACKUP_CODE_LENGTH)
+                .map(char::from)
+                .collect::<String>()
+                .to_lowercase()
+        })
+        .collect()
+}
+
+// Backup codes are random and single use, so a plain digest is enough to keep them out of the db
+pub fn hash_backup_code(code: &str) -> String {
+    sha256::digest(code.trim().to_lowercase())
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{AuthEvent, DomainEvent},
+    models::LoginAttempts,
+    ports::{event_publisher::EventPublisher, user_repository::UserRepository},
+};
+
+#[derive(Clone)]
+pub struct UnlockUser {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UnlockUserInput {
+    pub user_id: String,
+}
+
+pub type UnlockUserOutput = Result<(), UnlockUserFailure>;
+
+impl UnlockUser {
+    pub async fn unlock_user(&self, input: UnlockUserInput) -> UnlockUserOutput {
+        let user = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(|e| UnlockUserFailure::Internal(e.to_string()))?
+            .ok_or(UnlockUserFailure::NotFound)?;
+
+        // Clears the lockout along with the failure count, so the user gets a fresh start
+        self.user_repository
+            .delete_login_attempts(LoginAttempts::account_key(&user.email))
+            .await
+            .map_err(|e| UnlockUserFailure::Internal(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(user.id, AuthEvent::UserUnlocked))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum UnlockUserFailure {
+    #[error("User does not exist!")]
+    NotFound,
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{AuthEvent, DomainEvent},
+    ports::{event_publisher::EventPublisher, user_repository::UserRepository},
+};
+
+#[derive(Clone)]
+pub struct UpdateProfile {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateProfileInput {
+    pub user_id: String,
+    pub display_name: Option<String>,
+    pub default_worksite_id: Option<String>,
+}
+
+pub type UpdateProfileOutput = Result<(), UpdateProfileFailure>;
+
+impl UpdateProfile {
+    pub async fn update_profile(&self, input: UpdateProfileInput) -> UpdateProfileOutput {
+        let user = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(|e| UpdateProfileFailure::Internal(e.to_string()))?
+            .ok_or(UpdateProfileFailure::NotFound)?;
+
+        // Blank form fields clear the value instead of saving an empty string
+        let display_name = input.display_name.filter(|name| !name.trim().is_empty());
+        let default_worksite_id = input.default_worksite_id.filter(|id| !id.is_empty());
+
+        let previous = user.clone();
+        let user = user.update_profile(display_name, default_worksite_id);
+
+        self.user_repository
+            .save(user.clone())
+            .await
+            .map_err(|e| UpdateProfileFailure::Internal(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                user.id.clone(),
+                AuthEvent::ProfileUpdated { previous, user },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum UpdateProfileFailure {
+    #[error("User does not exist!")]
+    NotFound,
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -2,11 +2,16 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
-use crate::{models::UserRole, ports::user_repository::UserRepository};
+use crate::{
+    events::{AuthEvent, DomainEvent},
+    models::UserRole,
+    ports::{event_publisher::EventPublisher, user_repository::UserRepository},
+};
 
 #[derive(Clone)]
 pub struct UpdateUser {
     pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -30,15 +35,21 @@ impl UpdateUser {
         let role =
             UserRole::new(&input.role).ok_or(UpdateUserFailure::UnknownRole(input.role.clone()))?;
 
-        let user = user
-            .map(|u| u.update(input.email, role))
-            .ok_or(UpdateUserFailure::NotFound)?;
+        let previous = user.ok_or(UpdateUserFailure::NotFound)?;
+        let user = previous.update(input.email, role);
 
         self.user_repository
-            .save(user)
+            .save(user.clone())
             .await
             .map_err(|e| UpdateUserFailure::Internal(e.to_string()))?;
 
+        self.event_publisher
+            .publish(DomainEvent::new(
+                user.id.clone(),
+                AuthEvent::UserUpdated { previous, user },
+            ))
+            .await;
+
         Ok(())
     }
 }
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use thiserror::Error;
+
+use crate::{
+    events::{AuthEvent, DomainEvent},
+    models::{LockoutEvent, LoginAttempts, LoginThrottlePolicy, User},
+    ports::{event_publisher::EventPublisher, user_repository::UserRepository},
+    two_factor,
+};
+
+#[derive(Clone)]
+pub struct VerifyTwoFactor {
+    pub user_repository: Arc<dyn UserRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+    pub throttle_policy: LoginThrottlePolicy,
+}
+
+#[derive(Clone, Debug)]
+pub struct VerifyTwoFactorInput {
+    pub user_id: String,
+    // Either a code from the authenticator app or one of the backup codes
+    pub code: String,
+}
+
+pub type VerifyTwoFactorOutput = Result<User, VerifyTwoFactorFailure>;
+
+impl VerifyTwoFactor {
+    pub async fn verify_two_factor(&self, input: VerifyTwoFactorInput) -> VerifyTwoFactorOutput {
+        let now = Utc::now();
+
+        let user = self
+            .user_repository
+            .find_by_id(input.user_id)
+            .await
+            .map_err(|e| VerifyTwoFactorFailure::Internal(e.to_string()))?
+            .ok_or(VerifyTwoFactorFailure::NotFound)?;
+
+        let secret = match &user.two_factor {
+            Some(two_factor) if two_factor.confirmed => two_factor.secret.clone(),
+            _ => return Err(VerifyTwoFactorFailure::NotEnabled),
+        };
+
+        // Wrong codes count against the same account lockout as wrong passwords
+        let key = LoginAttempts::account_key(&user.email);
+        let attempts = self
+            .user_repository
+            .find_login_attempts(key.clone())
+            .await
+            .map_err(|e| VerifyTwoFactorFailure::Internal(e.to_string()))?
+            .unwrap_or(LoginAttempts::new(key.clone()));
+
+        if attempts.is_locked(now) {
+            return Err(VerifyTwoFactorFailure::AccountLocked(
+                attempts.locked_until.unwrap_or(now),
+            ));
+        }
+        if let Some(retry_at) = attempts.blocked_until(&self.throttle_policy, now) {
+            return Err(VerifyTwoFactorFailure::TooManyAttempts(retry_at));
+        }
+
+        let verified_user = if two_factor::check_code(&secret, &user.email, &input.code) {
+            Some(user.clone())
+        } else {
+            user.use_backup_code(&two_factor::hash_backup_code(&input.code))
+        };
+
+        match verified_user {
+            Some(verified_user) => {
+                // Persists the removal of a used backup code
+                if verified_user != user {
+                    self.user_repository
+                        .save(verified_user.clone())
+                        .await
+                        .map_err(|e| VerifyTwoFactorFailure::Internal(e.to_string()))?;
+
+                    self.event_publisher
+                        .publish(DomainEvent::new(
+                            verified_user.id.clone(),
+                            AuthEvent::BackupCodeUsed,
+                        ))
+                        .await;
+                }
+                if attempts.failures > 0 {
+                    self.user_repository
+                        .delete_login_attempts(key)
+                        .await
+                        .map_err(|e| VerifyTwoFactorFailure::Internal(e.to_string()))?;
+                }
+
+                Ok(verified_user)
+            }
+            None => {
+                let max_failures = self.throttle_policy.max_account_failures;
+                let attempts = self
+                    .user_repository
+                    .record_login_failure(key, max_failures, &self.throttle_policy, now)
+                    .await
+                    .map_err(|e| VerifyTwoFactorFailure::Internal(e.to_string()))?;
+
+                if attempts.is_locked(now) && attempts.failures == max_failures {
+                    self.user_repository
+                        .save_lockout_event(LockoutEvent::new(&attempts, now))
+                        .await
+                        .map_err(|e| VerifyTwoFactorFailure::Internal(e.to_string()))?;
+                }
+
+                Err(VerifyTwoFactorFailure::InvalidCode)
+            }
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum VerifyTwoFactorFailure {
+    #[error("User does not exist!")]
+    NotFound,
+    #[error("Two-factor authentication is not enabled")]
+    NotEnabled,
+    #[error("Invalid code")]
+    InvalidCode,
+    #[error("Too many login attempts, try again later")]
+    TooManyAttempts(chrono::DateTime<Utc>),
+    #[error("Account is locked")]
+    AccountLocked(chrono::DateTime<Utc>),
+    #[error("Internal Error")]
+    Internal(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
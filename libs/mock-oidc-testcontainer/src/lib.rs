@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+use testcontainers::{core::WaitFor, Image};
+
+const NAME: &str = "ghcr.io/navikt/mock-oauth2-server";
+const TAG: &str = "2.1.0";
+
+// Issuers are served under any path, e.g. http://localhost:{port}/default
+pub const PORT: u16 = 8080;
+
+/**
+* A local OIDC issuer for tests. It signs in whoever asks without showing a login page,
+* with the claims from the given mock-oauth2-server JSON_CONFIG.
+*/
+#[derive(Default, Debug)]
+pub struct MockOidcIssuer {
+    env_vars: HashMap<String, String>,
+}
+
+impl MockOidcIssuer {
+    pub fn with_json_config(json_config: String) -> Self {
+        Self {
+            env_vars: HashMap::from([("JSON_CONFIG".to_owned(), json_config)]),
+        }
+    }
+}
+
+impl Image for MockOidcIssuer {
+    type Args = ();
+
+    fn name(&self) -> String {
+        NAME.to_owned()
+    }
+
+    fn tag(&self) -> String {
+        TAG.to_owned()
+    }
+
+    // The server does not log anything reliable once it is listening, so callers
+    // should retry their first request instead
+    fn ready_conditions(&self) -> Vec<WaitFor> {
+        vec![WaitFor::Nothing]
+    }
+
+    fn env_vars(&self) -> Box<dyn Iterator<Item = (&String, &String)> + '_> {
+        Box::new(self.env_vars.iter())
+    }
+
+    fn expose_ports(&self) -> Vec<u16> {
+        vec![PORT]
+    }
+}
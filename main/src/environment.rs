@@ -1,6 +1,8 @@
 use std::env;
 
+use auth_service::models::{OidcRoleMapping, UserRole};
 use dotenvy::dotenv;
+use openid_connect_identity_provider::OpenIdConnectConfig;
 use tracing::instrument;
 
 /**
@@ -13,6 +15,14 @@ use tracing::instrument;
 */
 pub struct Environment {
     pub auth_mongo_db_url: String,
+    pub two_factor_required_roles: Vec<UserRole>,
+    // None unless single sign-on is configured
+    pub oidc: Option<OidcEnvironment>,
+}
+
+pub struct OidcEnvironment {
+    pub config: OpenIdConnectConfig,
+    pub role_mapping: OidcRoleMapping,
 }
 
 /**
@@ -23,5 +33,60 @@ pub fn load_environment() -> Environment {
     dotenv().ok();
     Environment {
         auth_mongo_db_url: env::var("AUTH_MONGO_DB_URL").expect("AUTH_MONGO_DB_URL must be set"),
+        two_factor_required_roles: env::var("TWO_FACTOR_REQUIRED_ROLES")
+            .unwrap_or_default()
+            .split(',')
+            .map(|role| role.trim())
+            .filter(|role| !role.is_empty())
+            .map(|role| {
+                UserRole::new(role).expect("TWO_FACTOR_REQUIRED_ROLES contains an unknown role")
+            })
+            .collect(),
+        oidc: load_oidc_environment(),
     }
 }
+
+fn load_oidc_environment() -> Option<OidcEnvironment> {
+    let issuer_url = env::var("OIDC_ISSUER_URL")
+        .ok()
+        .filter(|url| !url.is_empty())?;
+
+    let config = OpenIdConnectConfig {
+        issuer_url,
+        client_id: env::var("OIDC_CLIENT_ID").expect("OIDC_CLIENT_ID must be set"),
+        client_secret: env::var("OIDC_CLIENT_SECRET")
+            .ok()
+            .filter(|secret| !secret.is_empty()),
+        redirect_url: env::var("OIDC_REDIRECT_URL").expect("OIDC_REDIRECT_URL must be set"),
+        role_claim: env::var("OIDC_ROLE_CLAIM")
+            .ok()
+            .filter(|claim| !claim.is_empty()),
+    };
+
+    let role_mapping = OidcRoleMapping {
+        default_role: env::var("OIDC_DEFAULT_ROLE")
+            .ok()
+            .filter(|role| !role.is_empty())
+            .map(|role| UserRole::new(role).expect("OIDC_DEFAULT_ROLE is an unknown role"))
+            .unwrap_or(UserRole::Organizer),
+        claim_roles: env::var("OIDC_ROLE_MAPPINGS")
+            .unwrap_or_default()
+            .split(',')
+            .map(|mapping| mapping.trim())
+            .filter(|mapping| !mapping.is_empty())
+            .map(|mapping| {
+                let (value, role) = mapping
+                    .split_once('=')
+                    .expect("OIDC_ROLE_MAPPINGS must look like claim-value=Role");
+                let role = UserRole::new(role.trim())
+                    .expect("OIDC_ROLE_MAPPINGS contains an unknown role");
+                (value.trim().to_string(), role)
+            })
+            .collect(),
+    };
+
+    Some(OidcEnvironment {
+        config,
+        role_mapping,
+    })
+}
@@ -1,25 +1,41 @@
+use audit_service::{audit_event_publisher::AuditEventPublisher, service::AuditService};
 use auth_service::{
-    create_user::CreateUserInput, get_user_for_login::GetUserForLoginInput, service::AuthService,
+    create_user::CreateUserInput,
+    get_user_for_login::GetUserForLoginInput,
+    models::OidcRoleMapping,
+    ports::{event_publisher::EventPublishers, identity_provider::IdentityProvider},
+    service::AuthService,
 };
 use axum::{
     error_handling::HandleErrorLayer, http::StatusCode, response::IntoResponse, routing::get,
     BoxError, Router,
 };
 use axum_login::{tower_sessions::SessionManagerLayer, AuthManagerLayerBuilder};
+use broadcast_event_publisher::BroadcastEventPublisher;
 use chrono::prelude::*;
 use environment::load_environment;
+use mongo_audit_log_repository::MongoAuditLogRepository;
+use mongo_task_repository::MongoTaskRepository;
 use mongo_user_repository::{MongoUserRepository, MongoUserStore};
 use mongo_worksite_repository::MongoWorksiteRepository;
+use openid_connect_identity_provider::OpenIdConnectIdentityProvider;
 use std::{net::SocketAddr, sync::Arc};
+use task_service::service::TaskService;
 use tower::ServiceBuilder;
 
 use tower_sessions::{cookie::time::Duration, mongodb::Client, Expiry, MongoDBStore};
+use web_api::{routes as api_routes, state::WebApiState};
 use web_htmx::{livereload, routes as web_routes, state::WebHtmxState};
 use worksite_service::{
     models::{
-        Address, Assessment, AssignedTag, Location, Shift, ShiftWorker, Tag, Worker, Worksite,
+        Address, Assessment, AssignedTag, AuthorizationCard, CardThreshold, Eligibility,
+        Employment, EmploymentStatus, EmploymentType, ExclusionReason, Location, Relationship,
+        RelationshipKind, Shift, ShiftWorker, Tag, Worker, Worksite,
+    },
+    ports::{
+        event_publisher::EventPublishers as WorksiteEventPublishers,
+        worksite_repository::WorksiteRepository,
     },
-    ports::worksite_repository::WorksiteRepository,
     service::WorksiteService,
 };
 
@@ -90,6 +106,7 @@ async fn main() {
                 first_name: "Jim".into(),
                 last_name: "Halpert".into(),
                 email: "jim.halpert@skynet.org".into(),
+                phone: "".into(),
                 assessments: vec![Assessment {
                     id: "1".into(),
                     value: 1,
@@ -98,18 +115,39 @@ async fn main() {
                     created_at: Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap(),
                     updated_at: Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap(),
                 }],
+                conversations: vec![],
+                committee_member: true,
+                leader_id: None,
+                authorization_card: Some(AuthorizationCard {
+                    signed_on: NaiveDate::from_ymd_opt(2023, 9, 14).unwrap(),
+                    witnessed_by: "Victoria Hall".into(),
+                    on_file: true,
+                }),
+                eligibility: Eligibility::Eligible,
                 tags: vec![
                     AssignedTag::new("1".into()),
                     AssignedTag::new("2".into()),
                     AssignedTag::new("3".into()),
                 ],
                 address: Some(Address::default()),
+                employment: Employment {
+                    job_title: "Sales Representative".into(),
+                    department: "Sales".into(),
+                    hire_date: NaiveDate::from_ymd_opt(2005, 3, 24),
+                    pay_rate: "Commission".into(),
+                    employment_type: Some(EmploymentType::FullTime),
+                },
+                status: EmploymentStatus::Active,
+                status_changes: vec![],
+                supervisor_id: None,
+                custom_values: vec![],
             },
             Worker {
                 id: "2".into(),
                 first_name: "Pam".into(),
                 last_name: "Beesly".into(),
                 email: "pam.beesly@skynet.org".into(),
+                phone: "".into(),
                 assessments: vec![Assessment {
                     id: "2".into(),
                     value: 2,
@@ -118,18 +156,29 @@ async fn main() {
                     created_at: Utc.with_ymd_and_hms(2022, 10, 12, 0, 0, 0).unwrap(),
                     updated_at: Utc.with_ymd_and_hms(2022, 10, 12, 0, 0, 0).unwrap(),
                 }],
+                conversations: vec![],
+                committee_member: false,
+                leader_id: Some("1".into()),
+                authorization_card: None,
+                eligibility: Eligibility::Eligible,
                 tags: vec![
                     AssignedTag::new("1".into()),
                     AssignedTag::new("2".into()),
                     AssignedTag::new("3".into()),
                 ],
                 address: Some(Address::default()),
+                employment: Employment::default(),
+                status: EmploymentStatus::Active,
+                status_changes: vec![],
+                supervisor_id: None,
+                custom_values: vec![],
             },
             Worker {
                 id: "3".into(),
                 first_name: "Dwight".into(),
                 last_name: "Schrute".into(),
                 email: "dwight.schrute@skynet.org".into(),
+                phone: "".into(),
                 assessments: vec![
                     Assessment {
                         id: "3".into(),
@@ -148,14 +197,25 @@ async fn main() {
                         updated_at: Utc.with_ymd_and_hms(2022, 6, 2, 0, 0, 0).unwrap(),
                     },
                 ],
+                conversations: vec![],
+                committee_member: false,
+                leader_id: Some("1".into()),
+                authorization_card: None,
+                eligibility: Eligibility::Eligible,
                 tags: vec![AssignedTag::new("3".into())],
                 address: Some(Address::default()),
+                employment: Employment::default(),
+                status: EmploymentStatus::Active,
+                status_changes: vec![],
+                supervisor_id: None,
+                custom_values: vec![],
             },
             Worker {
                 id: "4".into(),
                 first_name: "Darryl".into(),
                 last_name: "Philbin".into(),
                 email: "darryl.philbin@skynet.org".into(),
+                phone: "".into(),
                 assessments: vec![Assessment {
                     id: "4".into(),
                     value: 1,
@@ -164,14 +224,25 @@ async fn main() {
                     created_at: Utc.with_ymd_and_hms(2023, 7, 4, 0, 0, 0).unwrap(),
                     updated_at: Utc.with_ymd_and_hms(2023, 7, 4, 0, 0, 0).unwrap(),
                 }],
+                conversations: vec![],
+                committee_member: false,
+                leader_id: None,
+                authorization_card: None,
+                eligibility: Eligibility::Excluded(ExclusionReason::Supervisor),
                 tags: vec![AssignedTag::new("2".into()), AssignedTag::new("3".into())],
                 address: Some(Address::default()),
+                employment: Employment::default(),
+                status: EmploymentStatus::Active,
+                status_changes: vec![],
+                supervisor_id: None,
+                custom_values: vec![],
             },
             Worker {
                 id: "5".into(),
                 first_name: "Nate".into(),
                 last_name: "Nickerson".into(),
                 email: "nate.nickerson@skynet.org".into(),
+                phone: "".into(),
                 assessments: vec![Assessment {
                     id: "5".into(),
                     value: 3,
@@ -180,14 +251,25 @@ async fn main() {
                     created_at: Utc.with_ymd_and_hms(2023, 2, 6, 0, 0, 0).unwrap(),
                     updated_at: Utc.with_ymd_and_hms(2023, 2, 6, 0, 0, 0).unwrap(),
                 }],
+                conversations: vec![],
+                committee_member: false,
+                leader_id: None,
+                authorization_card: None,
+                eligibility: Eligibility::Eligible,
                 tags: vec![AssignedTag::new("1".into())],
                 address: Some(Address::default()),
+                employment: Employment::default(),
+                status: EmploymentStatus::Active,
+                status_changes: vec![],
+                supervisor_id: Some("4".into()),
+                custom_values: vec![],
             },
             Worker {
                 id: "6".into(),
                 first_name: "Roy".into(),
                 last_name: "Anderson".into(),
                 email: "roy.anderson@skynet.org".into(),
+                phone: "".into(),
                 assessments: vec![Assessment {
                     id: "3".into(),
                     value: 3,
@@ -196,14 +278,25 @@ async fn main() {
                     created_at: Utc.with_ymd_and_hms(2023, 4, 9, 0, 0, 0).unwrap(),
                     updated_at: Utc.with_ymd_and_hms(2023, 4, 9, 0, 0, 0).unwrap(),
                 }],
+                conversations: vec![],
+                committee_member: false,
+                leader_id: None,
+                authorization_card: None,
+                eligibility: Eligibility::Eligible,
                 tags: vec![AssignedTag::new("2".into()), AssignedTag::new("3".into())],
                 address: Some(Address::default()),
+                employment: Employment::default(),
+                status: EmploymentStatus::Active,
+                status_changes: vec![],
+                supervisor_id: Some("4".into()),
+                custom_values: vec![],
             },
             Worker {
                 id: "7".into(),
                 first_name: "Val".into(),
                 last_name: "Johnson".into(),
                 email: "val.johnson@skynet.org".into(),
+                phone: "".into(),
                 assessments: vec![Assessment {
                     id: "7".into(),
                     value: 2,
@@ -212,21 +305,69 @@ async fn main() {
                     created_at: Utc.with_ymd_and_hms(2023, 10, 18, 0, 0, 0).unwrap(),
                     updated_at: Utc.with_ymd_and_hms(2023, 10, 18, 0, 0, 0).unwrap(),
                 }],
+                conversations: vec![],
+                committee_member: false,
+                leader_id: None,
+                authorization_card: None,
+                eligibility: Eligibility::Eligible,
                 tags: vec![
                     AssignedTag::new("1".into()),
                     AssignedTag::new("2".into()),
                     AssignedTag::new("3".into()),
                 ],
                 address: Some(Address::default()),
+                employment: Employment::default(),
+                status: EmploymentStatus::Active,
+                status_changes: vec![],
+                supervisor_id: Some("4".into()),
+                custom_values: vec![],
+            },
+        ],
+        turf_assignments: vec![],
+        actions: vec![],
+        card_thresholds: CardThreshold::defaults(),
+        custom_fields: vec![],
+        relationships: vec![
+            Relationship {
+                id: "1".into(),
+                from_worker_id: "1".into(),
+                to_worker_id: "2".into(),
+                kind: RelationshipKind::Friend,
+                directional: false,
+            },
+            Relationship {
+                id: "2".into(),
+                from_worker_id: "6".into(),
+                to_worker_id: "7".into(),
+                kind: RelationshipKind::Carpool,
+                directional: false,
             },
         ],
     };
+    // Create audit service, recording the changes made by the other services
+    let audit_log_repository = Arc::new(
+        MongoAuditLogRepository::new(&env.auth_mongo_db_url)
+            .await
+            .expect("Could not create audit log repository"),
+    );
+    let audit_service = Arc::new(AuditService::new(audit_log_repository));
+    let audit_publisher = Arc::new(AuditEventPublisher {
+        audit_service: audit_service.clone(),
+    });
+
     let worksite_repository = Arc::new(
         MongoWorksiteRepository::new(&env.auth_mongo_db_url)
             .await
             .expect("Could not create worksite repository"),
     );
-    let worksite_service = WorksiteService::new(worksite_repository.clone());
+    let worksite_events = BroadcastEventPublisher::new();
+    let worksite_service = WorksiteService::new(
+        worksite_repository.clone(),
+        Arc::new(WorksiteEventPublishers(vec![
+            Arc::new(worksite_events.clone()),
+            audit_publisher.clone(),
+        ])),
+    );
 
     let existing_worksite = worksite_repository
         .get_worksite(DEFAULT_WORKSITE_ID.into())
@@ -247,13 +388,31 @@ async fn main() {
             .await
             .expect("Could not create user repository"),
     );
-    let auth_service = AuthService::new(user_repository.clone());
+    let (identity_provider, oidc_role_mapping) = match env.oidc {
+        Some(oidc) => {
+            let identity_provider: Arc<dyn IdentityProvider> = Arc::new(
+                OpenIdConnectIdentityProvider::discover(oidc.config)
+                    .await
+                    .expect("Could not discover the OIDC issuer"),
+            );
+            (Some(identity_provider), oidc.role_mapping)
+        }
+        None => (None, OidcRoleMapping::default()),
+    };
+    let auth_service = AuthService::new(
+        user_repository.clone(),
+        Arc::new(EventPublishers(vec![audit_publisher])),
+        env.two_factor_required_roles.clone(),
+        identity_provider,
+        oidc_role_mapping,
+    );
 
     // Create a default user
     let existing_user = auth_service
         .get_user_for_login(GetUserForLoginInput {
             email: "user@yallchart.com".into(),
             password: "password".into(),
+            client_ip: None,
         })
         .await;
     if existing_user.is_ok() {
@@ -279,16 +438,36 @@ async fn main() {
             .expect("Failed to create default super admin user");
     }
 
+    let auth_service = Arc::new(auth_service);
+
+    let worksite_service = Arc::new(worksite_service);
+
+    let task_repository = Arc::new(
+        MongoTaskRepository::new(&env.auth_mongo_db_url)
+            .await
+            .expect("Could not create task repository"),
+    );
+    let task_service = Arc::new(TaskService::new(task_repository));
+
     // Create WebHtmxState
     let web_htmx_state = WebHtmxState {
-        auth_service: Arc::new(auth_service),
-        worksite_service: Arc::new(worksite_service),
+        auth_service: auth_service.clone(),
+        worksite_service: worksite_service.clone(),
+        worksite_events,
+        audit_service,
+        task_service,
         flash_config: axum_flash::Config::new(axum_flash::Key::generate()),
         default_worksite_id: DEFAULT_WORKSITE_ID.into(),
         default_worksite_name: DEFAULT_WORKSITE_NAME.into(),
     };
 
+    let web_api_state = WebApiState {
+        auth_service: auth_service.clone(),
+        worksite_service,
+    };
+
     let app = Router::new()
+        .merge(api_routes(web_api_state))
         .merge(web_routes(web_htmx_state))
         .route("/healthcheck", get(get_health_check));
 
@@ -308,6 +487,7 @@ async fn main() {
 
     let user_memory_store = MongoUserStore {
         users: user_repository.clone(),
+        complete_oidc_login: auth_service.complete_oidc_login.clone(),
     };
     let session_layer = SessionManagerLayer::new(session_store)
         .with_secure(false)
@@ -323,9 +503,13 @@ async fn main() {
 
     let addr = SocketAddr::from(([0, 0, 0, 0], 3000));
     let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
-    axum::serve(listener, app.into_make_service())
-        .await
-        .expect("Failed to start server");
+    // Connect info gives the login throttling a client ip when there is no proxy in front
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .expect("Failed to start server");
 }
 
 async fn get_health_check() -> impl IntoResponse {
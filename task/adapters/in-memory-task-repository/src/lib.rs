@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use task_service::models::{Task, TaskFilter};
+use task_service::ports::task_repository::{RepositoryFailure, TaskRepository};
+use tokio::sync::RwLock;
+
+#[derive(Clone, Debug)]
+pub struct InMemoryTaskRepository {
+    pub tasks: Arc<RwLock<Vec<Task>>>,
+}
+
+impl InMemoryTaskRepository {
+    pub fn empty() -> Self {
+        Self {
+            tasks: Arc::new(RwLock::new(vec![])),
+        }
+    }
+
+    pub fn with(tasks: Vec<Task>) -> Self {
+        Self {
+            tasks: Arc::new(RwLock::new(tasks)),
+        }
+    }
+}
+
+#[async_trait]
+impl TaskRepository for InMemoryTaskRepository {
+    async fn get_task(&self, id: String) -> Result<Option<Task>, RepositoryFailure> {
+        let tasks = self.tasks.read().await;
+        Ok(tasks.iter().find(|t| t.id == id).cloned())
+    }
+
+    async fn find(&self, filter: TaskFilter) -> Result<Vec<Task>, RepositoryFailure> {
+        let tasks = self.tasks.read().await;
+        let mut found: Vec<Task> = tasks
+            .iter()
+            .filter(|task| filter.matches(task))
+            .cloned()
+            .collect();
+        found.sort_by(|a, b| {
+            a.due_on
+                .cmp(&b.due_on)
+                .then(a.created_at.cmp(&b.created_at))
+        });
+
+        Ok(found)
+    }
+
+    async fn save(&self, task: Task) -> Result<(), RepositoryFailure> {
+        let mut tasks = self.tasks.write().await;
+        tasks.retain(|t| t.id != task.id);
+        tasks.push(task);
+        Ok(())
+    }
+
+    async fn delete(&self, id: String) -> Result<(), RepositoryFailure> {
+        let mut tasks = self.tasks.write().await;
+        tasks.retain(|t| t.id != id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::NaiveDate;
+    use task_service::{
+        change_task_status::ChangeTaskStatusInput,
+        create_task::{CreateTaskFailure, CreateTaskInput},
+        delete_task::{DeleteTaskFailure, DeleteTaskInput},
+        get_tasks::GetTasksInput,
+        models::{TaskFilter, TaskStatus},
+        reassign_tasks::ReassignTasksInput,
+        service::TaskService,
+    };
+
+    use super::InMemoryTaskRepository;
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+    }
+
+    fn task_input(assignee_id: &str, title: &str, due_on: NaiveDate) -> CreateTaskInput {
+        CreateTaskInput {
+            worksite_id: "worksite-1".into(),
+            worker_id: "worker-1".into(),
+            assignee_id: assignee_id.into(),
+            title: title.into(),
+            due_on,
+        }
+    }
+
+    async fn titles(task_service: &TaskService, filter: TaskFilter) -> Vec<String> {
+        task_service
+            .get_tasks(GetTasksInput { filter })
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|task| task.title)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn finds_overdue_tasks_soonest_first() {
+        let task_service = TaskService::new(Arc::new(InMemoryTaskRepository::empty()));
+        for (assignee_id, title, due_on) in [
+            ("organizer-1", "Talk to Roy after his shift", day(7)),
+            ("organizer-1", "Bring Pam the petition", day(4)),
+            ("organizer-2", "Call Darryl", day(5)),
+            ("organizer-1", "Meet Kevin for lunch", day(20)),
+        ] {
+            task_service
+                .create_task(task_input(assignee_id, title, due_on))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            titles(
+                &task_service,
+                TaskFilter {
+                    assignee_id: Some("organizer-1".into()),
+                    ..TaskFilter::overdue(day(10))
+                }
+            )
+            .await,
+            vec!["Bring Pam the petition", "Talk to Roy after his shift"]
+        );
+        assert_eq!(
+            titles(&task_service, TaskFilter::overdue(day(5))).await,
+            vec!["Bring Pam the petition"]
+        );
+    }
+
+    #[tokio::test]
+    async fn closed_tasks_are_never_overdue() {
+        let task_service = TaskService::new(Arc::new(InMemoryTaskRepository::empty()));
+        let task = task_service
+            .create_task(task_input("organizer-1", "Call Darryl", day(1)))
+            .await
+            .unwrap();
+        assert!(task.is_overdue(day(2)));
+
+        let task = task_service
+            .change_task_status(ChangeTaskStatusInput {
+                id: task.id,
+                status: TaskStatus::Done,
+            })
+            .await
+            .unwrap();
+
+        assert!(!task.is_overdue(day(2)));
+        assert!(titles(&task_service, TaskFilter::overdue(day(2)))
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn rejects_blank_titles_and_missing_tasks() {
+        let task_service = TaskService::new(Arc::new(InMemoryTaskRepository::empty()));
+
+        assert_eq!(
+            task_service
+                .create_task(task_input("organizer-1", "  ", day(1)))
+                .await,
+            Err(CreateTaskFailure::MissingTitle)
+        );
+        assert_eq!(
+            task_service
+                .delete_task(DeleteTaskInput {
+                    id: "missing".into()
+                })
+                .await,
+            Err(DeleteTaskFailure::NotFound)
+        );
+    }
+
+    #[tokio::test]
+    async fn reassigns_only_that_workers_tasks_at_the_worksite() {
+        let task_service = TaskService::new(Arc::new(InMemoryTaskRepository::empty()));
+        for (worksite_id, worker_id, title) in [
+            ("worksite-1", "worker-1", "Call Darryl"),
+            ("worksite-1", "worker-1", "Bring Darryl the petition"),
+            ("worksite-1", "worker-3", "Meet Kevin for lunch"),
+            ("worksite-2", "worker-1", "Talk to Roy after his shift"),
+        ] {
+            task_service
+                .create_task(CreateTaskInput {
+                    worksite_id: worksite_id.into(),
+                    worker_id: worker_id.into(),
+                    ..task_input("organizer-1", title, day(1))
+                })
+                .await
+                .unwrap();
+        }
+
+        let moved = task_service
+            .reassign_tasks(ReassignTasksInput {
+                worksite_id: "worksite-1".into(),
+                from_worker_id: "worker-1".into(),
+                to_worker_id: "worker-2".into(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(moved.len(), 2);
+
+        let for_worker = |worksite_id: &str, worker_id: &str| TaskFilter {
+            worksite_id: Some(worksite_id.into()),
+            worker_id: Some(worker_id.into()),
+            ..TaskFilter::default()
+        };
+        let mut reassigned = titles(&task_service, for_worker("worksite-1", "worker-2")).await;
+        reassigned.sort();
+        assert_eq!(reassigned, vec!["Bring Darryl the petition", "Call Darryl"]);
+        assert!(titles(&task_service, for_worker("worksite-1", "worker-1"))
+            .await
+            .is_empty());
+        assert_eq!(
+            titles(&task_service, for_worker("worksite-1", "worker-3")).await,
+            vec!["Meet Kevin for lunch"]
+        );
+        assert_eq!(
+            titles(&task_service, for_worker("worksite-2", "worker-1")).await,
+            vec!["Talk to Roy after his shift"]
+        );
+    }
+}
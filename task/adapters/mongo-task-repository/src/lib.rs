@@ -0,0 +1,224 @@
+use async_trait::async_trait;
+use chrono::{serde::ts_seconds, DateTime, NaiveDate, Utc};
+use futures::stream::TryStreamExt;
+use mongodb::bson::{doc, Document};
+use serde::{Deserialize, Serialize};
+
+use task_service::models::{Task, TaskFilter, TaskStatus};
+use task_service::ports::task_repository::{RepositoryFailure, TaskRepository};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TaskRecord {
+    pub id: String,
+    pub worksite_id: String,
+    pub worker_id: String,
+    pub assignee_id: String,
+    pub title: String,
+    // Stored as "YYYY-MM-DD", which sorts and compares in date order
+    pub due_on: NaiveDate,
+    pub status: String,
+    #[serde(with = "ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TaskRecord {
+    pub fn to_task(&self) -> Task {
+        Task {
+            id: self.id.clone(),
+            worksite_id: self.worksite_id.clone(),
+            worker_id: self.worker_id.clone(),
+            assignee_id: self.assignee_id.clone(),
+            title: self.title.clone(),
+            due_on: self.due_on,
+            // Only ever written from a TaskStatus
+            status: TaskStatus::new(&self.status).unwrap_or(TaskStatus::Open),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+fn to_task_record(task: &Task) -> TaskRecord {
+    TaskRecord {
+        id: task.id.clone(),
+        worksite_id: task.worksite_id.clone(),
+        worker_id: task.worker_id.clone(),
+        assignee_id: task.assignee_id.clone(),
+        title: task.title.clone(),
+        due_on: task.due_on,
+        status: task.status.to_string(),
+        created_at: task.created_at,
+        updated_at: task.updated_at,
+    }
+}
+
+fn to_filter_document(filter: &TaskFilter) -> Document {
+    let mut document = Document::new();
+    let fields = [
+        ("worksite_id", &filter.worksite_id),
+        ("worker_id", &filter.worker_id),
+        ("assignee_id", &filter.assignee_id),
+    ];
+    for (field, value) in fields {
+        if let Some(value) = value {
+            document.insert(field, value.clone());
+        }
+    }
+    if let Some(status) = filter.status {
+        document.insert("status", status.to_string());
+    }
+    if let Some(due_before) = filter.due_before {
+        document.insert("due_on", doc! { "$lt": due_before.to_string() });
+    }
+    document
+}
+
+#[derive(Clone, Debug)]
+pub struct MongoTaskRepository {
+    collection: mongodb::Collection<TaskRecord>,
+}
+
+impl MongoTaskRepository {
+    pub async fn new(url: &String) -> Result<Self, mongodb::error::Error> {
+        let client = mongodb::Client::with_uri_str(url).await?;
+        Self::from_client(&client)
+    }
+    pub fn from_client(client: &mongodb::Client) -> Result<Self, mongodb::error::Error> {
+        let db = client.database("task");
+        Ok(Self {
+            collection: db.collection::<TaskRecord>("tasks"),
+        })
+    }
+}
+
+#[async_trait]
+impl TaskRepository for MongoTaskRepository {
+    async fn get_task(&self, id: String) -> Result<Option<Task>, RepositoryFailure> {
+        let task = self
+            .collection
+            .find_one(doc! { "id": id }, None)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        Ok(task.map(|t| t.to_task()))
+    }
+
+    async fn find(&self, filter: TaskFilter) -> Result<Vec<Task>, RepositoryFailure> {
+        let options = mongodb::options::FindOptions::builder()
+            .sort(doc! { "due_on": 1, "created_at": 1 })
+            .build();
+        let cursor = self
+            .collection
+            .find(to_filter_document(&filter), options)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        let tasks: Vec<TaskRecord> = cursor
+            .try_collect()
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+
+        Ok(tasks.iter().map(|t| t.to_task()).collect())
+    }
+
+    async fn save(&self, task: Task) -> Result<(), RepositoryFailure> {
+        let options = mongodb::options::ReplaceOptions::builder()
+            .upsert(true)
+            .build();
+        self.collection
+            .replace_one(
+                doc! { "id": task.id.clone() },
+                to_task_record(&task),
+                options,
+            )
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+        Ok(())
+    }
+
+    async fn delete(&self, id: String) -> Result<(), RepositoryFailure> {
+        self.collection
+            .delete_one(doc! { "id": id }, None)
+            .await
+            .map_err(|e| RepositoryFailure::Unknown(e.to_string()))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use mongo_testcontainer::Mongo;
+    use mongodb::Client;
+    use pretty_assertions::assert_eq;
+    use task_service::models::{Task, TaskFilter, TaskStatus};
+    use task_service::ports::task_repository::TaskRepository;
+    use testcontainers::clients;
+
+    use crate::MongoTaskRepository;
+
+    fn make_task(id: &str, assignee_id: &str, due_day: u32) -> Task {
+        Task {
+            id: id.into(),
+            created_at: Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap(),
+            ..Task::new(
+                "worksite-1".into(),
+                "worker-1".into(),
+                assignee_id.into(),
+                "Talk to Roy after his shift".into(),
+                NaiveDate::from_ymd_opt(2024, 3, due_day).unwrap(),
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn tests() {
+        let docker_cli = clients::Cli::default();
+        let container = docker_cli.run(Mongo);
+        let host_port = container.get_host_port_ipv4(27017);
+        let url = format!("mongodb://127.0.0.1:{host_port}/");
+        let mongo_client: Client = Client::with_uri_str(&url).await.unwrap();
+        let repo = MongoTaskRepository::from_client(&mongo_client).unwrap();
+
+        test_save_get_and_delete(&repo).await;
+        test_find_overdue(&repo).await;
+    }
+
+    async fn test_save_get_and_delete(repo: &MongoTaskRepository) {
+        let task = make_task("1", "organizer-1", 7);
+        repo.save(task.clone()).await.unwrap();
+        assert_eq!(repo.get_task("1".into()).await.unwrap(), Some(task.clone()));
+
+        let done = Task {
+            status: TaskStatus::Done,
+            ..task
+        };
+        repo.save(done.clone()).await.unwrap();
+        assert_eq!(repo.get_task("1".into()).await.unwrap(), Some(done));
+
+        repo.delete("1".into()).await.unwrap();
+        assert_eq!(repo.get_task("1".into()).await.unwrap(), None);
+    }
+
+    async fn test_find_overdue(repo: &MongoTaskRepository) {
+        let later = make_task("2", "organizer-1", 12);
+        let sooner = make_task("3", "organizer-1", 4);
+        let not_yet = make_task("4", "organizer-1", 20);
+        let someone_elses = make_task("5", "organizer-2", 4);
+        for task in [&later, &sooner, &not_yet, &someone_elses] {
+            repo.save(task.clone()).await.unwrap();
+        }
+
+        let result = repo
+            .find(TaskFilter {
+                assignee_id: Some("organizer-1".into()),
+                ..TaskFilter::overdue(NaiveDate::from_ymd_opt(2024, 3, 15).unwrap())
+            })
+            .await
+            .unwrap();
+        assert_eq!(result, vec![sooner, later]);
+    }
+}
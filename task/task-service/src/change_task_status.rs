@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use thiserror::Error;
+
+use crate::{
+    models::{Task, TaskStatus},
+    ports::task_repository::TaskRepository,
+};
+
+#[derive(Clone)]
+pub struct ChangeTaskStatus {
+    pub task_repository: Arc<dyn TaskRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ChangeTaskStatusInput {
+    pub id: String,
+    pub status: TaskStatus,
+}
+
+pub type ChangeTaskStatusOutput = Result<Task, ChangeTaskStatusFailure>;
+
+impl ChangeTaskStatus {
+    pub async fn change_task_status(&self, input: ChangeTaskStatusInput) -> ChangeTaskStatusOutput {
+        let task = self
+            .task_repository
+            .get_task(input.id)
+            .await
+            .map_err(|e| ChangeTaskStatusFailure::Unknown(e.to_string()))?
+            .ok_or(ChangeTaskStatusFailure::NotFound)?;
+
+        let updated_task = Task {
+            status: input.status,
+            updated_at: Utc::now(),
+            ..task
+        };
+
+        self.task_repository
+            .save(updated_task.clone())
+            .await
+            .map_err(|e| ChangeTaskStatusFailure::Unknown(e.to_string()))?;
+
+        Ok(updated_task)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ChangeTaskStatusFailure {
+    #[error("Task does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::{models::Task, ports::task_repository::TaskRepository};
+
+#[derive(Clone)]
+pub struct CreateTask {
+    pub task_repository: Arc<dyn TaskRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CreateTaskInput {
+    pub worksite_id: String,
+    pub worker_id: String,
+    pub assignee_id: String,
+    pub title: String,
+    pub due_on: NaiveDate,
+}
+
+pub type CreateTaskOutput = Result<Task, CreateTaskFailure>;
+
+impl CreateTask {
+    pub async fn create_task(&self, input: CreateTaskInput) -> CreateTaskOutput {
+        let title = input.title.trim().to_string();
+        if title.is_empty() {
+            return Err(CreateTaskFailure::MissingTitle);
+        }
+
+        let task = Task::new(
+            input.worksite_id,
+            input.worker_id,
+            input.assignee_id,
+            title,
+            input.due_on,
+        );
+
+        self.task_repository
+            .save(task.clone())
+            .await
+            .map_err(|e| CreateTaskFailure::Unknown(e.to_string()))?;
+
+        Ok(task)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum CreateTaskFailure {
+    #[error("A task needs a title")]
+    MissingTitle,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
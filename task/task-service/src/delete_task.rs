@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::ports::task_repository::TaskRepository;
+
+#[derive(Clone)]
+pub struct DeleteTask {
+    pub task_repository: Arc<dyn TaskRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct DeleteTaskInput {
+    pub id: String,
+}
+
+pub type DeleteTaskOutput = Result<(), DeleteTaskFailure>;
+
+impl DeleteTask {
+    pub async fn delete_task(&self, input: DeleteTaskInput) -> DeleteTaskOutput {
+        self.task_repository
+            .get_task(input.id.clone())
+            .await
+            .map_err(|e| DeleteTaskFailure::Unknown(e.to_string()))?
+            .ok_or(DeleteTaskFailure::NotFound)?;
+
+        self.task_repository
+            .delete(input.id)
+            .await
+            .map_err(|e| DeleteTaskFailure::Unknown(e.to_string()))
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum DeleteTaskFailure {
+    #[error("Task does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
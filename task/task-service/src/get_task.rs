@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{models::Task, ports::task_repository::TaskRepository};
+
+#[derive(Clone)]
+pub struct GetTask {
+    pub task_repository: Arc<dyn TaskRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetTaskInput {
+    pub id: String,
+}
+
+pub type GetTaskOutput = Result<Option<Task>, GetTaskFailure>;
+
+impl GetTask {
+    pub async fn get_task(&self, input: GetTaskInput) -> GetTaskOutput {
+        self.task_repository
+            .get_task(input.id)
+            .await
+            .map_err(|e| GetTaskFailure::Unknown(e.to_string()))
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetTaskFailure {
+    #[error("Something went wrong")]
+    Unknown(String),
+}
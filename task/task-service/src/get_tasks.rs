@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    models::{Task, TaskFilter},
+    ports::task_repository::TaskRepository,
+};
+
+#[derive(Clone)]
+pub struct GetTasks {
+    pub task_repository: Arc<dyn TaskRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetTasksInput {
+    pub filter: TaskFilter,
+}
+
+// Soonest due first
+pub type GetTasksOutput = Result<Vec<Task>, GetTasksFailure>;
+
+impl GetTasks {
+    pub async fn get_tasks(&self, input: GetTasksInput) -> GetTasksOutput {
+        self.task_repository
+            .find(input.filter)
+            .await
+            .map_err(|e| GetTasksFailure::Unknown(e.to_string()))
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetTasksFailure {
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,10 @@
+pub mod change_task_status;
+pub mod create_task;
+pub mod delete_task;
+pub mod get_task;
+pub mod get_tasks;
+pub mod models;
+pub mod ports;
+pub mod reassign_tasks;
+pub mod service;
+pub mod update_task;
@@ -0,0 +1,113 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+
+/**
+* A follow-up with a worker, e.g. "talk to Roy again after his shift Thursday", that a user
+* has taken on.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Task {
+    pub id: String,
+    pub worksite_id: String,
+    pub worker_id: String,
+    // The user who is following up
+    pub assignee_id: String,
+    pub title: String,
+    pub due_on: NaiveDate,
+    pub status: TaskStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Task {
+    pub fn new(
+        worksite_id: String,
+        worker_id: String,
+        assignee_id: String,
+        title: String,
+        due_on: NaiveDate,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            worksite_id,
+            worker_id,
+            assignee_id,
+            title,
+            due_on,
+            status: TaskStatus::Open,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.status == TaskStatus::Open && self.due_on < today
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum TaskStatus {
+    Open,
+    Done,
+    Cancelled,
+}
+
+impl TaskStatus {
+    pub const ALL: [TaskStatus; 3] = [Self::Open, Self::Done, Self::Cancelled];
+
+    pub fn new<T: AsRef<str>>(status: T) -> Option<Self> {
+        match status.as_ref() {
+            "Open" => Some(Self::Open),
+            "Done" => Some(Self::Done),
+            "Cancelled" => Some(Self::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+impl Display for TaskStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            Self::Open => "Open",
+            Self::Done => "Done",
+            Self::Cancelled => "Cancelled",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskFilter {
+    pub worksite_id: Option<String>,
+    pub worker_id: Option<String>,
+    pub assignee_id: Option<String>,
+    pub status: Option<TaskStatus>,
+    // Only tasks due before this day
+    pub due_before: Option<NaiveDate>,
+}
+
+impl TaskFilter {
+    // Open tasks that should have been done before today
+    pub fn overdue(today: NaiveDate) -> Self {
+        Self {
+            status: Some(TaskStatus::Open),
+            due_before: Some(today),
+            ..Self::default()
+        }
+    }
+
+    pub fn matches(&self, task: &Task) -> bool {
+        let matches = |filter: &Option<String>, value: &String| match filter {
+            Some(filter) => value == filter,
+            None => true,
+        };
+
+        matches(&self.worksite_id, &task.worksite_id)
+            && matches(&self.worker_id, &task.worker_id)
+            && matches(&self.assignee_id, &task.assignee_id)
+            && self.status.map_or(true, |status| task.status == status)
+            && self.due_before.map_or(true, |day| task.due_on < day)
+    }
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::models::{Task, TaskFilter};
+
+#[async_trait]
+pub trait TaskRepository: Send + Sync + 'static {
+    async fn get_task(&self, id: String) -> Result<Option<Task>, RepositoryFailure>;
+    // Soonest due first
+    async fn find(&self, filter: TaskFilter) -> Result<Vec<Task>, RepositoryFailure>;
+
+    async fn save(&self, task: Task) -> Result<(), RepositoryFailure>;
+    async fn delete(&self, id: String) -> Result<(), RepositoryFailure>;
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RepositoryFailure {
+    #[error("Something went wrong")]
+    Unknown(String),
+}
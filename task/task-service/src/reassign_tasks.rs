@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use thiserror::Error;
+
+use crate::{
+    models::{Task, TaskFilter},
+    ports::task_repository::TaskRepository,
+};
+
+// Moves every task about one worker onto another, e.g. when duplicate workers are merged
+#[derive(Clone)]
+pub struct ReassignTasks {
+    pub task_repository: Arc<dyn TaskRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ReassignTasksInput {
+    pub worksite_id: String,
+    pub from_worker_id: String,
+    pub to_worker_id: String,
+}
+
+// The tasks that were moved
+pub type ReassignTasksOutput = Result<Vec<Task>, ReassignTasksFailure>;
+
+impl ReassignTasks {
+    pub async fn reassign_tasks(&self, input: ReassignTasksInput) -> ReassignTasksOutput {
+        let tasks = self
+            .task_repository
+            .find(TaskFilter {
+                worksite_id: Some(input.worksite_id),
+                worker_id: Some(input.from_worker_id),
+                ..TaskFilter::default()
+            })
+            .await
+            .map_err(|e| ReassignTasksFailure::Unknown(e.to_string()))?;
+
+        let mut reassigned = vec![];
+        for task in tasks {
+            let updated_task = Task {
+                worker_id: input.to_worker_id.clone(),
+                updated_at: Utc::now(),
+                ..task
+            };
+
+            self.task_repository
+                .save(updated_task.clone())
+                .await
+                .map_err(|e| ReassignTasksFailure::Unknown(e.to_string()))?;
+            reassigned.push(updated_task);
+        }
+
+        Ok(reassigned)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ReassignTasksFailure {
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::{
+    change_task_status::{ChangeTaskStatus, ChangeTaskStatusInput, ChangeTaskStatusOutput},
+    create_task::{CreateTask, CreateTaskInput, CreateTaskOutput},
+    delete_task::{DeleteTask, DeleteTaskInput, DeleteTaskOutput},
+    get_task::{GetTask, GetTaskInput, GetTaskOutput},
+    get_tasks::{GetTasks, GetTasksInput, GetTasksOutput},
+    ports::task_repository::TaskRepository,
+    reassign_tasks::{ReassignTasks, ReassignTasksInput, ReassignTasksOutput},
+    update_task::{UpdateTask, UpdateTaskInput, UpdateTaskOutput},
+};
+
+#[derive(Clone)]
+pub struct TaskService {
+    pub create_task: CreateTask,
+    pub get_task: GetTask,
+    pub get_tasks: GetTasks,
+    pub update_task: UpdateTask,
+    pub change_task_status: ChangeTaskStatus,
+    pub delete_task: DeleteTask,
+    pub reassign_tasks: ReassignTasks,
+}
+
+impl TaskService {
+    pub fn new(task_repository: Arc<dyn TaskRepository>) -> Self {
+        Self {
+            create_task: CreateTask {
+                task_repository: task_repository.clone(),
+            },
+            get_task: GetTask {
+                task_repository: task_repository.clone(),
+            },
+            get_tasks: GetTasks {
+                task_repository: task_repository.clone(),
+            },
+            update_task: UpdateTask {
+                task_repository: task_repository.clone(),
+            },
+            change_task_status: ChangeTaskStatus {
+                task_repository: task_repository.clone(),
+            },
+            delete_task: DeleteTask {
+                task_repository: task_repository.clone(),
+            },
+            reassign_tasks: ReassignTasks { task_repository },
+        }
+    }
+
+    pub async fn create_task(&self, input: CreateTaskInput) -> CreateTaskOutput {
+        self.create_task.create_task(input).await
+    }
+
+    pub async fn get_task(&self, input: GetTaskInput) -> GetTaskOutput {
+        self.get_task.get_task(input).await
+    }
+
+    pub async fn get_tasks(&self, input: GetTasksInput) -> GetTasksOutput {
+        self.get_tasks.get_tasks(input).await
+    }
+
+    pub async fn update_task(&self, input: UpdateTaskInput) -> UpdateTaskOutput {
+        self.update_task.update_task(input).await
+    }
+
+    pub async fn change_task_status(&self, input: ChangeTaskStatusInput) -> ChangeTaskStatusOutput {
+        self.change_task_status.change_task_status(input).await
+    }
+
+    pub async fn delete_task(&self, input: DeleteTaskInput) -> DeleteTaskOutput {
+        self.delete_task.delete_task(input).await
+    }
+
+    pub async fn reassign_tasks(&self, input: ReassignTasksInput) -> ReassignTasksOutput {
+        self.reassign_tasks.reassign_tasks(input).await
+    }
+}
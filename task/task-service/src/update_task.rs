@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, Utc};
+use thiserror::Error;
+
+use crate::{models::Task, ports::task_repository::TaskRepository};
+
+#[derive(Clone)]
+pub struct UpdateTask {
+    pub task_repository: Arc<dyn TaskRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateTaskInput {
+    pub id: String,
+    pub assignee_id: String,
+    pub title: String,
+    pub due_on: NaiveDate,
+}
+
+pub type UpdateTaskOutput = Result<Task, UpdateTaskFailure>;
+
+impl UpdateTask {
+    pub async fn update_task(&self, input: UpdateTaskInput) -> UpdateTaskOutput {
+        let title = input.title.trim().to_string();
+        if title.is_empty() {
+            return Err(UpdateTaskFailure::MissingTitle);
+        }
+
+        let task = self
+            .task_repository
+            .get_task(input.id)
+            .await
+            .map_err(|e| UpdateTaskFailure::Unknown(e.to_string()))?
+            .ok_or(UpdateTaskFailure::NotFound)?;
+
+        let updated_task = Task {
+            assignee_id: input.assignee_id,
+            title,
+            due_on: input.due_on,
+            updated_at: Utc::now(),
+            ..task
+        };
+
+        self.task_repository
+            .save(updated_task.clone())
+            .await
+            .map_err(|e| UpdateTaskFailure::Unknown(e.to_string()))?;
+
+        Ok(updated_task)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum UpdateTaskFailure {
+    #[error("Task does not exist")]
+    NotFound,
+    #[error("A task needs a title")]
+    MissingTitle,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
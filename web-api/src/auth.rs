@@ -0,0 +1,83 @@
+use audit_service::actor::with_actor;
+use auth_service::{
+    get_user_for_api_token::{GetUserForApiTokenFailure, GetUserForApiTokenInput},
+    models::{User, UserPermission},
+};
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Request, State},
+    middleware::Next,
+    response::Response,
+};
+use http::{header::AUTHORIZATION, request::Parts};
+
+use crate::{error::ApiError, state::WebApiState};
+
+/**
+* The user behind the request's `Authorization: Bearer <token>` header. Adding this to a
+* handler's arguments is what makes it require a token.
+*/
+pub struct ApiUser(pub User);
+
+impl ApiUser {
+    // The same permissions the htmx routes check with permission_required!
+    pub fn require(&self, permission: UserPermission) -> Result<(), ApiError> {
+        if self.0.has_perm(permission) {
+            Ok(())
+        } else {
+            Err(ApiError::forbidden())
+        }
+    }
+}
+
+/**
+* Resolves the bearer token up front, so the rest of the request runs with its user as the
+* audit log actor. Requests without a valid token carry on without a user and are rejected
+* by ApiUser, leaving the public routes like the openapi document reachable.
+*/
+pub async fn authenticate(
+    State(state): State<WebApiState>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let token = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(|value| value.to_string());
+
+    let user = match token {
+        Some(token) => match state
+            .auth_service
+            .get_user_for_api_token(GetUserForApiTokenInput { token })
+            .await
+        {
+            Ok(user) => Some(user),
+            Err(GetUserForApiTokenFailure::InvalidToken) => None,
+            Err(e) => return Err(ApiError::internal(e)),
+        },
+        None => None,
+    };
+
+    let actor_id = user.as_ref().map(|user| user.id.clone());
+    if let Some(user) = user {
+        request.extensions_mut().insert(user);
+    }
+
+    Ok(with_actor(actor_id, next.run(request)).await)
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for ApiUser {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<User>()
+            .cloned()
+            .map(ApiUser)
+            .ok_or(ApiError::unauthorized())
+    }
+}
@@ -0,0 +1,418 @@
+use axum::{middleware, Router};
+use state::WebApiState;
+
+use auth::authenticate;
+
+use openapi::openapi_routes;
+use resources::assessments::assessments_routes;
+use resources::locations::locations_routes;
+use resources::shifts::shifts_routes;
+use resources::tags::tags_routes;
+use resources::workers::workers_routes;
+use resources::worksites::worksites_routes;
+
+pub mod auth;
+pub mod error;
+pub mod models;
+pub mod openapi;
+pub mod resources;
+pub mod routes;
+pub mod state;
+
+/**
+* The versioned JSON api under /api/v1. Requests authenticate with a personal api token
+* from the profile page rather than the session cookie.
+*/
+pub fn routes(state: WebApiState) -> Router {
+    Router::new()
+        .merge(worksites_routes(state.clone()))
+        .merge(locations_routes(state.clone()))
+        .merge(shifts_routes(state.clone()))
+        .merge(workers_routes(state.clone()))
+        .merge(tags_routes(state.clone()))
+        .merge(assessments_routes(state.clone()))
+        .merge(openapi_routes())
+        .layer(middleware::from_fn_with_state(state, authenticate))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use audit_service::{
+        audit_event_publisher::AuditEventPublisher, get_audit_entries::GetAuditEntriesInput,
+        models::AuditFilter, service::AuditService,
+    };
+    use auth_service::{
+        create_api_token::CreateApiTokenInput, create_user::CreateUserInput,
+        models::OidcRoleMapping, ports::event_publisher::EventPublishers, service::AuthService,
+    };
+    use axum::{body::Body, Router};
+    use broadcast_event_publisher::BroadcastEventPublisher;
+    use chrono::Utc;
+    use http::{header::AUTHORIZATION, Request, StatusCode};
+    use in_memory_audit_log_repository::InMemoryAuditLogRepository;
+    use in_memory_user_repository::InMemoryUserRepository;
+    use in_memory_worksite_repository::InMemoryWorksiteRepository;
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+    use worksite_service::{
+        models::{Assessment, Location, Shift, Tag, Worker, Worksite},
+        ports::event_publisher::EventPublishers as WorksiteEventPublishers,
+        service::WorksiteService,
+    };
+
+    use super::*;
+    use crate::{
+        models::WorksiteSummaryResponse,
+        openapi::{openapi, openapi_path},
+    };
+
+    async fn setup(role: &str) -> (Router, String, AuditService) {
+        let audit_service = AuditService::new(Arc::new(InMemoryAuditLogRepository::empty()));
+        let audit_publisher = Arc::new(AuditEventPublisher {
+            audit_service: Arc::new(audit_service.clone()),
+        });
+
+        let auth_service = AuthService::new(
+            Arc::new(InMemoryUserRepository::empty()),
+            Arc::new(EventPublishers(vec![audit_publisher.clone()])),
+            vec![],
+            None,
+            OidcRoleMapping::default(),
+        );
+        let user = auth_service
+            .create_user(CreateUserInput {
+                email: "steward@example.com".into(),
+                password: "password".into(),
+                role: role.into(),
+            })
+            .await
+            .unwrap();
+        let created = auth_service
+            .create_api_token(CreateApiTokenInput {
+                user_id: user.id,
+                name: "Spreadsheet sync".into(),
+            })
+            .await
+            .unwrap();
+
+        let worker = Worker {
+            id: "1".into(),
+            assessments: vec![Assessment {
+                id: "1".into(),
+                value: 2,
+                notes: "".into(),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                assessor: "Michael Scott".into(),
+            }],
+            ..Worker::new("Jim".into(), "Halpert".into(), "jim@example.com".into())
+        };
+        let worksite = Worksite {
+            id: "1".into(),
+            name: "Dunder Mifflin".into(),
+            locations: vec![Location {
+                id: "1".into(),
+                name: "Scranton".into(),
+                shifts: vec![Shift {
+                    id: "1".into(),
+                    name: "Day".into(),
+                    workers: vec![],
+                }],
+            }],
+            tags: vec![Tag {
+                id: "1".into(),
+                name: "Baked a cake".into(),
+                icon: "🍰".into(),
+            }],
+            workers: vec![worker],
+            turf_assignments: vec![],
+            actions: vec![],
+            card_thresholds: vec![],
+            custom_fields: vec![],
+            relationships: vec![],
+        };
+        let worksite_service = WorksiteService::new(
+            Arc::new(InMemoryWorksiteRepository::with(vec![worksite])),
+            Arc::new(WorksiteEventPublishers(vec![
+                Arc::new(BroadcastEventPublisher::new()),
+                audit_publisher,
+            ])),
+        );
+
+        let router = routes(WebApiState {
+            auth_service: Arc::new(auth_service),
+            worksite_service: Arc::new(worksite_service),
+        });
+
+        (router, created.token, audit_service)
+    }
+
+    fn request(method: &str, uri: &str, token: Option<&str>) -> Request<Body> {
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(token) = token {
+            builder = builder.header(AUTHORIZATION, format!("Bearer {}", token));
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    fn json_request(method: &str, uri: &str, token: &str, body: Option<Value>) -> Request<Body> {
+        let builder = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(AUTHORIZATION, format!("Bearer {}", token));
+        match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+            None => builder.body(Body::empty()).unwrap(),
+        }
+    }
+
+    // A valid body for each operation that takes one
+    fn example_body(method: &str, path: &str) -> Option<Value> {
+        match (method, path) {
+            ("post", routes::WORKSITES)
+            | ("put", routes::WORKSITE)
+            | ("post", routes::LOCATIONS)
+            | ("post", routes::SHIFTS) => Some(json!({ "name": "Stamford" })),
+            ("post", routes::WORKERS) | ("put", routes::WORKER) => Some(json!({
+                "first_name": "Pam",
+                "last_name": "Beesly",
+                "email": "pam@example.com"
+            })),
+            ("put", routes::WORKER_TAGS) => Some(json!({ "tag_ids": ["1"] })),
+            ("post", routes::TAGS) | ("put", routes::TAG) => {
+                Some(json!({ "name": "Pranks", "icon": "🎭" }))
+            }
+            ("post", routes::ASSESSMENTS) | ("put", routes::ASSESSMENT) => {
+                Some(json!({ "value": 1 }))
+            }
+            _ => None,
+        }
+    }
+
+    #[tokio::test]
+    async fn requires_a_valid_token() {
+        let (router, _, _) = setup("Organizer").await;
+
+        let missing = router
+            .clone()
+            .oneshot(request("GET", "/api/v1/worksites", None))
+            .await
+            .unwrap();
+        assert_eq!(missing.status(), StatusCode::UNAUTHORIZED);
+
+        let invalid = router
+            .oneshot(request("GET", "/api/v1/worksites", Some("yct_nope")))
+            .await
+            .unwrap();
+        assert_eq!(invalid.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn lists_worksites_for_a_token() {
+        let (router, token, _) = setup("Organizer").await;
+
+        let response = router
+            .oneshot(request("GET", "/api/v1/worksites", Some(&token)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let worksites: Vec<WorksiteSummaryResponse> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            worksites,
+            vec![WorksiteSummaryResponse {
+                id: "1".into(),
+                name: "Dunder Mifflin".into(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_worksites_are_not_found() {
+        let (router, token, _) = setup("Organizer").await;
+
+        let response = router
+            .oneshot(request("GET", "/api/v1/worksites/2/tags", Some(&token)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn deletes_respect_user_permissions() {
+        let (router, token, _) = setup("Organizer").await;
+        let response = router
+            .oneshot(request(
+                "DELETE",
+                "/api/v1/worksites/1/tags/1",
+                Some(&token),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let (router, token, _) = setup("SuperAdmin").await;
+        let response = router
+            .oneshot(request(
+                "DELETE",
+                "/api/v1/worksites/1/tags/1",
+                Some(&token),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn records_changes_as_the_token_user() {
+        let (router, token, audit_service) = setup("SuperAdmin").await;
+        router
+            .oneshot(request(
+                "DELETE",
+                "/api/v1/worksites/1/tags/1",
+                Some(&token),
+            ))
+            .await
+            .unwrap();
+
+        let entries = |entity: &str| {
+            audit_service.get_audit_entries(GetAuditEntriesInput {
+                filter: AuditFilter {
+                    entity: Some(entity.into()),
+                    ..AuditFilter::default()
+                },
+            })
+        };
+        let user = entries("User").await.unwrap().pop().unwrap();
+        let removed = entries("Tag").await.unwrap().pop().unwrap();
+
+        assert_eq!(removed.action, "Removed");
+        assert_eq!(removed.actor_id, Some(user.entity_id));
+    }
+
+    // The documented paths have to be exactly the ones the router serves, each documented
+    // operation has to answer with its documented status, and every other method has to be
+    // rejected, so the spec can't drift from the router in either direction.
+    #[tokio::test]
+    async fn openapi_spec_matches_the_handlers() {
+        let (router, token, _) = setup("SuperAdmin").await;
+        let spec = openapi();
+        let paths = spec["paths"].as_object().unwrap();
+
+        let mut documented: Vec<String> = paths.keys().cloned().collect();
+        let mut served: Vec<String> = routes::ALL
+            .iter()
+            .map(|path| openapi_path(path).0)
+            .collect();
+        documented.sort();
+        served.sort();
+        assert_eq!(documented, served);
+
+        let mut calls = vec![];
+        for path in routes::ALL {
+            for method in ["get", "post", "put", "delete"] {
+                calls.push((method, path));
+            }
+        }
+        // Deletes go last so the other operations still have something to act on
+        calls.sort_by_key(|(method, _)| *method == "delete");
+
+        for (method, path) in calls {
+            let uri = path
+                .split('/')
+                .map(|segment| match segment.starts_with(':') {
+                    true => "1",
+                    false => segment,
+                })
+                .collect::<Vec<_>>()
+                .join("/");
+            let operation = paths[&openapi_path(path).0].get(method);
+
+            let anonymous = router
+                .clone()
+                .oneshot(request(&method.to_uppercase(), &uri, None))
+                .await
+                .unwrap();
+            let response = router
+                .clone()
+                .oneshot(json_request(
+                    &method.to_uppercase(),
+                    &uri,
+                    &token,
+                    example_body(method, path),
+                ))
+                .await
+                .unwrap();
+
+            let Some(operation) = operation else {
+                assert_eq!(
+                    response.status(),
+                    StatusCode::METHOD_NOT_ALLOWED,
+                    "{} {}",
+                    method,
+                    path
+                );
+                continue;
+            };
+
+            let status = response.status();
+            let documented_success: Vec<&String> = operation["responses"]
+                .as_object()
+                .unwrap()
+                .keys()
+                .filter(|code| code.starts_with('2'))
+                .collect();
+            assert_eq!(
+                documented_success,
+                vec![&status.as_u16().to_string()],
+                "{} {}",
+                method,
+                path
+            );
+
+            let expected = match operation["security"] == json!([]) {
+                true => StatusCode::OK,
+                false => StatusCode::UNAUTHORIZED,
+            };
+            assert_eq!(anonymous.status(), expected, "{} {}", method, path);
+        }
+    }
+
+    #[tokio::test]
+    async fn openapi_schemas_match_the_responses() {
+        let (router, token, _) = setup("Organizer").await;
+        let spec = openapi();
+
+        let response = router
+            .oneshot(request("GET", "/api/v1/worksites/1", Some(&token)))
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let worksite: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        let schema = &spec["paths"]["/api/v1/worksites/{worksite_id}"]["get"]["responses"]["200"]
+            ["content"]["application/json"]["schema"]["$ref"];
+        assert_eq!(schema, "#/components/schemas/WorksiteResponse");
+
+        let mut documented: Vec<&String> = spec["components"]["schemas"]["WorksiteResponse"]
+            ["properties"]
+            .as_object()
+            .unwrap()
+            .keys()
+            .collect();
+        let mut returned: Vec<&String> = worksite.as_object().unwrap().keys().collect();
+        documented.sort();
+        returned.sort();
+        assert_eq!(documented, returned);
+    }
+}
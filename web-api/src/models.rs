@@ -0,0 +1,261 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use worksite_service::models::{
+    Address, Assessment, Employment, Location, Shift, Tag, Worker, Worksite,
+};
+
+/**
+* The JSON shapes of the api. They are kept separate from the worksite-service models so the
+* api can stay stable while the models change.
+*/
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct WorksiteSummaryResponse {
+    pub id: String,
+    pub name: String,
+}
+
+impl From<&Worksite> for WorksiteSummaryResponse {
+    fn from(worksite: &Worksite) -> Self {
+        Self {
+            id: worksite.id.clone(),
+            name: worksite.name.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct WorksiteResponse {
+    pub id: String,
+    pub name: String,
+    pub locations: Vec<LocationResponse>,
+    pub tags: Vec<TagResponse>,
+}
+
+impl From<&Worksite> for WorksiteResponse {
+    fn from(worksite: &Worksite) -> Self {
+        Self {
+            id: worksite.id.clone(),
+            name: worksite.name.clone(),
+            locations: worksite.locations.iter().map(|l| l.into()).collect(),
+            tags: worksite.tags.iter().map(|t| t.into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct LocationResponse {
+    pub id: String,
+    pub name: String,
+    pub shifts: Vec<ShiftResponse>,
+}
+
+impl From<&Location> for LocationResponse {
+    fn from(location: &Location) -> Self {
+        Self {
+            id: location.id.clone(),
+            name: location.name.clone(),
+            shifts: location.shifts.iter().map(|s| s.into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct ShiftResponse {
+    pub id: String,
+    pub name: String,
+    pub worker_ids: Vec<String>,
+}
+
+impl From<&Shift> for ShiftResponse {
+    fn from(shift: &Shift) -> Self {
+        Self {
+            id: shift.id.clone(),
+            name: shift.name.clone(),
+            worker_ids: shift.workers.iter().map(|w| w.0.clone()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct WorkerResponse {
+    pub id: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+    pub phone: String,
+    pub address: Option<AddressResponse>,
+    pub employment: EmploymentResponse,
+    /// Active, OnLeave, Terminated, Quit or Transferred
+    pub status: String,
+    pub supervisor_id: Option<String>,
+    pub tag_ids: Vec<String>,
+}
+
+impl From<&Worker> for WorkerResponse {
+    fn from(worker: &Worker) -> Self {
+        Self {
+            id: worker.id.clone(),
+            first_name: worker.first_name.clone(),
+            last_name: worker.last_name.clone(),
+            email: worker.email.clone(),
+            phone: worker.phone.clone(),
+            address: worker.address.as_ref().map(|a| a.into()),
+            employment: (&worker.employment).into(),
+            status: worker.status.to_string(),
+            supervisor_id: worker.supervisor_id.clone(),
+            tag_ids: worker.tags.iter().map(|t| t.0.clone()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct AddressResponse {
+    pub street_address: String,
+    pub city: String,
+    pub region: String,
+    pub postal_code: String,
+}
+
+impl From<&Address> for AddressResponse {
+    fn from(address: &Address) -> Self {
+        Self {
+            street_address: address.street_address.clone(),
+            city: address.city.clone(),
+            region: address.region.clone(),
+            postal_code: address.postal_code.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct EmploymentResponse {
+    pub job_title: String,
+    pub department: String,
+    pub hire_date: Option<NaiveDate>,
+    pub pay_rate: String,
+    /// FullTime, PartTime or Temp
+    pub employment_type: Option<String>,
+}
+
+impl From<&Employment> for EmploymentResponse {
+    fn from(employment: &Employment) -> Self {
+        Self {
+            job_title: employment.job_title.clone(),
+            department: employment.department.clone(),
+            hire_date: employment.hire_date,
+            pay_rate: employment.pay_rate.clone(),
+            employment_type: employment.employment_type.map(|t| t.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct TagResponse {
+    pub id: String,
+    pub name: String,
+    pub icon: String,
+}
+
+impl From<&Tag> for TagResponse {
+    fn from(tag: &Tag) -> Self {
+        Self {
+            id: tag.id.clone(),
+            name: tag.name.clone(),
+            icon: tag.icon.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct AssessmentResponse {
+    pub id: String,
+    pub value: u8,
+    pub notes: String,
+    pub assessor: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<&Assessment> for AssessmentResponse {
+    fn from(assessment: &Assessment) -> Self {
+        Self {
+            id: assessment.id.clone(),
+            value: assessment.value,
+            notes: assessment.notes.clone(),
+            assessor: assessment.assessor.clone(),
+            created_at: assessment.created_at,
+            updated_at: assessment.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct WorksiteRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct LocationRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ShiftRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct WorkerRequest {
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+    #[serde(default)]
+    pub phone: String,
+    #[serde(default)]
+    pub street_address: String,
+    #[serde(default)]
+    pub city: String,
+    #[serde(default)]
+    pub region: String,
+    #[serde(default)]
+    pub postal_code: String,
+    #[serde(default)]
+    pub job_title: String,
+    #[serde(default)]
+    pub department: String,
+    pub hire_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub pay_rate: String,
+    /// FullTime, PartTime or Temp
+    pub employment_type: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct WorkerTagsRequest {
+    pub tag_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct TagRequest {
+    pub name: String,
+    pub icon: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct AssessmentRequest {
+    pub value: u8,
+    #[serde(default)]
+    pub notes: String,
+    /// Defaults to the name of the token's user
+    pub assessor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct WorkersQuery {
+    /// Matches against worker names, like the search box on the workers page
+    pub filter: Option<String>,
+    /// Only workers under this supervisor, directly or further down the chain
+    pub supervisor_id: Option<String>,
+}
@@ -0,0 +1,309 @@
+use axum::{routing::get, Json, Router};
+use http::StatusCode;
+use schemars::{
+    gen::{SchemaGenerator, SchemaSettings},
+    schema::{Schema, SchemaObject},
+    JsonSchema,
+};
+use serde_json::{json, Map, Value};
+
+use crate::{
+    error::ErrorBody,
+    models::{
+        AssessmentRequest, AssessmentResponse, LocationRequest, LocationResponse, ShiftRequest,
+        ShiftResponse, TagRequest, TagResponse, WorkerRequest, WorkerResponse, WorkerTagsRequest,
+        WorkersQuery, WorksiteRequest, WorksiteResponse, WorksiteSummaryResponse,
+    },
+    routes,
+};
+
+pub fn openapi_routes() -> Router {
+    Router::new().route(routes::OPENAPI, get(get_openapi))
+}
+
+// Public, so client generators can fetch it without a token
+async fn get_openapi() -> Json<Value> {
+    Json(openapi())
+}
+
+type SchemaFn = fn(&mut SchemaGenerator) -> Schema;
+
+fn schema<T: JsonSchema>(generator: &mut SchemaGenerator) -> Schema {
+    generator.subschema_for::<T>()
+}
+
+fn object_schema<T: JsonSchema>(generator: &mut SchemaGenerator) -> SchemaObject {
+    generator.root_schema_for::<T>().schema
+}
+
+type QueryFn = fn(&mut SchemaGenerator) -> SchemaObject;
+
+/**
+* One route and method of the api. The request and response bodies are the same types the
+* handlers use, so the schemas can't drift from what is actually sent.
+*/
+struct Operation {
+    method: &'static str,
+    path: &'static str,
+    summary: &'static str,
+    query: Option<QueryFn>,
+    request: Option<SchemaFn>,
+    status: StatusCode,
+    response: Option<SchemaFn>,
+    permission: bool,
+}
+
+impl Operation {
+    fn new(method: &'static str, path: &'static str, summary: &'static str) -> Self {
+        Self {
+            method,
+            path,
+            summary,
+            query: None,
+            request: None,
+            status: StatusCode::NO_CONTENT,
+            response: None,
+            permission: false,
+        }
+    }
+    fn query<T: JsonSchema>(self) -> Self {
+        Self {
+            query: Some(object_schema::<T>),
+            ..self
+        }
+    }
+    fn request<T: JsonSchema>(self) -> Self {
+        Self {
+            request: Some(schema::<T>),
+            ..self
+        }
+    }
+    fn response<T: JsonSchema>(self, status: StatusCode) -> Self {
+        Self {
+            status,
+            response: Some(schema::<T>),
+            ..self
+        }
+    }
+    fn empty_response(self, status: StatusCode) -> Self {
+        Self { status, ..self }
+    }
+    // Only users whose role has the matching UserPermission may call it
+    fn requires_permission(self) -> Self {
+        Self {
+            permission: true,
+            ..self
+        }
+    }
+}
+
+fn operations() -> Vec<Operation> {
+    vec![
+        Operation::new("get", routes::WORKSITES, "List worksites")
+            .response::<Vec<WorksiteSummaryResponse>>(StatusCode::OK),
+        Operation::new("post", routes::WORKSITES, "Create a worksite")
+            .request::<WorksiteRequest>()
+            .response::<WorksiteResponse>(StatusCode::CREATED),
+        Operation::new("get", routes::WORKSITE, "Get a worksite")
+            .response::<WorksiteResponse>(StatusCode::OK),
+        Operation::new("put", routes::WORKSITE, "Rename a worksite")
+            .request::<WorksiteRequest>()
+            .response::<WorksiteResponse>(StatusCode::OK),
+        Operation::new("get", routes::LOCATIONS, "List locations")
+            .response::<Vec<LocationResponse>>(StatusCode::OK),
+        Operation::new("post", routes::LOCATIONS, "Add a location")
+            .request::<LocationRequest>()
+            .empty_response(StatusCode::CREATED),
+        Operation::new("get", routes::LOCATION, "Get a location")
+            .response::<LocationResponse>(StatusCode::OK),
+        Operation::new("get", routes::SHIFTS, "List a location's shifts")
+            .response::<Vec<ShiftResponse>>(StatusCode::OK),
+        Operation::new("post", routes::SHIFTS, "Add a shift")
+            .request::<ShiftRequest>()
+            .empty_response(StatusCode::CREATED),
+        Operation::new("put", routes::SHIFT_WORKER, "Assign a worker to a shift"),
+        Operation::new(
+            "delete",
+            routes::SHIFT_WORKER,
+            "Remove a worker from a shift",
+        )
+        .requires_permission(),
+        Operation::new("get", routes::WORKERS, "List workers")
+            .query::<WorkersQuery>()
+            .response::<Vec<WorkerResponse>>(StatusCode::OK),
+        Operation::new("post", routes::WORKERS, "Add a worker")
+            .request::<WorkerRequest>()
+            .empty_response(StatusCode::CREATED),
+        Operation::new("get", routes::WORKER, "Get a worker")
+            .response::<WorkerResponse>(StatusCode::OK),
+        Operation::new("put", routes::WORKER, "Update a worker")
+            .request::<WorkerRequest>()
+            .response::<WorkerResponse>(StatusCode::OK),
+        Operation::new("put", routes::WORKER_TAGS, "Replace a worker's tags")
+            .request::<WorkerTagsRequest>(),
+        Operation::new("get", routes::TAGS, "List tags")
+            .response::<Vec<TagResponse>>(StatusCode::OK),
+        Operation::new("post", routes::TAGS, "Add a tag")
+            .request::<TagRequest>()
+            .empty_response(StatusCode::CREATED),
+        Operation::new("get", routes::TAG, "Get a tag").response::<TagResponse>(StatusCode::OK),
+        Operation::new("put", routes::TAG, "Update a tag")
+            .request::<TagRequest>()
+            .response::<TagResponse>(StatusCode::OK),
+        Operation::new("delete", routes::TAG, "Delete a tag").requires_permission(),
+        Operation::new("get", routes::ASSESSMENTS, "List a worker's assessments").response::<Vec<
+            AssessmentResponse,
+        >>(
+            StatusCode::OK
+        ),
+        Operation::new("post", routes::ASSESSMENTS, "Assess a worker")
+            .request::<AssessmentRequest>()
+            .empty_response(StatusCode::CREATED),
+        Operation::new("get", routes::ASSESSMENT, "Get an assessment")
+            .response::<AssessmentResponse>(StatusCode::OK),
+        Operation::new("put", routes::ASSESSMENT, "Update an assessment")
+            .request::<AssessmentRequest>()
+            .response::<AssessmentResponse>(StatusCode::OK),
+        Operation::new("delete", routes::ASSESSMENT, "Delete an assessment").requires_permission(),
+    ]
+}
+
+// Axum's /:param segments are written as /{param} in OpenAPI
+pub(crate) fn openapi_path(path: &str) -> (String, Vec<String>) {
+    let mut params = vec![];
+    let segments: Vec<String> = path
+        .split('/')
+        .map(|segment| match segment.strip_prefix(':') {
+            Some(param) => {
+                params.push(param.to_string());
+                format!("{{{}}}", param)
+            }
+            None => segment.to_string(),
+        })
+        .collect();
+
+    (segments.join("/"), params)
+}
+
+fn json_content(schema: Schema) -> Value {
+    json!({ "application/json": { "schema": schema } })
+}
+
+/**
+* The OpenAPI 3 document for the api, served at routes::OPENAPI for generating clients.
+*/
+pub fn openapi() -> Value {
+    let mut generator = SchemaSettings::openapi3().into_generator();
+    let error = schema::<ErrorBody>(&mut generator);
+    let mut paths = Map::new();
+
+    for operation in operations() {
+        let (path, path_params) = openapi_path(operation.path);
+
+        let mut parameters: Vec<Value> = path_params
+            .iter()
+            .map(|name| {
+                json!({
+                    "name": name,
+                    "in": "path",
+                    "required": true,
+                    "schema": { "type": "string" }
+                })
+            })
+            .collect();
+        if let Some(query) = operation.query {
+            let query = query(&mut generator);
+            if let Some(object) = query.object {
+                for (name, schema) in object.properties {
+                    parameters.push(json!({
+                        "name": name,
+                        "in": "query",
+                        "required": object.required.contains(&name),
+                        "schema": schema
+                    }));
+                }
+            }
+        }
+
+        let mut responses = Map::new();
+        responses.insert(
+            operation.status.as_u16().to_string(),
+            match operation.response {
+                Some(response) => json!({
+                    "description": operation.status.canonical_reason(),
+                    "content": json_content(response(&mut generator))
+                }),
+                None => json!({ "description": operation.status.canonical_reason() }),
+            },
+        );
+        let mut errors = vec![(StatusCode::UNAUTHORIZED, "Missing or invalid api token")];
+        if operation.permission {
+            errors.push((StatusCode::FORBIDDEN, "The user's role does not allow this"));
+        }
+        if !path_params.is_empty() {
+            errors.push((
+                StatusCode::NOT_FOUND,
+                "Something in the path does not exist",
+            ));
+        }
+        for (status, description) in errors {
+            responses.insert(
+                status.as_u16().to_string(),
+                json!({
+                    "description": description,
+                    "content": json_content(error.clone())
+                }),
+            );
+        }
+
+        let mut spec = json!({
+            "summary": operation.summary,
+            "parameters": parameters,
+            "responses": responses
+        });
+        if let Some(request) = operation.request {
+            spec["requestBody"] = json!({
+                "required": true,
+                "content": json_content(request(&mut generator))
+            });
+        }
+
+        paths
+            .entry(path)
+            .or_insert(json!({}))
+            .as_object_mut()
+            .expect("Paths are objects")
+            .insert(operation.method.to_string(), spec);
+    }
+
+    paths.insert(
+        routes::OPENAPI.to_string(),
+        json!({
+            "get": {
+                "summary": "This document",
+                "security": [],
+                "responses": { "200": { "description": "OK" } }
+            }
+        }),
+    );
+
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "Yallchart API",
+            "version": "1"
+        },
+        "paths": paths,
+        "components": {
+            "schemas": generator.take_definitions(),
+            "securitySchemes": {
+                "apiToken": {
+                    "type": "http",
+                    "scheme": "bearer",
+                    "description": "A personal api token from the profile page"
+                }
+            }
+        },
+        "security": [{ "apiToken": [] }]
+    })
+}
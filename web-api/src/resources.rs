@@ -0,0 +1,23 @@
+use worksite_service::{get_worksite::GetWorksiteInput, models::Worksite};
+
+use crate::{
+    error::{ApiError, ApiResult},
+    state::WebApiState,
+};
+
+pub mod assessments;
+pub mod locations;
+pub mod shifts;
+pub mod tags;
+pub mod workers;
+pub mod worksites;
+
+// Nested resources check their worksite first so a bad id is a 404 rather than a 500
+pub(crate) async fn find_worksite(state: &WebApiState, worksite_id: String) -> ApiResult<Worksite> {
+    state
+        .worksite_service
+        .get_worksite(GetWorksiteInput { id: worksite_id })
+        .await
+        .map_err(ApiError::internal)?
+        .ok_or(ApiError::not_found("Worksite"))
+}
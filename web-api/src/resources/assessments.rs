@@ -0,0 +1,159 @@
+use auth_service::models::UserPermission;
+use axum::{
+    extract::{Path, State},
+    routing::get,
+    Json, Router,
+};
+use http::StatusCode;
+use worksite_service::{
+    add_assessment::AddAssessmentInput, get_assessments::GetAssessmentsInput, models::Worksite,
+    remove_assessment::RemoveAssessmentInput, update_assessment::UpdateAssessmentInput,
+};
+
+use crate::{
+    auth::ApiUser,
+    error::{ApiError, ApiResult},
+    models::{AssessmentRequest, AssessmentResponse},
+    resources::find_worksite,
+    routes,
+    state::WebApiState,
+};
+
+pub fn assessments_routes(state: WebApiState) -> Router {
+    Router::new()
+        .route(
+            routes::ASSESSMENTS,
+            get(get_assessments).post(post_assessments),
+        )
+        .route(
+            routes::ASSESSMENT,
+            get(get_assessment)
+                .put(put_assessment)
+                .delete(delete_assessment),
+        )
+        .with_state(state)
+}
+
+async fn find_worker_worksite(
+    state: &WebApiState,
+    worksite_id: String,
+    worker_id: String,
+) -> ApiResult<Worksite> {
+    let worksite = find_worksite(state, worksite_id).await?;
+    worksite
+        .get_worker(worker_id)
+        .ok_or(ApiError::not_found("Worker"))?;
+
+    Ok(worksite)
+}
+
+async fn get_assessments(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path((worksite_id, worker_id)): Path<(String, String)>,
+) -> ApiResult<Json<Vec<AssessmentResponse>>> {
+    find_worker_worksite(&state, worksite_id.clone(), worker_id.clone()).await?;
+
+    let assessments = state
+        .worksite_service
+        .get_assessments(GetAssessmentsInput {
+            worksite_id,
+            worker_id,
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    Ok(Json(assessments.iter().map(|a| a.into()).collect()))
+}
+
+async fn post_assessments(
+    State(state): State<WebApiState>,
+    ApiUser(user): ApiUser,
+    Path((worksite_id, worker_id)): Path<(String, String)>,
+    Json(request): Json<AssessmentRequest>,
+) -> ApiResult<StatusCode> {
+    find_worker_worksite(&state, worksite_id.clone(), worker_id.clone()).await?;
+
+    state
+        .worksite_service
+        .add_assessment(AddAssessmentInput {
+            worksite_id,
+            worker_id,
+            value: request.value,
+            notes: request.notes,
+            assessor: request.assessor.unwrap_or(user.name()),
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    Ok(StatusCode::CREATED)
+}
+
+async fn get_assessment(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path((worksite_id, worker_id, assessment_id)): Path<(String, String, String)>,
+) -> ApiResult<Json<AssessmentResponse>> {
+    let assessment = find_worker_worksite(&state, worksite_id, worker_id.clone())
+        .await?
+        .get_assessment_for_worker(worker_id, assessment_id)
+        .ok_or(ApiError::not_found("Assessment"))?;
+
+    Ok(Json((&assessment).into()))
+}
+
+async fn put_assessment(
+    State(state): State<WebApiState>,
+    ApiUser(user): ApiUser,
+    Path((worksite_id, worker_id, assessment_id)): Path<(String, String, String)>,
+    Json(request): Json<AssessmentRequest>,
+) -> ApiResult<Json<AssessmentResponse>> {
+    find_worker_worksite(&state, worksite_id.clone(), worker_id.clone())
+        .await?
+        .get_assessment_for_worker(worker_id.clone(), assessment_id.clone())
+        .ok_or(ApiError::not_found("Assessment"))?;
+
+    state
+        .worksite_service
+        .update_assessment(UpdateAssessmentInput {
+            worksite_id: worksite_id.clone(),
+            worker_id: worker_id.clone(),
+            assessment_id: assessment_id.clone(),
+            value: request.value,
+            notes: request.notes,
+            assessor: request.assessor.unwrap_or(user.name()),
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    let assessment = find_worker_worksite(&state, worksite_id, worker_id.clone())
+        .await?
+        .get_assessment_for_worker(worker_id, assessment_id)
+        .ok_or(ApiError::not_found("Assessment"))?;
+    Ok(Json((&assessment).into()))
+}
+
+async fn delete_assessment(
+    State(state): State<WebApiState>,
+    user: ApiUser,
+    Path((worksite_id, worker_id, assessment_id)): Path<(String, String, String)>,
+) -> ApiResult<StatusCode> {
+    user.require(UserPermission::DeleteAssessment)?;
+
+    find_worker_worksite(&state, worksite_id.clone(), worker_id.clone())
+        .await?
+        .get_assessment_for_worker(worker_id.clone(), assessment_id.clone())
+        .ok_or(ApiError::not_found("Assessment"))?;
+
+    state
+        .worksite_service
+        .remove_assessment(RemoveAssessmentInput {
+            worksite_id,
+            worker_id,
+            assessment_id,
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
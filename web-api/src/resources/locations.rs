@@ -0,0 +1,68 @@
+use axum::{
+    extract::{Path, State},
+    routing::get,
+    Json, Router,
+};
+use http::StatusCode;
+use worksite_service::add_location::AddLocationInput;
+
+use crate::{
+    auth::ApiUser,
+    error::{ApiError, ApiResult},
+    models::{LocationRequest, LocationResponse},
+    resources::find_worksite,
+    routes,
+    state::WebApiState,
+};
+
+pub fn locations_routes(state: WebApiState) -> Router {
+    Router::new()
+        .route(routes::LOCATIONS, get(get_locations).post(post_locations))
+        .route(routes::LOCATION, get(get_location))
+        .with_state(state)
+}
+
+async fn get_locations(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path(worksite_id): Path<String>,
+) -> ApiResult<Json<Vec<LocationResponse>>> {
+    let worksite = find_worksite(&state, worksite_id).await?;
+
+    Ok(Json(worksite.locations.iter().map(|l| l.into()).collect()))
+}
+
+async fn post_locations(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path(worksite_id): Path<String>,
+    Json(request): Json<LocationRequest>,
+) -> ApiResult<StatusCode> {
+    find_worksite(&state, worksite_id.clone()).await?;
+
+    state
+        .worksite_service
+        .add_location(AddLocationInput {
+            worksite_id,
+            location_name: request.name,
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    Ok(StatusCode::CREATED)
+}
+
+async fn get_location(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path((worksite_id, location_id)): Path<(String, String)>,
+) -> ApiResult<Json<LocationResponse>> {
+    let worksite = find_worksite(&state, worksite_id).await?;
+
+    worksite
+        .locations
+        .iter()
+        .find(|l| l.id == location_id)
+        .map(|l| Json(l.into()))
+        .ok_or(ApiError::not_found("Location"))
+}
@@ -0,0 +1,139 @@
+use auth_service::models::UserPermission;
+use axum::{
+    extract::{Path, State},
+    routing::{get, put},
+    Json, Router,
+};
+use http::StatusCode;
+use serde::Deserialize;
+use worksite_service::{
+    add_shift::AddShiftInput, assign_worker::AssignWorkerInput, models::Worksite,
+    remove_worker_from_shift::RemoveWorkerFromShiftInput,
+};
+
+use crate::{
+    auth::ApiUser,
+    error::{ApiError, ApiResult},
+    models::{ShiftRequest, ShiftResponse},
+    resources::find_worksite,
+    routes,
+    state::WebApiState,
+};
+
+pub fn shifts_routes(state: WebApiState) -> Router {
+    Router::new()
+        .route(routes::SHIFTS, get(get_shifts).post(post_shifts))
+        .route(
+            routes::SHIFT_WORKER,
+            put(put_shift_worker).delete(delete_shift_worker),
+        )
+        .with_state(state)
+}
+
+async fn get_shifts(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path((worksite_id, location_id)): Path<(String, String)>,
+) -> ApiResult<Json<Vec<ShiftResponse>>> {
+    let worksite = find_worksite(&state, worksite_id).await?;
+    let location = worksite
+        .locations
+        .iter()
+        .find(|l| l.id == location_id)
+        .ok_or(ApiError::not_found("Location"))?;
+
+    Ok(Json(location.shifts.iter().map(|s| s.into()).collect()))
+}
+
+async fn post_shifts(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path((worksite_id, location_id)): Path<(String, String)>,
+    Json(request): Json<ShiftRequest>,
+) -> ApiResult<StatusCode> {
+    let worksite = find_worksite(&state, worksite_id.clone()).await?;
+    if !worksite.locations.iter().any(|l| l.id == location_id) {
+        return Err(ApiError::not_found("Location"));
+    }
+
+    state
+        .worksite_service
+        .add_shift(AddShiftInput {
+            worksite_id,
+            location_id,
+            shift_name: request.name,
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    Ok(StatusCode::CREATED)
+}
+
+#[derive(Deserialize)]
+struct ShiftWorkerPath {
+    worksite_id: String,
+    location_id: String,
+    shift_id: String,
+    worker_id: String,
+}
+
+fn check_shift_worker(worksite: &Worksite, path: &ShiftWorkerPath) -> ApiResult<()> {
+    let location = worksite
+        .locations
+        .iter()
+        .find(|l| l.id == path.location_id)
+        .ok_or(ApiError::not_found("Location"))?;
+    if !location.shifts.iter().any(|s| s.id == path.shift_id) {
+        return Err(ApiError::not_found("Shift"));
+    }
+    worksite
+        .get_worker(path.worker_id.clone())
+        .ok_or(ApiError::not_found("Worker"))?;
+
+    Ok(())
+}
+
+async fn put_shift_worker(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path(path): Path<ShiftWorkerPath>,
+) -> ApiResult<StatusCode> {
+    let worksite = find_worksite(&state, path.worksite_id.clone()).await?;
+    check_shift_worker(&worksite, &path)?;
+
+    state
+        .worksite_service
+        .assign_worker(AssignWorkerInput {
+            worksite_id: path.worksite_id,
+            location_id: path.location_id,
+            shift_id: path.shift_id,
+            worker_id: path.worker_id,
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn delete_shift_worker(
+    State(state): State<WebApiState>,
+    user: ApiUser,
+    Path(path): Path<ShiftWorkerPath>,
+) -> ApiResult<StatusCode> {
+    user.require(UserPermission::DeleteAssignedWorker)?;
+
+    let worksite = find_worksite(&state, path.worksite_id.clone()).await?;
+    check_shift_worker(&worksite, &path)?;
+
+    state
+        .worksite_service
+        .remove_worker_from_shift(RemoveWorkerFromShiftInput {
+            id: path.worksite_id,
+            shift_id: path.shift_id,
+            worker_id: path.worker_id,
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
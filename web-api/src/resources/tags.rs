@@ -0,0 +1,130 @@
+use auth_service::models::UserPermission;
+use axum::{
+    extract::{Path, State},
+    routing::get,
+    Json, Router,
+};
+use http::StatusCode;
+use worksite_service::{
+    add_tag::AddTagInput, get_tags::GetTagsInput, remove_tag::RemoveTagInput,
+    update_tag::UpdateTagInput,
+};
+
+use crate::{
+    auth::ApiUser,
+    error::{ApiError, ApiResult},
+    models::{TagRequest, TagResponse},
+    resources::find_worksite,
+    routes,
+    state::WebApiState,
+};
+
+pub fn tags_routes(state: WebApiState) -> Router {
+    Router::new()
+        .route(routes::TAGS, get(get_tags).post(post_tags))
+        .route(routes::TAG, get(get_tag).put(put_tag).delete(delete_tag))
+        .with_state(state)
+}
+
+async fn get_tags(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path(worksite_id): Path<String>,
+) -> ApiResult<Json<Vec<TagResponse>>> {
+    find_worksite(&state, worksite_id.clone()).await?;
+
+    let tags = state
+        .worksite_service
+        .get_tags(GetTagsInput { worksite_id })
+        .await
+        .map_err(ApiError::internal)?;
+
+    Ok(Json(tags.iter().map(|t| t.into()).collect()))
+}
+
+async fn post_tags(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path(worksite_id): Path<String>,
+    Json(request): Json<TagRequest>,
+) -> ApiResult<StatusCode> {
+    find_worksite(&state, worksite_id.clone()).await?;
+
+    state
+        .worksite_service
+        .add_tag(AddTagInput {
+            worksite_id,
+            name: request.name,
+            icon: request.icon,
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    Ok(StatusCode::CREATED)
+}
+
+async fn get_tag(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path((worksite_id, tag_id)): Path<(String, String)>,
+) -> ApiResult<Json<TagResponse>> {
+    let tag = find_worksite(&state, worksite_id)
+        .await?
+        .get_tag(tag_id)
+        .ok_or(ApiError::not_found("Tag"))?;
+
+    Ok(Json((&tag).into()))
+}
+
+async fn put_tag(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path((worksite_id, tag_id)): Path<(String, String)>,
+    Json(request): Json<TagRequest>,
+) -> ApiResult<Json<TagResponse>> {
+    find_worksite(&state, worksite_id.clone())
+        .await?
+        .get_tag(tag_id.clone())
+        .ok_or(ApiError::not_found("Tag"))?;
+
+    state
+        .worksite_service
+        .update_tag(UpdateTagInput {
+            worksite_id: worksite_id.clone(),
+            tag_id: tag_id.clone(),
+            name: request.name,
+            icon: request.icon,
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    let tag = find_worksite(&state, worksite_id)
+        .await?
+        .get_tag(tag_id)
+        .ok_or(ApiError::not_found("Tag"))?;
+    Ok(Json((&tag).into()))
+}
+
+async fn delete_tag(
+    State(state): State<WebApiState>,
+    user: ApiUser,
+    Path((worksite_id, tag_id)): Path<(String, String)>,
+) -> ApiResult<StatusCode> {
+    user.require(UserPermission::DeleteTag)?;
+
+    find_worksite(&state, worksite_id.clone())
+        .await?
+        .get_tag(tag_id.clone())
+        .ok_or(ApiError::not_found("Tag"))?;
+
+    state
+        .worksite_service
+        .remove_tag(RemoveTagInput {
+            worksite_id,
+            tag_id,
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,188 @@
+use axum::{
+    extract::{Path, Query, State},
+    routing::{get, put},
+    Json, Router,
+};
+use http::StatusCode;
+use worksite_service::{
+    add_worker::AddWorkerInput,
+    assign_tags::AssignTagsInput,
+    filter_workers::FilterWorkersInput,
+    models::{Employment, EmploymentType},
+    update_worker::UpdateWorkerInput,
+};
+
+use crate::{
+    auth::ApiUser,
+    error::{ApiError, ApiResult},
+    models::{WorkerRequest, WorkerResponse, WorkerTagsRequest, WorkersQuery},
+    resources::find_worksite,
+    routes,
+    state::WebApiState,
+};
+
+pub fn workers_routes(state: WebApiState) -> Router {
+    Router::new()
+        .route(routes::WORKERS, get(get_workers).post(post_workers))
+        .route(routes::WORKER, get(get_worker).put(put_worker))
+        .route(routes::WORKER_TAGS, put(put_worker_tags))
+        .with_state(state)
+}
+
+async fn get_workers(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path(worksite_id): Path<String>,
+    Query(query): Query<WorkersQuery>,
+) -> ApiResult<Json<Vec<WorkerResponse>>> {
+    let worksite = find_worksite(&state, worksite_id.clone()).await?;
+
+    let workers = match (query.filter, query.supervisor_id) {
+        (None, None) => worksite.workers,
+        (filter, supervisor_id) => state
+            .worksite_service
+            .filter_workers(FilterWorkersInput {
+                worksite_id,
+                filter: filter.unwrap_or_default(),
+                supervisor_id,
+            })
+            .await
+            .map_err(ApiError::internal)?,
+    };
+
+    Ok(Json(workers.iter().map(|w| w.into()).collect()))
+}
+
+async fn post_workers(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path(worksite_id): Path<String>,
+    Json(request): Json<WorkerRequest>,
+) -> ApiResult<StatusCode> {
+    find_worksite(&state, worksite_id.clone()).await?;
+    let employment = employment(&request)?;
+
+    state
+        .worksite_service
+        .add_worker(AddWorkerInput {
+            worksite_id,
+            first_name: request.first_name,
+            last_name: request.last_name,
+            email: request.email,
+            phone: request.phone,
+            street_address: request.street_address,
+            city: request.city,
+            region: request.region,
+            postal_code: request.postal_code,
+            employment,
+            custom_values: vec![],
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    Ok(StatusCode::CREATED)
+}
+
+async fn get_worker(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path((worksite_id, worker_id)): Path<(String, String)>,
+) -> ApiResult<Json<WorkerResponse>> {
+    let worksite = find_worksite(&state, worksite_id).await?;
+    let worker = worksite
+        .get_worker(worker_id)
+        .ok_or(ApiError::not_found("Worker"))?;
+
+    Ok(Json((&worker).into()))
+}
+
+async fn put_worker(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path((worksite_id, worker_id)): Path<(String, String)>,
+    Json(request): Json<WorkerRequest>,
+) -> ApiResult<Json<WorkerResponse>> {
+    let worksite = find_worksite(&state, worksite_id.clone()).await?;
+    let existing = worksite
+        .get_worker(worker_id.clone())
+        .ok_or(ApiError::not_found("Worker"))?;
+    let employment = employment(&request)?;
+
+    state
+        .worksite_service
+        .update_worker(UpdateWorkerInput {
+            worker_id: worker_id.clone(),
+            worksite_id: worksite_id.clone(),
+            first_name: request.first_name,
+            last_name: request.last_name,
+            email: request.email,
+            phone: request.phone,
+            street_address: request.street_address,
+            city: request.city,
+            region: request.region,
+            postal_code: request.postal_code,
+            employment,
+            // Custom fields aren't part of the api yet, so keep whatever the worker has
+            custom_values: existing.custom_values,
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    let worker = find_worksite(&state, worksite_id)
+        .await?
+        .get_worker(worker_id)
+        .ok_or(ApiError::not_found("Worker"))?;
+    Ok(Json((&worker).into()))
+}
+
+fn employment(request: &WorkerRequest) -> ApiResult<Employment> {
+    let employment_type = match &request.employment_type {
+        None => None,
+        Some(employment_type) => Some(EmploymentType::new(employment_type).ok_or(
+            ApiError::bad_request(format!("Unknown employment type {}", employment_type)),
+        )?),
+    };
+
+    Ok(Employment {
+        job_title: request.job_title.clone(),
+        department: request.department.clone(),
+        hire_date: request.hire_date,
+        pay_rate: request.pay_rate.clone(),
+        employment_type,
+    })
+}
+
+// Replaces the worker's tags, the same as saving the tags form on the worker page
+async fn put_worker_tags(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path((worksite_id, worker_id)): Path<(String, String)>,
+    Json(request): Json<WorkerTagsRequest>,
+) -> ApiResult<StatusCode> {
+    let worksite = find_worksite(&state, worksite_id.clone()).await?;
+    worksite
+        .get_worker(worker_id.clone())
+        .ok_or(ApiError::not_found("Worker"))?;
+    if let Some(tag_id) = request
+        .tag_ids
+        .iter()
+        .find(|id| worksite.get_tag(id.to_string()).is_none())
+    {
+        return Err(ApiError::bad_request(format!(
+            "Tag {} does not exist",
+            tag_id
+        )));
+    }
+
+    state
+        .worksite_service
+        .assign_tags(AssignTagsInput {
+            worksite_id,
+            worker_id,
+            tags: request.tag_ids,
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
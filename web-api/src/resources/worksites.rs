@@ -0,0 +1,85 @@
+use axum::{
+    extract::{Path, State},
+    routing::get,
+    Json, Router,
+};
+use http::StatusCode;
+use worksite_service::{
+    create_worksite::CreateWorksiteInput, update_worksite::UpdateWorksiteInput,
+};
+
+use crate::{
+    auth::ApiUser,
+    error::{ApiError, ApiResult},
+    models::{WorksiteRequest, WorksiteResponse, WorksiteSummaryResponse},
+    resources::find_worksite,
+    routes,
+    state::WebApiState,
+};
+
+pub fn worksites_routes(state: WebApiState) -> Router {
+    Router::new()
+        .route(routes::WORKSITES, get(get_worksites).post(post_worksites))
+        .route(routes::WORKSITE, get(get_worksite).put(put_worksite))
+        .with_state(state)
+}
+
+async fn get_worksites(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+) -> ApiResult<Json<Vec<WorksiteSummaryResponse>>> {
+    let worksites = state
+        .worksite_service
+        .get_worksites()
+        .await
+        .map_err(ApiError::internal)?;
+
+    Ok(Json(worksites.iter().map(|w| w.into()).collect()))
+}
+
+async fn post_worksites(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Json(request): Json<WorksiteRequest>,
+) -> ApiResult<(StatusCode, Json<WorksiteResponse>)> {
+    let worksite = state
+        .worksite_service
+        .create_worksite(CreateWorksiteInput {
+            worksite_name: request.name,
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    Ok((StatusCode::CREATED, Json((&worksite).into())))
+}
+
+async fn get_worksite(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path(worksite_id): Path<String>,
+) -> ApiResult<Json<WorksiteResponse>> {
+    let worksite = find_worksite(&state, worksite_id).await?;
+
+    Ok(Json((&worksite).into()))
+}
+
+async fn put_worksite(
+    State(state): State<WebApiState>,
+    _user: ApiUser,
+    Path(worksite_id): Path<String>,
+    Json(request): Json<WorksiteRequest>,
+) -> ApiResult<Json<WorksiteResponse>> {
+    find_worksite(&state, worksite_id.clone()).await?;
+
+    state
+        .worksite_service
+        .update_worksite(UpdateWorksiteInput {
+            worksite_id: worksite_id.clone(),
+            worksite_name: request.name,
+        })
+        .await
+        .map_err(ApiError::internal)?;
+
+    let worksite = find_worksite(&state, worksite_id).await?;
+    Ok(Json((&worksite).into()))
+}
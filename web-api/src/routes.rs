@@ -0,0 +1,40 @@
+pub const WORKSITES: &str = "/api/v1/worksites";
+pub const WORKSITE: &str = "/api/v1/worksites/:worksite_id";
+
+pub const LOCATIONS: &str = "/api/v1/worksites/:worksite_id/locations";
+pub const LOCATION: &str = "/api/v1/worksites/:worksite_id/locations/:location_id";
+
+pub const SHIFTS: &str = "/api/v1/worksites/:worksite_id/locations/:location_id/shifts";
+pub const SHIFT_WORKER: &str =
+    "/api/v1/worksites/:worksite_id/locations/:location_id/shifts/:shift_id/workers/:worker_id";
+
+pub const WORKERS: &str = "/api/v1/worksites/:worksite_id/workers";
+pub const WORKER: &str = "/api/v1/worksites/:worksite_id/workers/:worker_id";
+pub const WORKER_TAGS: &str = "/api/v1/worksites/:worksite_id/workers/:worker_id/tags";
+
+pub const TAGS: &str = "/api/v1/worksites/:worksite_id/tags";
+pub const TAG: &str = "/api/v1/worksites/:worksite_id/tags/:tag_id";
+
+pub const ASSESSMENTS: &str = "/api/v1/worksites/:worksite_id/workers/:worker_id/assessments";
+pub const ASSESSMENT: &str =
+    "/api/v1/worksites/:worksite_id/workers/:worker_id/assessments/:assessment_id";
+
+pub const OPENAPI: &str = "/api/v1/openapi.json";
+
+// Every path the api serves, so the OpenAPI document can be checked against the router
+pub const ALL: [&str; 14] = [
+    WORKSITES,
+    WORKSITE,
+    LOCATIONS,
+    LOCATION,
+    SHIFTS,
+    SHIFT_WORKER,
+    WORKERS,
+    WORKER,
+    WORKER_TAGS,
+    TAGS,
+    TAG,
+    ASSESSMENTS,
+    ASSESSMENT,
+    OPENAPI,
+];
@@ -0,0 +1,9 @@
+use auth_service::service::AuthService;
+use std::sync::Arc;
+use worksite_service::service::WorksiteService;
+
+#[derive(Clone)]
+pub struct WebApiState {
+    pub auth_service: Arc<AuthService>,
+    pub worksite_service: Arc<WorksiteService>,
+}
@@ -14,15 +14,11 @@ fn profile_links() -> Vec<MenuLink> {
         crate::context::context().expect("Unable to retrieve htmx context.");
 
     match ctx.current_user {
-        Some(user) => {
+        Some(_) => {
             vec![
                 MenuLink::builder()
                     .label("Your Profile")
-                    .attrs(
-                        Attrs::with("hx-get", routes::user_edit_form(&user.id))
-                            .set("hx-target", modal_target())
-                            .set("hx-push-url", routes::user_edit_form(&user.id)),
-                    )
+                    .attrs(Attrs::with("href", routes::profile()))
                     .build(),
                 MenuLink::builder()
                     .label("Support")
@@ -54,8 +50,26 @@ pub fn Nav() -> String {
         ("Wallchart", routes::wallchart(), None),
         ("Workers", routes::workers(&worksite_id), None),
         ("Tags", routes::tags(&worksite_id), None),
+        (
+            "Fields",
+            routes::custom_fields(&worksite_id),
+            Some(UserPermission::ManageCustomFields),
+        ),
+        ("Turf", routes::turf(&worksite_id), None),
+        ("Committee", routes::committee(&worksite_id), None),
+        ("Actions", routes::actions(&worksite_id), None),
+        ("Cards", routes::cards(&worksite_id), None),
+        ("Turnover", routes::turnover(&worksite_id), None),
+        ("Roster", routes::roster(&worksite_id), None),
+        ("Duplicates", routes::duplicates(&worksite_id), None),
+        ("My Tasks", routes::my_tasks(), None),
         ("Users", routes::users(), Some(UserPermission::CreateUser)),
         ("Import", routes::csv_upload(), None),
+        (
+            "Audit Log",
+            routes::audit_log(),
+            Some(UserPermission::ReadAuditLog),
+        ),
     ]
     .into_iter()
     .filter_map(|(label, href, permission)| match permission {
@@ -35,6 +35,7 @@ pub fn PageLayout(props: PageLayoutProps) -> String {
                     ></script>
                     <script src="https://unpkg.com/htmx.org/dist/ext/loading-states.js"></script>
                     <script src="https://unpkg.com/htmx.org/dist/ext/response-targets.js"></script>
+                    <script src="https://unpkg.com/htmx.org/dist/ext/sse.js"></script>
                     <script>{
                         r#"
                         htmx.on("htmx:sendError", function() {
@@ -1,10 +1,17 @@
 use std::collections::HashMap;
 
-use rscx::{component, html, props};
+use chrono::NaiveDate;
+use rscx::{component, html, props, CollectFragment, CollectFragmentAsync};
 use serde::Deserialize;
 use validator::{Validate, ValidationError, ValidationErrorsKind};
 
-use web_client::server::form::{CellSpan, GridCell, GridLayout, Label, TextInput};
+use web_client::server::{
+    attrs::Attrs,
+    form::{CellSpan, GridCell, GridLayout, Label, Select, SelectOption, TextInput},
+};
+use worksite_service::models::{
+    CustomField, CustomFieldType, CustomFieldValue, CustomValue, Employment, EmploymentType,
+};
 
 #[derive(Clone, Debug, Default, Deserialize, Validate)]
 pub struct WorkerProfileFormData {
@@ -14,10 +21,102 @@ pub struct WorkerProfileFormData {
     pub last_name: String,
     #[validate(email)]
     pub email: String,
+    pub phone: String,
     pub street_address: String,
     pub city: String,
     pub region: String,
     pub postal_code: String,
+    pub job_title: String,
+    pub department: String,
+    // YYYY-MM-DD from the date input, or empty
+    pub hire_date: String,
+    pub pay_rate: String,
+    // Empty when unknown
+    pub employment_type: String,
+    // The worksite's custom fields, keyed by custom_field_key
+    #[serde(flatten)]
+    pub custom: HashMap<String, String>,
+}
+
+impl WorkerProfileFormData {
+    pub fn employment(&self) -> Employment {
+        Employment {
+            job_title: self.job_title.trim().to_string(),
+            department: self.department.trim().to_string(),
+            hire_date: NaiveDate::parse_from_str(&self.hire_date, "%Y-%m-%d").ok(),
+            pay_rate: self.pay_rate.trim().to_string(),
+            employment_type: EmploymentType::new(&self.employment_type),
+        }
+    }
+
+    // Fails with a message to show the user when something doesn't fit its field
+    pub fn custom_values(&self, fields: &[CustomField]) -> Result<Vec<CustomValue>, String> {
+        let mut values = vec![];
+        for field in fields {
+            let inputs = match field.field_type {
+                CustomFieldType::MultiSelect => (0..field.options.len())
+                    .filter_map(|index| self.custom.get(&custom_option_key(&field.id, index)))
+                    .cloned()
+                    .collect(),
+                _ => self
+                    .custom
+                    .get(&custom_field_key(&field.id))
+                    .cloned()
+                    .into_iter()
+                    .collect(),
+            };
+
+            if let Some(value) = field.parse_value(inputs)? {
+                values.push(CustomValue {
+                    field_id: field.id.clone(),
+                    value,
+                });
+            }
+        }
+
+        Ok(values)
+    }
+
+    // The form inputs for values a worker already has
+    pub fn with_custom_values(self, fields: &[CustomField], values: &[CustomValue]) -> Self {
+        let mut custom = HashMap::new();
+        for custom_value in values {
+            let field = match fields.iter().find(|f| f.id == custom_value.field_id) {
+                Some(field) => field,
+                None => continue,
+            };
+
+            match &custom_value.value {
+                CustomFieldValue::MultiSelect(chosen) => {
+                    field
+                        .options
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, option)| chosen.contains(option))
+                        .for_each(|(index, option)| {
+                            custom.insert(custom_option_key(&field.id, index), option.clone());
+                        });
+                }
+                CustomFieldValue::Boolean(answer) => {
+                    custom.insert(custom_field_key(&field.id), answer.to_string());
+                }
+                value => {
+                    custom.insert(custom_field_key(&field.id), value.to_string());
+                }
+            }
+        }
+
+        Self { custom, ..self }
+    }
+}
+
+fn custom_field_key(field_id: &String) -> String {
+    format!("custom-{}", field_id)
+}
+
+// Multi-selects are a checkbox per option, each with its own name
+fn custom_option_key(field_id: &String, index: usize) -> String {
+    format!("custom-{}-{}", field_id, index)
 }
 
 fn is_not_empty(s: &str) -> Result<(), ValidationError> {
@@ -35,6 +134,9 @@ pub struct WorkerProfileFieldsetProps {
 
     #[builder(default=HashMap::new())]
     errors: HashMap<&'static str, ValidationErrorsKind>,
+
+    #[builder(default)]
+    custom_fields: Vec<CustomField>,
 }
 
 #[component]
@@ -72,6 +174,11 @@ pub fn WorkerProfileFieldset(props: WorkerProfileFieldsetProps) -> String {
                 />
             </GridCell>
 
+            <GridCell span=2>
+                <Label for_input="phone">Phone number</Label>
+                <TextInput input_type="tel" name="phone" autocomplete="tel" value=props.form.phone />
+            </GridCell>
+
             <GridCell span=CellSpan::Full>
                 <Label for_input="street_address">Street address</Label>
                 <TextInput name="street_address" autocomplete="street-address" value=props.form.street_address />
@@ -91,6 +198,312 @@ pub fn WorkerProfileFieldset(props: WorkerProfileFieldsetProps) -> String {
                 <Label for_input="postal_code">Zip Code</Label>
                 <TextInput name="postal_code" autocomplete="postal-code" value=props.form.postal_code />
             </GridCell>
+
+            <GridCell span=3>
+                <Label for_input="job_title">Job title</Label>
+                <TextInput name="job_title" autocomplete="organization-title" value=props.form.job_title />
+            </GridCell>
+
+            <GridCell span=3>
+                <Label for_input="department">Department</Label>
+                <TextInput name="department" value=props.form.department />
+            </GridCell>
+
+            <GridCell span=2>
+                <Label for_input="hire_date">Hire date</Label>
+                <TextInput input_type="date" name="hire_date" value=props.form.hire_date />
+            </GridCell>
+
+            <GridCell span=2>
+                <Label for_input="pay_rate">Pay rate or classification</Label>
+                <TextInput name="pay_rate" value=props.form.pay_rate />
+            </GridCell>
+
+            <GridCell span=2>
+                <Label for_input="employment_type">Employment type</Label>
+                <Select name="employment_type">
+                    <SelectOption value="" selected=props.form.employment_type.is_empty()>
+                        Unknown
+                    </SelectOption>
+                    {
+                        EmploymentType::ALL
+                            .iter()
+                            .map(|employment_type| {
+                                let value = employment_type.to_string();
+                                let selected = props.form.employment_type == value;
+
+                                async move {
+                                    html! {
+                                        <SelectOption value=value selected=selected>
+                                            {employment_type.label()}
+                                        </SelectOption>
+                                    }
+                                }
+                            })
+                            .collect_fragment_async()
+                            .await
+                    }
+                </Select>
+            </GridCell>
+
+            {
+                props.custom_fields
+                    .iter()
+                    .map(|field| async {
+                        html! {
+                            <CustomFieldInput field=field.clone() values=props.form.custom.clone() />
+                        }
+                    })
+                    .collect_fragment_async()
+                    .await
+            }
         </GridLayout>
     }
 }
+
+#[props]
+struct CustomFieldInputProps {
+    field: CustomField,
+    values: HashMap<String, String>,
+}
+
+#[component]
+fn CustomFieldInput(props: CustomFieldInputProps) -> String {
+    let field = props.field;
+    let name = custom_field_key(&field.id);
+    let value = props.values.get(&name).cloned().unwrap_or_default();
+
+    let input = match field.field_type {
+        CustomFieldType::Text => html! { <TextInput name=&name value=value /> },
+        CustomFieldType::Number => html! {
+            <TextInput
+                input_type="number"
+                name=&name
+                value=value
+                attrs=Attrs::with("step", "any".into())
+            />
+        },
+        CustomFieldType::Date => html! { <TextInput input_type="date" name=&name value=value /> },
+        CustomFieldType::SingleSelect => html! {
+            <Select name=&name>
+                <SelectOption value="" selected=value.is_empty()>"—"</SelectOption>
+                {
+                    field.options
+                        .iter()
+                        .map(|option| {
+                            let selected = option == &value;
+
+                            async move {
+                                html! {
+                                    <SelectOption value=option.clone() selected=selected>{option}</SelectOption>
+                                }
+                            }
+                        })
+                        .collect_fragment_async()
+                        .await
+                }
+            </Select>
+        },
+        CustomFieldType::Boolean => html! {
+            <Select name=&name>
+                <SelectOption value="" selected=value.is_empty()>"—"</SelectOption>
+                <SelectOption value="true" selected=value == "true">Yes</SelectOption>
+                <SelectOption value="false" selected=value == "false">No</SelectOption>
+            </Select>
+        },
+        CustomFieldType::MultiSelect => html! {
+            <div class="mt-2 flex flex-wrap gap-x-6 gap-y-2">
+                {
+                    field.options
+                        .iter()
+                        .enumerate()
+                        .map(|(index, option)| {
+                            let key = custom_option_key(&field.id, index);
+                            let checked = props.values.contains_key(&key);
+
+                            html! {
+                                <label class="flex items-center gap-2 text-sm text-gray-900">
+                                    <input
+                                        name=key
+                                        type="checkbox"
+                                        class="h-4 w-4 rounded border-gray-300 text-indigo-600 focus:ring-indigo-600"
+                                        {
+                                            String::from(
+                                                Attrs::default().set_if("checked", "checked".into(), checked)
+                                            )
+                                        }
+                                        value=option
+                                    />
+                                    {option}
+                                </label>
+                            }
+                        })
+                        .collect_fragment()
+                }
+            </div>
+        },
+    };
+
+    let span = match field.field_type {
+        CustomFieldType::MultiSelect => CellSpan::Full,
+        _ => CellSpan::Size(3),
+    };
+
+    html! {
+        <GridCell span=span>
+            <Label for_input=&name>{&field.name}</Label>
+            {input}
+        </GridCell>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(id: &str, field_type: CustomFieldType, options: Vec<&str>) -> CustomField {
+        CustomField {
+            id: id.into(),
+            name: format!("Field {}", id),
+            field_type,
+            options: options.into_iter().map(String::from).collect(),
+        }
+    }
+
+    fn fields() -> Vec<CustomField> {
+        vec![
+            field("text", CustomFieldType::Text, vec![]),
+            field("number", CustomFieldType::Number, vec![]),
+            field("date", CustomFieldType::Date, vec![]),
+            field("boolean", CustomFieldType::Boolean, vec![]),
+            field(
+                "single",
+                CustomFieldType::SingleSelect,
+                vec!["Day", "Night"],
+            ),
+            field(
+                "multi",
+                CustomFieldType::MultiSelect,
+                vec!["Heat", "Pay", "Safety"],
+            ),
+        ]
+    }
+
+    fn form(custom: Vec<(&str, &str)>) -> WorkerProfileFormData {
+        WorkerProfileFormData {
+            custom: custom
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn value(field_id: &str, value: CustomFieldValue) -> CustomValue {
+        CustomValue {
+            field_id: field_id.into(),
+            value,
+        }
+    }
+
+    #[test]
+    fn parses_a_value_for_each_kind_of_field() {
+        let form = form(vec![
+            ("custom-text", "  Forklift certified "),
+            ("custom-number", "2.50"),
+            ("custom-date", "2024-03-01"),
+            ("custom-boolean", "false"),
+            ("custom-single", "Night"),
+            ("custom-multi-0", "Heat"),
+            ("custom-multi-2", "Safety"),
+        ]);
+
+        assert_eq!(
+            form.custom_values(&fields()),
+            Ok(vec![
+                value("text", CustomFieldValue::Text("Forklift certified".into())),
+                value("number", CustomFieldValue::Number("2.50".into())),
+                value(
+                    "date",
+                    CustomFieldValue::Date(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap())
+                ),
+                value("boolean", CustomFieldValue::Boolean(false)),
+                value("single", CustomFieldValue::SingleSelect("Night".into())),
+                value(
+                    "multi",
+                    CustomFieldValue::MultiSelect(vec!["Heat".into(), "Safety".into()])
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn leaves_out_blank_values() {
+        let form = form(vec![("custom-text", "  "), ("custom-single", "")]);
+
+        assert_eq!(form.custom_values(&fields()), Ok(vec![]));
+    }
+
+    #[test]
+    fn fails_on_values_that_dont_fit_their_field() {
+        for (key, input, message) in [
+            ("custom-number", "twelve", "Field number must be a number"),
+            ("custom-date", "March", "Field date must be a date"),
+            ("custom-boolean", "maybe", "Field boolean must be yes or no"),
+            (
+                "custom-single",
+                "Swing",
+                "Field single has an option that doesn't exist",
+            ),
+        ] {
+            assert_eq!(
+                form(vec![(key, input)]).custom_values(&fields()),
+                Err(message.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn fills_in_the_inputs_for_a_workers_values() {
+        let values = vec![
+            value("boolean", CustomFieldValue::Boolean(true)),
+            value("multi", CustomFieldValue::MultiSelect(vec!["Pay".into()])),
+            value("removed", CustomFieldValue::Text("Gone".into())),
+        ];
+
+        let form = WorkerProfileFormData::default().with_custom_values(&fields(), &values);
+
+        assert_eq!(
+            form.custom,
+            HashMap::from([
+                ("custom-boolean".to_string(), "true".to_string()),
+                ("custom-multi-1".to_string(), "Pay".to_string()),
+            ])
+        );
+        assert_eq!(form.custom_values(&fields()), Ok(values[..2].to_vec()));
+    }
+
+    #[tokio::test]
+    async fn renders_a_workers_choices_as_selected() {
+        let values = vec![
+            value("single", CustomFieldValue::SingleSelect("Night".into())),
+            value("multi", CustomFieldValue::MultiSelect(vec!["Pay".into()])),
+        ];
+        let form = WorkerProfileFormData::default().with_custom_values(&fields(), &values);
+
+        let html = html! { <WorkerProfileFieldset form=form custom_fields=fields() /> };
+
+        let tags_with = |tag: &str, flag: &str| -> Vec<String> {
+            html.split(tag)
+                .skip(1)
+                .map(|element| &element[..element.find('>').unwrap()])
+                .filter(|element| element.contains(flag))
+                .filter_map(|element| element.split(r#"value=""#).nth(1))
+                .map(|rest| rest[..rest.find('"').unwrap()].to_string())
+                .collect()
+        };
+        assert!(tags_with("<option", "selected").contains(&"Night".to_string()));
+        assert!(!tags_with("<option", "selected").contains(&"Day".to_string()));
+        assert_eq!(tags_with("<input", "checked"), vec!["Pay"]);
+    }
+}
@@ -9,6 +9,7 @@ use axum_login::{tower_sessions::Session, AuthSession};
 use mongo_user_repository::MongoUserStore;
 use std::{collections::HashMap, future::Future};
 
+use audit_service::actor::with_actor;
 use auth_service::models::{User, UserPermission, UserRole};
 
 use crate::state::WebHtmxState;
@@ -33,6 +34,14 @@ pub struct LoggedInUser {
 }
 
 impl LoggedInUser {
+    pub fn new(user: User) -> Self {
+        Self {
+            id: user.id.clone(),
+            email: user.email.clone(),
+            role: user.role.clone(),
+            user,
+        }
+    }
     pub fn has_perm(&self, perm: UserPermission) -> bool {
         self.user.has_perm(perm)
     }
@@ -66,15 +75,9 @@ pub async fn provide_context_layer(
         .unwrap_or(None)
         .unwrap_or(state.default_worksite_name);
 
-    let current_user = match auth.user {
-        Some(user) => Some(LoggedInUser {
-            id: user.id.clone(),
-            email: user.email.clone(),
-            role: user.role.clone(),
-            user,
-        }),
-        None => None,
-    };
+    let current_user = auth.user.map(LoggedInUser::new);
+
+    let actor_id = current_user.as_ref().map(|user| user.id.clone());
 
     let context = Context {
         page_url: request.uri().path().to_string(),
@@ -85,8 +88,8 @@ pub async fn provide_context_layer(
         is_partial_request,
     };
 
-    // Set the context for this request.
-    provide_context(context, next.run(request)).await
+    // Set the context for this request, and who to record in the audit log for its changes.
+    provide_context(context, with_actor(actor_id, next.run(request))).await
 }
 
 pub async fn provide_context<F: Future<Output = O>, O>(context: Context, f: F) -> O {
@@ -15,16 +15,37 @@ use web_client::routes as client_routes;
 //##PLOP USE RESOURCE HOOK##
 use components::{not_found_message::NotFoundMessage, page::PageLayout};
 use context::provide_context_layer;
+use resources::actions::actions_routes;
+use resources::activity::activity_routes;
+use resources::api_tokens::api_tokens_routes;
 use resources::assessments::assessments_routes;
 use resources::assigned_tags::assigned_tags_routes;
+use resources::audit_log::audit_log_routes;
 use resources::auth::login_routes;
+use resources::cards::cards_routes;
+use resources::committee::committee_routes;
+use resources::conversations::conversations_routes;
 use resources::csv_upload::csv_upload_routes;
+use resources::custom_fields::custom_fields_routes;
+use resources::duplicates::duplicates_routes;
+use resources::eligibility::eligibility_routes;
+use resources::invites::{accept_invite_routes, invites_routes};
 use resources::locations::locations_routes;
+use resources::org_chart::org_chart_routes;
+use resources::profile::profile_routes;
+use resources::relationships::relationships_routes;
+use resources::roster::roster_routes;
 use resources::selected_worksite::selected_worksite_routes;
 use resources::shift_assignments::shift_assignments_routes;
 use resources::shifts::shifts_routes;
+use resources::status::status_routes;
+use resources::supervisor::supervisor_routes;
 use resources::support::support_routes;
 use resources::tags::tags_routes;
+use resources::tasks::tasks_routes;
+use resources::turf::turf_routes;
+use resources::turnover::turnover_routes;
+use resources::two_factor::{two_factor_login_routes, two_factor_routes};
 use resources::users::users_routes;
 use resources::workers::workers_routes;
 use resources::worksite::worksite_routes;
@@ -45,6 +66,7 @@ pub fn routes(state: WebHtmxState) -> Router {
         .merge(support_routes())
         .merge(selected_worksite_routes(state.clone()))
         .merge(assessments_routes(state.clone()))
+        .merge(conversations_routes(state.clone()))
         .merge(csv_upload_routes(state.clone()))
         .merge(shift_assignments_routes(state.clone()))
         .merge(shifts_routes(state.clone()))
@@ -54,9 +76,31 @@ pub fn routes(state: WebHtmxState) -> Router {
         .merge(workers_routes(state.clone()))
         .merge(assigned_tags_routes(state.clone()))
         .merge(users_routes(state.clone()))
+        .merge(invites_routes(state.clone()))
+        .merge(profile_routes(state.clone()))
+        .merge(two_factor_routes(state.clone()))
+        .merge(api_tokens_routes(state.clone()))
+        .merge(audit_log_routes(state.clone()))
+        .merge(activity_routes(state.clone()))
+        .merge(tasks_routes(state.clone()))
+        .merge(turf_routes(state.clone()))
+        .merge(committee_routes(state.clone()))
+        .merge(actions_routes(state.clone()))
+        .merge(cards_routes(state.clone()))
+        .merge(eligibility_routes(state.clone()))
+        .merge(custom_fields_routes(state.clone()))
+        .merge(supervisor_routes(state.clone()))
+        .merge(org_chart_routes(state.clone()))
+        .merge(relationships_routes(state.clone()))
+        .merge(status_routes(state.clone()))
+        .merge(turnover_routes(state.clone()))
+        .merge(roster_routes(state.clone()))
+        .merge(duplicates_routes(state.clone()))
         // Anything above this RequireAuth route will require authentication
         .route_layer(login_required!(MongoUserStore, login_url = routes::login()))
         .merge(login_routes(state.clone()))
+        .merge(two_factor_login_routes(state.clone()))
+        .merge(accept_invite_routes(state.clone()))
         .route(HOME, get(Redirect::temporary(HOME_REDIRECT)))
         .nest(PLAYGROUND, playground::routes())
         .nest_service(CLIENT, client_routes())
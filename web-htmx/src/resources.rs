@@ -1,13 +1,34 @@
+pub mod actions;
+pub mod activity;
+pub mod api_tokens;
 pub mod assessments;
 pub mod assigned_tags;
+pub mod audit_log;
 pub mod auth;
+pub mod cards;
+pub mod committee;
+pub mod conversations;
 pub mod csv_upload;
+pub mod custom_fields;
+pub mod duplicates;
+pub mod eligibility;
+pub mod invites;
 pub mod locations;
+pub mod org_chart;
+pub mod profile;
+pub mod relationships;
+pub mod roster;
 pub mod selected_worksite;
 pub mod shift_assignments;
 pub mod shifts;
+pub mod status;
+pub mod supervisor;
 pub mod support;
 pub mod tags;
+pub mod tasks;
+pub mod turf;
+pub mod turnover;
+pub mod two_factor;
 pub mod users;
 pub mod workers;
 pub mod worksite;
@@ -0,0 +1,625 @@
+use std::collections::HashMap;
+
+use axum::{
+    extract::{self, State},
+    response::{Html, IntoResponse},
+    routing::{delete, get},
+    Form, Router,
+};
+use axum_flash::{Flash, IncomingFlashes};
+use axum_login::permission_required;
+use chrono::{NaiveDate, Utc};
+use futures::future::join_all;
+use http::StatusCode;
+use mongo_user_repository::MongoUserStore;
+use rscx::{component, html, props, CollectFragmentAsync};
+use serde::Deserialize;
+
+use auth_service::models::UserPermission;
+use web_client::server::{
+    attrs::Attrs,
+    button::{PrimaryButton, SecondaryButton},
+    card::{Card, CardContent, CardFooter},
+    form::{Button, GridCell, GridLayout, Label, Select, SelectOption, TextInput},
+    headers::SecondaryHeader,
+    modal::{modal_target, Modal},
+    notification::NotificationFlashes,
+    table::{
+        ActionLink, Confirm, DeleteActionLink, TDVariant, Table, TableData, TableDataActions,
+        TableHeading,
+    },
+};
+use worksite_service::{
+    add_action::AddActionInput,
+    get_action_report::{ActionReportRow, GetActionReportInput},
+    get_actions::GetActionsInput,
+    get_worksite::GetWorksiteInput,
+    models::{ParticipationCount, ParticipationStatus, Worker},
+    record_participation::RecordParticipationInput,
+    remove_action::RemoveActionInput,
+    update_action::UpdateActionInput,
+};
+
+use crate::{
+    components::{
+        page::PageLayout, page_content::PageContent, permission_required::PermissionRequired,
+    },
+    routes,
+    state::WebHtmxState,
+};
+
+pub fn actions_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(routes::ACTION, delete(delete_action))
+        .route_layer(permission_required!(
+            MongoUserStore,
+            login_url = "/forbidden",
+            "action.delete",
+        ))
+        .route(routes::ACTIONS, get(get_actions).post(post_action))
+        .route(routes::ACTION, get(get_action).put(put_action))
+        .route(
+            routes::ACTION_PARTICIPATION,
+            get(get_participation_form).put(put_participation),
+        )
+        .with_state(state)
+}
+
+fn today() -> NaiveDate {
+    Utc::now().date_naive()
+}
+
+#[props]
+pub struct ParticipationBadgeProps {
+    status: ParticipationStatus,
+}
+
+#[component]
+pub fn ParticipationBadge(props: ParticipationBadgeProps) -> String {
+    let class = match props.status {
+        ParticipationStatus::Asked => "text-gray-600 bg-gray-50 ring-gray-500/10",
+        ParticipationStatus::Yes => "text-yellow-800 bg-yellow-50 ring-yellow-600/20",
+        ParticipationStatus::No => "text-red-700 bg-red-50 ring-red-600/10",
+        ParticipationStatus::DidIt => "text-green-700 bg-green-50 ring-green-600/20",
+    };
+
+    html! {
+        <span class=format!("rounded-md whitespace-nowrap mt-0.5 px-1.5 py-0.5 text-xs font-medium ring-1 ring-inset {}", class)>
+            {props.status.label()}
+        </span>
+    }
+}
+
+// e.g. "40% (4 of 10)"
+fn participation_summary(count: &ParticipationCount) -> String {
+    format!(
+        "{}% ({} of {})",
+        count.percent(),
+        count.participated,
+        count.total
+    )
+}
+
+async fn get_actions(
+    flashes: IncomingFlashes,
+    extract::Path(worksite_id): extract::Path<String>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let worksite = state
+        .worksite_service
+        .get_worksite(GetWorksiteInput {
+            id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get worksite")
+        .ok_or("Worksite not found")
+        .expect("Worksite not found");
+
+    let actions = state
+        .worksite_service
+        .get_actions(GetActionsInput {
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get actions");
+
+    let workers = &worksite.workers;
+    let worksite_id = &worksite_id;
+
+    let html = Html(html! {
+        <PageLayout header=format!("Actions: {}", worksite.name)>
+            <NotificationFlashes flashes=flashes.clone() />
+            <PageContent title="Plan an action">
+                <form>
+                    <Card>
+                        <CardContent padded=true>
+                            <ActionFormFields form_data=ActionFormData::default() />
+                        </CardContent>
+                        <CardFooter>
+                            <PrimaryButton hx_post=routes::actions(worksite_id)>
+                                Add Action
+                            </PrimaryButton>
+                        </CardFooter>
+                    </Card>
+                </form>
+            </PageContent>
+            <PageContent title="Actions and who took part">
+                <Card>
+                    {
+                        if actions.is_empty() {
+                            html! {
+                                <p class="p-6 text-sm text-gray-500">"No actions yet."</p>
+                            }
+                        } else {
+                            html! {
+                                <Table
+                                    headings=vec![
+                                        TableHeading::title("Date"),
+                                        TableHeading::title("Action"),
+                                        TableHeading::title("Did it"),
+                                        TableHeading::empty("Actions"),
+                                    ]
+                                    body=join_all(actions.into_iter().map(|action| async move {
+                                        let count = action.count_participants(workers);
+
+                                        html! {
+                                            <TableData variant=TDVariant::First>
+                                                {action.held_on.format("%b %-d, %Y").to_string()}
+                                            </TableData>
+                                            <TableData>{&action.description}</TableData>
+                                            <TableData>{participation_summary(&count)}</TableData>
+                                            <TableData variant=TDVariant::Last>
+                                                <TableDataActions>
+                                                    <ActionLink
+                                                        attrs=Attrs::with("href", routes::action(worksite_id, &action.id))
+                                                        sr_text=format!(", {}", &action.description)
+                                                    >
+                                                        Report
+                                                    </ActionLink>
+                                                    <ActionLink
+                                                        attrs=Attrs::with("href", routes::action_wallchart(worksite_id, &action.id))
+                                                        sr_text=format!(", {}", &action.description)
+                                                    >
+                                                        Wallchart
+                                                    </ActionLink>
+                                                    <PermissionRequired permission=UserPermission::DeleteAction>
+                                                        <DeleteActionLink
+                                                            hx_delete=routes::action(worksite_id, &action.id)
+                                                            hx_swap="outerHTML swap:1s"
+                                                            hx_target="closest tr"
+                                                            confirm=Confirm {
+                                                                title: "Remove Action".into(),
+                                                                message: format!("Are you sure you want to remove {} and everything recorded about who took part?", &action.description),
+                                                            }
+                                                            sr_text=format!(", {}", &action.description)
+                                                            show_loader_on_delete=true
+                                                        >
+                                                            Remove
+                                                        </DeleteActionLink>
+                                                    </PermissionRequired>
+                                                </TableDataActions>
+                                            </TableData>
+                                        }
+                                    }))
+                                    .await
+                                />
+                            }
+                        }
+                    }
+                </Card>
+            </PageContent>
+        </PageLayout>
+    });
+
+    (flashes, html)
+}
+
+async fn get_action(
+    flashes: IncomingFlashes,
+    extract::Path((worksite_id, action_id)): extract::Path<(String, String)>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let report = state
+        .worksite_service
+        .get_action_report(GetActionReportInput {
+            worksite_id: worksite_id.clone(),
+            action_id: action_id.clone(),
+        })
+        .await
+        .expect("Failed to get action report");
+    let action = report.action;
+
+    // How many workers are at each step, including those who said no
+    let status_counts: Vec<(ParticipationStatus, usize)> = ParticipationStatus::ALL
+        .into_iter()
+        .map(|status| {
+            let count = action
+                .participation
+                .iter()
+                .filter(|p| p.status == status)
+                .count();
+            (status, count)
+        })
+        .collect();
+
+    let html = Html(html! {
+        <PageLayout header=format!("Action: {}", action.description)>
+            <NotificationFlashes flashes=flashes.clone() />
+            <PageContent title="Who took part">
+                <Card>
+                    <div class="p-6">
+                        <p class="text-3xl font-semibold tracking-tight text-gray-900">
+                            {format!("{}%", report.overall.percent())}
+                        </p>
+                        <p class="mt-1 text-sm text-gray-500">
+                            {format!(
+                                "{} of {} workers did it",
+                                report.overall.participated, report.overall.total
+                            )}
+                        </p>
+                        <div class="mt-4 flex flex-wrap gap-x-4 gap-y-2 text-sm text-gray-500">
+                            {
+                                status_counts
+                                    .into_iter()
+                                    .map(|(status, count)| async move {
+                                        html! {
+                                            <span>
+                                                <ParticipationBadge status=status />
+                                                <span class="ml-1">{count.to_string()}</span>
+                                            </span>
+                                        }
+                                    })
+                                    .collect_fragment_async()
+                                    .await
+                            }
+                        </div>
+                    </div>
+                    <CardFooter>
+                        <SecondaryButton
+                            tag="a"
+                            href=routes::action_wallchart(&worksite_id, &action_id)
+                        >
+                            Show on Wallchart
+                        </SecondaryButton>
+                        <PrimaryButton
+                            hx_get=routes::action_participation(&worksite_id, &action_id)
+                            hx_target=modal_target()
+                            hx_swap="beforeend"
+                            hx_push_url=routes::page_modal_from(routes::action_participation(&worksite_id, &action_id))
+                        >
+                            Record Participation
+                        </PrimaryButton>
+                    </CardFooter>
+                </Card>
+            </PageContent>
+            <PageContent title="By location">
+                <Card>
+                    <ParticipationTable heading="Location" rows=report.by_location />
+                </Card>
+            </PageContent>
+            <PageContent title="By shift">
+                <Card>
+                    <ParticipationTable heading="Shift" rows=report.by_shift />
+                </Card>
+            </PageContent>
+            <PageContent title="Details">
+                <form>
+                    <Card>
+                        <CardContent padded=true>
+                            <ActionFormFields
+                                form_data=ActionFormData {
+                                    held_on: action.held_on.to_string(),
+                                    description: action.description.clone(),
+                                }
+                            />
+                        </CardContent>
+                        <CardFooter>
+                            <PrimaryButton hx_put=routes::action(&worksite_id, &action_id)>
+                                Save
+                            </PrimaryButton>
+                        </CardFooter>
+                    </Card>
+                </form>
+            </PageContent>
+        </PageLayout>
+    });
+
+    (flashes, html)
+}
+
+#[props]
+struct ParticipationTableProps {
+    #[builder(setter(into))]
+    heading: String,
+    rows: Vec<ActionReportRow>,
+}
+
+#[component]
+fn ParticipationTable(props: ParticipationTableProps) -> String {
+    if props.rows.is_empty() {
+        return html! {
+            <p class="p-6 text-sm text-gray-500">"Nothing to break down yet."</p>
+        };
+    }
+
+    html! {
+        <Table
+            headings=vec![
+                TableHeading::title(props.heading),
+                TableHeading::title("Did it"),
+            ]
+            body=join_all(props.rows.into_iter().map(|row| async move {
+                html! {
+                    <TableData variant=TDVariant::First>{row.name}</TableData>
+                    <TableData variant=TDVariant::LastNonEmptyHeading>
+                        {participation_summary(&row.count)}
+                    </TableData>
+                }
+            }))
+            .await
+        />
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct ActionFormData {
+    held_on: String,
+    description: String,
+}
+
+impl Default for ActionFormData {
+    fn default() -> Self {
+        Self {
+            held_on: today().to_string(),
+            description: "".into(),
+        }
+    }
+}
+
+impl ActionFormData {
+    // Browsers without a date picker may send anything, so fall back to today
+    fn held_on(&self) -> NaiveDate {
+        NaiveDate::parse_from_str(&self.held_on, "%Y-%m-%d").unwrap_or(today())
+    }
+}
+
+#[props]
+struct ActionFormFieldsProps {
+    form_data: ActionFormData,
+}
+
+#[component]
+fn ActionFormFields(props: ActionFormFieldsProps) -> String {
+    html! {
+        <GridLayout>
+            <GridCell span=4>
+                <Label for_input="description">Action</Label>
+                <TextInput
+                    name="description"
+                    placeholder="Everyone wears a union button on Friday"
+                    value=&props.form_data.description
+                />
+            </GridCell>
+            <GridCell span=2>
+                <Label for_input="held_on">Date</Label>
+                <TextInput input_type="date" name="held_on" value=&props.form_data.held_on />
+            </GridCell>
+        </GridLayout>
+    }
+}
+
+async fn post_action(
+    extract::Path(worksite_id): extract::Path<String>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<ActionFormData>,
+) -> impl IntoResponse {
+    let result = worksite_service
+        .add_action(AddActionInput {
+            worksite_id: worksite_id.clone(),
+            held_on: form.held_on(),
+            description: form.description.clone(),
+        })
+        .await;
+
+    let (flash, redirect) = match result {
+        Ok(action) => (
+            flash.success("Action added successfully!"),
+            routes::action(&worksite_id, &action.id),
+        ),
+        Err(e) => (flash.error(e.to_string()), routes::actions(&worksite_id)),
+    };
+
+    (
+        StatusCode::OK,
+        flash,
+        [("hx-redirect", redirect), ("hx-retarget", "body".into())],
+    )
+}
+
+async fn put_action(
+    extract::Path((worksite_id, action_id)): extract::Path<(String, String)>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<ActionFormData>,
+) -> impl IntoResponse {
+    let result = worksite_service
+        .update_action(UpdateActionInput {
+            worksite_id,
+            action_id,
+            held_on: form.held_on(),
+            description: form.description.clone(),
+        })
+        .await;
+
+    let flash = match result {
+        Ok(_) => flash.success("Action updated successfully!"),
+        Err(e) => flash.error(e.to_string()),
+    };
+
+    (StatusCode::OK, flash, [("hx-refresh", "true")])
+}
+
+async fn delete_action(
+    extract::Path((worksite_id, action_id)): extract::Path<(String, String)>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let result = worksite_service
+        .remove_action(RemoveActionInput {
+            worksite_id,
+            action_id,
+        })
+        .await;
+
+    match result {
+        Ok(_) => "".into_response(),
+        Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Error removing action").into_response(),
+    }
+}
+
+async fn get_participation_form(
+    extract::Path((worksite_id, action_id)): extract::Path<(String, String)>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let worksite = state
+        .worksite_service
+        .get_worksite(GetWorksiteInput {
+            id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get worksite")
+        .ok_or("Worksite not found")
+        .expect("Worksite not found");
+    let action = worksite
+        .get_action(action_id.clone())
+        .ok_or("Action not found")
+        .expect("Action not found");
+
+    let mut workers = worksite.workers.clone();
+    workers.sort_by(|a, b| {
+        a.last_name
+            .cmp(&b.last_name)
+            .then(a.first_name.cmp(&b.first_name))
+    });
+
+    html! {
+        <Modal>
+            <SecondaryHeader
+                title="✊ Record Participation"
+                subtitle=action.description.clone()
+            />
+            <div class="mt-4">
+                <form hx-put=routes::action_participation(&worksite_id, &action_id)>
+                    <Table
+                        headings=vec![
+                            TableHeading::title("Worker"),
+                            TableHeading::title("Participation"),
+                        ]
+                        body=join_all(workers.into_iter().map(|worker| {
+                            let status = action.get_participation(&worker.id);
+                            async move {
+                                html! {
+                                    <ParticipationRow worker=worker status=status />
+                                }
+                            }
+                        }))
+                        .await
+                    />
+                    <GridLayout>
+                        <GridCell span=6>
+                            <div class="mt-6 flex items-center justify-end gap-x-6">
+                                <Button
+                                    onclick="history.go(-1)"
+                                    attrs=Attrs::with("data-toggle-action", "close".into())
+                                >
+                                    Cancel
+                                </Button>
+                                <Button kind="submit">Save</Button>
+                            </div>
+                        </GridCell>
+                    </GridLayout>
+                </form>
+            </div>
+        </Modal>
+    }
+}
+
+#[props]
+struct ParticipationRowProps {
+    worker: Worker,
+    status: Option<ParticipationStatus>,
+}
+
+#[component]
+fn ParticipationRow(props: ParticipationRowProps) -> String {
+    let status = props.status;
+
+    html! {
+        <TableData variant=TDVariant::First>{props.worker.full_name()}</TableData>
+        <TableData variant=TDVariant::LastNonEmptyHeading>
+            <Select name=props.worker.id.clone()>
+                <SelectOption value="" selected=status.is_none()>Not asked</SelectOption>
+                {
+                    ParticipationStatus::ALL
+                        .into_iter()
+                        .map(|option| async move {
+                            html! {
+                                <SelectOption
+                                    value=option.to_string()
+                                    selected=status == Some(option)
+                                >
+                                    {option.label()}
+                                </SelectOption>
+                            }
+                        })
+                        .collect_fragment_async()
+                        .await
+                }
+            </Select>
+        </TableData>
+    }
+}
+
+async fn put_participation(
+    extract::Path((worksite_id, action_id)): extract::Path<(String, String)>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    // Worker ids to statuses, with "" for workers who were not asked
+    Form(form): Form<HashMap<String, String>>,
+) -> impl IntoResponse {
+    let result = worksite_service
+        .record_participation(RecordParticipationInput {
+            worksite_id: worksite_id.clone(),
+            action_id: action_id.clone(),
+            participation: form
+                .into_iter()
+                .map(|(worker_id, status)| (worker_id, ParticipationStatus::new(status)))
+                .collect(),
+        })
+        .await;
+
+    let flash = match result {
+        Ok(_) => flash.success("Participation recorded successfully!"),
+        Err(e) => flash.error(e.to_string()),
+    };
+
+    (
+        StatusCode::OK,
+        flash,
+        [
+            (
+                "hx-redirect",
+                routes::action_wallchart(&worksite_id, &action_id),
+            ),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
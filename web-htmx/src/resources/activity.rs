@@ -0,0 +1,526 @@
+use std::collections::HashMap;
+
+use axum::{
+    extract::{self, Query, State},
+    response::{Html, IntoResponse},
+    routing::get,
+    Router,
+};
+use rscx::{component, html, props, CollectFragment};
+use serde::Deserialize;
+use serde_json::Value;
+
+use audit_service::{
+    get_audit_entries::GetAuditEntriesInput,
+    models::{AuditEntry, AuditFilter},
+};
+use worksite_service::{
+    get_worksite::GetWorksiteInput,
+    models::{EmploymentStatus, ExclusionReason, RelationshipKind, Turf, Worksite},
+};
+
+use crate::{resources::turf::turf_name, routes, state::WebHtmxState};
+
+const ACTIVITY_PAGE_SIZE: usize = 20;
+
+pub fn activity_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(routes::WORKSITE_ACTIVITY, get(get_worksite_activity))
+        .route(routes::WORKER_ACTIVITY, get(get_worker_activity))
+        .with_state(state)
+}
+
+#[derive(Deserialize, Debug)]
+struct ActivityQuery {
+    #[serde(default)]
+    offset: usize,
+}
+
+async fn get_worksite_activity(
+    extract::Path(worksite_id): extract::Path<String>,
+    State(state): State<WebHtmxState>,
+    Query(query): Query<ActivityQuery>,
+) -> impl IntoResponse {
+    let filter = AuditFilter {
+        worksite_id: Some(worksite_id.clone()),
+        ..AuditFilter::default()
+    };
+
+    get_activity(
+        state,
+        filter,
+        routes::worksite_activity(&worksite_id),
+        query.offset,
+    )
+    .await
+}
+
+async fn get_worker_activity(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(state): State<WebHtmxState>,
+    Query(query): Query<ActivityQuery>,
+) -> impl IntoResponse {
+    let filter = AuditFilter {
+        worksite_id: Some(worksite_id.clone()),
+        entity: Some("Worker".into()),
+        entity_id: Some(worker_id.clone()),
+        ..AuditFilter::default()
+    };
+
+    get_activity(
+        state,
+        filter,
+        routes::worker_activity(&worksite_id, &worker_id),
+        query.offset,
+    )
+    .await
+}
+
+// One page of feed items, swapped in place of the feed's "Load more" item
+async fn get_activity(
+    state: WebHtmxState,
+    filter: AuditFilter,
+    route: String,
+    offset: usize,
+) -> impl IntoResponse {
+    let worksite_id = filter.worksite_id.clone().unwrap_or_default();
+    let entries = state
+        .audit_service
+        .get_audit_entries(GetAuditEntriesInput {
+            filter: AuditFilter {
+                offset,
+                limit: ACTIVITY_PAGE_SIZE,
+                ..filter
+            },
+        })
+        .await
+        .expect("Failed to get activity");
+
+    let worksite = state
+        .worksite_service
+        .get_worksite(GetWorksiteInput { id: worksite_id })
+        .await
+        .expect("Failed to get worksite")
+        .ok_or("Worksite not found")
+        .expect("Worksite not found");
+
+    let users = state
+        .auth_service
+        .get_users()
+        .await
+        .expect("Failed to get users");
+
+    let presenter = ActivityPresenter {
+        worksite,
+        actors: users
+            .into_iter()
+            .map(|u| (u.id.clone(), u.name()))
+            .collect(),
+    };
+
+    let has_more = entries.len() == ACTIVITY_PAGE_SIZE;
+    let next_page = format!("{}?offset={}", route, offset + ACTIVITY_PAGE_SIZE);
+
+    Html(html! {
+        {
+            entries
+                .iter()
+                .map(|entry| html! {
+                    <li class="flex gap-x-4 py-3">
+                        <p class="flex-auto text-sm leading-6 text-gray-700">
+                            {presenter.sentence(entry)}
+                        </p>
+                        <time
+                            class="flex-none text-xs leading-6 text-gray-500"
+                            datetime=entry.occurred_at.to_rfc3339()
+                        >
+                            {entry.occurred_at.format("%b %-d, %H:%M").to_string()}
+                        </time>
+                    </li>
+                })
+                .collect_fragment()
+        }
+        {
+            match (has_more, entries.is_empty() && offset == 0) {
+                (true, _) => html! {
+                    <li class="py-3 text-center">
+                        <button
+                            class="text-sm font-semibold leading-6 text-indigo-600 hover:text-indigo-500"
+                            hx-get=next_page
+                            hx-target="closest li"
+                            hx-swap="outerHTML"
+                        >
+                            Load more
+                        </button>
+                    </li>
+                },
+                (false, true) => html! {
+                    <li class="py-3 text-sm text-gray-500">"Nothing has happened yet."</li>
+                },
+                (false, false) => "".into(),
+            }
+        }
+    })
+}
+
+/**
+* Turns recorded changes into sentences like "Victoria assessed Jim Halpert as 2". Names
+* come from the worksite as it is now, falling back to what was recorded for anything
+* that has since been removed.
+*/
+struct ActivityPresenter {
+    worksite: Worksite,
+    actors: HashMap<String, String>,
+}
+
+impl ActivityPresenter {
+    fn sentence(&self, entry: &AuditEntry) -> String {
+        let actor = match &entry.actor_id {
+            Some(actor_id) => self.actor_name(actor_id),
+            None => "Someone".into(),
+        };
+        let before = |name: &str| field(entry.before.as_ref(), name);
+        let after = |name: &str| field(entry.after.as_ref(), name);
+
+        let action = match (entry.entity.as_str(), entry.action.as_str()) {
+            ("Worksite", "Created") => format!("created the worksite {}", after("name")),
+            ("Worksite", "Renamed") => format!(
+                "renamed the worksite from {} to {}",
+                before("name"),
+                after("name")
+            ),
+            ("Worksite", "Workers imported") => {
+                let count = entry
+                    .after
+                    .as_ref()
+                    .and_then(|after| after.get("worker_ids"))
+                    .and_then(|ids| ids.as_array())
+                    .map(|ids| ids.len())
+                    .unwrap_or_default();
+                format!("imported {} workers", count)
+            }
+            ("Worksite", "Roster reconciled") => {
+                let count = |name: &str| {
+                    entry
+                        .after
+                        .as_ref()
+                        .and_then(|after| after.get(name))
+                        .and_then(|ids| ids.as_array())
+                        .map(|ids| ids.len())
+                        .unwrap_or_default()
+                };
+                format!(
+                    "reconciled the roster, with {} hired, {} back, {} moved and {} gone",
+                    count("hired"),
+                    count("returned"),
+                    count("moved"),
+                    count("departed")
+                )
+            }
+            ("Worksite", "Card thresholds updated") => "changed the card thresholds".into(),
+            ("Location", "Added") => format!("added the location {}", after("name")),
+            ("Shift", "Added") => format!(
+                "added the {} shift at {}",
+                after("name"),
+                self.location_name(&after("location_id"))
+            ),
+            ("Tag", "Added") => format!("added the tag {} {}", after("icon"), after("name")),
+            ("Tag", "Updated") => format!("updated the tag {} {}", after("icon"), after("name")),
+            ("Tag", "Removed") => {
+                format!("removed the tag {} {}", before("icon"), before("name"))
+            }
+            ("Turf", "Assigned") => format!(
+                "assigned {} to {}",
+                self.turf(entry.after.as_ref()),
+                self.actor_name(&after("user_id"))
+            ),
+            ("Turf", "Unassigned") => format!(
+                "took {} off the turf of {}",
+                self.turf(entry.before.as_ref()),
+                self.actor_name(&before("user_id"))
+            ),
+            ("Action", "Added") => format!("planned the action {}", after("description")),
+            ("Action", "Updated") => format!("updated the action {}", after("description")),
+            ("Action", "Removed") => format!("removed the action {}", before("description")),
+            ("Action", "Participation recorded") => {
+                let count = entry
+                    .after
+                    .as_ref()
+                    .and_then(|after| after.as_object())
+                    .map(|changes| changes.len())
+                    .unwrap_or_default();
+                format!(
+                    "recorded how {} workers took part in {}",
+                    count,
+                    self.action_name(&entry.entity_id)
+                )
+            }
+            ("CustomField", "Added") => format!("added the custom field {}", after("name")),
+            ("CustomField", "Updated") => {
+                format!("updated the custom field {}", after("name"))
+            }
+            ("CustomField", "Removed") => {
+                format!("removed the custom field {}", before("name"))
+            }
+            ("Relationship", "Added") => format!(
+                "linked {} ({})",
+                self.relationship_pair(entry.after.as_ref()),
+                relationship_kind(entry.after.as_ref())
+            ),
+            ("Relationship", "Removed") => format!(
+                "unlinked {} ({})",
+                self.relationship_pair(entry.before.as_ref()),
+                relationship_kind(entry.before.as_ref())
+            ),
+            ("Worker", action) => {
+                let worker = self.worker_name(entry);
+                match action {
+                    "Added" => format!("added {}", worker),
+                    "Updated" => format!("updated the profile of {}", worker),
+                    "Merged" => format!(
+                        "merged {} {} into {}",
+                        before("first_name"),
+                        before("last_name"),
+                        worker
+                    ),
+                    "Assigned to shift" => {
+                        format!(
+                            "moved {} to {}",
+                            worker,
+                            self.shift_name(&after("shift_id"))
+                        )
+                    }
+                    "Removed from shift" => format!(
+                        "removed {} from {}",
+                        worker,
+                        self.shift_name(&before("shift_id"))
+                    ),
+                    "Tag assigned" => {
+                        format!("tagged {} as {}", worker, self.tag_name(&after("tag_id")))
+                    }
+                    "Tag unassigned" => format!(
+                        "removed the {} tag from {}",
+                        self.tag_name(&before("tag_id")),
+                        worker
+                    ),
+                    "Assessed" => format!("assessed {} as {}", worker, after("value")),
+                    "Assessment updated" => format!(
+                        "changed the assessment of {} from {} to {}",
+                        worker,
+                        before("value"),
+                        after("value")
+                    ),
+                    "Assessment removed" => format!(
+                        "removed the assessment of {} as {}",
+                        worker,
+                        before("value")
+                    ),
+                    "Conversation logged" => format!("logged a conversation with {}", worker),
+                    "Conversation updated" => {
+                        format!("updated a conversation with {}", worker)
+                    }
+                    "Conversation removed" => {
+                        format!("removed a conversation with {}", worker)
+                    }
+                    "Joined committee" => {
+                        format!("added {} to the organizing committee", worker)
+                    }
+                    "Left committee" => {
+                        format!("took {} off the organizing committee", worker)
+                    }
+                    "Leader assigned" => match (before("leader_id"), after("leader_id")) {
+                        (_, leader_id) if !leader_id.is_empty() => format!(
+                            "put {} on the list of {}",
+                            worker,
+                            self.leader_name(&leader_id)
+                        ),
+                        (leader_id, _) => format!(
+                            "took {} off the list of {}",
+                            worker,
+                            self.leader_name(&leader_id)
+                        ),
+                    },
+                    "Supervisor changed" => {
+                        match (before("supervisor_id"), after("supervisor_id")) {
+                            (_, supervisor_id) if !supervisor_id.is_empty() => {
+                                format!("put {} under {}", worker, self.leader_name(&supervisor_id))
+                            }
+                            (supervisor_id, _) => format!(
+                                "took {} out from under {}",
+                                worker,
+                                self.leader_name(&supervisor_id)
+                            ),
+                        }
+                    }
+                    "Status changed" => match EmploymentStatus::new(after("status")) {
+                        Some(EmploymentStatus::Terminated) => {
+                            format!("recorded that {} was terminated", worker)
+                        }
+                        Some(EmploymentStatus::Quit) => format!("recorded that {} quit", worker),
+                        Some(EmploymentStatus::Transferred) => {
+                            format!("recorded that {} transferred out", worker)
+                        }
+                        Some(EmploymentStatus::OnLeave) => format!("put {} on leave", worker),
+                        _ => format!("marked {} as active", worker),
+                    },
+                    "Card recorded" => match (&entry.before, &entry.after) {
+                        (None, Some(_)) => format!("recorded a signed card from {}", worker),
+                        (Some(_), None) => format!("removed the card of {}", worker),
+                        _ => format!("updated the card of {}", worker),
+                    },
+                    "Eligibility changed" => {
+                        match ExclusionReason::new(after("exclusion_reason")) {
+                            Some(ExclusionReason::Other) => {
+                                format!("excluded {} from the unit", worker)
+                            }
+                            Some(reason) => format!(
+                                "excluded {} from the unit as a {}",
+                                worker,
+                                reason.label().to_lowercase()
+                            ),
+                            None => format!("put {} back in the unit", worker),
+                        }
+                    }
+                    action => format!("{} {}", action.to_lowercase(), worker),
+                }
+            }
+            (entity, action) => format!("{} {}", action.to_lowercase(), entity.to_lowercase()),
+        };
+
+        format!("{} {}", actor, action)
+    }
+
+    fn actor_name(&self, user_id: &String) -> String {
+        self.actors
+            .get(user_id)
+            .cloned()
+            .unwrap_or("A removed user".into())
+    }
+
+    // Turf snapshots look like {"kind": "Shift", "id": "1"}
+    fn turf(&self, snapshot: Option<&Value>) -> String {
+        let turf = snapshot.and_then(|snapshot| snapshot.get("turf"));
+        let turf = Turf::new(field(turf, "kind"), field(turf, "id"));
+
+        match turf {
+            Some(turf) => turf_name(&self.worksite, &turf),
+            None => "some turf".into(),
+        }
+    }
+
+    fn worker_name(&self, entry: &AuditEntry) -> String {
+        if let Some(worker) = self.worksite.get_worker(entry.entity_id.clone()) {
+            return worker.full_name();
+        }
+
+        // Only worker snapshots have names, so this only helps for adds and updates
+        match entry.after.as_ref().or(entry.before.as_ref()) {
+            Some(snapshot) if snapshot.get("first_name").is_some() => format!(
+                "{} {}",
+                field(Some(snapshot), "first_name"),
+                field(Some(snapshot), "last_name")
+            ),
+            _ => "a removed worker".into(),
+        }
+    }
+
+    // Leaders, supervisors and anyone in a relationship are all workers at the worksite
+    fn leader_name(&self, leader_id: &str) -> String {
+        self.worksite
+            .get_worker(leader_id.to_string())
+            .map(|leader| leader.full_name())
+            .unwrap_or("a removed worker".into())
+    }
+
+    // e.g. "Jim Halpert and Pam Beesly", or "Jim Halpert to Pam Beesly" when one-way
+    fn relationship_pair(&self, snapshot: Option<&Value>) -> String {
+        let joiner = match snapshot.and_then(|snapshot| snapshot.get("directional")) {
+            Some(Value::Bool(true)) => "to",
+            _ => "and",
+        };
+
+        format!(
+            "{} {} {}",
+            self.leader_name(&field(snapshot, "from_worker_id")),
+            joiner,
+            self.leader_name(&field(snapshot, "to_worker_id"))
+        )
+    }
+
+    fn action_name(&self, action_id: &str) -> String {
+        self.worksite
+            .get_action(action_id.to_string())
+            .map(|action| action.description)
+            .unwrap_or("a removed action".into())
+    }
+
+    fn location_name(&self, location_id: &String) -> String {
+        self.worksite
+            .locations
+            .iter()
+            .find(|location| &location.id == location_id)
+            .map(|location| location.name.clone())
+            .unwrap_or("a removed location".into())
+    }
+
+    fn shift_name(&self, shift_id: &String) -> String {
+        self.worksite
+            .locations
+            .iter()
+            .find_map(|location| {
+                location
+                    .shifts
+                    .iter()
+                    .find(|shift| &shift.id == shift_id)
+                    .map(|shift| format!("the {} shift at {}", shift.name, location.name))
+            })
+            .unwrap_or("a removed shift".into())
+    }
+
+    fn tag_name(&self, tag_id: &str) -> String {
+        self.worksite
+            .get_tag(tag_id.to_string())
+            .map(|tag| format!("{} {}", tag.icon, tag.name))
+            .unwrap_or("a removed tag".into())
+    }
+}
+
+// The kind's label, lowercased to sit mid-sentence
+fn relationship_kind(snapshot: Option<&Value>) -> String {
+    RelationshipKind::new(field(snapshot, "kind"))
+        .map(|kind| kind.label().to_lowercase())
+        .unwrap_or("relationship".into())
+}
+
+fn field(snapshot: Option<&Value>, name: &str) -> String {
+    match snapshot.and_then(|snapshot| snapshot.get(name)) {
+        Some(Value::String(value)) => value.clone(),
+        Some(Value::Null) | None => "".into(),
+        Some(value) => value.to_string(),
+    }
+}
+
+#[props]
+pub struct ActivityFeedProps {
+    // routes::worksite_activity or routes::worker_activity
+    #[builder(setter(into))]
+    route: String,
+}
+
+/**
+* A list of recent changes, newest first. The first page loads once the feed scrolls into
+* view and each following page is loaded on request.
+*/
+#[component]
+pub fn ActivityFeed(props: ActivityFeedProps) -> String {
+    html! {
+        <ul role="list" class="divide-y divide-gray-100">
+            <li hx-get=props.route hx-trigger="revealed" hx-swap="outerHTML" class="py-3">
+                <svg class="animate-spin m-auto text-gray-100 h-8 w-8" xmlns="http://www.w3.org/2000/svg" fill="none" viewBox="0 0 24 24">
+                    <circle class="opacity-25" cx="12" cy="12" r="10" stroke="currentColor" stroke-width="4"></circle>
+                    <path class="opacity-75" fill="currentColor" d="M4 12a8 8 0 018-8V0C5.373 0 0 5.373 0 12h4zm2 5.291A7.962 7.962 0 014 12H0c0 3.042 1.135 5.824 3 7.938l3-2.647z"></path>
+                </svg>
+            </li>
+        </ul>
+    }
+}
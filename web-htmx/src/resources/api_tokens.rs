@@ -0,0 +1,243 @@
+use axum::{
+    extract::{self, State},
+    response::{Html, IntoResponse},
+    routing::{delete, post},
+    Form, Router,
+};
+use futures::future::join_all;
+use http::StatusCode;
+use rscx::{component, html, props};
+use serde::Deserialize;
+
+use auth_service::{
+    create_api_token::{CreateApiTokenFailure, CreateApiTokenInput},
+    get_api_tokens::GetApiTokensInput,
+    models::ApiToken,
+    revoke_api_token::RevokeApiTokenInput,
+};
+use web_client::server::{
+    alert::{Alert, AlertKind},
+    form::{Button, GridCell, GridLayout, Label, TextInput},
+    table::{Confirm, DeleteActionLink, TDVariant, Table, TableData, TableHeading},
+};
+
+use crate::{routes, state::WebHtmxState};
+
+// Personal tokens for the JSON api, managed from the profile page
+pub fn api_tokens_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(routes::PROFILE_API_TOKENS, post(post_api_token))
+        .route(routes::PROFILE_API_TOKEN, delete(delete_api_token))
+        .with_state(state)
+}
+
+#[derive(Deserialize, Debug)]
+struct ApiTokenFormData {
+    name: String,
+}
+
+async fn post_api_token(
+    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+    Form(form): Form<ApiTokenFormData>,
+) -> impl IntoResponse {
+    let ctx: crate::context::Context =
+        crate::context::context().expect("Unable to retrieve htmx context.");
+    let current_user = ctx.current_user.expect("Profile requires a logged in user");
+
+    let result = auth_service
+        .create_api_token(CreateApiTokenInput {
+            user_id: current_user.id.clone(),
+            name: form.name,
+        })
+        .await;
+
+    let (new_token, error) = match result {
+        Ok(created) => (Some(created.token), ""),
+        Err(CreateApiTokenFailure::EmptyName) => (None, "Please give the token a name."),
+        Err(_) => (None, "Something went wrong creating your token."),
+    };
+
+    let api_tokens = auth_service
+        .get_api_tokens(GetApiTokensInput {
+            user_id: current_user.id,
+        })
+        .await
+        .expect("Failed to get api tokens");
+
+    let status = if error.is_empty() {
+        StatusCode::OK
+    } else {
+        StatusCode::BAD_REQUEST
+    };
+
+    (
+        status,
+        Html(html! {
+            <ApiTokensSection api_tokens=api_tokens new_token=new_token error=error />
+        }),
+    )
+}
+
+async fn delete_api_token(
+    extract::Path(api_token_id): extract::Path<String>,
+    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let ctx: crate::context::Context =
+        crate::context::context().expect("Unable to retrieve htmx context.");
+    let current_user = ctx.current_user.expect("Profile requires a logged in user");
+
+    let result = auth_service
+        .revoke_api_token(RevokeApiTokenInput {
+            user_id: current_user.id,
+            api_token_id,
+        })
+        .await;
+
+    match result {
+        Ok(_) => "".into_response(),
+        Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Error revoking token").into_response(),
+    }
+}
+
+#[props]
+pub struct ApiTokensSectionProps {
+    api_tokens: Vec<ApiToken>,
+
+    #[builder(default)]
+    new_token: Option<String>,
+
+    #[builder(setter(into), default)]
+    error: String,
+}
+
+// Swapped as a whole after creating a token, so the new token shows above the list
+#[component]
+pub fn ApiTokensSection(props: ApiTokensSectionProps) -> String {
+    html! {
+        <div id="api-tokens">
+            {
+                match props.new_token {
+                    Some(token) => html! {
+                        <Alert
+                            class="mt-6"
+                            kind=AlertKind::Success
+                            title="Copy your new token now. It will not be shown again."
+                        >
+                            <input
+                                type="text"
+                                readonly="readonly"
+                                class="mt-2 w-full rounded-md border-0 py-1.5 font-mono text-gray-900 shadow-sm ring-1 ring-inset ring-gray-300 sm:text-sm sm:leading-6"
+                                value=token
+                                onclick="this.select(); navigator.clipboard.writeText(this.value)"
+                            />
+                        </Alert>
+                    },
+                    None => "".into(),
+                }
+            }
+            {
+                if props.api_tokens.is_empty() {
+                    html! {
+                        <p class="mt-6 text-sm leading-6 text-gray-600">"You don't have any api tokens yet."</p>
+                    }
+                } else {
+                    html! { <ApiTokensTable api_tokens=props.api_tokens /> }
+                }
+            }
+            <ApiTokenForm error=props.error />
+        </div>
+    }
+}
+
+#[props]
+struct ApiTokensTableProps {
+    api_tokens: Vec<ApiToken>,
+}
+
+#[component]
+fn ApiTokensTable(props: ApiTokensTableProps) -> String {
+    html! {
+        <Table
+            headings=vec![
+                TableHeading::title("Name"),
+                TableHeading::title("Created"),
+                TableHeading::title("Last Used"),
+                TableHeading::empty("Actions"),
+            ]
+            body=join_all(props
+                .api_tokens
+                .into_iter()
+                .map(|api_token| async move {
+                    html! {
+                        <TableData variant=TDVariant::First>
+                            {&api_token.name}
+                        </TableData>
+                        <TableData>
+                            {api_token.created_at.format("%b %e, %Y").to_string()}
+                        </TableData>
+                        <TableData>
+                            {
+                                api_token
+                                    .last_used_at
+                                    .map(|at| at.format("%b %e, %Y").to_string())
+                                    .unwrap_or("Never".into())
+                            }
+                        </TableData>
+                        <TableData variant=TDVariant::Last>
+                            <DeleteActionLink
+                                hx_delete=routes::profile_api_token(&api_token.id)
+                                hx_swap="outerHTML swap:1s"
+                                hx_target="closest tr"
+                                confirm=Confirm {
+                                    title: "Revoke Token".into(),
+                                    message: format!("Are you sure you want to revoke {}? Anything using it will stop working.", &api_token.name),
+                                }
+                                sr_text=&api_token.name
+                                show_loader_on_delete=true
+                            >
+                                Revoke
+                            </DeleteActionLink>
+                        </TableData>
+                    }
+                }))
+                .await
+        />
+    }
+}
+
+#[props]
+struct ApiTokenFormProps {
+    #[builder(setter(into), default)]
+    error: String,
+}
+
+#[component]
+fn ApiTokenForm(props: ApiTokenFormProps) -> String {
+    html! {
+        <form
+            hx-ext="response-targets"
+            hx-post=routes::profile_api_tokens()
+            hx-target="#api-tokens"
+            hx-target-4xx="#api-tokens"
+            hx-swap="outerHTML"
+            class="mt-6"
+        >
+            {
+                if props.error.is_empty() {
+                    "".into()
+                } else {
+                    html! { <Alert class="mb-4" title=props.error /> }
+                }
+            }
+            <GridLayout>
+                <GridCell span=4>
+                    <Label for_input="name">Token Name</Label>
+                    <TextInput name="name" placeholder="e.g. Roster sync" />
+                </GridCell>
+            </GridLayout>
+            <div class="mt-6 flex items-center justify-end gap-x-6">
+                <Button kind="submit">Create Token</Button>
+            </div>
+        </form>
+    }
+}
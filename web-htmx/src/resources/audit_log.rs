@@ -0,0 +1,309 @@
+use std::collections::HashMap;
+
+use axum::{
+    extract::{Query, State},
+    response::{Html, IntoResponse},
+    routing::get,
+    Router,
+};
+use axum_login::permission_required;
+use futures::future::join_all;
+use rscx::{component, html, props, CollectFragment, CollectFragmentAsync};
+use serde::Deserialize;
+
+use audit_service::{
+    get_audit_entries::GetAuditEntriesInput,
+    models::{AuditEntry, AuditFilter, AUDITED_ENTITIES},
+};
+use mongo_user_repository::MongoUserStore;
+use web_client::server::{
+    card::Card,
+    form::{Button, GridCell, GridLayout, Label, Select, SelectOption, TextInput},
+    table::{TDVariant, Table, TableData, TableHeading},
+};
+
+use crate::{
+    components::{page::PageLayout, page_content::PageContent},
+    routes,
+    state::WebHtmxState,
+};
+
+const FILTERS_FORM_ID: &str = "audit-log-filters";
+
+pub fn audit_log_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(routes::AUDIT_LOG, get(get_audit_log))
+        .route(routes::AUDIT_LOG_ENTRIES, get(get_audit_log_entries))
+        .with_state(state)
+        .route_layer(permission_required!(
+            MongoUserStore,
+            login_url = "/forbidden",
+            "audit_log.read",
+        ))
+}
+
+#[derive(Deserialize, Debug)]
+struct AuditLogQuery {
+    #[serde(default)]
+    actor_id: String,
+    #[serde(default)]
+    worksite_id: String,
+    #[serde(default)]
+    entity: String,
+    #[serde(default)]
+    action: String,
+    #[serde(default)]
+    offset: usize,
+}
+
+impl AuditLogQuery {
+    fn to_filter(&self) -> AuditFilter {
+        // The form submits every field, so blank ones mean no filter
+        let optional = |value: &String| match value.trim() {
+            "" => None,
+            value => Some(value.to_string()),
+        };
+
+        AuditFilter {
+            actor_id: optional(&self.actor_id),
+            worksite_id: optional(&self.worksite_id),
+            entity: optional(&self.entity),
+            action: optional(&self.action),
+            offset: self.offset,
+            ..AuditFilter::default()
+        }
+    }
+}
+
+// Ids to the names shown in the table, gathered once per page
+struct AuditLogNames {
+    actors: HashMap<String, String>,
+    worksites: HashMap<String, String>,
+}
+
+async fn get_names(state: &WebHtmxState) -> AuditLogNames {
+    let users = state
+        .auth_service
+        .get_users()
+        .await
+        .expect("Failed to get users");
+    let worksites = state
+        .worksite_service
+        .get_worksites()
+        .await
+        .expect("Failed to get worksites");
+
+    AuditLogNames {
+        actors: users.into_iter().map(|u| (u.id, u.email)).collect(),
+        worksites: worksites.into_iter().map(|w| (w.id, w.name)).collect(),
+    }
+}
+
+async fn get_entries(state: &WebHtmxState, query: &AuditLogQuery) -> Vec<AuditEntry> {
+    state
+        .audit_service
+        .get_audit_entries(GetAuditEntriesInput {
+            filter: query.to_filter(),
+        })
+        .await
+        .expect("Failed to get audit entries")
+}
+
+async fn get_audit_log(
+    State(state): State<WebHtmxState>,
+    Query(query): Query<AuditLogQuery>,
+) -> impl IntoResponse {
+    let entries = get_entries(&state, &query).await;
+    let names = get_names(&state).await;
+
+    let mut actors: Vec<(String, String)> = names.actors.clone().into_iter().collect();
+    actors.sort_by(|a, b| a.1.cmp(&b.1));
+    let mut worksites: Vec<(String, String)> = names.worksites.clone().into_iter().collect();
+    worksites.sort_by(|a, b| a.1.cmp(&b.1));
+
+    Html(html! {
+        <PageLayout header="Audit Log">
+            <PageContent title="Who changed what, newest first">
+                <Card>
+                    <form id=FILTERS_FORM_ID action=routes::audit_log() method="get" class="p-6">
+                        <GridLayout>
+                            <GridCell span=2>
+                                <Label for_input="actor_id">User</Label>
+                                <Select name="actor_id">
+                                    <FilterOptions options=actors selected=query.actor_id.clone() />
+                                </Select>
+                            </GridCell>
+                            <GridCell span=2>
+                                <Label for_input="worksite_id">Worksite</Label>
+                                <Select name="worksite_id">
+                                    <FilterOptions options=worksites selected=query.worksite_id.clone() />
+                                </Select>
+                            </GridCell>
+                            <GridCell span=2>
+                                <Label for_input="entity">Type</Label>
+                                <Select name="entity">
+                                    <FilterOptions
+                                        options=AUDITED_ENTITIES
+                                            .iter()
+                                            .map(|entity| (entity.to_string(), entity.to_string()))
+                                            .collect()
+                                        selected=query.entity.clone()
+                                    />
+                                </Select>
+                            </GridCell>
+                            <GridCell span=3>
+                                <Label for_input="action">Action</Label>
+                                <TextInput name="action" value=query.action.clone() />
+                            </GridCell>
+                        </GridLayout>
+                        <div class="mt-6 flex items-center justify-end gap-x-6">
+                            <a href=routes::audit_log() class="text-sm font-semibold leading-6 text-gray-900">
+                                Clear
+                            </a>
+                            <Button kind="submit">Filter</Button>
+                        </div>
+                    </form>
+                </Card>
+            </PageContent>
+            <PageContent>
+                <Card>
+                    <Table
+                        headings=vec![
+                            TableHeading::title("When"),
+                            TableHeading::title("User"),
+                            TableHeading::title("Worksite"),
+                            TableHeading::title("Type"),
+                            TableHeading::title("Action"),
+                            TableHeading::title("Changes"),
+                        ]
+                        body=audit_log_rows(entries, &names, &query).await
+                    />
+                </Card>
+            </PageContent>
+        </PageLayout>
+    })
+}
+
+// The next page of rows, swapped in place of the "Load more" row
+async fn get_audit_log_entries(
+    State(state): State<WebHtmxState>,
+    Query(query): Query<AuditLogQuery>,
+) -> impl IntoResponse {
+    let entries = get_entries(&state, &query).await;
+    let names = get_names(&state).await;
+
+    Html(
+        audit_log_rows(entries, &names, &query)
+            .await
+            .into_iter()
+            .map(|row| html! { <tr data-loading-states>{row}</tr> })
+            .collect_fragment(),
+    )
+}
+
+async fn audit_log_rows(
+    entries: Vec<AuditEntry>,
+    names: &AuditLogNames,
+    query: &AuditLogQuery,
+) -> Vec<String> {
+    let limit = query.to_filter().limit;
+    let has_more = entries.len() == limit;
+
+    let mut rows = join_all(entries.iter().map(|entry| audit_log_row(entry, names))).await;
+
+    if has_more {
+        rows.push(html! {
+            <td colspan="6" class="py-4 text-center">
+                <button
+                    class="text-sm font-semibold leading-6 text-indigo-600 hover:text-indigo-500"
+                    hx-get=routes::audit_log_entries()
+                    hx-include=format!("#{}", FILTERS_FORM_ID)
+                    hx-vals=format!("{{\"offset\": {}}}", query.offset + limit)
+                    hx-target="closest tr"
+                    hx-swap="outerHTML"
+                >
+                    Load more
+                </button>
+            </td>
+        });
+    }
+
+    rows
+}
+
+async fn audit_log_row(entry: &AuditEntry, names: &AuditLogNames) -> String {
+    let actor = match &entry.actor_id {
+        // Deleted users no longer have an email to show
+        Some(actor_id) => names.actors.get(actor_id).unwrap_or(actor_id).clone(),
+        None => "System".into(),
+    };
+    let worksite = entry
+        .worksite_id
+        .as_ref()
+        .map(|id| names.worksites.get(id).unwrap_or(id).clone())
+        .unwrap_or_default();
+
+    html! {
+        <TableData variant=TDVariant::First>
+            {entry.occurred_at.format("%Y-%m-%d %H:%M UTC").to_string()}
+        </TableData>
+        <TableData>{actor}</TableData>
+        <TableData>{worksite}</TableData>
+        <TableData>{entry.entity.clone()}</TableData>
+        <TableData>{entry.action.clone()}</TableData>
+        <TableData variant=TDVariant::LastNonEmptyHeading>
+            <ul class="text-gray-500">
+                {
+                    entry
+                        .changes()
+                        .into_iter()
+                        .map(|change| html! {
+                            <li>
+                                <span class="font-medium text-gray-900">{format!("{}: ", change.field)}</span>{
+                                    match (change.before, change.after) {
+                                        (Some(before), Some(after)) => format!("{} → {}", before, after),
+                                        (None, Some(after)) => after,
+                                        (Some(before), None) => format!("{} (removed)", before),
+                                        (None, None) => "".into(),
+                                    }
+                                }
+                            </li>
+                        })
+                        .collect_fragment()
+                }
+            </ul>
+        </TableData>
+    }
+}
+
+#[props]
+struct FilterOptionsProps {
+    // (value, label) pairs
+    options: Vec<(String, String)>,
+
+    #[builder(setter(into))]
+    selected: String,
+}
+
+#[component]
+fn FilterOptions(props: FilterOptionsProps) -> String {
+    let selected = &props.selected;
+
+    html! {
+        <SelectOption value="" selected=props.selected.is_empty()>All</SelectOption>
+        {
+            props
+                .options
+                .into_iter()
+                .map(|(value, label)| async move {
+                    html! {
+                        <SelectOption value=value.clone() selected=&value == selected>
+                            {label}
+                        </SelectOption>
+                    }
+                })
+                .collect_fragment_async()
+                .await
+        }
+    }
+}
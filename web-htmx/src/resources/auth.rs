@@ -1,33 +1,54 @@
 use crate::routes::LOGIN;
 use crate::state::WebHtmxState;
 use crate::{components::page::PageLayout, routes};
-use auth_service::get_user_for_login::GetUserForLoginInput;
-use axum::extract::Query;
+use auth_service::{
+    complete_oidc_login::CompleteOidcLoginInput,
+    get_two_factor_status::GetTwoFactorStatusInput,
+    get_user_for_login::{GetUserForLoginFailure, GetUserForLoginInput},
+    models::{OidcPendingLogin, TwoFactorStatus, User},
+    service::AuthService,
+};
+use axum::extract::{ConnectInfo, Query};
 use axum::{
     extract::State,
-    response::{Html, IntoResponse},
+    response::{Html, IntoResponse, Redirect},
     routing::{get, post},
     Form, Router,
 };
-use axum_login::AuthSession;
-use http::StatusCode;
-use mongo_user_repository::MongoUserStore;
+use axum_login::{tower_sessions::Session, AuthSession};
+use chrono::Utc;
+use http::{HeaderMap, StatusCode};
+use mongo_user_repository::{Credentials, MongoUserStore};
 use rscx::{component, html, props};
 use serde::Deserialize;
-use web_client::server::form::{Button, GridCell, GridLayout, Label, TextInput};
+use std::net::SocketAddr;
+use web_client::server::{
+    alert::Alert,
+    form::{Button, GridCell, GridLayout, Label, TextInput},
+};
+use worksite_service::{get_worksite::GetWorksiteInput, service::WorksiteService};
 
 pub fn login_routes(state: WebHtmxState) -> Router {
     Router::new()
         .route(routes::LOGIN, get(get_login).post(post_login))
+        .route(routes::LOGIN_OIDC, get(get_login_oidc))
+        .route(routes::LOGIN_OIDC_CALLBACK, get(get_login_oidc_callback))
         .route(routes::LOGOUT, post(post_logout))
         .route(routes::FORBIDDEN, get(get_forbidden))
         .with_state(state)
 }
 
-async fn get_login(Query(NextUrl { next }): Query<NextUrl>) -> impl IntoResponse {
+async fn get_login(
+    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+    Query(NextUrl { next }): Query<NextUrl>,
+) -> impl IntoResponse {
     Html(html! {
         <PageLayout header="Login">
-            <LoginForm login_route=routes::login() next=next/>
+            <LoginForm
+                login_route=routes::login()
+                next=next
+                oidc_enabled=auth_service.is_oidc_enabled()
+            />
         </PageLayout>
     })
 }
@@ -47,40 +68,268 @@ pub struct NextUrl {
 }
 
 async fn post_login(
-    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+    State(WebHtmxState {
+        auth_service,
+        worksite_service,
+        ..
+    }): State<WebHtmxState>,
     mut auth: AuthSession<MongoUserStore>,
+    session: Session,
+    headers: HeaderMap,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
     Form(login_form): Form<LoginForm>,
 ) -> impl IntoResponse {
     let result = auth_service
         .get_user_for_login(GetUserForLoginInput {
             email: login_form.email,
             password: login_form.password,
+            client_ip: client_ip(&headers, connect_info),
         })
         .await;
 
+    let next = local_redirect(login_form.next);
+
     match result {
-        Ok(user) => match auth.login(&user).await {
-            Ok(_) => {
-                let next = match login_form.next {
-                    Some(next) => next,
-                    None => routes::home(),
-                };
-                (
+        Ok(user) => {
+            let second_step = match two_factor_step(&auth_service, &session, &user, &next).await {
+                Ok(second_step) => second_step,
+                Err(_) => return (StatusCode::INTERNAL_SERVER_ERROR, "Login failed").into_response(),
+            };
+
+            if let Some(second_step) = second_step {
+                return (
+                    StatusCode::OK,
+                    [
+                        ("hx-redirect", second_step),
+                        ("hx-retarget", "body".to_string()),
+                    ],
+                )
+                    .into_response();
+            }
+
+            match complete_login(&mut auth, &session, &worksite_service, &user).await {
+                Ok(_) => (
                     StatusCode::OK,
                     [("hx-redirect", next), ("hx-retarget", "body".to_string())],
                 )
-                    .into_response()
+                    .into_response(),
+                Err(_) => (StatusCode::BAD_REQUEST, "Login failed").into_response(),
             }
-            Err(_) => (StatusCode::BAD_REQUEST, "Login failed").into_response(),
-        },
+        }
+        Err(GetUserForLoginFailure::TooManyAttempts(retry_at)) => (
+            StatusCode::TOO_MANY_REQUESTS,
+            Html(html! {
+                <Alert
+                    title=format!(
+                        "Too many login attempts. Try again in {} seconds.",
+                        (retry_at - Utc::now()).num_seconds().max(1)
+                    )
+                />
+            }),
+        )
+            .into_response(),
+        Err(GetUserForLoginFailure::AccountLocked(locked_until)) => (
+            StatusCode::TOO_MANY_REQUESTS,
+            Html(html! {
+                <Alert
+                    title=format!(
+                        "Login is locked after too many failed attempts. Try again after {} UTC or ask an admin to unlock your account.",
+                        locked_until.format("%H:%M")
+                    )
+                />
+            }),
+        )
+            .into_response(),
         Err(_) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            "Login failed".to_string(),
+            StatusCode::UNAUTHORIZED,
+            Html(html! {
+                <Alert title="Login failed" />
+            }),
         )
             .into_response(),
     }
 }
 
+// Session keys for a login that is waiting on the identity provider
+const OIDC_CSRF_STATE: &str = "oidc_csrf_state";
+const OIDC_NONCE: &str = "oidc_nonce";
+const OIDC_PKCE_VERIFIER: &str = "oidc_pkce_verifier";
+const OIDC_NEXT: &str = "oidc_next";
+
+async fn get_login_oidc(
+    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+    session: Session,
+    Query(NextUrl { next }): Query<NextUrl>,
+) -> impl IntoResponse {
+    let authorization = match auth_service.begin_oidc_login().await {
+        Ok(authorization) => authorization,
+        Err(_) => return Redirect::to(&routes::login()).into_response(),
+    };
+
+    session.insert_value(OIDC_CSRF_STATE, authorization.pending.csrf_state.into());
+    session.insert_value(OIDC_NONCE, authorization.pending.nonce.into());
+    session.insert_value(
+        OIDC_PKCE_VERIFIER,
+        authorization.pending.pkce_verifier.into(),
+    );
+    session.insert_value(OIDC_NEXT, next.unwrap_or(routes::home()).into());
+
+    Redirect::to(&authorization.url).into_response()
+}
+
+#[derive(Deserialize, Debug)]
+struct OidcCallback {
+    code: Option<String>,
+    state: Option<String>,
+}
+
+// The pending login is removed straight away so a callback can only be used once
+fn take_oidc_pending_login(session: &Session) -> Option<OidcPendingLogin> {
+    let take = |key: &str| -> Option<String> { session.remove(key).ok().unwrap_or(None) };
+
+    Some(OidcPendingLogin {
+        csrf_state: take(OIDC_CSRF_STATE)?,
+        nonce: take(OIDC_NONCE)?,
+        pkce_verifier: take(OIDC_PKCE_VERIFIER)?,
+    })
+}
+
+// We can't tell whether the identity provider asked for a second factor, so single
+// sign-on users go through our own two-factor step like everyone else.
+async fn get_login_oidc_callback(
+    State(WebHtmxState {
+        auth_service,
+        worksite_service,
+        ..
+    }): State<WebHtmxState>,
+    mut auth: AuthSession<MongoUserStore>,
+    session: Session,
+    Query(callback): Query<OidcCallback>,
+) -> impl IntoResponse {
+    let pending = take_oidc_pending_login(&session);
+    let next = local_redirect(session.remove(OIDC_NEXT).ok().unwrap_or(None));
+
+    let (Some(code), Some(state), Some(pending)) = (callback.code, callback.state, pending) else {
+        return oidc_login_failed().await.into_response();
+    };
+
+    let user = auth
+        .authenticate(Credentials::Oidc(CompleteOidcLoginInput {
+            code,
+            state,
+            pending,
+        }))
+        .await;
+
+    let Ok(Some(user)) = user else {
+        return oidc_login_failed().await.into_response();
+    };
+
+    match two_factor_step(&auth_service, &session, &user, &next).await {
+        Ok(Some(second_step)) => return Redirect::to(&second_step).into_response(),
+        Ok(None) => {}
+        Err(_) => return oidc_login_failed().await.into_response(),
+    }
+
+    match complete_login(&mut auth, &session, &worksite_service, &user).await {
+        Ok(_) => Redirect::to(&next).into_response(),
+        Err(_) => oidc_login_failed().await.into_response(),
+    }
+}
+
+async fn oidc_login_failed() -> impl IntoResponse {
+    (
+        StatusCode::UNAUTHORIZED,
+        Html(html! {
+            <PageLayout header="Login">
+                <Alert
+                    title="Single sign-on failed"
+                >
+                    <a href=routes::login() class="font-semibold underline">Try logging in again</a>
+                </Alert>
+            </PageLayout>
+        }),
+    )
+}
+
+// Where to send the user once they're logged in. The "next" url comes from the
+// query string, so only paths on this site are followed. "//host" and "/\\host"
+// are treated as other hosts by browsers.
+fn local_redirect(next: Option<String>) -> String {
+    next.filter(|next| next.starts_with('/') && !next.starts_with("//") && !next.starts_with("/\\"))
+        .unwrap_or(routes::home())
+}
+
+// Session keys for a login that is waiting on its two-factor step
+pub(crate) const TWO_FACTOR_USER_ID: &str = "two_factor_user_id";
+pub(crate) const TWO_FACTOR_NEXT: &str = "two_factor_next";
+
+// Users with two-factor only get logged in after the second step. Returns where to
+// send them for it, with the pending login kept in the session.
+async fn two_factor_step(
+    auth_service: &AuthService,
+    session: &Session,
+    user: &User,
+    next: &str,
+) -> Result<Option<String>, ()> {
+    let status = auth_service
+        .get_two_factor_status(GetTwoFactorStatusInput {
+            user_id: user.id.clone(),
+        })
+        .await
+        .map_err(|_| ())?;
+
+    let second_step = match status {
+        TwoFactorStatus::Disabled => None,
+        TwoFactorStatus::Enabled => Some(routes::login_two_factor()),
+        TwoFactorStatus::Required => Some(routes::login_two_factor_setup()),
+    };
+
+    if second_step.is_some() {
+        session.insert_value(TWO_FACTOR_USER_ID, user.id.clone().into());
+        session.insert_value(TWO_FACTOR_NEXT, next.into());
+    }
+
+    Ok(second_step)
+}
+
+pub(crate) async fn complete_login(
+    auth: &mut AuthSession<MongoUserStore>,
+    session: &Session,
+    worksite_service: &WorksiteService,
+    user: &User,
+) -> Result<(), ()> {
+    auth.login(user).await.map_err(|_| ())?;
+
+    // Start the user off on their preferred worksite, if they have one
+    if let Some(worksite_id) = user.default_worksite_id.clone() {
+        if let Ok(Some(worksite)) = worksite_service
+            .get_worksite(GetWorksiteInput {
+                id: worksite_id.clone(),
+            })
+            .await
+        {
+            session.insert_value("selected_worksite_id", worksite_id.into());
+            session.insert_value("selected_worksite_name", worksite.name.into());
+        }
+    }
+
+    Ok(())
+}
+
+// The app runs behind a proxy in production, so prefer the address it forwards.
+// Clients can send their own x-forwarded-for, so only the last hop, which the
+// proxy appends, can be trusted.
+fn client_ip(headers: &HeaderMap, connect_info: Option<ConnectInfo<SocketAddr>>) -> Option<String> {
+    headers
+        .get("x-forwarded-for")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.rsplit(',').next())
+        .map(|ip| ip.trim().to_string())
+        .filter(|ip| !ip.is_empty())
+        .or(connect_info.map(|ConnectInfo(addr)| addr.ip().to_string()))
+}
+
 #[props]
 struct LoginFormProps {
     #[builder(setter(into))]
@@ -88,12 +337,20 @@ struct LoginFormProps {
 
     #[builder(setter(into))]
     next: Option<String>,
+
+    #[builder(default)]
+    oidc_enabled: bool,
 }
 
 #[component]
 fn LoginForm(props: LoginFormProps) -> String {
     html! {
-        <form hx-post=props.login_route>
+        <form
+            hx-ext="response-targets"
+            hx-post=props.login_route
+            hx-target-error="#login-errors"
+        >
+            <div id="login-errors"></div>
             <div class="pb-12">
                 <p class="mt-1 text-sm leading-6 text-gray-600">
                     "pssst: try user@yallchart.com / password"
@@ -115,7 +372,7 @@ fn LoginForm(props: LoginFormProps) -> String {
                 </GridLayout>
             </div>
             {
-                match props.next {
+                match props.next.clone() {
                     Some(next) => html! {
                         <input type="hidden" name="next" value=next />
                     },
@@ -123,6 +380,30 @@ fn LoginForm(props: LoginFormProps) -> String {
                 }
             }
         </form>
+        {
+            if props.oidc_enabled {
+                // A plain form so the browser leaves for the identity provider and encodes next
+                html! {
+                    <form
+                        action=routes::login_oidc()
+                        method="get"
+                        class="border-t border-gray-200 pt-6 flex items-center justify-end"
+                    >
+                        {
+                            match props.next {
+                                Some(next) => html! {
+                                    <input type="hidden" name="next" value=next />
+                                },
+                                None => html! {},
+                            }
+                        }
+                        <Button kind="submit">Sign in with your union account</Button>
+                    </form>
+                }
+            } else {
+                "".into()
+            }
+        }
     }
 }
 
@@ -144,3 +425,30 @@ async fn get_forbidden() -> impl IntoResponse {
         </PageLayout>
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::local_redirect;
+    use crate::routes;
+
+    #[test]
+    fn only_redirects_to_local_paths() {
+        assert_eq!(local_redirect(Some("/worksites".into())), "/worksites");
+        assert_eq!(
+            local_redirect(Some("/tasks?page=2".into())),
+            "/tasks?page=2"
+        );
+
+        for next in [
+            "https://evil.example.com",
+            "javascript:alert(1)",
+            "//evil.example.com",
+            "/\\evil.example.com",
+            "evil.example.com",
+            "",
+        ] {
+            assert_eq!(local_redirect(Some(next.into())), routes::home());
+        }
+        assert_eq!(local_redirect(None), routes::home());
+    }
+}
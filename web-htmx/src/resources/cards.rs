@@ -0,0 +1,447 @@
+use axum::{
+    extract::{self, State},
+    response::{Html, IntoResponse},
+    routing::get,
+    Form, Router,
+};
+use axum_flash::{Flash, IncomingFlashes};
+use chrono::NaiveDate;
+use futures::future::join_all;
+use http::StatusCode;
+use rscx::{component, html, props, CollectFragment, CollectFragmentAsync};
+use serde::Deserialize;
+
+use web_client::server::{
+    attrs::Attrs,
+    button::PrimaryButton,
+    card::{Card, CardContent, CardFooter},
+    form::{GridCell, GridLayout, Label, TextInput},
+    headers::SecondaryHeader,
+    modal::modal_target,
+    notification::NotificationFlashes,
+    table::{TDVariant, Table, TableData, TableHeading},
+};
+use worksite_service::{
+    get_card_report::{CardReport, GetCardReportInput, UnsignedWorkers},
+    get_worker::GetWorkerInput,
+    models::{AuthorizationCard, CardThreshold},
+    record_authorization_card::RecordAuthorizationCardInput,
+    update_card_thresholds::UpdateCardThresholdsInput,
+};
+
+use crate::{
+    components::{page::PageLayout, page_content::PageContent},
+    routes,
+    state::WebHtmxState,
+};
+
+pub fn cards_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(routes::CARDS, get(get_cards).put(put_card_thresholds))
+        .route(
+            routes::WORKER_CARD,
+            get(get_worker_card).put(put_worker_card),
+        )
+        .with_state(state)
+}
+
+async fn get_cards(
+    flashes: IncomingFlashes,
+    extract::Path(worksite_id): extract::Path<String>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let report = state
+        .worksite_service
+        .get_card_report(GetCardReportInput {
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get card report");
+
+    // A blank row at the end for adding another threshold
+    let mut thresholds: Vec<(String, String)> = report
+        .thresholds
+        .iter()
+        .map(|t| (t.threshold.name.clone(), t.threshold.percent.to_string()))
+        .collect();
+    thresholds.push(("".into(), "".into()));
+
+    let html = Html(html! {
+        <PageLayout header="Authorization Cards">
+            <NotificationFlashes flashes=flashes.clone() />
+            <PageContent title="How much of the unit has signed">
+                <Card>
+                    <CardProgress report=report.clone() />
+                </Card>
+            </PageContent>
+            <PageContent title="Thresholds">
+                <form>
+                    <Card>
+                        <CardContent padded=true>
+                            <GridLayout>
+                                {
+                                    thresholds
+                                        .into_iter()
+                                        .map(|(name, percent)| async move {
+                                            html! {
+                                                <GridCell span=4>
+                                                    <Label for_input="name">Name</Label>
+                                                    <TextInput
+                                                        name="name"
+                                                        placeholder="File for an election"
+                                                        value=name
+                                                    />
+                                                </GridCell>
+                                                <GridCell span=2>
+                                                    <Label for_input="percent">Percent</Label>
+                                                    <TextInput
+                                                        input_type="number"
+                                                        name="percent"
+                                                        placeholder="30"
+                                                        value=percent
+                                                    />
+                                                </GridCell>
+                                            }
+                                        })
+                                        .collect_fragment_async()
+                                        .await
+                                }
+                            </GridLayout>
+                            <p class="mt-4 text-sm text-gray-500">
+                                "Clear a threshold's name and percent to remove it."
+                            </p>
+                        </CardContent>
+                        <CardFooter>
+                            <PrimaryButton hx_put=routes::cards(&worksite_id)>
+                                Save Thresholds
+                            </PrimaryButton>
+                        </CardFooter>
+                    </Card>
+                </form>
+            </PageContent>
+            <PageContent title="Unsigned workers by location">
+                <Card>
+                    <UnsignedWorkersTable
+                        worksite_id=worksite_id.clone()
+                        heading="Location"
+                        groups=report.unsigned_by_location
+                    />
+                </Card>
+            </PageContent>
+            <PageContent title="Unsigned workers by shift">
+                <Card>
+                    <UnsignedWorkersTable
+                        worksite_id=worksite_id.clone()
+                        heading="Shift"
+                        groups=report.unsigned_by_shift
+                    />
+                </Card>
+            </PageContent>
+        </PageLayout>
+    });
+
+    (flashes, html)
+}
+
+#[props]
+pub struct CardProgressProps {
+    report: CardReport,
+}
+
+// The share of the unit that has signed, measured against each threshold
+#[component]
+pub fn CardProgress(props: CardProgressProps) -> String {
+    let report = props.report;
+    let percent_signed = report.percent_signed();
+
+    html! {
+        <div class="p-6">
+            <p class="text-3xl font-semibold tracking-tight text-gray-900">
+                {format!("{}%", percent_signed)}
+            </p>
+            <p class="mt-1 text-sm text-gray-500">
+                {format!("{} of {} workers have signed a card", report.signed, report.total)}
+            </p>
+            <div class="relative mt-4 h-2 rounded-full bg-gray-200">
+                <div
+                    class="h-2 rounded-full bg-indigo-600"
+                    style=format!("width: {}%", percent_signed.min(100))
+                ></div>
+                {
+                    report
+                        .thresholds
+                        .iter()
+                        .map(|t| html! {
+                            <div
+                                class="absolute -top-1 h-4 w-0.5 bg-gray-900"
+                                style=format!("left: {}%", t.threshold.percent)
+                                title=t.threshold.name.clone()
+                            ></div>
+                        })
+                        .collect_fragment()
+                }
+            </div>
+            <ul role="list" class="mt-4 divide-y divide-gray-100">
+                {
+                    report
+                        .thresholds
+                        .into_iter()
+                        .map(|t| html! {
+                            <li class="flex justify-between gap-x-4 py-2 text-sm">
+                                <span class="text-gray-900">
+                                    {format!("{} ({}%)", t.threshold.name, t.threshold.percent)}
+                                </span>
+                                {
+                                    if t.met {
+                                        html! {
+                                            <span class="font-medium text-green-700">Reached</span>
+                                        }
+                                    } else {
+                                        html! {
+                                            <span class="text-gray-500">
+                                                {format!("{} more cards needed", t.cards_needed)}
+                                            </span>
+                                        }
+                                    }
+                                }
+                            </li>
+                        })
+                        .collect_fragment()
+                }
+            </ul>
+        </div>
+    }
+}
+
+#[props]
+struct UnsignedWorkersTableProps {
+    worksite_id: String,
+    #[builder(setter(into))]
+    heading: String,
+    groups: Vec<UnsignedWorkers>,
+}
+
+#[component]
+fn UnsignedWorkersTable(props: UnsignedWorkersTableProps) -> String {
+    if props.groups.is_empty() {
+        return html! {
+            <p class="p-6 text-sm text-gray-500">"Everyone has signed."</p>
+        };
+    }
+
+    let worksite_id = &props.worksite_id;
+
+    html! {
+        <Table
+            headings=vec![
+                TableHeading::title(props.heading),
+                TableHeading::title("Unsigned"),
+                TableHeading::title("Workers"),
+            ]
+            body=join_all(props.groups.into_iter().map(|group| async move {
+                html! {
+                    <TableData variant=TDVariant::First>{group.name}</TableData>
+                    <TableData>{group.workers.len().to_string()}</TableData>
+                    <TableData variant=TDVariant::LastNonEmptyHeading>
+                        <div class="flex flex-wrap gap-x-3 gap-y-1">
+                            {
+                                group
+                                    .workers
+                                    .into_iter()
+                                    .map(|worker| html! {
+                                        <button
+                                            class="text-indigo-600 hover:text-indigo-900"
+                                            hx-get=routes::worker(worksite_id, &worker.id)
+                                            hx-target=modal_target()
+                                            hx-swap="beforeend"
+                                        >
+                                            {worker.full_name()}
+                                        </button>
+                                    })
+                                    .collect_fragment()
+                            }
+                        </div>
+                    </TableData>
+                }
+            }))
+            .await
+        />
+    }
+}
+
+async fn put_card_thresholds(
+    extract::Path(worksite_id): extract::Path<String>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    // Repeated name and percent fields, one pair per row
+    Form(form): Form<Vec<(String, String)>>,
+) -> impl IntoResponse {
+    let field = |name: &str| -> Vec<String> {
+        form.iter()
+            .filter(|(key, _)| key == name)
+            .map(|(_, value)| value.trim().to_string())
+            .collect()
+    };
+
+    let thresholds: Result<Vec<CardThreshold>, String> = field("name")
+        .into_iter()
+        .zip(field("percent"))
+        .filter(|(name, percent)| !name.is_empty() || !percent.is_empty())
+        .map(|(name, percent)| match percent.parse::<u8>() {
+            Ok(percent) => Ok(CardThreshold::new(name, percent)),
+            Err(_) => Err(format!("{} is not a percent.", percent)),
+        })
+        .collect();
+
+    let result = match thresholds {
+        Ok(thresholds) => worksite_service
+            .update_card_thresholds(UpdateCardThresholdsInput {
+                worksite_id: worksite_id.clone(),
+                thresholds,
+            })
+            .await
+            .map_err(|e| e.to_string()),
+        Err(e) => Err(e),
+    };
+
+    let flash = match result {
+        Ok(_) => flash.success("Thresholds updated successfully!"),
+        Err(e) => flash.error(e),
+    };
+
+    (
+        StatusCode::OK,
+        flash,
+        [
+            ("hx-redirect", routes::cards(&worksite_id)),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
+
+async fn get_worker_card(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let worker = state
+        .worksite_service
+        .get_worker(GetWorkerInput {
+            id: worker_id.clone(),
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get worker")
+        .ok_or("Worker not found")
+        .expect("Worker not found");
+
+    let card = worker.authorization_card.clone();
+    let on_file = card.as_ref().is_some_and(|card| card.on_file);
+
+    html! {
+        <section aria-labelledby="worker-card-heading">
+            <form>
+                <Card>
+                    <CardContent padded=true>
+                        <SecondaryHeader
+                            id="worker-card-heading"
+                            title="🪪 Authorization Card"
+                            subtitle="Clear the date to remove a card recorded by mistake."
+                        />
+                        <GridLayout>
+                            <GridCell span=3>
+                                <Label for_input="signed_on">Signed</Label>
+                                <TextInput
+                                    input_type="date"
+                                    name="signed_on"
+                                    value=card.as_ref().map(|card| card.signed_on.to_string()).unwrap_or_default()
+                                />
+                            </GridCell>
+                            <GridCell span=3>
+                                <Label for_input="witnessed_by">Witnessed by</Label>
+                                <TextInput
+                                    name="witnessed_by"
+                                    value=card.as_ref().map(|card| card.witnessed_by.clone()).unwrap_or_default()
+                                />
+                            </GridCell>
+                        </GridLayout>
+                        <div class="mt-4 relative flex items-start py-4 border-t border-gray-200">
+                            <div class="min-w-0 flex-1 text-sm leading-6">
+                                <label for="inp-card-on-file" class="select-none font-medium text-gray-900">
+                                    Physical card on file
+                                </label>
+                            </div>
+                            <div class="ml-3 flex h-6 items-center">
+                                <input
+                                    id="inp-card-on-file"
+                                    name="on_file"
+                                    type="checkbox"
+                                    class="h-4 w-4 rounded border-gray-300 text-indigo-600 focus:ring-indigo-600"
+                                    {
+                                        String::from(
+                                            Attrs::default().set_if("checked", "checked".into(), on_file)
+                                        )
+                                    }
+                                    value="true"
+                                />
+                            </div>
+                        </div>
+                    </CardContent>
+                    <CardFooter>
+                        <PrimaryButton hx_put=routes::worker_card(&worksite_id, &worker_id)>
+                            Save
+                        </PrimaryButton>
+                    </CardFooter>
+                </Card>
+            </form>
+        </section>
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct WorkerCardFormData {
+    signed_on: String,
+    witnessed_by: String,
+    // Unchecked checkboxes aren't sent at all
+    on_file: Option<String>,
+}
+
+async fn put_worker_card(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<WorkerCardFormData>,
+) -> impl IntoResponse {
+    let card = NaiveDate::parse_from_str(&form.signed_on, "%Y-%m-%d")
+        .ok()
+        .map(|signed_on| AuthorizationCard {
+            signed_on,
+            witnessed_by: form.witnessed_by.trim().to_string(),
+            on_file: form.on_file.is_some(),
+        });
+
+    let result = worksite_service
+        .record_authorization_card(RecordAuthorizationCardInput {
+            worksite_id,
+            worker_id,
+            card,
+        })
+        .await;
+
+    let flash = match result {
+        Ok(_) => flash.success("Card updated successfully!"),
+        Err(e) => flash.error(e.to_string()),
+    };
+
+    (
+        StatusCode::OK,
+        flash,
+        [
+            ("hx-redirect", routes::wallchart()),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
@@ -0,0 +1,440 @@
+use axum::{
+    extract::{self, State},
+    response::{Html, IntoResponse},
+    routing::get,
+    Form, Router,
+};
+use axum_flash::Flash;
+use futures::future::join_all;
+use http::StatusCode;
+use rscx::{component, html, props, CollectFragmentAsync};
+use serde::Deserialize;
+
+use web_client::server::{
+    attrs::Attrs,
+    button::PrimaryButton,
+    card::{Card, CardContent, CardFooter},
+    form::{GridCell, GridLayout, Label, Select, SelectOption},
+    headers::SecondaryHeader,
+    modal::modal_target,
+    table::{TDVariant, Table, TableData, TableHeading},
+};
+use worksite_service::{
+    assign_leader::AssignLeaderInput,
+    get_committee_report::{CommitteeLeader, GetCommitteeReportInput},
+    get_worksite::GetWorksiteInput,
+    models::{Worker, COMMITTEE_RATIO},
+    set_committee_member::SetCommitteeMemberInput,
+};
+
+use crate::{
+    components::{page::PageLayout, page_content::PageContent},
+    routes,
+    state::WebHtmxState,
+};
+
+pub fn committee_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(routes::COMMITTEE, get(get_committee))
+        .route(
+            routes::WORKER_COMMITTEE,
+            get(get_worker_committee).put(put_worker_committee),
+        )
+        .with_state(state)
+}
+
+async fn get_committee(
+    extract::Path(worksite_id): extract::Path<String>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let report = state
+        .worksite_service
+        .get_committee_report(GetCommitteeReportInput {
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get committee report");
+
+    let over_ratio: Vec<CommitteeLeader> = report
+        .leaders
+        .iter()
+        .filter(|leader| leader.is_over_ratio())
+        .cloned()
+        .collect();
+
+    Html(html! {
+        <PageLayout header="Organizing Committee">
+            <PageContent title=format!("About 1 leader for every {} coworkers", COMMITTEE_RATIO)>
+                <Card>
+                    <dl class="grid grid-cols-1 divide-y divide-gray-200 sm:grid-cols-3 sm:divide-x sm:divide-y-0">
+                        <CommitteeStat label="Committee members" value=report.leaders.len() />
+                        <CommitteeStat label="Leaders over ratio" value=over_ratio.len() />
+                        <CommitteeStat
+                            label="Workers with no leader"
+                            value=report.workers_without_leader.len()
+                        />
+                    </dl>
+                </Card>
+            </PageContent>
+            <PageContent title="Leaders over ratio">
+                <Card>
+                    <LeaderRatioTable worksite_id=worksite_id.clone() leaders=over_ratio />
+                </Card>
+            </PageContent>
+            <PageContent title="Workers with no leader">
+                <Card>
+                    <UnledWorkersTable
+                        worksite_id=worksite_id.clone()
+                        workers=report.workers_without_leader
+                    />
+                </Card>
+            </PageContent>
+            <PageContent title="Each leader's list">
+                <CommitteeLists worksite_id=worksite_id leaders=report.leaders />
+            </PageContent>
+        </PageLayout>
+    })
+}
+
+#[props]
+struct CommitteeStatProps {
+    #[builder(setter(into))]
+    label: String,
+    value: usize,
+}
+
+#[component]
+fn CommitteeStat(props: CommitteeStatProps) -> String {
+    html! {
+        <div class="px-6 py-5">
+            <dt class="text-sm font-medium text-gray-500">{props.label}</dt>
+            <dd class="mt-1 text-3xl font-semibold tracking-tight text-gray-900">{props.value.to_string()}</dd>
+        </div>
+    }
+}
+
+#[props]
+struct WorkerButtonProps {
+    worksite_id: String,
+    worker: Worker,
+}
+
+// Opens the worker's detail flyout
+#[component]
+fn WorkerButton(props: WorkerButtonProps) -> String {
+    html! {
+        <button
+            hx-get=routes::worker(&props.worksite_id, &props.worker.id)
+            hx-target=modal_target()
+            hx-swap="beforeend"
+        >
+            {props.worker.full_name()}
+        </button>
+    }
+}
+
+#[props]
+struct LeaderRatioTableProps {
+    worksite_id: String,
+    leaders: Vec<CommitteeLeader>,
+}
+
+#[component]
+fn LeaderRatioTable(props: LeaderRatioTableProps) -> String {
+    if props.leaders.is_empty() {
+        return html! {
+            <p class="p-6 text-sm text-gray-500">"Every leader is within ratio."</p>
+        };
+    }
+
+    let worksite_id = &props.worksite_id;
+
+    html! {
+        <Table
+            headings=vec![
+                TableHeading::title("Leader"),
+                TableHeading::title("Coworkers"),
+                TableHeading::title("Over by"),
+            ]
+            body=join_all(props.leaders.into_iter().map(|leader| async move {
+                let count = leader.workers.len();
+
+                html! {
+                    <TableData variant=TDVariant::First>
+                        <WorkerButton worksite_id=worksite_id.clone() worker=leader.leader />
+                    </TableData>
+                    <TableData>{count.to_string()}</TableData>
+                    <TableData variant=TDVariant::LastNonEmptyHeading>
+                        {(count - COMMITTEE_RATIO).to_string()}
+                    </TableData>
+                }
+            }))
+            .await
+        />
+    }
+}
+
+#[props]
+struct UnledWorkersTableProps {
+    worksite_id: String,
+    workers: Vec<Worker>,
+}
+
+#[component]
+fn UnledWorkersTable(props: UnledWorkersTableProps) -> String {
+    if props.workers.is_empty() {
+        return html! {
+            <p class="p-6 text-sm text-gray-500">"Every worker has a leader."</p>
+        };
+    }
+
+    let worksite_id = &props.worksite_id;
+
+    html! {
+        <Table
+            headings=vec![TableHeading::title("Worker"), TableHeading::title("Email")]
+            body=join_all(props.workers.into_iter().map(|worker| async move {
+                let email = worker.email.clone();
+
+                html! {
+                    <TableData variant=TDVariant::First>
+                        <WorkerButton worksite_id=worksite_id.clone() worker=worker />
+                    </TableData>
+                    <TableData variant=TDVariant::LastNonEmptyHeading>{email}</TableData>
+                }
+            }))
+            .await
+        />
+    }
+}
+
+#[props]
+pub struct CommitteeListsProps {
+    #[builder(setter(into))]
+    worksite_id: String,
+    leaders: Vec<CommitteeLeader>,
+}
+
+// A card per committee member with the coworkers they are responsible for
+#[component]
+pub fn CommitteeLists(props: CommitteeListsProps) -> String {
+    if props.leaders.is_empty() {
+        return html! {
+            <Card>
+                <p class="p-6 text-sm text-gray-500">"Nobody is on the committee yet."</p>
+            </Card>
+        };
+    }
+
+    let worksite_id = &props.worksite_id;
+
+    html! {
+        <ul role="list" class="grid grid-cols-1 gap-6 sm:grid-cols-2 lg:grid-cols-3">
+            {
+                props.leaders.into_iter().map(|leader| async move {
+                    let count_class = if leader.is_over_ratio() {
+                        "text-red-700 bg-red-50 ring-red-600/10"
+                    } else {
+                        "text-gray-600 bg-gray-50 ring-gray-500/10"
+                    };
+
+                    html! {
+                        <li class="col-span-1 rounded-lg bg-white shadow">
+                            <div class="flex items-center justify-between border-b border-gray-200 px-4 py-3">
+                                <p class="text-sm font-semibold text-gray-900">
+                                    "⭐ "<WorkerButton worksite_id=worksite_id.clone() worker=leader.leader.clone() />
+                                </p>
+                                <span class=format!("rounded-md px-1.5 py-0.5 text-xs font-medium ring-1 ring-inset {}", count_class)>
+                                    {format!("{} / {}", leader.workers.len(), COMMITTEE_RATIO)}
+                                </span>
+                            </div>
+                            <ul role="list" class="divide-y divide-gray-100 px-4">
+                                {
+                                    if leader.workers.is_empty() {
+                                        html! {
+                                            <li class="py-2 text-sm text-gray-500">"No coworkers yet."</li>
+                                        }
+                                    } else {
+                                        leader.workers.into_iter().map(|worker| async move {
+                                            html! {
+                                                <li class="py-2 text-sm text-gray-700">
+                                                    <WorkerButton worksite_id=worksite_id.clone() worker=worker />
+                                                </li>
+                                            }
+                                        })
+                                        .collect_fragment_async()
+                                        .await
+                                    }
+                                }
+                            </ul>
+                        </li>
+                    }
+                })
+                .collect_fragment_async()
+                .await
+            }
+        </ul>
+    }
+}
+
+async fn get_worker_committee(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let worksite = state
+        .worksite_service
+        .get_worksite(GetWorksiteInput {
+            id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get worksite")
+        .ok_or("Worksite not found")
+        .expect("Worksite not found");
+    let worker = worksite
+        .get_worker(worker_id.clone())
+        .ok_or("Worker not found")
+        .expect("Worker not found");
+
+    let mut committee = worksite.get_committee();
+    committee.sort_by(|a, b| a.last_name.cmp(&b.last_name));
+    let leader_id = worker.leader_id.clone().unwrap_or_default();
+    let leader_id = &leader_id;
+    let list = worksite.get_workers_for_leader(&worker.id);
+
+    html! {
+        <section aria-labelledby="worker-committee-heading">
+            <form>
+                <Card>
+                    <CardContent padded=true>
+                        <SecondaryHeader
+                            id="worker-committee-heading"
+                            title="⭐ Organizing Committee"
+                            subtitle="Committee members each keep in touch with a list of coworkers."
+                        />
+                        <div class="mt-4 relative flex items-start py-4 border-t border-gray-200">
+                            <div class="min-w-0 flex-1 text-sm leading-6">
+                                <label for="inp-committee-member" class="select-none font-medium text-gray-900">
+                                    On the organizing committee
+                                </label>
+                            </div>
+                            <div class="ml-3 flex h-6 items-center">
+                                <input
+                                    id="inp-committee-member"
+                                    name="committee_member"
+                                    type="checkbox"
+                                    class="h-4 w-4 rounded border-gray-300 text-indigo-600 focus:ring-indigo-600"
+                                    {
+                                        String::from(
+                                            Attrs::default()
+                                                .set_if("checked", "checked".into(), worker.committee_member)
+                                        )
+                                    }
+                                    value="true"
+                                />
+                            </div>
+                        </div>
+                        {
+                            if worker.committee_member {
+                                html! {
+                                    <p class="text-sm text-gray-500">
+                                        {format!("{} coworkers on their list", list.len())}
+                                    </p>
+                                }
+                            } else {
+                                html! {
+                                    <GridLayout>
+                                        <GridCell span=6>
+                                            <Label for_input="leader_id">Leader</Label>
+                                            <Select name="leader_id">
+                                                <SelectOption value="" selected=leader_id.is_empty()>
+                                                    No leader
+                                                </SelectOption>
+                                                {
+                                                    committee
+                                                        .into_iter()
+                                                        .map(|leader| async move {
+                                                            html! {
+                                                                <SelectOption
+                                                                    value=leader.id.clone()
+                                                                    selected=&leader.id == leader_id
+                                                                >
+                                                                    {leader.full_name()}
+                                                                </SelectOption>
+                                                            }
+                                                        })
+                                                        .collect_fragment_async()
+                                                        .await
+                                                }
+                                            </Select>
+                                        </GridCell>
+                                    </GridLayout>
+                                }
+                            }
+                        }
+                    </CardContent>
+                    <CardFooter>
+                        <PrimaryButton
+                            hx_put=routes::worker_committee(&worksite_id, &worker_id)
+                        >
+                            Save
+                        </PrimaryButton>
+                    </CardFooter>
+                </Card>
+            </form>
+        </section>
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct WorkerCommitteeFormData {
+    // Unchecked checkboxes aren't sent at all
+    committee_member: Option<String>,
+    leader_id: Option<String>,
+}
+
+async fn put_worker_committee(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<WorkerCommitteeFormData>,
+) -> impl IntoResponse {
+    let committee_member = form.committee_member.is_some();
+
+    let result = worksite_service
+        .set_committee_member(SetCommitteeMemberInput {
+            worksite_id: worksite_id.clone(),
+            worker_id: worker_id.clone(),
+            committee_member,
+        })
+        .await
+        .map_err(|e| e.to_string());
+
+    // The leader select is only shown to workers who weren't on the committee
+    let result = match (result, form.leader_id) {
+        (Ok(_), Some(leader_id)) if !committee_member => worksite_service
+            .assign_leader(AssignLeaderInput {
+                worksite_id,
+                worker_id,
+                leader_id: Some(leader_id).filter(|id| !id.is_empty()),
+            })
+            .await
+            .map_err(|e| e.to_string()),
+        (result, _) => result,
+    };
+
+    let flash = match result {
+        Ok(_) => flash.success("Committee updated successfully!"),
+        Err(e) => flash.error(e),
+    };
+
+    (
+        StatusCode::OK,
+        flash,
+        [
+            ("hx-redirect", routes::wallchart()),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
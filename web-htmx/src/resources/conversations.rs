@@ -0,0 +1,505 @@
+use auth_service::models::UserPermission;
+use axum::{
+    extract::{self, State},
+    response::IntoResponse,
+    routing::{delete, get},
+    Form, Router,
+};
+use axum_flash::Flash;
+use axum_login::permission_required;
+use chrono::{NaiveDate, Utc};
+use http::StatusCode;
+use mongo_user_repository::MongoUserStore;
+use rscx::{component, html, props, CollectFragmentAsync};
+use serde::Deserialize;
+
+use web_client::server::{
+    attrs::Attrs,
+    button::PrimaryButton,
+    card::{Card, CardContent, CardFooter},
+    form::{Button, GridCell, GridLayout, Label, Select, SelectOption, TextInput},
+    headers::SecondaryHeader,
+    modal::{modal_target, Modal},
+    popup_menu::{MenuItem, MenuSize, PopupMenu},
+};
+use worksite_service::{
+    add_conversation::AddConversationInput,
+    get_conversation::GetConversationInput,
+    get_conversations::GetConversationsInput,
+    models::{Conversation, ConversationChannel},
+    remove_conversation::RemoveConversationInput,
+    update_conversation::UpdateConversationInput,
+};
+
+use crate::{components::permission_required::PermissionRequired, routes, state::WebHtmxState};
+
+pub fn conversations_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(routes::CONVERSATION, delete(delete_conversation))
+        .route_layer(permission_required!(
+            MongoUserStore,
+            login_url = "/forbidden",
+            "conversation.delete",
+        ))
+        .route(
+            routes::CONVERSATIONS,
+            get(get_conversations).post(post_conversations),
+        )
+        .route(
+            routes::CONVERSATION,
+            get(get_conversation_form).put(put_conversation),
+        )
+        .with_state(state)
+}
+
+async fn get_conversations(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let conversations = state
+        .worksite_service
+        .get_conversations(GetConversationsInput {
+            worksite_id: worksite_id.clone(),
+            worker_id: worker_id.clone(),
+        })
+        .await
+        .expect("Failed to get conversations");
+
+    html! {
+        <section>
+            <form>
+                <Card>
+                    <CardContent padded=true>
+                        <SecondaryHeader
+                            id="worker-conversations-heading"
+                            title="💬 Conversations"
+                            subtitle="Every contact with this worker, rated or not."
+                        />
+                        <section class="mt-4 divide-y divide-gray-200 border-b border-t border-gray-200">
+                            <ConversationList
+                                worksite_id=worksite_id.clone()
+                                worker_id=worker_id.clone()
+                                conversations=conversations
+                            />
+                        </section>
+                        <section class="mt-4">
+                            <h3 class="text-md mb-2 font-medium leading-6 text-gray-900">"Log a conversation"</h3>
+                            <ConversationFormFields />
+                        </section>
+                    </CardContent>
+                    <CardFooter>
+                        <PrimaryButton
+                            hx_post=routes::conversations(&worksite_id, &worker_id)
+                        >
+                            Log Conversation
+                        </PrimaryButton>
+                    </CardFooter>
+                </Card>
+            </form>
+        </section>
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct ConversationFormData {
+    held_on: String,
+    organizer: String,
+    channel: String,
+    // Comma separated
+    issues: String,
+    notes: String,
+    next_step: String,
+}
+
+impl Default for ConversationFormData {
+    fn default() -> Self {
+        Self {
+            held_on: Utc::now().date_naive().to_string(),
+            organizer: "".into(),
+            channel: ConversationChannel::InPerson.to_string(),
+            issues: "".into(),
+            notes: "".into(),
+            next_step: "".into(),
+        }
+    }
+}
+
+impl ConversationFormData {
+    fn from_conversation(conversation: Conversation) -> Self {
+        Self {
+            held_on: conversation.held_on.to_string(),
+            organizer: conversation.organizer,
+            channel: conversation.channel.to_string(),
+            issues: conversation.issues.join(", "),
+            notes: conversation.notes,
+            next_step: conversation.next_step,
+        }
+    }
+
+    // Browsers without a date picker may send anything, so fall back to today
+    fn held_on(&self) -> NaiveDate {
+        NaiveDate::parse_from_str(&self.held_on, "%Y-%m-%d").unwrap_or(Utc::now().date_naive())
+    }
+
+    fn channel(&self) -> ConversationChannel {
+        ConversationChannel::new(&self.channel).unwrap_or(ConversationChannel::InPerson)
+    }
+
+    fn issues(&self) -> Vec<String> {
+        self.issues
+            .split(',')
+            .map(|issue| issue.trim())
+            .filter(|issue| !issue.is_empty())
+            .map(|issue| issue.to_string())
+            .collect()
+    }
+}
+
+async fn post_conversations(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<ConversationFormData>,
+) -> impl IntoResponse {
+    worksite_service
+        .add_conversation(AddConversationInput {
+            worksite_id,
+            worker_id,
+            held_on: form.held_on(),
+            organizer: form.organizer.clone(),
+            channel: form.channel(),
+            issues: form.issues(),
+            notes: form.notes,
+            next_step: form.next_step,
+        })
+        .await
+        .expect("Failed to add conversation");
+
+    (
+        StatusCode::OK,
+        flash.success("Conversation logged successfully!"),
+        [
+            ("hx-redirect", routes::wallchart()),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
+
+async fn get_conversation_form(
+    extract::Path((worksite_id, worker_id, conversation_id)): extract::Path<(
+        String,
+        String,
+        String,
+    )>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let conversation = worksite_service
+        .get_conversation(GetConversationInput {
+            worksite_id: worksite_id.clone(),
+            worker_id: worker_id.clone(),
+            conversation_id: conversation_id.clone(),
+        })
+        .await
+        .expect("Failed to get conversation")
+        .ok_or("Conversation not found")
+        .expect("Conversation not found");
+
+    html! {
+        <Modal>
+            <SecondaryHeader
+                title="💬 Update Conversation"
+                subtitle="Enter new values below."
+            />
+            <div class="mt-4">
+                <form hx-put=routes::conversation(&worksite_id, &worker_id, &conversation_id)>
+                    <ConversationFormFields
+                        form_data=ConversationFormData::from_conversation(conversation)
+                    />
+                    <GridLayout>
+                        <GridCell span=6>
+                            <div class="mt-6 flex items-center justify-end gap-x-6">
+                                <Button
+                                    onclick="history.go(-1)"
+                                    attrs=Attrs::with("data-toggle-action", "close".into())
+                                >
+                                    Cancel
+                                </Button>
+                                <Button kind="submit">Save</Button>
+                            </div>
+                        </GridCell>
+                    </GridLayout>
+                </form>
+            </div>
+        </Modal>
+    }
+}
+
+async fn put_conversation(
+    extract::Path((worksite_id, worker_id, conversation_id)): extract::Path<(
+        String,
+        String,
+        String,
+    )>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<ConversationFormData>,
+) -> impl IntoResponse {
+    worksite_service
+        .update_conversation(UpdateConversationInput {
+            worksite_id,
+            worker_id,
+            conversation_id,
+            held_on: form.held_on(),
+            organizer: form.organizer.clone(),
+            channel: form.channel(),
+            issues: form.issues(),
+            notes: form.notes,
+            next_step: form.next_step,
+        })
+        .await
+        .expect("Failed to update conversation");
+
+    (
+        StatusCode::OK,
+        flash.success("Conversation updated successfully!"),
+        [
+            ("hx-redirect", routes::wallchart()),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
+
+async fn delete_conversation(
+    extract::Path((worksite_id, worker_id, conversation_id)): extract::Path<(
+        String,
+        String,
+        String,
+    )>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+) -> impl IntoResponse {
+    worksite_service
+        .remove_conversation(RemoveConversationInput {
+            worksite_id,
+            worker_id,
+            conversation_id,
+        })
+        .await
+        .expect("Failed to remove conversation");
+
+    (
+        StatusCode::OK,
+        flash.success("Conversation removed successfully!"),
+        [
+            ("hx-redirect", routes::wallchart()),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
+
+#[props]
+struct ConversationListProps {
+    worksite_id: String,
+    worker_id: String,
+    conversations: Vec<Conversation>,
+}
+
+#[component]
+fn ConversationList(props: ConversationListProps) -> String {
+    let worksite_id = &props.worksite_id;
+    let worker_id = &props.worker_id;
+
+    if props.conversations.is_empty() {
+        return html! {
+            <p class="py-5 text-sm text-gray-500">"No conversations logged yet."</p>
+        };
+    }
+
+    html! {
+        <ul role="list" class="divide-y divide-gray-100">
+            {
+                props.conversations.iter().map(|conversation| async move { html! {
+                    <li class="flex items-start justify-between gap-x-6 py-5">
+                        <div class="min-w-0">
+                            <div class="flex items-start gap-x-3">
+                                <p class="text-sm font-semibold leading-6 text-gray-900">
+                                    <time datetime=conversation.held_on.to_string()>
+                                        {conversation.held_on.format("%b %e, %Y").to_string()}
+                                    </time>
+                                </p>
+                                <p class="rounded-md whitespace-nowrap mt-0.5 px-1.5 py-0.5 text-xs font-medium ring-1 ring-inset text-gray-600 bg-gray-50 ring-gray-500/10">
+                                    {conversation.channel.label()}
+                                </p>
+                            </div>
+                            <div class="mt-1 flex items-center gap-x-2 text-xs leading-5 text-gray-500">
+                                <p class="truncate">{format!("Talked with {}", &conversation.organizer)}</p>
+                            </div>
+                            {
+                                match conversation.issues.is_empty() {
+                                    true => "".into(),
+                                    false => html! {
+                                        <div class="mt-2 flex flex-wrap gap-1">
+                                            {
+                                                conversation.issues.iter().map(|issue| html! {
+                                                    <span class="rounded-md px-1.5 py-0.5 text-xs font-medium text-indigo-700 bg-indigo-50">
+                                                        {issue}
+                                                    </span>
+                                                }).collect::<String>()
+                                            }
+                                        </div>
+                                    },
+                                }
+                            }
+                            <div class="mt-5 flex flex-col gap-x-3 text-xs ">
+                                <p class="font-semibold leading-6 text-gray-900">Notes</p>
+                                {
+                                    match conversation.notes.is_empty() {
+                                        true => html! { <p class="text-gray-500">No notes.</p> },
+                                        false => html! { <p>{&conversation.notes}</p> },
+                                    }
+                                }
+                            </div>
+                            {
+                                match conversation.next_step.is_empty() {
+                                    true => "".into(),
+                                    false => html! {
+                                        <div class="mt-3 flex flex-col gap-x-3 text-xs ">
+                                            <p class="font-semibold leading-6 text-gray-900">Next step</p>
+                                            <p>{&conversation.next_step}</p>
+                                        </div>
+                                    },
+                                }
+                            }
+                        </div>
+                        <div class="flex flex-none items-center gap-x-4">
+                            <ConversationMenuButton
+                                route=routes::conversation(worksite_id, worker_id, &conversation.id)
+                            />
+                        </div>
+                    </li>
+                }})
+                .collect_fragment_async()
+                .await
+            }
+        </ul>
+    }
+}
+
+#[props]
+struct ConversationMenuButtonProps {
+    route: String,
+}
+
+#[component]
+fn ConversationMenuButton(props: ConversationMenuButtonProps) -> String {
+    html! {
+        <PopupMenu
+            id="conversation-menu"
+            class="flex-none"
+            size=MenuSize::Small
+            button_class="-m-2.5 block p-2.5 text-gray-500 hover:text-gray-900"
+            button_content=html! {
+                <svg class="h-5 w-5" viewBox="0 0 20 20" fill="currentColor" aria-hidden="true">
+                    <path d="M10 3a1.5 1.5 0 110 3 1.5 1.5 0 010-3zM10 8.5a1.5 1.5 0 110 3 1.5 1.5 0 010-3zM11.5 15.5a1.5 1.5 0 10-3 0 1.5 1.5 0 003 0z" />
+                </svg>
+            }
+        >
+            <MenuItem
+                title="Edit"
+                sr_suffix=", Conversation"
+                hx_get=props.route.clone()
+                hx_target=modal_target()
+                hx_swap="beforeend"
+            />
+            <PermissionRequired
+                permission=UserPermission::DeleteConversation
+            >
+                <MenuItem
+                    title="Remove"
+                    sr_suffix=", Conversation"
+                    hx_delete=props.route.clone()
+                    hx_target=modal_target()
+                    hx_swap="beforeend"
+                    hx_confirm="Delete Conversation"
+                    attrs=Attrs::with(
+                        "data-confirm-message", "Are you sure you want to delete this conversation?".into()
+                    )
+                />
+            </PermissionRequired>
+        </PopupMenu>
+    }
+}
+
+#[props]
+struct ConversationFormFieldsProps {
+    #[builder(default = ConversationFormData::default())]
+    form_data: ConversationFormData,
+}
+
+#[component]
+fn ConversationFormFields(props: ConversationFormFieldsProps) -> String {
+    let channel = &props.form_data.channel;
+
+    html! {
+        <GridLayout>
+            <GridCell span=3>
+                <Label for_input="held_on">Date</Label>
+                <TextInput input_type="date" name="held_on" value=&props.form_data.held_on />
+            </GridCell>
+            <GridCell span=3>
+                <Label for_input="channel">Channel</Label>
+                <Select name="channel">
+                    {
+                        ConversationChannel::ALL
+                            .iter()
+                            .map(|option| async move {
+                                html! {
+                                    <SelectOption
+                                        value=option.to_string()
+                                        selected=&option.to_string() == channel
+                                    >
+                                        {option.label()}
+                                    </SelectOption>
+                                }
+                            })
+                            .collect_fragment_async()
+                            .await
+                    }
+                </Select>
+            </GridCell>
+            <GridCell span=6>
+                <Label for_input="organizer">Organizer</Label>
+                <TextInput name="organizer" value=&props.form_data.organizer />
+            </GridCell>
+            <GridCell span=6>
+                <Label for_input="issues">Issues raised</Label>
+                <TextInput
+                    name="issues"
+                    placeholder="Scheduling, Pay"
+                    value=&props.form_data.issues
+                />
+            </GridCell>
+            <GridCell span=6>
+                <Label for_input="notes">Notes</Label>
+                <TextInput
+                    input_type="textarea"
+                    class="block w-full rounded-md border-0 py-1.5 text-gray-900 shadow-sm ring-1 ring-inset ring-gray-300 placeholder:text-gray-400 focus:ring-2 focus:ring-inset focus:ring-indigo-600 sm:text-sm sm:leading-6"
+                    name="notes"
+                    value=&props.form_data.notes
+                />
+            </GridCell>
+            <GridCell span=6>
+                <Label for_input="next_step">Next step</Label>
+                <TextInput name="next_step" value=&props.form_data.next_step />
+            </GridCell>
+        </GridLayout>
+    }
+}
@@ -50,6 +50,9 @@ async fn get_csv_upload(State(_state): State<WebHtmxState>) -> impl IntoResponse
                                 <p>
                                     "The shift columns are optional. If a worker is present in multiple locations/worksites, including those as extra rows."
                                 </p>
+                                <p>
+                                    "The phone, job_title, department, hire_date (YYYY-MM-DD), pay_rate and employment_type (Full time, Part time or Temp) columns are optional too."
+                                </p>
                                 <p>
                                     "This upload will only produce completely new worksites, and the names of the worksites, locations, shifts, and workers will be used to decide uniqueness within a single CSV upload batch."
                                 </p>
@@ -58,10 +61,10 @@ async fn get_csv_upload(State(_state): State<WebHtmxState>) -> impl IntoResponse
                         <section>
                             <h2>"📂 See the following csv example:"</h2>
                             <pre class="my-2 p-6 whitespace-pre-line bg-gray-200 rounded">
-                                <b>"worksite,location,shift1,shift2,shift3,first_name,last_name,email"</b><br />
-                                "Dunder Miflin,Office,Day,,,Jim,Halpert,jim@dundermiflin.com
-                                Dunder Miflin,Warehouse,Day,Night,,Doug,Worker,doug@dundermiflin.com
-                                Office Max,Office,Day,,,Jay,Hackett,jay@officemax.com"
+                                <b>"worksite,location,shift1,shift2,shift3,first_name,last_name,email,phone,job_title,department,hire_date,pay_rate,employment_type"</b><br />
+                                "Dunder Miflin,Office,Day,,,Jim,Halpert,jim@dundermiflin.com,570-555-0134,Sales Representative,Sales,2005-03-24,Commission,Full time
+                                Dunder Miflin,Warehouse,Day,Night,,Doug,Worker,doug@dundermiflin.com,,Loader,Warehouse,,Grade 2,Part time
+                                Office Max,Office,Day,,,Jay,Hackett,jay@officemax.com,,,,,,"
                             </pre>
                         </section>
                         <form id="form"
@@ -0,0 +1,380 @@
+use axum::{
+    extract::{self, State},
+    response::{Html, IntoResponse},
+    routing::{delete, get},
+    Form, Router,
+};
+use axum_flash::{Flash, IncomingFlashes};
+use axum_login::permission_required;
+use futures::future::join_all;
+use http::StatusCode;
+use mongo_user_repository::MongoUserStore;
+use rscx::{component, html, props, CollectFragmentAsync};
+use serde::Deserialize;
+
+use web_client::server::{
+    button::PrimaryButton,
+    card::Card,
+    form::{GridCell, Label, Select, SelectOption, TextInput},
+    headers::SecondaryHeader,
+    modal::{modal_target, Modal},
+    notification::NotificationFlashes,
+    table::{
+        ActionLink, Confirm, DeleteActionLink, TDVariant, Table, TableData, TableDataActions,
+        TableHeading,
+    },
+};
+use worksite_service::{
+    add_custom_field::AddCustomFieldInput,
+    get_custom_field::GetCustomFieldInput,
+    get_custom_fields::GetCustomFieldsInput,
+    models::{CustomField, CustomFieldType},
+    remove_custom_field::RemoveCustomFieldInput,
+    update_custom_field::UpdateCustomFieldInput,
+};
+
+use crate::{
+    components::{
+        page::{PageHeader, PageLayout},
+        page_content::PageContent,
+        simple_form::{SimpleForm, SimpleFormData},
+    },
+    routes::{
+        self, custom_field_edit_form, custom_fields_create_form, CUSTOM_FIELD, CUSTOM_FIELDS,
+        CUSTOM_FIELDS_CREATE_FORM, CUSTOM_FIELD_EDIT_FORM,
+    },
+    state::WebHtmxState,
+};
+
+pub fn custom_fields_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(CUSTOM_FIELDS, get(get_custom_fields))
+        .route(
+            CUSTOM_FIELDS_CREATE_FORM,
+            get(get_create_form).post(post_create_form),
+        )
+        .route(
+            CUSTOM_FIELD_EDIT_FORM,
+            get(get_edit_form).post(post_edit_form),
+        )
+        .route(CUSTOM_FIELD, delete(delete_custom_field))
+        .route_layer(permission_required!(
+            MongoUserStore,
+            login_url = "/forbidden",
+            "custom_field.manage",
+        ))
+        .with_state(state)
+}
+
+async fn get_custom_fields(
+    extract::Path(worksite_id): extract::Path<String>,
+    flashes: IncomingFlashes,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let custom_fields = state
+        .worksite_service
+        .get_custom_fields(GetCustomFieldsInput {
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get custom fields");
+
+    Html(html! {
+        <PageLayout
+            header=PageHeader::Toolbar {
+                title: "Custom Fields".into(),
+                buttons: html! {
+                    <PrimaryButton
+                        hx_get=custom_fields_create_form(&worksite_id)
+                        hx_target=modal_target()
+                        hx_swap="beforeend"
+                        hx_push_url=routes::page_modal_from(custom_fields_create_form(&worksite_id))
+                    >
+                        Add Field
+                    </PrimaryButton>
+                }
+            }
+        >
+            <NotificationFlashes flashes=flashes.clone() />
+            <PageContent title="Track anything else about workers, like languages spoken">
+                <Card>
+                    <CustomFieldsTable
+                        worksite_id=worksite_id.clone()
+                        custom_fields=custom_fields
+                    />
+                </Card>
+            </PageContent>
+        </PageLayout>
+    })
+}
+
+async fn get_create_form(
+    extract::Path(worksite_id): extract::Path<String>,
+    State(_): State<WebHtmxState>,
+) -> impl IntoResponse {
+    Html(html! {
+        <PageLayout
+            header="Add Field"
+        >
+            <Modal>
+                <SecondaryHeader
+                    title="🗂️ Add Field"
+                    subtitle="Every worker in this worksite gets the new field."
+                />
+                <CustomFieldForm
+                    action=custom_fields_create_form(&worksite_id)
+                />
+            </Modal>
+        </PageLayout>
+    })
+}
+
+async fn post_create_form(
+    extract::Path(worksite_id): extract::Path<String>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<CustomFieldFormData>,
+) -> impl IntoResponse {
+    let result = worksite_service
+        .add_custom_field(AddCustomFieldInput {
+            worksite_id: worksite_id.clone(),
+            name: form.name,
+            field_type: CustomFieldType::new(&form.field_type).unwrap_or(CustomFieldType::Text),
+            options: form.options.lines().map(String::from).collect(),
+        })
+        .await;
+
+    let flash = match result {
+        Ok(_) => flash.success("Added new field!"),
+        Err(e) => flash.error(e.to_string()),
+    };
+
+    (
+        StatusCode::OK,
+        flash,
+        [
+            ("hx-redirect", routes::custom_fields(&worksite_id)),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
+
+async fn get_edit_form(
+    extract::Path((worksite_id, field_id)): extract::Path<(String, String)>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let field = worksite_service
+        .get_custom_field(GetCustomFieldInput {
+            worksite_id: worksite_id.clone(),
+            field_id: field_id.clone(),
+        })
+        .await
+        .expect("Failed to get custom field")
+        .ok_or("Custom field not found")
+        .expect("Custom field not found");
+
+    Html(html! {
+        <PageLayout
+            header="Edit Field"
+        >
+            <Modal>
+                <SecondaryHeader
+                    title="🗂️ Edit Field"
+                    subtitle="Values for options you take away are cleared."
+                />
+                <CustomFieldForm
+                    action=custom_field_edit_form(&worksite_id, &field_id)
+                    data=CustomFieldFormData {
+                        name: field.name,
+                        field_type: field.field_type.to_string(),
+                        options: field.options.join("\n"),
+                    }
+                    editing=true
+                />
+            </Modal>
+        </PageLayout>
+    })
+}
+
+async fn post_edit_form(
+    extract::Path((worksite_id, field_id)): extract::Path<(String, String)>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<CustomFieldFormData>,
+) -> impl IntoResponse {
+    let result = worksite_service
+        .update_custom_field(UpdateCustomFieldInput {
+            worksite_id: worksite_id.clone(),
+            field_id,
+            name: form.name,
+            options: form.options.lines().map(String::from).collect(),
+        })
+        .await;
+
+    let flash = match result {
+        Ok(_) => flash.success("Field updated!"),
+        Err(e) => flash.error(e.to_string()),
+    };
+
+    (
+        StatusCode::OK,
+        flash,
+        [
+            ("hx-redirect", routes::custom_fields(&worksite_id)),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
+
+async fn delete_custom_field(
+    extract::Path((worksite_id, field_id)): extract::Path<(String, String)>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+) -> impl IntoResponse {
+    worksite_service
+        .remove_custom_field(RemoveCustomFieldInput {
+            worksite_id: worksite_id.clone(),
+            field_id,
+        })
+        .await
+        .expect("Failed to remove custom field");
+
+    (
+        StatusCode::OK,
+        flash.success("Field removed!"),
+        [
+            ("hx-redirect", routes::custom_fields(&worksite_id)),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct CustomFieldFormData {
+    name: String,
+    // Not sent when editing, since the type can't change
+    #[serde(default)]
+    field_type: String,
+    // One option per line
+    #[serde(default)]
+    options: String,
+}
+
+#[props]
+struct CustomFieldFormProps {
+    action: String,
+
+    #[builder(default=CustomFieldFormData::default())]
+    data: CustomFieldFormData,
+
+    #[builder(default = false)]
+    editing: bool,
+}
+
+#[component]
+fn CustomFieldForm(props: CustomFieldFormProps) -> String {
+    let field_type = props.data.field_type.clone();
+    let submit_button_text = if props.editing { "Save" } else { "Add" };
+
+    html! {
+        <SimpleForm
+            action=props.action
+            data=SimpleFormData {
+                name: props.data.name,
+            }
+            submit_button_text=submit_button_text
+        >
+            {
+                if props.editing {
+                    String::from("")
+                } else {
+                    html! {
+                        <GridCell>
+                            <Label for_input="field_type">Type</Label>
+                            <Select name="field_type">
+                                {
+                                    CustomFieldType::ALL
+                                        .iter()
+                                        .map(|option| {
+                                            let selected = option.to_string() == field_type;
+
+                                            async move {
+                                                html! {
+                                                    <SelectOption value=option.to_string() selected=selected>
+                                                        {option.label()}
+                                                    </SelectOption>
+                                                }
+                                            }
+                                        })
+                                        .collect_fragment_async()
+                                        .await
+                                }
+                            </Select>
+                        </GridCell>
+                    }
+                }
+            }
+            <GridCell>
+                <Label for_input="options">"Options, one per line (for pick one and pick any fields)"</Label>
+                <TextInput input_type="textarea" name="options" value=props.data.options />
+            </GridCell>
+        </SimpleForm>
+    }
+}
+
+#[props]
+struct CustomFieldsTableProps {
+    worksite_id: String,
+    custom_fields: Vec<CustomField>,
+}
+
+#[component]
+fn CustomFieldsTable(props: CustomFieldsTableProps) -> String {
+    html! {
+        <Table
+            headings=vec![
+                TableHeading::title("Field"),
+                TableHeading::title("Type"),
+                TableHeading::title("Options"),
+                TableHeading::empty("Actions"),
+            ]
+            body=join_all(props.custom_fields.iter().map(|field| async { html! {
+                <TableData variant=TDVariant::First>{&field.name}</TableData>
+                <TableData>{field.field_type.label()}</TableData>
+                <TableData>{field.options.join(", ")}</TableData>
+                <TableData variant=TDVariant::Last>
+                    <TableDataActions>
+                        <ActionLink
+                            hx_get=custom_field_edit_form(&props.worksite_id, &field.id)
+                            hx_target=modal_target()
+                            hx_swap="beforeend"
+                            hx_push_url=routes::page_modal_from(custom_field_edit_form(&props.worksite_id, &field.id))
+                            sr_text=&field.name
+                        >
+                            Edit
+                        </ActionLink>
+                        <DeleteActionLink
+                            hx_delete=routes::custom_field(&props.worksite_id, &field.id)
+                            confirm=Confirm {
+                                title: "Delete Field".into(),
+                                message: format!("Are you sure you want to delete the field {}? Every worker's value for it will be cleared.", &field.name),
+                            }
+                            sr_text=&field.name
+                        >
+                            Remove
+                        </DeleteActionLink>
+                    </TableDataActions>
+                </TableData>
+            }}))
+            .await
+        />
+    }
+}
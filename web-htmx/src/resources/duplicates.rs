@@ -0,0 +1,223 @@
+use auth_service::models::UserPermission;
+use axum::{
+    extract::{self, State},
+    response::{Html, IntoResponse},
+    routing::{get, post},
+    Form, Router,
+};
+use axum_flash::{Flash, IncomingFlashes};
+use axum_login::permission_required;
+use futures::future::join_all;
+use http::StatusCode;
+use mongo_user_repository::MongoUserStore;
+use rscx::html;
+use serde::Deserialize;
+use task_service::reassign_tasks::ReassignTasksInput;
+
+use web_client::server::{
+    attrs::Attrs,
+    card::Card,
+    modal::modal_target,
+    notification::NotificationFlashes,
+    table::{ActionLink, TDVariant, Table, TableData, TableDataActions, TableHeading},
+};
+use worksite_service::{
+    get_likely_duplicates::GetLikelyDuplicatesInput, merge_workers::MergeWorkersInput,
+    models::Worker,
+};
+
+use crate::{
+    components::{
+        page::PageLayout, page_content::PageContent, permission_required::PermissionRequired,
+    },
+    routes,
+    state::WebHtmxState,
+};
+
+pub fn duplicates_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(routes::DUPLICATES, post(post_merge_workers))
+        .route_layer(permission_required!(
+            MongoUserStore,
+            login_url = "/forbidden",
+            "worker.merge",
+        ))
+        .route(routes::DUPLICATES, get(get_duplicates))
+        .with_state(state)
+}
+
+async fn get_duplicates(
+    flashes: IncomingFlashes,
+    extract::Path(worksite_id): extract::Path<String>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let duplicates = state
+        .worksite_service
+        .get_likely_duplicates(GetLikelyDuplicatesInput {
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get likely duplicates");
+
+    let worksite_id = &worksite_id;
+    let worker_cell = |worker: &Worker| {
+        let details = match (worker.email.is_empty(), worker.assessments.len()) {
+            (true, assessments) => format!("No email · {} assessments", assessments),
+            (false, assessments) => format!("{} · {} assessments", worker.email, assessments),
+        };
+
+        html! {
+            <button
+                class="font-medium text-indigo-600 hover:text-indigo-900"
+                hx-get=routes::worker(worksite_id, &worker.id)
+                hx-target=modal_target()
+                hx-swap="beforeend"
+            >
+                {worker.full_name()}
+            </button>
+            <p class="text-gray-500">{details}</p>
+        }
+    };
+    // Merges the other worker into the one being kept
+    let keep_link = |keep: &Worker, other: &Worker| {
+        (
+            format!(
+                "{{\"worker_id\": \"{}\", \"duplicate_id\": \"{}\"}}",
+                keep.id, other.id
+            ),
+            Attrs::with("hx-confirm", "Merge Workers".into()).set(
+                "data-confirm-message",
+                format!(
+                    "Are you sure you want to merge {} into {}? {} will be removed.",
+                    other.full_name(),
+                    keep.full_name(),
+                    other.full_name()
+                ),
+            ),
+            format!(", merging {} into them", other.full_name()),
+        )
+    };
+
+    let html = Html(html! {
+        <PageLayout header="Duplicates">
+            <NotificationFlashes flashes=flashes.clone() />
+            <PageContent title="Workers who look like the same person">
+                <Card>
+                    {
+                        if duplicates.is_empty() {
+                            html! {
+                                <p class="p-6 text-sm text-gray-500">"No likely duplicates."</p>
+                            }
+                        } else {
+                            html! {
+                                <Table
+                                    headings=vec![
+                                        TableHeading::title("Worker"),
+                                        TableHeading::title("Looks like"),
+                                        TableHeading::title("Why"),
+                                        TableHeading::empty("Actions"),
+                                    ]
+                                    body=join_all(duplicates.iter().map(|duplicate| async move {
+                                        let (keep_worker_vals, keep_worker_attrs, keep_worker_sr) =
+                                            keep_link(&duplicate.worker, &duplicate.duplicate);
+                                        let (keep_duplicate_vals, keep_duplicate_attrs, keep_duplicate_sr) =
+                                            keep_link(&duplicate.duplicate, &duplicate.worker);
+
+                                        html! {
+                                            <TableData variant=TDVariant::First>
+                                                {worker_cell(&duplicate.worker)}
+                                            </TableData>
+                                            <TableData>{worker_cell(&duplicate.duplicate)}</TableData>
+                                            <TableData>{duplicate.reason.label()}</TableData>
+                                            <TableData variant=TDVariant::Last>
+                                                <PermissionRequired permission=UserPermission::MergeWorkers>
+                                                    <TableDataActions>
+                                                        <ActionLink
+                                                            hx_post=routes::duplicates(worksite_id)
+                                                            hx_vals=keep_worker_vals
+                                                            attrs=keep_worker_attrs
+                                                            sr_text=keep_worker_sr
+                                                        >
+                                                            {format!("Keep {}", duplicate.worker.first_name)}
+                                                        </ActionLink>
+                                                        <ActionLink
+                                                            hx_post=routes::duplicates(worksite_id)
+                                                            hx_vals=keep_duplicate_vals
+                                                            attrs=keep_duplicate_attrs
+                                                            sr_text=keep_duplicate_sr
+                                                        >
+                                                            {format!("Keep {}", duplicate.duplicate.first_name)}
+                                                        </ActionLink>
+                                                    </TableDataActions>
+                                                </PermissionRequired>
+                                            </TableData>
+                                        }
+                                    }))
+                                    .await
+                                />
+                            }
+                        }
+                    }
+                </Card>
+                <p class="mt-4 text-sm text-gray-500">
+                    "Merging keeps the assessments, conversations, tags and shifts of both, and fills in anything the kept worker is missing."
+                </p>
+            </PageContent>
+        </PageLayout>
+    });
+
+    (flashes, html)
+}
+
+#[derive(Deserialize, Debug)]
+struct MergeWorkersFormData {
+    worker_id: String,
+    duplicate_id: String,
+}
+
+async fn post_merge_workers(
+    extract::Path(worksite_id): extract::Path<String>,
+    State(WebHtmxState {
+        worksite_service,
+        task_service,
+        ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<MergeWorkersFormData>,
+) -> impl IntoResponse {
+    let result = worksite_service
+        .merge_workers(MergeWorkersInput {
+            worksite_id: worksite_id.clone(),
+            worker_id: form.worker_id.clone(),
+            duplicate_id: form.duplicate_id.clone(),
+        })
+        .await;
+
+    let flash = match result {
+        Ok(worker) => {
+            // The duplicate is gone, so its tasks follow it to the kept worker
+            let reassigned = task_service
+                .reassign_tasks(ReassignTasksInput {
+                    worksite_id: worksite_id.clone(),
+                    from_worker_id: form.duplicate_id,
+                    to_worker_id: form.worker_id,
+                })
+                .await;
+
+            match reassigned {
+                Ok(_) => flash.success(format!("Merged into {}!", worker.full_name())),
+                Err(e) => flash.error(e.to_string()),
+            }
+        }
+        Err(e) => flash.error(e.to_string()),
+    };
+
+    (
+        StatusCode::OK,
+        flash,
+        [
+            ("hx-redirect", routes::duplicates(&worksite_id)),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
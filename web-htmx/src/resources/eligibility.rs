@@ -0,0 +1,139 @@
+use axum::{
+    extract::{self, State},
+    response::IntoResponse,
+    routing::get,
+    Form, Router,
+};
+use axum_flash::Flash;
+use http::StatusCode;
+use rscx::{html, CollectFragmentAsync};
+use serde::Deserialize;
+
+use web_client::server::{
+    button::PrimaryButton,
+    card::{Card, CardContent, CardFooter},
+    form::{GridCell, GridLayout, Label, Select, SelectOption},
+    headers::SecondaryHeader,
+};
+use worksite_service::{
+    get_worker::GetWorkerInput,
+    models::{Eligibility, ExclusionReason},
+    set_eligibility::SetEligibilityInput,
+};
+
+use crate::{routes, state::WebHtmxState};
+
+pub fn eligibility_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(
+            routes::WORKER_ELIGIBILITY,
+            get(get_worker_eligibility).put(put_worker_eligibility),
+        )
+        .with_state(state)
+}
+
+async fn get_worker_eligibility(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let worker = state
+        .worksite_service
+        .get_worker(GetWorkerInput {
+            id: worker_id.clone(),
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get worker")
+        .ok_or("Worker not found")
+        .expect("Worker not found");
+
+    let exclusion_reason = worker.eligibility.exclusion_reason();
+
+    html! {
+        <section aria-labelledby="worker-eligibility-heading">
+            <form>
+                <Card>
+                    <CardContent padded=true>
+                        <SecondaryHeader
+                            id="worker-eligibility-heading"
+                            title="⚖️ Bargaining Unit"
+                            subtitle="Excluded workers stay on the wallchart but aren't counted."
+                        />
+                        <GridLayout>
+                            <GridCell span=4>
+                                <Label for_input="exclusion_reason">Eligibility</Label>
+                                <Select name="exclusion_reason">
+                                    <SelectOption value="" selected=exclusion_reason.is_none()>
+                                        In the unit
+                                    </SelectOption>
+                                    {
+                                        ExclusionReason::ALL
+                                            .iter()
+                                            .map(|reason| async move {
+                                                html! {
+                                                    <SelectOption
+                                                        value=reason.to_string()
+                                                        selected=exclusion_reason == Some(*reason)
+                                                    >
+                                                        {format!("Excluded: {}", reason.label())}
+                                                    </SelectOption>
+                                                }
+                                            })
+                                            .collect_fragment_async()
+                                            .await
+                                    }
+                                </Select>
+                            </GridCell>
+                        </GridLayout>
+                    </CardContent>
+                    <CardFooter>
+                        <PrimaryButton hx_put=routes::worker_eligibility(&worksite_id, &worker_id)>
+                            Save
+                        </PrimaryButton>
+                    </CardFooter>
+                </Card>
+            </form>
+        </section>
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct WorkerEligibilityFormData {
+    exclusion_reason: String,
+}
+
+async fn put_worker_eligibility(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<WorkerEligibilityFormData>,
+) -> impl IntoResponse {
+    let eligibility = match ExclusionReason::new(&form.exclusion_reason) {
+        Some(reason) => Eligibility::Excluded(reason),
+        None => Eligibility::Eligible,
+    };
+
+    let result = worksite_service
+        .set_eligibility(SetEligibilityInput {
+            worksite_id,
+            worker_id,
+            eligibility,
+        })
+        .await;
+
+    let flash = match result {
+        Ok(_) => flash.success("Eligibility updated successfully!"),
+        Err(e) => flash.error(e.to_string()),
+    };
+
+    (
+        StatusCode::OK,
+        flash,
+        [
+            ("hx-redirect", routes::wallchart()),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
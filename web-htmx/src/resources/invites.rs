@@ -0,0 +1,311 @@
+use axum::{
+    extract::{self, State},
+    response::{Html, IntoResponse},
+    routing::{delete, get, post},
+    Form, Router,
+};
+use axum_flash::Flash;
+use axum_login::permission_required;
+use http::StatusCode;
+use rscx::{component, html, props};
+use serde::Deserialize;
+
+use auth_service::{
+    accept_invite::{AcceptInviteFailure, AcceptInviteInput},
+    get_invite::GetInviteInput,
+    invite_user::{InviteUserFailure, InviteUserInput},
+    revoke_invite::RevokeInviteInput,
+};
+use mongo_user_repository::MongoUserStore;
+use web_client::server::{
+    alert::Alert,
+    attrs::Attrs,
+    form::{Button, GridCell, GridLayout, Label, Select, SelectOption, TextInput},
+    headers::SecondaryHeader,
+    modal::{Modal, ModalSize},
+};
+
+use crate::{components::page::PageLayout, routes, state::WebHtmxState};
+
+pub fn invites_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(routes::INVITES, post(post_invite_form))
+        .route(routes::INVITES_CREATE_FORM, get(get_invite_form))
+        .route(routes::INVITE, delete(delete_invite))
+        .with_state(state)
+        .route_layer(permission_required!(
+            MongoUserStore,
+            login_url = "/forbidden",
+            "user.create",
+            "user.read",
+            "user.update",
+            "user.delete",
+        ))
+}
+
+// These routes are public, the invitee does not have an account yet
+pub fn accept_invite_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(
+            routes::ACCEPT_INVITE,
+            get(get_accept_invite).post(post_accept_invite),
+        )
+        .with_state(state)
+}
+
+async fn get_invite_form() -> impl IntoResponse {
+    Html(html! {
+        <PageLayout
+            header="Invite User"
+        >
+            <Modal size=ModalSize::MediumScreen>
+                <SecondaryHeader
+                    title="✉️ Invite User"
+                    subtitle="Enter the email and role of the person you are inviting. They will choose their own password."
+                />
+                <InviteForm action=routes::invites() />
+            </Modal>
+        </PageLayout>
+    })
+}
+
+#[props]
+struct InviteFormProps {
+    #[builder(setter(into))]
+    action: String,
+
+    #[builder(setter(into), default)]
+    error: String,
+}
+
+#[component]
+fn InviteForm(props: InviteFormProps) -> String {
+    html! {
+        <form
+            hx-ext="response-targets"
+            hx-post=props.action
+            hx-target-4xx="this"
+            hx-swap="outerHTML"
+        >
+            {
+                if props.error.is_empty() {
+                    "".into()
+                } else {
+                    html! { <Alert class="mb-4" title=props.error /> }
+                }
+            }
+            <div class="pb-12">
+                <GridLayout class="mt-10">
+                    <GridCell span=3>
+                        <Label for_input="email">Email</Label>
+                        <TextInput name="email" autocomplete="email" input_type="email" />
+                    </GridCell>
+                    <GridCell span=3>
+                        <Label for_input="role">Role</Label>
+                        <Select name="role">
+                            <SelectOption>Organizer</SelectOption>
+                            <SelectOption>Admin</SelectOption>
+                            <SelectOption>SuperAdmin</SelectOption>
+                        </Select>
+                    </GridCell>
+                </GridLayout>
+            </div>
+            <div class="mt-6 flex items-center justify-end gap-x-6">
+                <Button
+                    onclick="history.go(-1)"
+                    attrs=Attrs::with("data-toggle-action", "close".into())
+                >
+                    Cancel
+                </Button>
+                <Button kind="submit">Send Invite</Button>
+            </div>
+        </form>
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct InviteFormData {
+    email: String,
+    role: String,
+}
+
+async fn post_invite_form(
+    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<InviteFormData>,
+) -> impl IntoResponse {
+    let result = auth_service
+        .invite_user(InviteUserInput {
+            email: form.email,
+            role: form.role,
+        })
+        .await;
+
+    match result {
+        Ok(_) => (
+            StatusCode::OK,
+            flash.success("Invite created! Share the accept link with the new user."),
+            [
+                ("hx-redirect", routes::users()),
+                ("hx-retarget", "body".into()),
+            ],
+        )
+            .into_response(),
+        Err(InviteUserFailure::UserAlreadyExists(email)) => (
+            StatusCode::BAD_REQUEST,
+            Html(html! {
+                <InviteForm
+                    action=routes::invites()
+                    error=format!("A user with the email {} already exists.", email)
+                />
+            }),
+        )
+            .into_response(),
+        Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Error inviting user").into_response(),
+    }
+}
+
+async fn delete_invite(
+    extract::Path(user_id): extract::Path<String>,
+    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let result = auth_service
+        .revoke_invite(RevokeInviteInput { user_id })
+        .await;
+
+    match result {
+        Ok(_) => "".into_response(),
+        Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Error revoking invite").into_response(),
+    }
+}
+
+async fn get_accept_invite(
+    extract::Path(invite_token): extract::Path<String>,
+    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let invite = auth_service
+        .get_invite(GetInviteInput {
+            invite_token: invite_token.clone(),
+        })
+        .await;
+
+    match invite {
+        Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Error loading invite").into_response(),
+        Ok(Some(user)) => Html(html! {
+            <PageLayout header="Accept Invite">
+                <p class="mt-1 text-sm leading-6 text-gray-600">
+                    {format!("Welcome! Choose a password for {} to finish setting up your account.", user.email)}
+                </p>
+                <AcceptInviteForm action=routes::accept_invite(&invite_token) />
+            </PageLayout>
+        })
+        .into_response(),
+        Ok(None) => (
+            StatusCode::NOT_FOUND,
+            Html(html! {
+                <PageLayout header="Accept Invite">
+                    <Alert
+                        title="This invite is no longer valid."
+                    >
+                        It may have already been accepted or revoked. Ask your admin for a new invite.
+                    </Alert>
+                </PageLayout>
+            }),
+        )
+            .into_response(),
+    }
+}
+
+#[props]
+struct AcceptInviteFormProps {
+    #[builder(setter(into))]
+    action: String,
+
+    #[builder(setter(into), default)]
+    error: String,
+}
+
+#[component]
+fn AcceptInviteForm(props: AcceptInviteFormProps) -> String {
+    html! {
+        <form
+            hx-ext="response-targets"
+            hx-post=props.action
+            hx-target-4xx="this"
+            hx-swap="outerHTML"
+        >
+            {
+                if props.error.is_empty() {
+                    "".into()
+                } else {
+                    html! { <Alert class="mb-4" title=props.error /> }
+                }
+            }
+            <div class="pb-12">
+                <GridLayout class="mt-10">
+                    <GridCell span=4>
+                        <Label for_input="password">Password</Label>
+                        <TextInput input_type="password" name="password" autocomplete="new-password" />
+                    </GridCell>
+                    <GridCell span=4>
+                        <div class="mt-6 flex items-center justify-end gap-x-6">
+                            <Button kind="submit">Set Password</Button>
+                        </div>
+                    </GridCell>
+                </GridLayout>
+            </div>
+        </form>
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct AcceptInviteFormData {
+    password: String,
+}
+
+async fn post_accept_invite(
+    extract::Path(invite_token): extract::Path<String>,
+    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<AcceptInviteFormData>,
+) -> impl IntoResponse {
+    let result = auth_service
+        .accept_invite(AcceptInviteInput {
+            invite_token: invite_token.clone(),
+            password: form.password,
+        })
+        .await;
+
+    match result {
+        Ok(_) => (
+            StatusCode::OK,
+            flash.success("Your account is ready! Log in with your new password."),
+            [
+                ("hx-redirect", routes::login()),
+                ("hx-retarget", "body".into()),
+            ],
+        )
+            .into_response(),
+        Err(AcceptInviteFailure::EmptyPassword) => (
+            StatusCode::BAD_REQUEST,
+            Html(html! {
+                <AcceptInviteForm
+                    action=routes::accept_invite(&invite_token)
+                    error="Please choose a password."
+                />
+            }),
+        )
+            .into_response(),
+        Err(AcceptInviteFailure::NotFound) => (
+            StatusCode::NOT_FOUND,
+            Html(html! {
+                <AcceptInviteForm
+                    action=routes::accept_invite(&invite_token)
+                    error="This invite is no longer valid."
+                />
+            }),
+        )
+            .into_response(),
+        Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Error accepting invite").into_response(),
+    }
+}
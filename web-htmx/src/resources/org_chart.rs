@@ -0,0 +1,183 @@
+use axum::{
+    extract::{self, State},
+    response::{Html, IntoResponse},
+    routing::get,
+    Router,
+};
+use rscx::{html, CollectFragment};
+
+use web_client::server::{card::Card, modal::modal_target};
+use worksite_service::{
+    get_org_chart::GetOrgChartInput, get_worksite::GetWorksiteInput, models::OrgChartNode,
+};
+
+use crate::{
+    components::{page::PageLayout, page_content::PageContent},
+    routes,
+    state::WebHtmxState,
+};
+
+pub fn org_chart_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(routes::LOCATION_ORG_CHART, get(get_org_chart))
+        .with_state(state)
+}
+
+async fn get_org_chart(
+    extract::Path((worksite_id, location_id)): extract::Path<(String, String)>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let worksite = state
+        .worksite_service
+        .get_worksite(GetWorksiteInput {
+            id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get worksite")
+        .ok_or("Worksite not found")
+        .expect("Worksite not found");
+
+    let location_name = worksite
+        .locations
+        .iter()
+        .find(|location| location.id == location_id)
+        .map(|location| location.name.clone())
+        .unwrap_or_default();
+
+    let nodes = state
+        .worksite_service
+        .get_org_chart(GetOrgChartInput {
+            worksite_id: worksite_id.clone(),
+            location_id,
+        })
+        .await
+        .expect("Failed to get org chart");
+
+    Html(html! {
+        <PageLayout header=format!("Org Chart: {}", location_name)>
+            <PageContent title="Who answers to whom, with how support looks under each supervisor">
+                <Card padded=true>
+                    {
+                        if nodes.is_empty() {
+                            html! {
+                                <p class="text-sm text-gray-500">Nobody works at this location yet.</p>
+                            }
+                        } else {
+                            org_chart_list(&worksite_id, &nodes)
+                        }
+                    }
+                </Card>
+            </PageContent>
+        </PageLayout>
+    })
+}
+
+// Plain html only, so the chart can be built recursively without async components
+fn org_chart_list(worksite_id: &String, nodes: &[OrgChartNode]) -> String {
+    html! {
+        <ul role="list" class="space-y-2">
+            {
+                nodes
+                    .iter()
+                    .map(|node| org_chart_item(worksite_id, node))
+                    .collect_fragment()
+            }
+        </ul>
+    }
+}
+
+fn org_chart_item(worksite_id: &String, node: &OrgChartNode) -> String {
+    let worker = &node.worker;
+
+    html! {
+        <li>
+            <div class="inline-flex items-center gap-2 rounded-md bg-white px-3 py-2 ring-1 ring-inset ring-gray-200">
+                <button
+                    hx-get=routes::worker(worksite_id, &worker.id)
+                    hx-target=modal_target()
+                    hx-swap="beforeend"
+                    class="text-sm font-medium text-gray-900"
+                >
+                    {worker.full_name()}
+                </button>
+                <span class="text-sm text-gray-500">{&worker.employment.job_title}</span>
+                {
+                    match worker.eligibility.exclusion_reason() {
+                        Some(reason) => html! {
+                            <span
+                                title="Not in the bargaining unit"
+                                class="rounded-md px-1.5 py-0.5 text-xs font-medium text-gray-600 bg-gray-100 ring-1 ring-inset ring-gray-500/10"
+                            >
+                                {format!("Excluded: {}", reason.label())}
+                            </span>
+                        },
+                        None => String::from(""),
+                    }
+                }
+                {
+                    match worker.last_assessment() {
+                        Some(assessment) => html! {
+                            <span class="text-xs text-gray-500">
+                                {format!("Assessment: {}", assessment.value)}
+                            </span>
+                        },
+                        None => String::from(""),
+                    }
+                }
+            </div>
+            {
+                if node.reports.is_empty() {
+                    String::from("")
+                } else {
+                    html! {
+                        <p class="mt-1 ml-3 text-xs text-gray-500">
+                            {support_summary(node)}
+                            " · "
+                            <a
+                                href=routes::supervisor_workers(worksite_id, &worker.id)
+                                class="text-indigo-600 hover:text-indigo-900"
+                            >
+                                See workers
+                            </a>
+                        </p>
+                        <div class="mt-2 ml-3 border-l border-gray-200 pl-4">
+                            {org_chart_list(worksite_id, &node.reports)}
+                        </div>
+                    }
+                }
+            }
+        </li>
+    }
+}
+
+// e.g. "8 in the unit below, 5 signed cards, average assessment 2.4"
+fn support_summary(node: &OrgChartNode) -> String {
+    let eligible = node
+        .all_reports()
+        .into_iter()
+        .filter(|worker| worker.is_eligible())
+        .collect::<Vec<_>>();
+    let signed = eligible
+        .iter()
+        .filter(|worker| worker.has_signed_card())
+        .count();
+    let assessments = eligible
+        .iter()
+        .filter_map(|worker| worker.last_assessment())
+        .map(|assessment| assessment.value as f32)
+        .collect::<Vec<f32>>();
+
+    let summary = format!(
+        "{} in the unit below, {} signed cards",
+        eligible.len(),
+        signed
+    );
+    match assessments.len() {
+        0 => summary,
+        count => format!(
+            "{}, average assessment {:.1}",
+            summary,
+            assessments.iter().sum::<f32>() / count as f32
+        ),
+    }
+}
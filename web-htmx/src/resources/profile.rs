@@ -0,0 +1,335 @@
+use axum::{
+    extract::State,
+    response::{Html, IntoResponse},
+    routing::{get, post},
+    Form, Router,
+};
+use axum_flash::Flash;
+use http::StatusCode;
+use rscx::{component, html, props, CollectFragmentAsync};
+use serde::Deserialize;
+
+use auth_service::{
+    change_password::{ChangePasswordFailure, ChangePasswordInput},
+    get_api_tokens::GetApiTokensInput,
+    get_two_factor_status::GetTwoFactorStatusInput,
+    get_user::GetUserInput,
+    models::TwoFactorStatus,
+    update_profile::UpdateProfileInput,
+};
+use web_client::server::{
+    alert::Alert,
+    card::Card,
+    form::{Button, GridCell, GridLayout, Label, Select, SelectOption, TextInput},
+    headers::SecondaryHeader,
+};
+use worksite_service::models::Worksite;
+
+use crate::{
+    components::{page::PageLayout, page_content::PageContent},
+    resources::{api_tokens::ApiTokensSection, two_factor::DisableTwoFactorForm},
+    routes,
+    state::WebHtmxState,
+};
+
+pub fn profile_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(routes::PROFILE, get(get_profile).post(post_profile))
+        .route(routes::PROFILE_PASSWORD, post(post_password))
+        .with_state(state)
+}
+
+async fn get_profile(
+    State(WebHtmxState {
+        auth_service,
+        worksite_service,
+        ..
+    }): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let ctx: crate::context::Context =
+        crate::context::context().expect("Unable to retrieve htmx context.");
+    let current_user = ctx.current_user.expect("Profile requires a logged in user");
+
+    let user = auth_service
+        .get_user(GetUserInput {
+            user_id: current_user.id,
+        })
+        .await
+        .expect("Failed to get user")
+        .ok_or("User not found")
+        .expect("User not found");
+
+    let two_factor_status = auth_service
+        .get_two_factor_status(GetTwoFactorStatusInput {
+            user_id: user.id.clone(),
+        })
+        .await
+        .expect("Failed to get two-factor status");
+
+    let api_tokens = auth_service
+        .get_api_tokens(GetApiTokensInput {
+            user_id: user.id.clone(),
+        })
+        .await
+        .expect("Failed to get api tokens");
+
+    let worksites = worksite_service
+        .get_worksites()
+        .await
+        .expect("Failed to get worksites");
+
+    Html(html! {
+        <PageLayout header="Your Profile">
+            <PageContent>
+                <Card>
+                    <div class="p-6">
+                        <SecondaryHeader
+                            title="👤 Profile"
+                            subtitle=format!("Signed in as {}", user.email)
+                        />
+                        <ProfileForm
+                            display_name=user.display_name.unwrap_or_default()
+                            default_worksite_id=user.default_worksite_id.unwrap_or_default()
+                            worksites=worksites
+                        />
+                    </div>
+                </Card>
+            </PageContent>
+            <PageContent>
+                <Card>
+                    <div class="p-6">
+                        <SecondaryHeader
+                            title="🔑 Change Password"
+                            subtitle="Enter your current password to choose a new one."
+                        />
+                        <PasswordForm />
+                    </div>
+                </Card>
+            </PageContent>
+            <PageContent>
+                <Card>
+                    <div class="p-6">
+                        <SecondaryHeader
+                            title="📱 Two-Factor Authentication"
+                            subtitle="Require a code from your authenticator app when you log in."
+                        />
+                        {
+                            match two_factor_status {
+                                TwoFactorStatus::Enabled => html! { <DisableTwoFactorForm /> },
+                                TwoFactorStatus::Disabled | TwoFactorStatus::Required => html! {
+                                    <div class="mt-6 flex items-center justify-end gap-x-6">
+                                        <a
+                                            href=routes::profile_two_factor()
+                                            class="rounded-md bg-indigo-600 px-3 py-2 text-sm font-semibold text-white shadow-sm hover:bg-indigo-500"
+                                        >
+                                            Set Up Two-Factor
+                                        </a>
+                                    </div>
+                                },
+                            }
+                        }
+                    </div>
+                </Card>
+            </PageContent>
+            <PageContent>
+                <Card>
+                    <div class="p-6">
+                        <SecondaryHeader
+                            title="🔌 API Tokens"
+                            subtitle="Tokens let scripts and other tools use the api as you. Revoke any you no longer use."
+                        />
+                        <ApiTokensSection api_tokens=api_tokens />
+                    </div>
+                </Card>
+            </PageContent>
+        </PageLayout>
+    })
+}
+
+#[props]
+struct ProfileFormProps {
+    #[builder(setter(into))]
+    display_name: String,
+
+    #[builder(setter(into))]
+    default_worksite_id: String,
+
+    worksites: Vec<Worksite>,
+}
+
+#[component]
+fn ProfileForm(props: ProfileFormProps) -> String {
+    html! {
+        <form hx-post=routes::profile()>
+            <div class="pb-6">
+                <GridLayout class="mt-6">
+                    <GridCell span=3>
+                        <Label for_input="display_name">Display Name</Label>
+                        <TextInput name="display_name" autocomplete="name" value=props.display_name />
+                    </GridCell>
+                    <GridCell span=3>
+                        <Label for_input="default_worksite_id">Default Worksite</Label>
+                        <Select name="default_worksite_id">
+                            <SelectOption value="" selected=props.default_worksite_id.is_empty()>
+                                None
+                            </SelectOption>
+                            {
+                                props
+                                    .worksites
+                                    .iter()
+                                    .map(|w| async {
+                                        html! {
+                                            <SelectOption
+                                                value=w.id.clone()
+                                                selected=w.id == props.default_worksite_id
+                                            >
+                                                {w.name.clone()}
+                                            </SelectOption>
+                                        }
+                                    })
+                                    .collect_fragment_async()
+                                    .await
+                            }
+                        </Select>
+                    </GridCell>
+                </GridLayout>
+            </div>
+            <div class="flex items-center justify-end gap-x-6">
+                <Button kind="submit">Save</Button>
+            </div>
+        </form>
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct ProfileFormData {
+    display_name: String,
+    default_worksite_id: String,
+}
+
+async fn post_profile(
+    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<ProfileFormData>,
+) -> impl IntoResponse {
+    let ctx: crate::context::Context =
+        crate::context::context().expect("Unable to retrieve htmx context.");
+    let current_user = ctx.current_user.expect("Profile requires a logged in user");
+
+    auth_service
+        .update_profile(UpdateProfileInput {
+            user_id: current_user.id,
+            display_name: Some(form.display_name),
+            default_worksite_id: Some(form.default_worksite_id),
+        })
+        .await
+        .expect("Failed to update profile");
+
+    (
+        StatusCode::OK,
+        flash.success("Updated profile successfully!"),
+        [
+            ("hx-redirect", routes::profile()),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
+
+#[props]
+struct PasswordFormProps {
+    #[builder(setter(into), default)]
+    error: String,
+}
+
+#[component]
+fn PasswordForm(props: PasswordFormProps) -> String {
+    html! {
+        <form
+            hx-ext="response-targets"
+            hx-post=routes::profile_password()
+            hx-target-4xx="this"
+            hx-swap="outerHTML"
+        >
+            {
+                if props.error.is_empty() {
+                    "".into()
+                } else {
+                    html! { <Alert class="mb-4" title=props.error /> }
+                }
+            }
+            <div class="pb-6">
+                <GridLayout class="mt-6">
+                    <GridCell span=3>
+                        <Label for_input="current_password">Current Password</Label>
+                        <TextInput name="current_password" autocomplete="current-password" input_type="password" />
+                    </GridCell>
+                    <GridCell span=3>
+                        <Label for_input="new_password">New Password</Label>
+                        <TextInput name="new_password" autocomplete="new-password" input_type="password" />
+                    </GridCell>
+                    <GridCell span=3>
+                        <Label for_input="new_password_confirmation">Confirm New Password</Label>
+                        <TextInput name="new_password_confirmation" autocomplete="new-password" input_type="password" />
+                    </GridCell>
+                </GridLayout>
+            </div>
+            <div class="flex items-center justify-end gap-x-6">
+                <Button kind="submit">Change Password</Button>
+            </div>
+        </form>
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct PasswordFormData {
+    current_password: String,
+    new_password: String,
+    new_password_confirmation: String,
+}
+
+async fn post_password(
+    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<PasswordFormData>,
+) -> impl IntoResponse {
+    let ctx: crate::context::Context =
+        crate::context::context().expect("Unable to retrieve htmx context.");
+    let current_user = ctx.current_user.expect("Profile requires a logged in user");
+
+    let result = auth_service
+        .change_password(ChangePasswordInput {
+            user_id: current_user.id,
+            current_password: form.current_password,
+            new_password: form.new_password,
+            new_password_confirmation: form.new_password_confirmation,
+        })
+        .await;
+
+    let error_message = match result {
+        // Changing the password changes the session auth hash, so the user
+        // will need to log in again with the new password.
+        Ok(_) => {
+            return (
+                StatusCode::OK,
+                flash.success("Password changed! Please log in with your new password."),
+                [
+                    ("hx-redirect", routes::login()),
+                    ("hx-retarget", "body".into()),
+                ],
+            )
+                .into_response()
+        }
+        Err(ChangePasswordFailure::WrongPassword) => "Your current password is incorrect.",
+        Err(ChangePasswordFailure::EmptyPassword) => "Please choose a new password.",
+        Err(ChangePasswordFailure::PasswordMismatch) => "The new passwords do not match.",
+        Err(_) => "Something went wrong changing your password.",
+    };
+
+    (
+        StatusCode::BAD_REQUEST,
+        Html(html! {
+            <PasswordForm error=error_message />
+        }),
+    )
+        .into_response()
+}
@@ -0,0 +1,344 @@
+use axum::{
+    extract::{self, State},
+    response::IntoResponse,
+    routing::{delete, get},
+    Form, Router,
+};
+use axum_flash::Flash;
+use axum_login::permission_required;
+use http::StatusCode;
+use mongo_user_repository::MongoUserStore;
+use rscx::{html, CollectFragment, CollectFragmentAsync};
+use serde::Deserialize;
+
+use auth_service::models::UserPermission;
+use web_client::server::{
+    button::PrimaryButton,
+    card::{Card, CardContent, CardFooter},
+    form::{GridCell, GridLayout, Label, Select, SelectOption},
+    headers::SecondaryHeader,
+    modal::modal_target,
+};
+use worksite_service::{
+    add_relationship::AddRelationshipInput,
+    get_influencers::GetInfluencersInput,
+    get_worksite::GetWorksiteInput,
+    models::{RelationshipKind, Worker},
+    remove_relationship::RemoveRelationshipInput,
+};
+
+use crate::{components::permission_required::PermissionRequired, routes, state::WebHtmxState};
+
+// How many of the strongest influences to show on a worker
+const INFLUENCES_SHOWN: usize = 5;
+
+pub fn relationships_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(
+            routes::WORKER_RELATIONSHIP,
+            delete(delete_worker_relationship),
+        )
+        .route_layer(permission_required!(
+            MongoUserStore,
+            login_url = "/forbidden",
+            "relationship.remove",
+        ))
+        .route(
+            routes::WORKER_RELATIONSHIPS,
+            get(get_worker_relationships).post(post_worker_relationship),
+        )
+        .with_state(state)
+}
+
+async fn get_worker_relationships(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let worksite = state
+        .worksite_service
+        .get_worksite(GetWorksiteInput {
+            id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get worksite")
+        .ok_or("Worksite not found")
+        .expect("Worksite not found");
+
+    let influences = state
+        .worksite_service
+        .get_influencers(GetInfluencersInput {
+            worksite_id: worksite_id.clone(),
+            worker_id: worker_id.clone(),
+        })
+        .await
+        .expect("Failed to get influencers");
+
+    let worker_link = |worker: &Worker| {
+        html! {
+            <button
+                hx-get=routes::worker(&worksite_id, &worker.id)
+                hx-target=modal_target()
+                hx-swap="beforeend"
+                class="font-medium text-indigo-600 hover:text-indigo-900"
+            >
+                {worker.full_name()}
+            </button>
+        }
+    };
+
+    let relationships = worksite
+        .get_relationships_for_worker(&worker_id)
+        .into_iter()
+        .filter_map(|relationship| {
+            let other = worksite.get_worker(relationship.other_worker_id(&worker_id).clone())?;
+            Some((relationship, other))
+        })
+        .collect::<Vec<_>>();
+
+    let mut coworkers: Vec<Worker> = worksite
+        .workers
+        .iter()
+        .filter(|coworker| coworker.id != worker_id)
+        .cloned()
+        .collect();
+    coworkers.sort_by(|a, b| (&a.last_name, &a.first_name).cmp(&(&b.last_name, &b.first_name)));
+
+    html! {
+        <section aria-labelledby="worker-relationships-heading">
+            <form>
+                <Card>
+                    <CardContent padded=true>
+                        <SecondaryHeader
+                            id="worker-relationships-heading"
+                            title="🤝 Relationships"
+                            subtitle="Who this worker is close to, and who could sway them."
+                        />
+                        <ul role="list" class="mt-4 divide-y divide-gray-100">
+                            {
+                                if relationships.is_empty() {
+                                    html! {
+                                        <li class="py-2 text-sm text-gray-500">No relationships yet.</li>
+                                    }
+                                } else {
+                                    relationships
+                                        .iter()
+                                        .map(|(relationship, other)| {
+                                            // Arrows show who sways whom in a one-way relationship
+                                            let direction = match (relationship.directional, relationship.from_worker_id == worker_id) {
+                                                (false, _) => "",
+                                                (true, true) => "→ ",
+                                                (true, false) => "← ",
+                                            };
+
+                                            let remove_route = routes::worker_relationship(&worksite_id, &worker_id, &relationship.id);
+
+                                            async move {
+                                                html! {
+                                                    <li class="flex items-center justify-between py-2 text-sm">
+                                                        <span>
+                                                            <span class="text-gray-500">{direction}</span>
+                                                            {worker_link(other)}
+                                                            <span class="ml-2 text-gray-500">{relationship.kind.label()}</span>
+                                                        </span>
+                                                        <PermissionRequired permission=UserPermission::RemoveRelationship>
+                                                            <a
+                                                                hx-delete=remove_route
+                                                                hx-confirm="Remove Relationship"
+                                                                data-confirm-message=format!("Are you sure you want to remove the relationship with {}?", other.full_name())
+                                                                class="cursor-pointer text-indigo-600 hover:text-indigo-900"
+                                                            >
+                                                                Remove<span class="sr-only">, {other.full_name()}</span>
+                                                            </a>
+                                                        </PermissionRequired>
+                                                    </li>
+                                                }
+                                            }
+                                        })
+                                        .collect_fragment_async()
+                                        .await
+                                }
+                            }
+                        </ul>
+                        <GridLayout class="mt-4">
+                            <GridCell span=2>
+                                <Label for_input="other_worker_id">Coworker</Label>
+                                <Select name="other_worker_id">
+                                    {
+                                        coworkers
+                                            .iter()
+                                            .map(|coworker| async move {
+                                                html! {
+                                                    <SelectOption value=coworker.id.clone()>
+                                                        {coworker.full_name()}
+                                                    </SelectOption>
+                                                }
+                                            })
+                                            .collect_fragment_async()
+                                            .await
+                                    }
+                                </Select>
+                            </GridCell>
+                            <GridCell span=2>
+                                <Label for_input="kind">Relationship</Label>
+                                <Select name="kind">
+                                    {
+                                        RelationshipKind::ALL
+                                            .iter()
+                                            .map(|kind| async move {
+                                                html! {
+                                                    <SelectOption value=kind.to_string()>
+                                                        {kind.label()}
+                                                    </SelectOption>
+                                                }
+                                            })
+                                            .collect_fragment_async()
+                                            .await
+                                    }
+                                </Select>
+                            </GridCell>
+                            <GridCell span=2>
+                                <Label for_input="direction">Direction</Label>
+                                <Select name="direction">
+                                    <SelectOption value="both">Both ways</SelectOption>
+                                    <SelectOption value="outgoing">This worker sways them</SelectOption>
+                                    <SelectOption value="incoming">They sway this worker</SelectOption>
+                                </Select>
+                            </GridCell>
+                        </GridLayout>
+                        <h4 class="mt-6 text-sm font-semibold text-gray-900">Who influences this person</h4>
+                        <ul role="list" class="mt-2 divide-y divide-gray-100">
+                            {
+                                if influences.is_empty() {
+                                    html! {
+                                        <li class="py-2 text-sm text-gray-500">
+                                            Add relationships to see who could sway this worker.
+                                        </li>
+                                    }
+                                } else {
+                                    influences
+                                        .iter()
+                                        .take(INFLUENCES_SHOWN)
+                                        .map(|influence| {
+                                            let assessment = influence
+                                                .worker
+                                                .last_assessment()
+                                                .map(|assessment| format!("Assessment: {}", assessment.value))
+                                                .unwrap_or("Not assessed".into());
+                                            let through = match influence.through.is_empty() {
+                                                true => "Direct".to_string(),
+                                                false => format!(
+                                                    "Through {}",
+                                                    influence
+                                                        .through
+                                                        .iter()
+                                                        .map(|worker| worker.full_name())
+                                                        .collect::<Vec<String>>()
+                                                        .join(", ")
+                                                ),
+                                            };
+
+                                            html! {
+                                                <li class="flex items-center justify-between py-2 text-sm">
+                                                    {worker_link(&influence.worker)}
+                                                    <span class="text-gray-500">
+                                                        {format!("{} · {}", assessment, through)}
+                                                    </span>
+                                                </li>
+                                            }
+                                        })
+                                        .collect_fragment()
+                                }
+                            }
+                        </ul>
+                    </CardContent>
+                    <CardFooter>
+                        <PrimaryButton hx_post=routes::worker_relationships(&worksite_id, &worker_id)>
+                            Add Relationship
+                        </PrimaryButton>
+                    </CardFooter>
+                </Card>
+            </form>
+        </section>
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct WorkerRelationshipFormData {
+    // Missing when there's nobody else at the worksite yet
+    #[serde(default)]
+    other_worker_id: String,
+    kind: String,
+    // both, outgoing or incoming
+    direction: String,
+}
+
+async fn post_worker_relationship(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<WorkerRelationshipFormData>,
+) -> impl IntoResponse {
+    let (from_worker_id, to_worker_id) = match form.direction.as_str() {
+        "incoming" => (form.other_worker_id, worker_id),
+        _ => (worker_id, form.other_worker_id),
+    };
+
+    let result = worksite_service
+        .add_relationship(AddRelationshipInput {
+            worksite_id,
+            from_worker_id,
+            to_worker_id,
+            kind: RelationshipKind::new(&form.kind).unwrap_or(RelationshipKind::Other),
+            directional: form.direction != "both",
+        })
+        .await;
+
+    let flash = match result {
+        Ok(_) => flash.success("Relationship added successfully!"),
+        Err(e) => flash.error(e.to_string()),
+    };
+
+    (
+        StatusCode::OK,
+        flash,
+        [
+            ("hx-redirect", routes::wallchart()),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
+
+async fn delete_worker_relationship(
+    extract::Path((worksite_id, _worker_id, relationship_id)): extract::Path<(
+        String,
+        String,
+        String,
+    )>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+) -> impl IntoResponse {
+    let result = worksite_service
+        .remove_relationship(RemoveRelationshipInput {
+            worksite_id,
+            relationship_id,
+        })
+        .await;
+
+    let flash = match result {
+        Ok(_) => flash.success("Relationship removed successfully!"),
+        Err(e) => flash.error(e.to_string()),
+    };
+
+    (
+        StatusCode::OK,
+        flash,
+        [
+            ("hx-redirect", routes::wallchart()),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
@@ -0,0 +1,342 @@
+use std::str::from_utf8;
+
+use auth_service::models::UserPermission;
+use axum::{
+    extract::{self, Multipart, State},
+    response::{Html, IntoResponse},
+    routing::{get, put},
+    Form, Router,
+};
+use axum_flash::{Flash, IncomingFlashes};
+use axum_login::permission_required;
+use chrono::{NaiveDate, Utc};
+use http::StatusCode;
+use mongo_user_repository::MongoUserStore;
+use rscx::{component, html, props, CollectFragment, CollectFragmentAsync};
+
+use web_client::server::{
+    alert::{Alert, AlertKind},
+    attrs::Attrs,
+    button::PrimaryButton,
+    card::{Card, CardContent, CardFooter},
+    form::{Button, FileInput, GridCell, GridLayout, Label, Select, SelectOption, TextInput},
+    headers::SecondaryHeader,
+    notification::NotificationFlashes,
+};
+use worksite_service::{
+    compare_roster::CompareRosterInput,
+    models::{EmploymentStatus, Placement, RosterChange},
+    reconcile_roster::ReconcileRosterInput,
+};
+
+use crate::{
+    components::{
+        page::PageLayout, page_content::PageContent, permission_required::PermissionRequired,
+    },
+    routes,
+    state::WebHtmxState,
+};
+
+pub fn roster_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(routes::ROSTER, put(put_roster))
+        .route_layer(permission_required!(
+            MongoUserStore,
+            login_url = "/forbidden",
+            "roster.reconcile",
+        ))
+        .route(routes::ROSTER, get(get_roster).post(post_roster))
+        .with_state(state)
+}
+
+async fn get_roster(
+    flashes: IncomingFlashes,
+    extract::Path(worksite_id): extract::Path<String>,
+) -> impl IntoResponse {
+    let html = Html(html! {
+        <PageLayout header="Roster">
+            <NotificationFlashes flashes=flashes.clone() />
+            <PageContent title="Compare the worksite against a new list from the employer.">
+                <Card padded=true>
+                    <div id="roster" class="flex flex-col gap-6">
+                        <SecondaryHeader title="Employer list" />
+                        <Alert
+                            title="Nothing changes until you pick what to apply."
+                            kind=AlertKind::Info
+                        >
+                            <div class="flex flex-col gap-6 mb-6">
+                                <p>
+                                    "The list uses the same columns as the CSV import, without the worksite column. People are matched by email, or by name when there's no email to go on."
+                                </p>
+                                <p>
+                                    "Moves are only spotted for rows that list shifts. Anyone in the unit who isn't on the list shows up as a departure."
+                                </p>
+                            </div>
+                        </Alert>
+                        <form
+                            hx-encoding="multipart/form-data"
+                            hx-post=routes::roster(&worksite_id)
+                            hx-target="#roster"
+                        >
+                            <Label for_input="file">
+                                File upload
+                            </Label>
+                            <FileInput
+                                id="file"
+                                name="file"
+                                file_hint_message="CSV up to 10MB"
+                                accept=".csv"
+                            />
+                            <Button class="mt-4" kind="submit">
+                                Compare
+                            </Button>
+                        </form>
+                    </div>
+                </Card>
+            </PageContent>
+        </PageLayout>
+    });
+
+    (flashes, html)
+}
+
+async fn post_roster(
+    extract::Path(worksite_id): extract::Path<String>,
+    State(state): State<WebHtmxState>,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    // Multipart chunks don't follow lines, so the file is put back together before parsing
+    let mut content: Vec<String> = vec![];
+    while let Some(field) = multipart.next_field().await.unwrap() {
+        let bytes = field.bytes().await.unwrap();
+        let data = from_utf8(&bytes).unwrap();
+        content.push(data.to_string());
+    }
+    let content: String = content.join("");
+
+    let result = state
+        .worksite_service
+        .compare_roster(CompareRosterInput {
+            worksite_id: worksite_id.clone(),
+            csv_input: content.clone(),
+        })
+        .await;
+
+    match result {
+        Ok(changes) => Html(html! {
+            <RosterDiff worksite_id=worksite_id csv_input=content changes=changes />
+        })
+        .into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e.to_string()).into_response(),
+    }
+}
+
+#[props]
+struct RosterDiffProps {
+    worksite_id: String,
+    csv_input: String,
+    changes: Vec<RosterChange>,
+}
+
+#[component]
+fn RosterDiff(props: RosterDiffProps) -> String {
+    if props.changes.is_empty() {
+        return html! {
+            <p class="text-sm text-gray-500">"The list matches the worksite. There's nothing to change."</p>
+        };
+    }
+
+    let of_kind = |keep: fn(&RosterChange) -> bool| -> Vec<RosterChange> {
+        props
+            .changes
+            .iter()
+            .filter(|change| keep(change))
+            .cloned()
+            .collect()
+    };
+    let hires = of_kind(|change| matches!(change, RosterChange::Hire(_)));
+    let returns = of_kind(|change| matches!(change, RosterChange::Return { .. }));
+    let moves = of_kind(|change| matches!(change, RosterChange::Move { .. }));
+    let departures = of_kind(|change| matches!(change, RosterChange::Departure(_)));
+
+    html! {
+        <form>
+            <Card>
+                <CardContent padded=true>
+                    <SecondaryHeader
+                        title="Changes"
+                        subtitle="Departures start unticked, since someone missing from the list may just be misspelled."
+                    />
+                    <textarea name="csv_input" class="hidden">{props.csv_input}</textarea>
+                    <RosterChangeList title="New hires" changes=hires checked=true />
+                    <RosterChangeList title="Back on the list" changes=returns checked=true />
+                    <RosterChangeList title="Moved" changes=moves checked=true />
+                    <RosterChangeList title="Gone from the list" changes=departures checked=false />
+                    <GridLayout class="mt-6">
+                        <GridCell span=3>
+                            <Label for_input="changed_on">Date of the list</Label>
+                            <TextInput
+                                input_type="date"
+                                name="changed_on"
+                                value=Utc::now().date_naive().to_string()
+                            />
+                        </GridCell>
+                        <GridCell span=3>
+                            <Label for_input="departed_as">Record departures as</Label>
+                            <Select name="departed_as">
+                                {
+                                    EmploymentStatus::ALL
+                                        .iter()
+                                        .filter(|status| status.has_left())
+                                        .map(|status| async move {
+                                            html! {
+                                                <SelectOption value=status.to_string()>
+                                                    {status.label()}
+                                                </SelectOption>
+                                            }
+                                        })
+                                        .collect_fragment_async()
+                                        .await
+                                }
+                            </Select>
+                        </GridCell>
+                    </GridLayout>
+                </CardContent>
+                <PermissionRequired permission=UserPermission::ReconcileRoster>
+                    <CardFooter>
+                        <PrimaryButton hx_put=routes::roster(&props.worksite_id)>
+                            Apply Selected Changes
+                        </PrimaryButton>
+                    </CardFooter>
+                </PermissionRequired>
+            </Card>
+        </form>
+    }
+}
+
+#[props]
+struct RosterChangeListProps {
+    #[builder(setter(into))]
+    title: String,
+    changes: Vec<RosterChange>,
+    checked: bool,
+}
+
+#[component]
+fn RosterChangeList(props: RosterChangeListProps) -> String {
+    if props.changes.is_empty() {
+        return String::from("");
+    }
+
+    let placements = |placements: &Vec<Placement>| match placements.is_empty() {
+        true => "No shift".to_string(),
+        false => placements
+            .iter()
+            .map(|placement| placement.to_string())
+            .collect::<Vec<String>>()
+            .join(", "),
+    };
+
+    html! {
+        <h4 class="mt-6 text-sm font-semibold text-gray-900">
+            {format!("{} ({})", props.title, props.changes.len())}
+        </h4>
+        <ul role="list" class="mt-2 divide-y divide-gray-100">
+            {
+                props
+                    .changes
+                    .iter()
+                    .map(|change| {
+                        let detail = match change {
+                            RosterChange::Hire(entry) => {
+                                format!("{} · {}", entry.email, placements(&entry.placements))
+                            }
+                            RosterChange::Return { placements: to, .. } => placements(to),
+                            RosterChange::Move { from, to, .. } => {
+                                format!("{} → {}", placements(from), placements(to))
+                            }
+                            RosterChange::Departure(worker) => worker.email.clone(),
+                        };
+                        let id = format!("inp-{}", change.key());
+
+                        html! {
+                            <li class="flex items-center gap-x-3 py-2 text-sm">
+                                <input
+                                    id=id.clone()
+                                    name="change"
+                                    type="checkbox"
+                                    class="h-4 w-4 rounded border-gray-300 text-indigo-600 focus:ring-indigo-600"
+                                    {
+                                        String::from(
+                                            Attrs::default().set_if("checked", "checked".into(), props.checked)
+                                        )
+                                    }
+                                    value=change.key()
+                                />
+                                <label for=id class="select-none">
+                                    <span class="font-medium text-gray-900">{change.full_name()}</span>
+                                    <span class="ml-2 text-gray-500">{detail}</span>
+                                </label>
+                            </li>
+                        }
+                    })
+                    .collect_fragment()
+            }
+        </ul>
+    }
+}
+
+async fn put_roster(
+    extract::Path(worksite_id): extract::Path<String>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    // The ticked changes repeat, so the form can't be a struct
+    Form(form): Form<Vec<(String, String)>>,
+) -> impl IntoResponse {
+    let field = |name: &str| -> Option<String> {
+        form.iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+    };
+    let keys: Vec<String> = form
+        .iter()
+        .filter(|(key, _)| key == "change")
+        .map(|(_, value)| value.clone())
+        .collect();
+
+    let departed_as = field("departed_as").and_then(EmploymentStatus::new);
+    let changed_on = field("changed_on")
+        .and_then(|changed_on| NaiveDate::parse_from_str(&changed_on, "%Y-%m-%d").ok());
+
+    let flash = match (departed_as, changed_on) {
+        (Some(departed_as), Some(changed_on)) => {
+            let result = worksite_service
+                .reconcile_roster(ReconcileRosterInput {
+                    worksite_id,
+                    csv_input: field("csv_input").unwrap_or_default(),
+                    keys,
+                    departed_as,
+                    changed_on,
+                })
+                .await;
+
+            match result {
+                Ok(_) => flash.success("Roster reconciled successfully!"),
+                Err(e) => flash.error(e.to_string()),
+            }
+        }
+        (None, _) => flash.error("Pick how to record departures"),
+        (_, None) => flash.error("Pick the date of the list"),
+    };
+
+    (
+        StatusCode::OK,
+        flash,
+        [
+            ("hx-redirect", routes::wallchart()),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
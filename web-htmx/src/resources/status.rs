@@ -0,0 +1,183 @@
+use axum::{
+    extract::{self, State},
+    response::IntoResponse,
+    routing::get,
+    Form, Router,
+};
+use axum_flash::Flash;
+use chrono::{NaiveDate, Utc};
+use http::StatusCode;
+use rscx::{html, CollectFragment, CollectFragmentAsync};
+use serde::Deserialize;
+
+use web_client::server::{
+    button::PrimaryButton,
+    card::{Card, CardContent, CardFooter},
+    form::{GridCell, GridLayout, Label, Select, SelectOption, TextInput},
+    headers::SecondaryHeader,
+};
+use worksite_service::{
+    change_status::ChangeStatusInput, get_worker::GetWorkerInput, models::EmploymentStatus,
+};
+
+use crate::{routes, state::WebHtmxState};
+
+pub fn status_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(
+            routes::WORKER_STATUS,
+            get(get_worker_status).put(put_worker_status),
+        )
+        .with_state(state)
+}
+
+async fn get_worker_status(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let worker = state
+        .worksite_service
+        .get_worker(GetWorkerInput {
+            id: worker_id.clone(),
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get worker")
+        .ok_or("Worker not found")
+        .expect("Worker not found");
+
+    let current = worker.status;
+
+    html! {
+        <section aria-labelledby="worker-status-heading">
+            <form>
+                <Card>
+                    <CardContent padded=true>
+                        <SecondaryHeader
+                            id="worker-status-heading"
+                            title="🗓️ Employment Status"
+                            subtitle="Workers who leave stay on the books so turnover can be tracked."
+                        />
+                        <ul role="list" class="mt-4 divide-y divide-gray-100">
+                            {
+                                if worker.status_changes.is_empty() {
+                                    html! {
+                                        <li class="py-2 text-sm text-gray-500">
+                                            {format!("{} since being added.", current.label())}
+                                        </li>
+                                    }
+                                } else {
+                                    // Newest first, like the rest of the worker's history
+                                    worker
+                                        .status_changes
+                                        .iter()
+                                        .rev()
+                                        .map(|change| html! {
+                                            <li class="flex items-center justify-between py-2 text-sm">
+                                                <span>
+                                                    <span class="font-medium text-gray-900">
+                                                        {format!("{} → {}", change.previous.label(), change.status.label())}
+                                                    </span>
+                                                    <span class="ml-2 text-gray-500">{&change.note}</span>
+                                                </span>
+                                                <span class="text-gray-500">
+                                                    {change.changed_on.format("%b %-d, %Y").to_string()}
+                                                </span>
+                                            </li>
+                                        })
+                                        .collect_fragment()
+                                }
+                            }
+                        </ul>
+                        <GridLayout class="mt-4">
+                            <GridCell span=2>
+                                <Label for_input="status">Status</Label>
+                                <Select name="status">
+                                    {
+                                        EmploymentStatus::ALL
+                                            .iter()
+                                            .map(|status| async move {
+                                                html! {
+                                                    <SelectOption value=status.to_string() selected=*status == current>
+                                                        {status.label()}
+                                                    </SelectOption>
+                                                }
+                                            })
+                                            .collect_fragment_async()
+                                            .await
+                                    }
+                                </Select>
+                            </GridCell>
+                            <GridCell span=2>
+                                <Label for_input="changed_on">As of</Label>
+                                <TextInput
+                                    input_type="date"
+                                    name="changed_on"
+                                    value=Utc::now().date_naive().to_string()
+                                />
+                            </GridCell>
+                            <GridCell span=2>
+                                <Label for_input="note">Note</Label>
+                                <TextInput name="note" />
+                            </GridCell>
+                        </GridLayout>
+                    </CardContent>
+                    <CardFooter>
+                        <PrimaryButton hx_put=routes::worker_status(&worksite_id, &worker_id)>
+                            Save
+                        </PrimaryButton>
+                    </CardFooter>
+                </Card>
+            </form>
+        </section>
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct WorkerStatusFormData {
+    status: String,
+    changed_on: String,
+    note: String,
+}
+
+async fn put_worker_status(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<WorkerStatusFormData>,
+) -> impl IntoResponse {
+    let status = EmploymentStatus::new(&form.status);
+    let changed_on = NaiveDate::parse_from_str(&form.changed_on, "%Y-%m-%d").ok();
+
+    let flash = match (status, changed_on) {
+        (Some(status), Some(changed_on)) => {
+            let result = worksite_service
+                .change_status(ChangeStatusInput {
+                    worksite_id,
+                    worker_id,
+                    status,
+                    changed_on,
+                    note: form.note.trim().to_string(),
+                })
+                .await;
+
+            match result {
+                Ok(_) => flash.success("Status updated successfully!"),
+                Err(e) => flash.error(e.to_string()),
+            }
+        }
+        (None, _) => flash.error("Pick a status"),
+        (_, None) => flash.error("Pick the date the status changed"),
+    };
+
+    (
+        StatusCode::OK,
+        flash,
+        [
+            ("hx-redirect", routes::wallchart()),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
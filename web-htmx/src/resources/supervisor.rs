@@ -0,0 +1,166 @@
+use axum::{
+    extract::{self, State},
+    response::IntoResponse,
+    routing::get,
+    Form, Router,
+};
+use axum_flash::Flash;
+use http::StatusCode;
+use rscx::{html, CollectFragmentAsync};
+use serde::Deserialize;
+
+use web_client::server::{
+    button::PrimaryButton,
+    card::{Card, CardContent, CardFooter},
+    form::{GridCell, GridLayout, Label, Select, SelectOption},
+    headers::SecondaryHeader,
+};
+use worksite_service::{
+    get_worksite::GetWorksiteInput, models::Worker, set_supervisor::SetSupervisorInput,
+};
+
+use crate::{routes, state::WebHtmxState};
+
+pub fn supervisor_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(
+            routes::WORKER_SUPERVISOR,
+            get(get_worker_supervisor).put(put_worker_supervisor),
+        )
+        .with_state(state)
+}
+
+async fn get_worker_supervisor(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let worksite = state
+        .worksite_service
+        .get_worksite(GetWorksiteInput {
+            id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get worksite")
+        .ok_or("Worksite not found")
+        .expect("Worksite not found");
+
+    let worker = worksite
+        .get_worker(worker_id.clone())
+        .ok_or("Worker not found")
+        .expect("Worker not found");
+
+    // Anyone already under this worker can't become their supervisor
+    let mut candidates: Vec<Worker> = worksite
+        .workers
+        .iter()
+        .filter(|candidate| {
+            candidate.id != worker.id && !worksite.reports_to(candidate, &worker.id)
+        })
+        .cloned()
+        .collect();
+    candidates.sort_by(|a, b| (&a.last_name, &a.first_name).cmp(&(&b.last_name, &b.first_name)));
+
+    let reports = worksite
+        .get_direct_reports(&worker.id)
+        .iter()
+        .map(|report| report.full_name())
+        .collect::<Vec<String>>();
+
+    html! {
+        <section aria-labelledby="worker-supervisor-heading">
+            <form>
+                <Card>
+                    <CardContent padded=true>
+                        <SecondaryHeader
+                            id="worker-supervisor-heading"
+                            title="🧭 Supervisor"
+                            subtitle="Who this worker answers to at work."
+                        />
+                        <GridLayout>
+                            <GridCell span=4>
+                                <Label for_input="supervisor_id">Supervisor</Label>
+                                <Select name="supervisor_id">
+                                    <SelectOption value="" selected=worker.supervisor_id.is_none()>
+                                        No supervisor
+                                    </SelectOption>
+                                    {
+                                        candidates
+                                            .iter()
+                                            .map(|candidate| {
+                                                let selected =
+                                                    worker.supervisor_id.as_ref() == Some(&candidate.id);
+
+                                                async move {
+                                                    html! {
+                                                        <SelectOption value=candidate.id.clone() selected=selected>
+                                                            {candidate.full_name()}
+                                                        </SelectOption>
+                                                    }
+                                                }
+                                            })
+                                            .collect_fragment_async()
+                                            .await
+                                    }
+                                </Select>
+                            </GridCell>
+                            {
+                                if reports.is_empty() {
+                                    String::from("")
+                                } else {
+                                    html! {
+                                        <GridCell>
+                                            <p class="text-sm text-gray-600">
+                                                {format!("Supervises {}", reports.join(", "))}
+                                            </p>
+                                        </GridCell>
+                                    }
+                                }
+                            }
+                        </GridLayout>
+                    </CardContent>
+                    <CardFooter>
+                        <PrimaryButton hx_put=routes::worker_supervisor(&worksite_id, &worker_id)>
+                            Save
+                        </PrimaryButton>
+                    </CardFooter>
+                </Card>
+            </form>
+        </section>
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct WorkerSupervisorFormData {
+    supervisor_id: String,
+}
+
+async fn put_worker_supervisor(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<WorkerSupervisorFormData>,
+) -> impl IntoResponse {
+    let result = worksite_service
+        .set_supervisor(SetSupervisorInput {
+            worksite_id,
+            worker_id,
+            supervisor_id: Some(form.supervisor_id).filter(|id| !id.is_empty()),
+        })
+        .await;
+
+    let flash = match result {
+        Ok(_) => flash.success("Supervisor updated successfully!"),
+        Err(e) => flash.error(e.to_string()),
+    };
+
+    (
+        StatusCode::OK,
+        flash,
+        [
+            ("hx-redirect", routes::wallchart()),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
@@ -0,0 +1,627 @@
+use std::collections::HashMap;
+
+use axum::{
+    extract::{self, State},
+    response::{Html, IntoResponse},
+    routing::{delete, get, put},
+    Form, Router,
+};
+use axum_flash::Flash;
+use axum_login::permission_required;
+use chrono::{NaiveDate, Utc};
+use futures::future::join_all;
+use http::StatusCode;
+use mongo_user_repository::MongoUserStore;
+use rscx::{component, html, props, CollectFragmentAsync};
+use serde::Deserialize;
+
+use auth_service::models::{User, UserPermission};
+use task_service::{
+    change_task_status::ChangeTaskStatusInput,
+    create_task::CreateTaskInput,
+    delete_task::DeleteTaskInput,
+    get_task::GetTaskInput,
+    get_tasks::GetTasksInput,
+    models::{Task, TaskFilter, TaskStatus},
+    service::TaskService,
+    update_task::UpdateTaskInput,
+};
+use web_client::server::{
+    attrs::Attrs,
+    button::PrimaryButton,
+    card::{Card, CardContent, CardFooter},
+    form::{Button, GridCell, GridLayout, Label, Select, SelectOption, TextInput},
+    headers::SecondaryHeader,
+    modal::{modal_target, Modal},
+    table::{
+        ActionLink, Confirm, DeleteActionLink, TDVariant, Table, TableData, TableDataActions,
+        TableHeading,
+    },
+};
+
+use crate::{
+    components::{
+        page::PageLayout, page_content::PageContent, permission_required::PermissionRequired,
+    },
+    routes,
+    state::WebHtmxState,
+};
+
+pub fn tasks_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(routes::TASK, delete(delete_task))
+        .route_layer(permission_required!(
+            MongoUserStore,
+            login_url = "/forbidden",
+            "task.delete",
+        ))
+        .route(routes::MY_TASKS, get(get_my_tasks))
+        .route(
+            routes::WORKER_TASKS,
+            get(get_worker_tasks).post(post_worker_tasks),
+        )
+        .route(routes::TASK, get(get_task_form).put(put_task))
+        .route(routes::TASK_STATUS, put(put_task_status))
+        .with_state(state)
+}
+
+fn today() -> NaiveDate {
+    Utc::now().date_naive()
+}
+
+// Number of overdue tasks per worker, for the badges on the wallchart
+pub async fn count_overdue_tasks(
+    task_service: &TaskService,
+    worksite_id: &str,
+) -> HashMap<String, usize> {
+    let tasks = task_service
+        .get_tasks(GetTasksInput {
+            filter: TaskFilter {
+                worksite_id: Some(worksite_id.to_string()),
+                ..TaskFilter::overdue(today())
+            },
+        })
+        .await
+        .expect("Failed to get overdue tasks");
+
+    let mut counts = HashMap::new();
+    for task in tasks {
+        *counts.entry(task.worker_id).or_insert(0) += 1;
+    }
+    counts
+}
+
+async fn get_my_tasks(State(state): State<WebHtmxState>) -> impl IntoResponse {
+    let ctx: crate::context::Context =
+        crate::context::context().expect("Unable to retrieve htmx context.");
+    let current_user = ctx
+        .current_user
+        .expect("My tasks requires a logged in user");
+
+    let tasks = state
+        .task_service
+        .get_tasks(GetTasksInput {
+            filter: TaskFilter {
+                assignee_id: Some(current_user.id),
+                ..TaskFilter::default()
+            },
+        })
+        .await
+        .expect("Failed to get tasks");
+
+    // (worksite id, worker id) to the worker's and worksite's names
+    let worksites = state
+        .worksite_service
+        .get_worksites()
+        .await
+        .expect("Failed to get worksites");
+    let workers: HashMap<(String, String), (String, String)> = worksites
+        .iter()
+        .flat_map(|worksite| {
+            worksite.workers.iter().map(|worker| {
+                (
+                    (worksite.id.clone(), worker.id.clone()),
+                    (worker.full_name(), worksite.name.clone()),
+                )
+            })
+        })
+        .collect();
+
+    let (open, closed): (Vec<Task>, Vec<Task>) = tasks
+        .into_iter()
+        .partition(|task| task.status == TaskStatus::Open);
+
+    Html(html! {
+        <PageLayout header="My Tasks">
+            <PageContent title="Follow-ups assigned to you, soonest due first">
+                <Card>
+                    <MyTasksTable tasks=open workers=workers.clone() />
+                </Card>
+            </PageContent>
+            <PageContent title="Done and cancelled">
+                <Card>
+                    <MyTasksTable tasks=closed workers=workers />
+                </Card>
+            </PageContent>
+        </PageLayout>
+    })
+}
+
+#[props]
+struct MyTasksTableProps {
+    tasks: Vec<Task>,
+    workers: HashMap<(String, String), (String, String)>,
+}
+
+#[component]
+fn MyTasksTable(props: MyTasksTableProps) -> String {
+    if props.tasks.is_empty() {
+        return html! {
+            <p class="p-6 text-sm text-gray-500">"Nothing here."</p>
+        };
+    }
+
+    let workers = &props.workers;
+
+    html! {
+        <Table
+            headings=vec![
+                TableHeading::title("Due"),
+                TableHeading::title("Task"),
+                TableHeading::title("Worker"),
+                TableHeading::title("Worksite"),
+                TableHeading::title("Status"),
+                TableHeading::empty("Actions"),
+            ]
+            body=join_all(props.tasks.into_iter().map(|task| async move {
+                let (worker_name, worksite_name) = workers
+                    .get(&(task.worksite_id.clone(), task.worker_id.clone()))
+                    .cloned()
+                    .unwrap_or(("A removed worker".into(), "".into()));
+                let worker_url = routes::worker(&task.worksite_id, &task.worker_id);
+
+                html! {
+                    <TableData variant=TDVariant::First>
+                        {task.due_on.format("%b %e, %Y").to_string()}
+                    </TableData>
+                    <TableData>{&task.title}</TableData>
+                    <TableData>
+                        <button
+                            hx-get=worker_url
+                            hx-target=modal_target()
+                            hx-swap="beforeend"
+                        >
+                            {worker_name}
+                        </button>
+                    </TableData>
+                    <TableData>{worksite_name}</TableData>
+                    <TableData>
+                        <TaskStatusBadge task=task.clone() />
+                    </TableData>
+                    <TableData variant=TDVariant::Last>
+                        <TaskActions task=task.clone() />
+                    </TableData>
+                }
+            }))
+            .await
+        />
+    }
+}
+
+async fn get_worker_tasks(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let tasks = state
+        .task_service
+        .get_tasks(GetTasksInput {
+            filter: TaskFilter {
+                worksite_id: Some(worksite_id.clone()),
+                worker_id: Some(worker_id.clone()),
+                ..TaskFilter::default()
+            },
+        })
+        .await
+        .expect("Failed to get tasks");
+
+    let users = state
+        .auth_service
+        .get_users()
+        .await
+        .expect("Failed to get users");
+    let assignees: HashMap<String, String> =
+        users.iter().map(|u| (u.id.clone(), u.name())).collect();
+
+    let ctx: crate::context::Context =
+        crate::context::context().expect("Unable to retrieve htmx context.");
+    let form_data = TaskFormData {
+        assignee_id: ctx.current_user.map(|u| u.id).unwrap_or_default(),
+        ..TaskFormData::default()
+    };
+
+    html! {
+        <section>
+            <form>
+                <Card>
+                    <CardContent padded=true>
+                        <SecondaryHeader
+                            id="worker-tasks-heading"
+                            title="📌 Follow-ups"
+                            subtitle="Who is following up with this worker, and by when."
+                        />
+                        <section class="mt-4 divide-y divide-gray-200 border-b border-t border-gray-200">
+                            <WorkerTaskList tasks=tasks assignees=assignees />
+                        </section>
+                        <section class="mt-4">
+                            <h3 class="text-md mb-2 font-medium leading-6 text-gray-900">"Add a follow-up"</h3>
+                            <TaskFormFields users=users form_data=form_data />
+                        </section>
+                    </CardContent>
+                    <CardFooter>
+                        <PrimaryButton
+                            hx_post=routes::worker_tasks(&worksite_id, &worker_id)
+                        >
+                            Add Follow-up
+                        </PrimaryButton>
+                    </CardFooter>
+                </Card>
+            </form>
+        </section>
+    }
+}
+
+#[props]
+struct WorkerTaskListProps {
+    tasks: Vec<Task>,
+    assignees: HashMap<String, String>,
+}
+
+#[component]
+fn WorkerTaskList(props: WorkerTaskListProps) -> String {
+    if props.tasks.is_empty() {
+        return html! {
+            <p class="py-5 text-sm text-gray-500">"No follow-ups yet."</p>
+        };
+    }
+
+    let assignees = &props.assignees;
+
+    html! {
+        <ul role="list" class="divide-y divide-gray-100">
+            {
+                props.tasks.into_iter().map(|task| async move {
+                    let assignee = assignees
+                        .get(&task.assignee_id)
+                        .cloned()
+                        .unwrap_or("a removed user".into());
+
+                    html! {
+                        <li class="flex items-center justify-between gap-x-6 py-5">
+                            <div class="min-w-0">
+                                <div class="flex items-start gap-x-3">
+                                    <p class="text-sm font-semibold leading-6 text-gray-900">{&task.title}</p>
+                                    <TaskStatusBadge task=task.clone() />
+                                </div>
+                                <p class="mt-1 text-xs leading-5 text-gray-500">
+                                    {format!("Due {} for {}", task.due_on.format("%b %e, %Y"), assignee)}
+                                </p>
+                            </div>
+                            <div class="flex flex-none items-center gap-x-4 text-sm">
+                                <TaskActions task=task.clone() />
+                            </div>
+                        </li>
+                    }
+                })
+                .collect_fragment_async()
+                .await
+            }
+        </ul>
+    }
+}
+
+#[props]
+struct TaskStatusBadgeProps {
+    task: Task,
+}
+
+#[component]
+fn TaskStatusBadge(props: TaskStatusBadgeProps) -> String {
+    let (label, class) = match props.task.status {
+        TaskStatus::Open if props.task.is_overdue(today()) => (
+            "Overdue".to_string(),
+            "text-red-700 bg-red-50 ring-red-600/10",
+        ),
+        TaskStatus::Open => ("Open".into(), "text-gray-600 bg-gray-50 ring-gray-500/10"),
+        TaskStatus::Done => (
+            "Done".into(),
+            "text-green-700 bg-green-50 ring-green-600/20",
+        ),
+        TaskStatus::Cancelled => (
+            "Cancelled".into(),
+            "text-yellow-800 bg-yellow-50 ring-yellow-600/20",
+        ),
+    };
+
+    html! {
+        <span class=format!("rounded-md whitespace-nowrap mt-0.5 px-1.5 py-0.5 text-xs font-medium ring-1 ring-inset {}", class)>
+            {label}
+        </span>
+    }
+}
+
+#[props]
+struct TaskActionsProps {
+    task: Task,
+}
+
+#[component]
+fn TaskActions(props: TaskActionsProps) -> String {
+    let task = props.task;
+    let (status_label, next_status) = match task.status {
+        TaskStatus::Open => ("Mark done", TaskStatus::Done),
+        _ => ("Reopen", TaskStatus::Open),
+    };
+
+    html! {
+        <TableDataActions>
+            <ActionLink
+                hx_put=routes::task_status(&task.id)
+                hx_vals=format!("{{\"status\": \"{}\"}}", next_status)
+                sr_text=&task.title
+            >
+                {status_label}
+            </ActionLink>
+            <ActionLink
+                hx_get=routes::task(&task.id)
+                hx_target=modal_target()
+                hx_swap="beforeend"
+                sr_text=&task.title
+            >
+                Edit
+            </ActionLink>
+            <PermissionRequired permission=UserPermission::DeleteTask>
+                <DeleteActionLink
+                    hx_delete=routes::task(&task.id)
+                    hx_swap="outerHTML swap:1s"
+                    hx_target="closest tr, li"
+                    confirm=Confirm {
+                        title: "Remove Follow-up".into(),
+                        message: format!("Are you sure you want to remove this follow-up: {}?", &task.title),
+                    }
+                    sr_text=&task.title
+                    show_loader_on_delete=true
+                >
+                    Remove
+                </DeleteActionLink>
+            </PermissionRequired>
+        </TableDataActions>
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct TaskFormData {
+    title: String,
+    assignee_id: String,
+    due_on: String,
+}
+
+impl Default for TaskFormData {
+    fn default() -> Self {
+        Self {
+            title: "".into(),
+            assignee_id: "".into(),
+            due_on: today().to_string(),
+        }
+    }
+}
+
+impl TaskFormData {
+    // Browsers without a date picker may send anything, so fall back to today
+    fn due_on(&self) -> NaiveDate {
+        NaiveDate::parse_from_str(&self.due_on, "%Y-%m-%d").unwrap_or(today())
+    }
+}
+
+async fn post_worker_tasks(
+    extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
+    State(WebHtmxState { task_service, .. }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<TaskFormData>,
+) -> impl IntoResponse {
+    let result = task_service
+        .create_task(CreateTaskInput {
+            worksite_id,
+            worker_id,
+            assignee_id: form.assignee_id.clone(),
+            title: form.title.clone(),
+            due_on: form.due_on(),
+        })
+        .await;
+
+    match result {
+        Ok(_) => (
+            StatusCode::OK,
+            flash.success("Follow-up added successfully!"),
+            [
+                ("hx-redirect", routes::wallchart()),
+                ("hx-retarget", "body".into()),
+            ],
+        ),
+        Err(e) => (
+            StatusCode::OK,
+            flash.error(e.to_string()),
+            [
+                ("hx-redirect", routes::wallchart()),
+                ("hx-retarget", "body".into()),
+            ],
+        ),
+    }
+}
+
+async fn get_task_form(
+    extract::Path(task_id): extract::Path<String>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let task = state
+        .task_service
+        .get_task(GetTaskInput {
+            id: task_id.clone(),
+        })
+        .await
+        .expect("Failed to get task")
+        .ok_or("Task not found")
+        .expect("Task not found");
+    let users = state
+        .auth_service
+        .get_users()
+        .await
+        .expect("Failed to get users");
+
+    html! {
+        <Modal>
+            <SecondaryHeader
+                title="📌 Update Follow-up"
+                subtitle="Enter new values below."
+            />
+            <div class="mt-4">
+                <form hx-put=routes::task(&task_id)>
+                    <TaskFormFields
+                        users=users
+                        form_data=TaskFormData {
+                            title: task.title,
+                            assignee_id: task.assignee_id,
+                            due_on: task.due_on.to_string(),
+                        }
+                    />
+                    <GridLayout>
+                        <GridCell span=6>
+                            <div class="mt-6 flex items-center justify-end gap-x-6">
+                                <Button
+                                    onclick="history.go(-1)"
+                                    attrs=Attrs::with("data-toggle-action", "close".into())
+                                >
+                                    Cancel
+                                </Button>
+                                <Button kind="submit">Save</Button>
+                            </div>
+                        </GridCell>
+                    </GridLayout>
+                </form>
+            </div>
+        </Modal>
+    }
+}
+
+async fn put_task(
+    extract::Path(task_id): extract::Path<String>,
+    State(WebHtmxState { task_service, .. }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<TaskFormData>,
+) -> impl IntoResponse {
+    let result = task_service
+        .update_task(UpdateTaskInput {
+            id: task_id,
+            assignee_id: form.assignee_id.clone(),
+            title: form.title.clone(),
+            due_on: form.due_on(),
+        })
+        .await;
+
+    let flash = match result {
+        Ok(_) => flash.success("Follow-up updated successfully!"),
+        Err(e) => flash.error(e.to_string()),
+    };
+
+    // Edited from both the wallchart and the my tasks page
+    (StatusCode::OK, flash, [("hx-refresh", "true")])
+}
+
+#[derive(Deserialize, Debug)]
+struct TaskStatusFormData {
+    status: String,
+}
+
+async fn put_task_status(
+    extract::Path(task_id): extract::Path<String>,
+    State(WebHtmxState { task_service, .. }): State<WebHtmxState>,
+    Form(form): Form<TaskStatusFormData>,
+) -> impl IntoResponse {
+    let status = match TaskStatus::new(&form.status) {
+        Some(status) => status,
+        None => return (StatusCode::BAD_REQUEST, "Unknown status").into_response(),
+    };
+
+    task_service
+        .change_task_status(ChangeTaskStatusInput {
+            id: task_id,
+            status,
+        })
+        .await
+        .expect("Failed to change task status");
+
+    (StatusCode::OK, [("hx-refresh", "true")]).into_response()
+}
+
+async fn delete_task(
+    extract::Path(task_id): extract::Path<String>,
+    State(WebHtmxState { task_service, .. }): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let result = task_service
+        .delete_task(DeleteTaskInput { id: task_id })
+        .await;
+
+    match result {
+        Ok(_) => "".into_response(),
+        Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Error deleting task").into_response(),
+    }
+}
+
+#[props]
+struct TaskFormFieldsProps {
+    users: Vec<User>,
+    form_data: TaskFormData,
+}
+
+#[component]
+fn TaskFormFields(props: TaskFormFieldsProps) -> String {
+    let assignee_id = &props.form_data.assignee_id;
+
+    html! {
+        <GridLayout>
+            <GridCell span=6>
+                <Label for_input="title">Follow-up</Label>
+                <TextInput
+                    name="title"
+                    placeholder="Talk to Roy again after his shift Thursday"
+                    value=&props.form_data.title
+                />
+            </GridCell>
+            <GridCell span=3>
+                <Label for_input="assignee_id">Assigned to</Label>
+                <Select name="assignee_id">
+                    {
+                        props
+                            .users
+                            .iter()
+                            .map(|user| async move {
+                                html! {
+                                    <SelectOption
+                                        value=user.id.clone()
+                                        selected=&user.id == assignee_id
+                                    >
+                                        {user.name()}
+                                    </SelectOption>
+                                }
+                            })
+                            .collect_fragment_async()
+                            .await
+                    }
+                </Select>
+            </GridCell>
+            <GridCell span=3>
+                <Label for_input="due_on">Due</Label>
+                <TextInput input_type="date" name="due_on" value=&props.form_data.due_on />
+            </GridCell>
+        </GridLayout>
+    }
+}
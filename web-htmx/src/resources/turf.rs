@@ -0,0 +1,441 @@
+use std::collections::HashMap;
+
+use axum::{
+    extract::{self, State},
+    response::{Html, IntoResponse},
+    routing::{delete, get},
+    Form, Router,
+};
+use axum_flash::{Flash, IncomingFlashes};
+use axum_login::permission_required;
+use futures::future::join_all;
+use http::StatusCode;
+use mongo_user_repository::MongoUserStore;
+use rscx::{component, html, props, CollectFragmentAsync};
+use serde::Deserialize;
+
+use auth_service::models::{User, UserPermission};
+use web_client::server::{
+    attrs::Attrs,
+    button::PrimaryButton,
+    card::{Card, CardContent, CardFooter},
+    form::{GridCell, GridLayout, Label, Select, SelectOption},
+    modal::modal_target,
+    notification::NotificationFlashes,
+    table::{
+        ActionLink, Confirm, DeleteActionLink, TDVariant, Table, TableData, TableDataActions,
+        TableHeading,
+    },
+};
+use worksite_service::{
+    assign_turf::AssignTurfInput,
+    get_turf_coverage::{GetTurfCoverageInput, TurfCoverage},
+    get_worksite::GetWorksiteInput,
+    models::{Turf, TurfAssignment, Worker, Worksite},
+    unassign_turf::UnassignTurfInput,
+};
+
+use crate::{
+    components::{
+        page::PageLayout, page_content::PageContent, permission_required::PermissionRequired,
+    },
+    routes,
+    state::WebHtmxState,
+};
+
+pub fn turf_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(routes::TURF_ASSIGNMENT, delete(delete_turf_assignment))
+        .route_layer(permission_required!(
+            MongoUserStore,
+            login_url = "/forbidden",
+            "turf.unassign",
+        ))
+        .route(routes::TURF, get(get_turf).post(post_turf))
+        .with_state(state)
+}
+
+// Names a location, shift or worker, e.g. "the Day shift at Office"
+pub fn turf_name(worksite: &Worksite, turf: &Turf) -> String {
+    match turf {
+        Turf::Location(location_id) => worksite
+            .locations
+            .iter()
+            .find(|location| &location.id == location_id)
+            .map(|location| location.name.clone())
+            .unwrap_or("a removed location".into()),
+        Turf::Shift(shift_id) => worksite
+            .locations
+            .iter()
+            .find_map(|location| {
+                location
+                    .shifts
+                    .iter()
+                    .find(|shift| &shift.id == shift_id)
+                    .map(|shift| format!("the {} shift at {}", shift.name, location.name))
+            })
+            .unwrap_or("a removed shift".into()),
+        Turf::Worker(worker_id) => worksite
+            .get_worker(worker_id.clone())
+            .map(|worker| worker.full_name())
+            .unwrap_or("a removed worker".into()),
+    }
+}
+
+// Everything that can be assigned as turf, as (form value, label) pairs
+fn turf_options(worksite: &Worksite) -> Vec<(String, String)> {
+    let locations = worksite.locations.iter().map(|location| {
+        (
+            format!("Location:{}", location.id),
+            format!("📍 {}", location.name),
+        )
+    });
+    let shifts = worksite.locations.iter().flat_map(|location| {
+        location.shifts.iter().map(|shift| {
+            (
+                format!("Shift:{}", shift.id),
+                format!("🕒 {} – {}", location.name, shift.name),
+            )
+        })
+    });
+    let mut workers: Vec<&Worker> = worksite.workers.iter().collect();
+    workers.sort_by(|a, b| a.last_name.cmp(&b.last_name));
+    let workers = workers.into_iter().map(|worker| {
+        (
+            format!("Worker:{}", worker.id),
+            format!("👤 {}", worker.full_name()),
+        )
+    });
+
+    locations.chain(shifts).chain(workers).collect()
+}
+
+async fn get_turf(
+    flashes: IncomingFlashes,
+    extract::Path(worksite_id): extract::Path<String>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let worksite = state
+        .worksite_service
+        .get_worksite(GetWorksiteInput {
+            id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get worksite")
+        .ok_or("Worksite not found")
+        .expect("Worksite not found");
+
+    let coverage = state
+        .worksite_service
+        .get_turf_coverage(GetTurfCoverageInput {
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get turf coverage");
+
+    let mut users = state
+        .auth_service
+        .get_users()
+        .await
+        .expect("Failed to get users");
+    users.sort_by_key(|u| u.name());
+
+    let ctx: crate::context::Context =
+        crate::context::context().expect("Unable to retrieve htmx context.");
+    let current_user_id = ctx.current_user.map(|u| u.id).unwrap_or_default();
+    let current_user_id = &current_user_id;
+
+    let html = Html(html! {
+        <PageLayout header=format!("Turf: {}", worksite.name)>
+            <NotificationFlashes flashes=flashes.clone() />
+            <PageContent title="Who is covering which workers">
+                <Card>
+                    <TurfCoverageSummary coverage=coverage.clone() worksite=worksite.clone() />
+                </Card>
+            </PageContent>
+            <PageContent title="Assign turf to an organizer">
+                <form>
+                    <Card>
+                        <CardContent padded=true>
+                            <GridLayout>
+                                <GridCell span=3>
+                                    <Label for_input="user_id">Organizer</Label>
+                                    <Select name="user_id">
+                                        {
+                                            users
+                                                .iter()
+                                                .map(|user| async move {
+                                                    html! {
+                                                        <SelectOption
+                                                            value=user.id.clone()
+                                                            selected=&user.id == current_user_id
+                                                        >
+                                                            {user.name()}
+                                                        </SelectOption>
+                                                    }
+                                                })
+                                                .collect_fragment_async()
+                                                .await
+                                        }
+                                    </Select>
+                                </GridCell>
+                                <GridCell span=3>
+                                    <Label for_input="turf">Turf</Label>
+                                    <Select name="turf">
+                                        {
+                                            turf_options(&worksite)
+                                                .into_iter()
+                                                .map(|(value, label)| async move {
+                                                    html! {
+                                                        <SelectOption value=value>{label}</SelectOption>
+                                                    }
+                                                })
+                                                .collect_fragment_async()
+                                                .await
+                                        }
+                                    </Select>
+                                </GridCell>
+                            </GridLayout>
+                        </CardContent>
+                        <CardFooter>
+                            <PrimaryButton hx_post=routes::turf(&worksite_id)>
+                                Assign Turf
+                            </PrimaryButton>
+                        </CardFooter>
+                    </Card>
+                </form>
+            </PageContent>
+            <PageContent title="Organizers and their turf">
+                <Card>
+                    <TurfAssignmentsTable worksite=worksite.clone() users=users />
+                </Card>
+            </PageContent>
+        </PageLayout>
+    });
+
+    (flashes, html)
+}
+
+#[props]
+struct TurfCoverageSummaryProps {
+    coverage: TurfCoverage,
+    worksite: Worksite,
+}
+
+#[component]
+fn TurfCoverageSummary(props: TurfCoverageSummaryProps) -> String {
+    let coverage = props.coverage;
+    let worksite = &props.worksite;
+
+    html! {
+        <div class="p-6">
+            <p class="text-3xl font-semibold tracking-tight text-gray-900">
+                {format!("{}%", coverage.percent_covered())}
+            </p>
+            <p class="mt-1 text-sm text-gray-500">
+                {format!(
+                    "{} of {} workers have an organizer",
+                    coverage.covered_workers, coverage.total_workers
+                )}
+            </p>
+        </div>
+        {
+            if coverage.uncovered.is_empty() {
+                "".into()
+            } else {
+                html! {
+                    <Table
+                        headings=vec![
+                            TableHeading::title("Without an organizer"),
+                            TableHeading::title("Shifts"),
+                        ]
+                        body=join_all(coverage.uncovered.into_iter().map(|worker| async move {
+                            let shifts: Vec<String> = worksite
+                                .locations
+                                .iter()
+                                .flat_map(|location| {
+                                    location
+                                        .shifts
+                                        .iter()
+                                        .filter(|shift| shift.contains_worker(&worker))
+                                        .map(|shift| format!("{} – {}", location.name, shift.name))
+                                })
+                                .collect();
+
+                            html! {
+                                <TableData variant=TDVariant::First>
+                                    <button
+                                        hx-get=routes::worker(&worksite.id, &worker.id)
+                                        hx-target=modal_target()
+                                        hx-swap="beforeend"
+                                    >
+                                        {worker.full_name()}
+                                    </button>
+                                </TableData>
+                                <TableData variant=TDVariant::LastNonEmptyHeading>
+                                    {
+                                        if shifts.is_empty() {
+                                            "No shift".into()
+                                        } else {
+                                            shifts.join(", ")
+                                        }
+                                    }
+                                </TableData>
+                            }
+                        }))
+                        .await
+                    />
+                }
+            }
+        }
+    }
+}
+
+#[props]
+struct TurfAssignmentsTableProps {
+    worksite: Worksite,
+    users: Vec<User>,
+}
+
+#[component]
+fn TurfAssignmentsTable(props: TurfAssignmentsTableProps) -> String {
+    let worksite = &props.worksite;
+    let organizers: HashMap<String, String> = props
+        .users
+        .iter()
+        .map(|u| (u.id.clone(), u.name()))
+        .collect();
+    let organizers = &organizers;
+
+    // Grouped by organizer, in the order they appear in the user list
+    let mut assignments: Vec<TurfAssignment> = worksite.turf_assignments.clone();
+    let position = |user_id: &String| props.users.iter().position(|u| &u.id == user_id);
+    assignments.sort_by_key(|a| position(&a.user_id));
+
+    if assignments.is_empty() {
+        return html! {
+            <p class="p-6 text-sm text-gray-500">"Nobody has any turf yet."</p>
+        };
+    }
+
+    html! {
+        <Table
+            headings=vec![
+                TableHeading::title("Organizer"),
+                TableHeading::title("Type"),
+                TableHeading::title("Turf"),
+                TableHeading::empty("Actions"),
+            ]
+            body=join_all(assignments.into_iter().map(|assignment| async move {
+                let organizer = organizers
+                    .get(&assignment.user_id)
+                    .cloned()
+                    .unwrap_or("A removed user".into());
+                let turf = turf_name(worksite, &assignment.turf);
+
+                html! {
+                    <TableData variant=TDVariant::First>{&organizer}</TableData>
+                    <TableData>{assignment.turf.kind()}</TableData>
+                    <TableData>{&turf}</TableData>
+                    <TableData variant=TDVariant::Last>
+                        <TableDataActions>
+                            <ActionLink
+                                attrs=Attrs::with(
+                                    "href",
+                                    routes::organizer_wallchart(&worksite.id, &assignment.user_id),
+                                )
+                                sr_text=format!(", {}", &organizer)
+                            >
+                                Wallchart
+                            </ActionLink>
+                            <PermissionRequired permission=UserPermission::UnassignTurf>
+                                <DeleteActionLink
+                                    hx_delete=routes::turf_assignment(&worksite.id, &assignment.id)
+                                    hx_swap="outerHTML swap:1s"
+                                    hx_target="closest tr"
+                                    confirm=Confirm {
+                                        title: "Remove Turf".into(),
+                                        message: format!("Are you sure you want to take {} off {}'s turf?", &turf, &organizer),
+                                    }
+                                    sr_text=format!(", {}", &turf)
+                                    show_loader_on_delete=true
+                                >
+                                    Remove
+                                </DeleteActionLink>
+                            </PermissionRequired>
+                        </TableDataActions>
+                    </TableData>
+                }
+            }))
+            .await
+        />
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct TurfFormData {
+    user_id: String,
+    // Kind and id, e.g. "Shift:1"
+    turf: String,
+}
+
+async fn post_turf(
+    extract::Path(worksite_id): extract::Path<String>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<TurfFormData>,
+) -> impl IntoResponse {
+    let turf = form
+        .turf
+        .split_once(':')
+        .and_then(|(kind, id)| Turf::new(kind, id.to_string()));
+
+    let flash = match turf {
+        Some(turf) => {
+            let result = worksite_service
+                .assign_turf(AssignTurfInput {
+                    worksite_id: worksite_id.clone(),
+                    user_id: form.user_id,
+                    turf,
+                })
+                .await;
+
+            match result {
+                Ok(_) => flash.success("Turf assigned successfully!"),
+                Err(e) => flash.error(e.to_string()),
+            }
+        }
+        None => flash.error("Pick a location, shift or worker."),
+    };
+
+    (
+        StatusCode::OK,
+        flash,
+        [
+            ("hx-redirect", routes::turf(&worksite_id)),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
+
+async fn delete_turf_assignment(
+    extract::Path((worksite_id, assignment_id)): extract::Path<(String, String)>,
+    State(WebHtmxState {
+        worksite_service, ..
+    }): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let result = worksite_service
+        .unassign_turf(UnassignTurfInput {
+            worksite_id,
+            assignment_id,
+        })
+        .await;
+
+    // Refreshed rather than swapped out, so the coverage above stays accurate
+    match result {
+        Ok(_) => (StatusCode::OK, [("hx-refresh", "true")]).into_response(),
+        Err(_) => (StatusCode::INTERNAL_SERVER_ERROR, "Error removing turf").into_response(),
+    }
+}
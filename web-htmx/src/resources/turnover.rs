@@ -0,0 +1,175 @@
+use axum::{
+    extract::{self, State},
+    response::{Html, IntoResponse},
+    routing::get,
+    Router,
+};
+use axum_flash::IncomingFlashes;
+use futures::future::join_all;
+use rscx::{component, html, props, CollectFragment};
+
+use web_client::server::{
+    card::Card,
+    modal::modal_target,
+    notification::NotificationFlashes,
+    table::{TDVariant, Table, TableData, TableHeading},
+};
+use worksite_service::{
+    get_leaver_alerts::{GetLeaverAlertsInput, LeaverAlert},
+    get_turnover_report::GetTurnoverReportInput,
+};
+
+use crate::{
+    components::{page::PageLayout, page_content::PageContent},
+    routes,
+    state::WebHtmxState,
+};
+
+pub fn turnover_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(routes::TURNOVER, get(get_turnover))
+        .with_state(state)
+}
+
+async fn get_turnover(
+    flashes: IncomingFlashes,
+    extract::Path(worksite_id): extract::Path<String>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let report = state
+        .worksite_service
+        .get_turnover_report(GetTurnoverReportInput {
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get turnover report");
+
+    let alerts = state
+        .worksite_service
+        .get_leaver_alerts(GetLeaverAlertsInput {
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get leaver alerts");
+
+    let summary = format!(
+        "{} hired and {} gone since records began",
+        report.total_hired(),
+        report.total_left()
+    );
+
+    let html = Html(html! {
+        <PageLayout header="Turnover">
+            <NotificationFlashes flashes=flashes.clone() />
+            <PageContent title="Leaver alerts">
+                <Card>
+                    <LeaverAlerts worksite_id=worksite_id.clone() alerts=alerts />
+                </Card>
+            </PageContent>
+            <PageContent title=summary>
+                <Card>
+                    {
+                        if report.months.is_empty() {
+                            html! {
+                                <p class="p-6 text-sm text-gray-500">
+                                    "Record hire dates and status changes to see turnover."
+                                </p>
+                            }
+                        } else {
+                            html! {
+                                <Table
+                                    headings=vec![
+                                        TableHeading::title("Month"),
+                                        TableHeading::title("Headcount"),
+                                        TableHeading::title("Hired"),
+                                        TableHeading::title("Terminated"),
+                                        TableHeading::title("Quit"),
+                                        TableHeading::title("Transferred"),
+                                        TableHeading::title("Turnover"),
+                                    ]
+                                    body=join_all(report.months.into_iter().map(|month| async move {
+                                        html! {
+                                            <TableData variant=TDVariant::First>
+                                                {month.month.format("%B %Y").to_string()}
+                                            </TableData>
+                                            <TableData>{month.headcount.to_string()}</TableData>
+                                            <TableData>{month.hired.to_string()}</TableData>
+                                            <TableData>{month.terminated.to_string()}</TableData>
+                                            <TableData>{month.quit.to_string()}</TableData>
+                                            <TableData>{month.transferred.to_string()}</TableData>
+                                            <TableData variant=TDVariant::LastNonEmptyHeading>
+                                                {format!("{}%", month.turnover_percent())}
+                                            </TableData>
+                                        }
+                                    }))
+                                    .await
+                                />
+                            }
+                        }
+                    }
+                </Card>
+            </PageContent>
+        </PageLayout>
+    });
+
+    (flashes, html)
+}
+
+#[props]
+struct LeaverAlertsProps {
+    worksite_id: String,
+    alerts: Vec<LeaverAlert>,
+}
+
+// Committee members and strong supporters who have left, newest first
+#[component]
+fn LeaverAlerts(props: LeaverAlertsProps) -> String {
+    if props.alerts.is_empty() {
+        return html! {
+            <p class="p-6 text-sm text-gray-500">"No leaders have left."</p>
+        };
+    }
+
+    let worksite_id = &props.worksite_id;
+
+    html! {
+        <ul role="list" class="divide-y divide-gray-100">
+            {
+                props
+                    .alerts
+                    .into_iter()
+                    .map(|alert| {
+                        let reason = match (alert.worker.committee_member, alert.worker.last_assessment()) {
+                            (true, _) => "Committee member".to_string(),
+                            (false, Some(assessment)) => format!("Assessment: {}", assessment.value),
+                            (false, None) => "".to_string(),
+                        };
+
+                        html! {
+                            <li class="flex items-center justify-between gap-x-4 px-6 py-4 text-sm">
+                                <div>
+                                    <button
+                                        class="font-medium text-indigo-600 hover:text-indigo-900"
+                                        hx-get=routes::worker(worksite_id, &alert.worker.id)
+                                        hx-target=modal_target()
+                                        hx-swap="beforeend"
+                                    >
+                                        {alert.worker.full_name()}
+                                    </button>
+                                    <span class="ml-2 rounded-md px-1.5 py-0.5 text-xs font-medium text-red-700 bg-red-50 ring-1 ring-inset ring-red-600/10">
+                                        {alert.change.status.label()}
+                                    </span>
+                                    <span class="ml-2 text-gray-500">{reason}</span>
+                                    <p class="mt-1 text-gray-500">{alert.change.note}</p>
+                                </div>
+                                <span class="whitespace-nowrap text-gray-500">
+                                    {alert.change.changed_on.format("%b %-d, %Y").to_string()}
+                                </span>
+                            </li>
+                        }
+                    })
+                    .collect_fragment()
+            }
+        </ul>
+    }
+}
@@ -0,0 +1,540 @@
+use axum::{
+    extract::State,
+    response::{Html, IntoResponse, Redirect},
+    routing::{get, post},
+    Form, Router,
+};
+use axum_flash::Flash;
+use axum_login::{tower_sessions::Session, AuthSession};
+use chrono::Utc;
+use http::StatusCode;
+use mongo_user_repository::MongoUserStore;
+use qrcode::{render::svg, QrCode};
+use rscx::{component, html, props, CollectFragment};
+use serde::Deserialize;
+
+use auth_service::{
+    begin_two_factor_enrollment::{BeginTwoFactorEnrollmentFailure, BeginTwoFactorEnrollmentInput},
+    confirm_two_factor_enrollment::{
+        ConfirmTwoFactorEnrollmentFailure, ConfirmTwoFactorEnrollmentInput,
+    },
+    disable_two_factor::{DisableTwoFactorFailure, DisableTwoFactorInput},
+    get_user::GetUserInput,
+    models::TwoFactorEnrollment,
+    verify_two_factor::{VerifyTwoFactorFailure, VerifyTwoFactorInput},
+};
+use web_client::server::{
+    alert::{Alert, AlertKind},
+    card::Card,
+    form::{Button, GridCell, GridLayout, Label, TextInput},
+    headers::SecondaryHeader,
+};
+
+use crate::{
+    components::{page::PageLayout, page_content::PageContent},
+    resources::auth::{complete_login, TWO_FACTOR_NEXT, TWO_FACTOR_USER_ID},
+    routes,
+    state::WebHtmxState,
+};
+
+// Enrollment and removal for the logged in user
+pub fn two_factor_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(
+            routes::PROFILE_TWO_FACTOR,
+            get(get_profile_two_factor).post(post_profile_two_factor),
+        )
+        .route(
+            routes::PROFILE_TWO_FACTOR_DISABLE,
+            post(post_disable_two_factor),
+        )
+        .with_state(state)
+}
+
+// The second login step. These are public, the user is not logged in until they pass.
+pub fn two_factor_login_routes(state: WebHtmxState) -> Router {
+    Router::new()
+        .route(
+            routes::LOGIN_TWO_FACTOR,
+            get(get_login_two_factor).post(post_login_two_factor),
+        )
+        .route(
+            routes::LOGIN_TWO_FACTOR_SETUP,
+            get(get_login_two_factor_setup).post(post_login_two_factor_setup),
+        )
+        .with_state(state)
+}
+
+fn pending_user_id(session: &Session) -> Option<String> {
+    session.get(TWO_FACTOR_USER_ID).ok().unwrap_or(None)
+}
+
+async fn get_login_two_factor(session: Session) -> impl IntoResponse {
+    if pending_user_id(&session).is_none() {
+        return Redirect::to(&routes::login()).into_response();
+    }
+
+    Html(html! {
+        <PageLayout header="Two-Factor Authentication">
+            <p class="mt-1 text-sm leading-6 text-gray-600">
+                Enter the code from your authenticator app, or one of your backup codes.
+            </p>
+            <TwoFactorCodeForm action=routes::login_two_factor() />
+        </PageLayout>
+    })
+    .into_response()
+}
+
+#[props]
+struct TwoFactorCodeFormProps {
+    #[builder(setter(into))]
+    action: String,
+
+    #[builder(setter(into), default = "Verify".into())]
+    submit_label: String,
+}
+
+#[component]
+fn TwoFactorCodeForm(props: TwoFactorCodeFormProps) -> String {
+    html! {
+        <form
+            hx-ext="response-targets"
+            hx-post=props.action
+            hx-target="this"
+            hx-swap="outerHTML"
+            hx-target-error="#two-factor-errors"
+        >
+            <div id="two-factor-errors"></div>
+            <div class="pb-6">
+                <GridLayout class="mt-6">
+                    <GridCell span=4>
+                        <Label for_input="code">Code</Label>
+                        <TextInput name="code" autocomplete="one-time-code" />
+                    </GridCell>
+                    <GridCell span=4>
+                        <div class="mt-6 flex items-center justify-end gap-x-6">
+                            <Button kind="submit">{props.submit_label}</Button>
+                        </div>
+                    </GridCell>
+                </GridLayout>
+            </div>
+        </form>
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct TwoFactorCodeFormData {
+    code: String,
+}
+
+async fn post_login_two_factor(
+    State(WebHtmxState {
+        auth_service,
+        worksite_service,
+        ..
+    }): State<WebHtmxState>,
+    mut auth: AuthSession<MongoUserStore>,
+    session: Session,
+    Form(form): Form<TwoFactorCodeFormData>,
+) -> impl IntoResponse {
+    let Some(user_id) = pending_user_id(&session) else {
+        return login_expired().into_response();
+    };
+
+    let result = auth_service
+        .verify_two_factor(VerifyTwoFactorInput {
+            user_id,
+            code: form.code,
+        })
+        .await;
+
+    let error_message = match result {
+        Ok(user) => {
+            return match complete_login(&mut auth, &session, &worksite_service, &user).await {
+                Ok(_) => finish_second_step(&session).into_response(),
+                Err(_) => (StatusCode::BAD_REQUEST, "Login failed").into_response(),
+            }
+        }
+        Err(VerifyTwoFactorFailure::InvalidCode) => "That code is not valid.".to_string(),
+        Err(VerifyTwoFactorFailure::TooManyAttempts(retry_at)) => format!(
+            "Too many attempts. Try again in {} seconds.",
+            (retry_at - Utc::now()).num_seconds().max(1)
+        ),
+        Err(VerifyTwoFactorFailure::AccountLocked(locked_until)) => format!(
+            "Login is locked after too many failed attempts. Try again after {} UTC or ask an admin to unlock your account.",
+            locked_until.format("%H:%M")
+        ),
+        Err(_) => "Something went wrong verifying your code.".to_string(),
+    };
+
+    (
+        StatusCode::BAD_REQUEST,
+        Html(html! {
+            <Alert class="mb-4" title=error_message />
+        }),
+    )
+        .into_response()
+}
+
+async fn get_login_two_factor_setup(
+    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+    session: Session,
+) -> impl IntoResponse {
+    let Some(user_id) = pending_user_id(&session) else {
+        return Redirect::to(&routes::login()).into_response();
+    };
+
+    let enrollment = match auth_service
+        .begin_two_factor_enrollment(BeginTwoFactorEnrollmentInput { user_id })
+        .await
+    {
+        Ok(enrollment) => enrollment,
+        // Set up in another tab since the password step, so only the code is needed
+        Err(BeginTwoFactorEnrollmentFailure::AlreadyEnabled) => {
+            return Redirect::to(&routes::login_two_factor()).into_response()
+        }
+        Err(BeginTwoFactorEnrollmentFailure::NotFound) => {
+            return Redirect::to(&routes::login()).into_response()
+        }
+        Err(_) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to begin two-factor enrollment",
+            )
+                .into_response()
+        }
+    };
+
+    Html(html! {
+        <PageLayout header="Set Up Two-Factor Authentication">
+            <p class="mt-1 text-sm leading-6 text-gray-600">
+                Your role requires two-factor authentication. Set it up to finish logging in.
+            </p>
+            <TwoFactorEnrollmentSection
+                enrollment=enrollment
+                action=routes::login_two_factor_setup()
+            />
+        </PageLayout>
+    })
+    .into_response()
+}
+
+async fn post_login_two_factor_setup(
+    State(WebHtmxState {
+        auth_service,
+        worksite_service,
+        ..
+    }): State<WebHtmxState>,
+    mut auth: AuthSession<MongoUserStore>,
+    session: Session,
+    Form(form): Form<TwoFactorCodeFormData>,
+) -> impl IntoResponse {
+    let Some(user_id) = pending_user_id(&session) else {
+        return login_expired().into_response();
+    };
+
+    let result = auth_service
+        .confirm_two_factor_enrollment(ConfirmTwoFactorEnrollmentInput {
+            user_id: user_id.clone(),
+            code: form.code,
+        })
+        .await;
+
+    let backup_codes = match result {
+        Ok(backup_codes) => backup_codes,
+        Err(e) => return confirm_failure(e).await.into_response(),
+    };
+
+    let user = match auth_service.get_user(GetUserInput { user_id }).await {
+        Ok(Some(user)) => user,
+        // Removed while logging in
+        Ok(None) => return login_expired().into_response(),
+        Err(_) => return (StatusCode::BAD_REQUEST, "Login failed").into_response(),
+    };
+
+    if complete_login(&mut auth, &session, &worksite_service, &user)
+        .await
+        .is_err()
+    {
+        return (StatusCode::BAD_REQUEST, "Login failed").into_response();
+    }
+
+    let next = clear_pending_login(&session);
+
+    Html(html! {
+        <BackupCodes codes=backup_codes continue_url=next />
+    })
+    .into_response()
+}
+
+// Returns where the user was headed before the second step
+fn clear_pending_login(session: &Session) -> String {
+    session.remove_value(TWO_FACTOR_USER_ID);
+    session
+        .remove(TWO_FACTOR_NEXT)
+        .ok()
+        .unwrap_or(None)
+        .unwrap_or(routes::home())
+}
+
+fn finish_second_step(session: &Session) -> impl IntoResponse {
+    (
+        StatusCode::OK,
+        [
+            ("hx-redirect", clear_pending_login(session)),
+            ("hx-retarget", "body".to_string()),
+        ],
+    )
+}
+
+fn login_expired() -> impl IntoResponse {
+    (
+        StatusCode::OK,
+        [
+            ("hx-redirect", routes::login()),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
+
+async fn confirm_failure(failure: ConfirmTwoFactorEnrollmentFailure) -> impl IntoResponse {
+    let error_message = match failure {
+        ConfirmTwoFactorEnrollmentFailure::InvalidCode => {
+            "That code is not valid. Check the time on your device and try again."
+        }
+        ConfirmTwoFactorEnrollmentFailure::NotStarted => {
+            "Setup expired. Reload the page to start again."
+        }
+        _ => "Something went wrong setting up two-factor authentication.",
+    };
+
+    (
+        StatusCode::BAD_REQUEST,
+        Html(html! {
+            <Alert class="mb-4" title=error_message />
+        }),
+    )
+}
+
+async fn get_profile_two_factor(
+    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let ctx: crate::context::Context =
+        crate::context::context().expect("Unable to retrieve htmx context.");
+    let current_user = ctx.current_user.expect("Profile requires a logged in user");
+
+    let enrollment = auth_service
+        .begin_two_factor_enrollment(BeginTwoFactorEnrollmentInput {
+            user_id: current_user.id,
+        })
+        .await;
+
+    match enrollment {
+        Ok(enrollment) => Html(html! {
+            <PageLayout header="Set Up Two-Factor Authentication">
+                <PageContent>
+                    <Card>
+                        <div class="p-6">
+                            <TwoFactorEnrollmentSection
+                                enrollment=enrollment
+                                action=routes::profile_two_factor()
+                            />
+                        </div>
+                    </Card>
+                </PageContent>
+            </PageLayout>
+        })
+        .into_response(),
+        // Already enabled, nothing to set up
+        Err(_) => Redirect::to(&routes::profile()).into_response(),
+    }
+}
+
+async fn post_profile_two_factor(
+    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+    Form(form): Form<TwoFactorCodeFormData>,
+) -> impl IntoResponse {
+    let ctx: crate::context::Context =
+        crate::context::context().expect("Unable to retrieve htmx context.");
+    let current_user = ctx.current_user.expect("Profile requires a logged in user");
+
+    let result = auth_service
+        .confirm_two_factor_enrollment(ConfirmTwoFactorEnrollmentInput {
+            user_id: current_user.id,
+            code: form.code,
+        })
+        .await;
+
+    match result {
+        Ok(backup_codes) => Html(html! {
+            <BackupCodes codes=backup_codes continue_url=routes::profile() />
+        })
+        .into_response(),
+        Err(e) => confirm_failure(e).await.into_response(),
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct DisableTwoFactorFormData {
+    current_password: String,
+}
+
+async fn post_disable_two_factor(
+    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+    flash: Flash,
+    Form(form): Form<DisableTwoFactorFormData>,
+) -> impl IntoResponse {
+    let ctx: crate::context::Context =
+        crate::context::context().expect("Unable to retrieve htmx context.");
+    let current_user = ctx.current_user.expect("Profile requires a logged in user");
+
+    let result = auth_service
+        .disable_two_factor(DisableTwoFactorInput {
+            user_id: current_user.id,
+            current_password: form.current_password,
+        })
+        .await;
+
+    let error_message = match result {
+        Ok(_) => {
+            return (
+                StatusCode::OK,
+                flash.success("Two-factor authentication has been turned off."),
+                [
+                    ("hx-redirect", routes::profile()),
+                    ("hx-retarget", "body".into()),
+                ],
+            )
+                .into_response()
+        }
+        Err(DisableTwoFactorFailure::WrongPassword) => {
+            "Your current password is incorrect.".to_string()
+        }
+        Err(DisableTwoFactorFailure::RequiredForRole(role)) => {
+            format!("Two-factor authentication is required for {} users.", role)
+        }
+        Err(_) => "Something went wrong turning off two-factor authentication.".to_string(),
+    };
+
+    (
+        StatusCode::BAD_REQUEST,
+        Html(html! {
+            <DisableTwoFactorForm error=error_message />
+        }),
+    )
+        .into_response()
+}
+
+#[props]
+pub(crate) struct DisableTwoFactorFormProps {
+    #[builder(setter(into), default)]
+    error: String,
+}
+
+#[component]
+pub(crate) fn DisableTwoFactorForm(props: DisableTwoFactorFormProps) -> String {
+    html! {
+        <form
+            hx-ext="response-targets"
+            hx-post=routes::profile_two_factor_disable()
+            hx-target-4xx="this"
+            hx-swap="outerHTML"
+        >
+            {
+                if props.error.is_empty() {
+                    html! {
+                        <Alert kind=AlertKind::Success class="mt-6" title="Two-factor authentication is on." />
+                    }
+                } else {
+                    html! { <Alert class="mt-6" title=props.error /> }
+                }
+            }
+            <div class="pb-6">
+                <GridLayout class="mt-6">
+                    <GridCell span=3>
+                        <Label for_input="current_password">Current Password</Label>
+                        <TextInput name="current_password" autocomplete="current-password" input_type="password" />
+                    </GridCell>
+                </GridLayout>
+            </div>
+            <div class="flex items-center justify-end gap-x-6">
+                <Button kind="submit">Turn Off Two-Factor</Button>
+            </div>
+        </form>
+    }
+}
+
+#[props]
+struct TwoFactorEnrollmentSectionProps {
+    enrollment: TwoFactorEnrollment,
+
+    #[builder(setter(into))]
+    action: String,
+}
+
+#[component]
+fn TwoFactorEnrollmentSection(props: TwoFactorEnrollmentSectionProps) -> String {
+    html! {
+        <SecondaryHeader
+            title="📱 Scan the QR code"
+            subtitle="Scan this with your authenticator app, then enter the code it shows to confirm."
+        />
+        <div class="mt-6 flex flex-col items-center gap-4">
+            <div class="w-48 h-48">{qr_code_svg(&props.enrollment.otpauth_url)}</div>
+            <p class="text-sm text-gray-600">
+                "Can't scan it? Enter this key instead: "
+                <code class="font-mono">{props.enrollment.secret}</code>
+            </p>
+        </div>
+        <TwoFactorCodeForm action=props.action submit_label="Confirm" />
+    }
+}
+
+// Rendered server side so the secret never goes to a third party QR service
+fn qr_code_svg(otpauth_url: &str) -> String {
+    let svg = QrCode::new(otpauth_url.as_bytes())
+        .map(|code| code.render::<svg::Color>().min_dimensions(192, 192).build())
+        .unwrap_or_default();
+
+    // Drop the xml prolog so the svg can be inlined
+    match svg.find("<svg") {
+        Some(start) => svg[start..].to_string(),
+        None => svg,
+    }
+}
+
+#[props]
+struct BackupCodesProps {
+    codes: Vec<String>,
+
+    #[builder(setter(into))]
+    continue_url: String,
+}
+
+#[component]
+fn BackupCodes(props: BackupCodesProps) -> String {
+    html! {
+        <div class="mt-6">
+            <Alert
+                kind=AlertKind::Success
+                title="Two-factor authentication is on."
+            >
+                Save these backup codes somewhere safe. Each one can be used once if you lose your device, and they will not be shown again.
+            </Alert>
+            <ul class="mt-6 grid grid-cols-2 gap-2 font-mono text-sm">
+                {
+                    props
+                        .codes
+                        .iter()
+                        .map(|code| html! { <li>{code}</li> })
+                        .collect_fragment()
+                }
+            </ul>
+            <div class="mt-6 flex items-center justify-end gap-x-6">
+                <a href=props.continue_url class="text-sm font-semibold leading-6 text-indigo-600">
+                    "Continue →"
+                </a>
+            </div>
+        </div>
+    }
+}
@@ -1,7 +1,7 @@
 use axum::{
     extract::{self, State},
     response::{Html, IntoResponse},
-    routing::{delete, get},
+    routing::{delete, get, post},
     Form, Router,
 };
 use axum_flash::Flash;
@@ -12,9 +12,12 @@ use rscx::{component, html, props};
 use serde::Deserialize;
 
 use auth_service::{
-    create_user::CreateUserInput, get_user::GetUserInput, update_user::UpdateUserInput,
+    delete_user::DeleteUserInput,
+    models::{LoginAttempts, User},
+};
+use auth_service::{
+    get_user::GetUserInput, unlock_user::UnlockUserInput, update_user::UpdateUserInput,
 };
-use auth_service::{delete_user::DeleteUserInput, models::User};
 use mongo_user_repository::MongoUserStore;
 use web_client::server::{
     attrs::Attrs,
@@ -23,7 +26,10 @@ use web_client::server::{
     form::{Button, GridCell, GridLayout, Label, Select, SelectOption, TextInput},
     headers::SecondaryHeader,
     modal::{modal_target, Modal, ModalSize},
-    table::{Confirm, DeleteActionLink, TDVariant, Table, TableData, TableHeading},
+    table::{
+        ActionLink, Confirm, DeleteActionLink, TDVariant, Table, TableData, TableDataActions,
+        TableHeading,
+    },
 };
 
 use crate::{
@@ -38,15 +44,12 @@ use crate::{
 pub fn users_routes(state: WebHtmxState) -> Router {
     Router::new()
         .route(routes::USERS, get(get_users))
-        .route(
-            routes::USERS_CREATE_FORM,
-            get(get_create_form).post(post_create_form),
-        )
         .route(
             routes::USER_EDIT_FORM,
             get(get_edit_form).post(post_edit_form),
         )
         .route(routes::USER, delete(delete_user))
+        .route(routes::USER_UNLOCK, post(post_unlock_user))
         .with_state(state)
         .route_layer(permission_required!(
             MongoUserStore,
@@ -65,7 +68,16 @@ async fn get_users(State(state): State<WebHtmxState>) -> impl IntoResponse {
         .await
         .expect("Failed to get users");
 
-    let presenter = UsersTablePresenter::new(users);
+    let locked_accounts = state
+        .auth_service
+        .get_locked_accounts()
+        .await
+        .expect("Failed to get locked accounts");
+
+    let (pending_invites, users): (Vec<User>, Vec<User>) =
+        users.into_iter().partition(|user| user.is_pending_invite());
+
+    let presenter = UsersTablePresenter::new(users, locked_accounts);
     let view_model: UsersTableProps = presenter.into();
 
     Html(html! {
@@ -74,12 +86,12 @@ async fn get_users(State(state): State<WebHtmxState>) -> impl IntoResponse {
                 title: "Users".into(),
                 buttons: html! {
                     <PrimaryButton
-                        hx_get=routes::users_create_form()
+                        hx_get=routes::invites_create_form()
                         hx_target=modal_target()
                         hx_swap="beforeend"
-                        hx_push_url=routes::page_modal_from(routes::users_create_form())
+                        hx_push_url=routes::page_modal_from(routes::invites_create_form())
                     >
-                        Add New User
+                        Invite User
                     </PrimaryButton>
                 }
             }
@@ -89,22 +101,41 @@ async fn get_users(State(state): State<WebHtmxState>) -> impl IntoResponse {
                     <UsersTable users=view_model.users />
                 </Card>
             </PageContent>
+            {
+                if pending_invites.is_empty() {
+                    "".into()
+                } else {
+                    html! {
+                        <PageContent title="Pending invites">
+                            <Card>
+                                <PendingInvitesTable invites=pending_invites />
+                            </Card>
+                        </PageContent>
+                    }
+                }
+            }
         </PageLayout>
     })
 }
 
 struct UsersTablePresenter {
     users: Vec<User>, // TODO This should be the out model not domain model
+    locked_accounts: Vec<LoginAttempts>,
 }
 
 impl UsersTablePresenter {
-    fn new(users: Vec<User>) -> Self {
-        Self { users }
+    fn new(users: Vec<User>, locked_accounts: Vec<LoginAttempts>) -> Self {
+        Self {
+            users,
+            locked_accounts,
+        }
     }
 }
 
 impl From<UsersTablePresenter> for UsersTableProps {
     fn from(presenter: UsersTablePresenter) -> Self {
+        let locked_accounts = presenter.locked_accounts;
+
         Self {
             users: presenter
                 .users
@@ -112,6 +143,12 @@ impl From<UsersTablePresenter> for UsersTableProps {
                 .map(|user| UserVM {
                     edit_form_url: routes::user_edit_form(&user.id),
                     delete_url: routes::user(&user.id),
+                    unlock_url: routes::user_unlock(&user.id),
+                    locked_until: locked_accounts
+                        .iter()
+                        .find(|a| a.key == LoginAttempts::account_key(&user.email))
+                        .and_then(|a| a.locked_until)
+                        .map(|until| until.format("%H:%M UTC").to_string()),
                     email: user.email,
                     role: user.role.to_string(),
                 })
@@ -132,6 +169,7 @@ fn UsersTable(props: UsersTableProps) -> String {
             headings=vec![
                 TableHeading::title("Email"),
                 TableHeading::title("Role"),
+                TableHeading::title("Status"),
                 TableHeading::empty("Actions"),
             ]
             body=join_all(props
@@ -154,6 +192,12 @@ pub struct UserVM {
 
     // routes::user(&user.id)
     delete_url: String,
+
+    // routes::user_unlock(&user.id)
+    unlock_url: String,
+
+    // Set while the account is locked out from too many failed logins
+    locked_until: Option<String>,
     email: String,
     role: String,
 }
@@ -178,68 +222,106 @@ fn UserTableRow(props: UserTableRowProps) -> String {
         <TableData>
             {&props.user.role}
         </TableData>
+        <TableData>
+            {
+                match &props.user.locked_until {
+                    Some(until) => format!("Locked until {}", until),
+                    None => "Active".into(),
+                }
+            }
+        </TableData>
         <TableData variant=TDVariant::Last>
-            <DeleteActionLink
-                hx_delete=props.user.delete_url
-                hx_swap="outerHTML swap:1s"
-                hx_target="closest tr"
-                confirm=Confirm {
-                    title: "Remove User".into(),
-                    message: format!("Are you sure you want to remove this user: {}?", &props.user.email),
+            <TableDataActions>
+                {
+                    if props.user.locked_until.is_some() {
+                        html! {
+                            <ActionLink
+                                hx_post=props.user.unlock_url
+                                sr_text=&props.user.email
+                            >
+                                Unlock
+                            </ActionLink>
+                        }
+                    } else {
+                        "".into()
+                    }
                 }
-                sr_text=&props.user.email
-                show_loader_on_delete=true
-            >
-                Remove
-            </DeleteActionLink>
+                <DeleteActionLink
+                    hx_delete=props.user.delete_url
+                    hx_swap="outerHTML swap:1s"
+                    hx_target="closest tr"
+                    confirm=Confirm {
+                        title: "Remove User".into(),
+                        message: format!("Are you sure you want to remove this user: {}?", &props.user.email),
+                    }
+                    sr_text=&props.user.email
+                    show_loader_on_delete=true
+                >
+                    Remove
+                </DeleteActionLink>
+            </TableDataActions>
         </TableData>
     }
 }
 
-#[derive(Deserialize, Debug)]
-struct AddUserFormData {
-    email: String,
-    password: String,
-    role: String,
+#[props]
+struct PendingInvitesTableProps {
+    invites: Vec<User>,
 }
 
-async fn post_create_form(
-    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
-    flash: Flash,
-    Form(form): Form<AddUserFormData>,
-) -> impl IntoResponse {
-    auth_service
-        .create_user(CreateUserInput {
-            email: form.email,
-            password: form.password,
-            role: form.role,
-        })
-        .await
-        .expect("Failed to add user");
-    (
-        StatusCode::OK,
-        flash.success("User added successfully!"),
-        [
-            ("hx-redirect", routes::users()),
-            ("hx-retarget", "body".into()),
-        ],
-    )
-}
+#[component]
+fn PendingInvitesTable(props: PendingInvitesTableProps) -> String {
+    html! {
+        <Table
+            headings=vec![
+                TableHeading::title("Email"),
+                TableHeading::title("Role"),
+                TableHeading::title("Accept Link"),
+                TableHeading::empty("Actions"),
+            ]
+            body=join_all(props
+                .invites
+                .into_iter()
+                .map(|invite| async move {
+                    // Only pending invites are passed in, so the token is always set
+                    let accept_url = routes::accept_invite(&invite.invite_token.unwrap_or_default());
 
-async fn get_create_form() -> impl IntoResponse {
-    Html(html! {
-        <PageLayout
-            header="Add User"
-        >
-            <Modal size=ModalSize::MediumScreen>
-                <SecondaryHeader
-                    title="👤 Add User"
-                    subtitle="Enter user details below."
-                />
-                <UserForm action=routes::users_create_form() />
-            </Modal>
-        </PageLayout>
-    })
+                    html! {
+                        <TableData variant=TDVariant::First>
+                            {&invite.email}
+                        </TableData>
+                        <TableData>
+                            {invite.role.to_string()}
+                        </TableData>
+                        <TableData>
+                            <input
+                                type="text"
+                                readonly="readonly"
+                                class="w-full rounded-md border-0 py-1.5 text-gray-900 shadow-sm ring-1 ring-inset ring-gray-300 sm:text-sm sm:leading-6"
+                                value=accept_url
+                                onclick="this.select(); navigator.clipboard.writeText(window.location.origin + this.value)"
+                            />
+                        </TableData>
+                        <TableData variant=TDVariant::Last>
+                            <DeleteActionLink
+                                hx_delete=routes::invite(&invite.id)
+                                hx_swap="outerHTML swap:1s"
+                                hx_target="closest tr"
+                                confirm=Confirm {
+                                    title: "Revoke Invite".into(),
+                                    message: format!("Are you sure you want to revoke the invite for {}?", &invite.email),
+                                }
+                                sr_text=&invite.email
+                                show_loader_on_delete=true
+                            >
+                                Revoke
+                            </DeleteActionLink>
+                        </TableData>
+                    }
+                }))
+                .await
+        />
+    }
 }
 
 #[props]
@@ -252,9 +334,6 @@ pub struct UserFormProps {
 
     #[builder(setter(into), default)]
     role: String,
-
-    #[builder(setter(into), default = true)]
-    show_password: bool,
 }
 
 #[component]
@@ -268,18 +347,6 @@ pub fn UserForm(props: UserFormProps) -> String {
                         <TextInput name="email" autocomplete="email" input_type="email" value=props.email/>
                     </GridCell>
 
-                    {
-                        if props.show_password {
-                            html! {
-                                <GridCell span=3>
-                                    <Label for_input="password">Password</Label>
-                                    <TextInput name="password" autocomplete="password" input_type="password" />
-                                </GridCell>
-                            }
-                        }
-                        else { "".into() }
-                    }
-
                     <GridCell span=3>
                         <Label for_input="role">Role</Label>
                         <Select name="role">
@@ -319,6 +386,26 @@ async fn delete_user(
     }
 }
 
+async fn post_unlock_user(
+    extract::Path(user_id): extract::Path<String>,
+    State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
+    flash: Flash,
+) -> impl IntoResponse {
+    auth_service
+        .unlock_user(UnlockUserInput { user_id })
+        .await
+        .expect("Failed to unlock user");
+
+    (
+        StatusCode::OK,
+        flash.success("User unlocked!"),
+        [
+            ("hx-redirect", routes::users()),
+            ("hx-retarget", "body".into()),
+        ],
+    )
+}
+
 async fn get_edit_form(
     extract::Path(user_id): extract::Path<String>,
     State(WebHtmxState { auth_service, .. }): State<WebHtmxState>,
@@ -345,7 +432,6 @@ async fn get_edit_form(
                     action=routes::user_edit_form(&user.id)
                     email=user.email.clone()
                     role=user.role.to_string()
-                    show_password=false
                 />
             </Modal>
         </PageLayout>
@@ -7,8 +7,8 @@ use axum::{
 use axum_flash::{Flash, IncomingFlashes};
 use axum_macros::debug_handler;
 use futures::future::join_all;
-use http::StatusCode;
-use rscx::{component, html, props, CollectFragment};
+use http::{header, StatusCode};
+use rscx::{component, html, props, CollectFragment, CollectFragmentAsync};
 use serde::Deserialize;
 use std::collections::HashMap;
 use validator::{Validate, ValidationErrorsKind};
@@ -16,10 +16,10 @@ use validator::{Validate, ValidationErrorsKind};
 use web_client::server::{
     alert::Alert,
     attrs::Attrs,
-    button::PrimaryButton,
+    button::{PrimaryButton, SecondaryButton},
     card::{Card, CardContent, CardFooter},
     flyout::Flyout,
-    form::{Button, GridCell, TextInput},
+    form::{Button, GridCell, Select, SelectOption, TextInput},
     headers::SecondaryHeader,
     modal::{modal_target, Modal, ModalSize},
     notification::NotificationFlashes,
@@ -27,11 +27,13 @@ use web_client::server::{
 };
 use worksite_service::{
     add_worker::AddWorkerInput,
+    export_workers::ExportWorkersInput,
     filter_workers::FilterWorkersInput,
+    get_custom_fields::GetCustomFieldsInput,
     get_worker::GetWorkerInput,
     get_workers::GetWorkersInput,
     get_worksite::GetWorksiteInput,
-    models::{Tag, Worker, Worksite},
+    models::{CustomField, Tag, Worker, Worksite},
     update_worker::UpdateWorkerInput,
 };
 
@@ -41,9 +43,10 @@ use crate::{
         page_content::PageContent,
         worker_profile_fieldset::{WorkerProfileFieldset, WorkerProfileFormData},
     },
+    resources::activity::ActivityFeed,
     routes::{
         self, worker, worker_profile, workers, workers_create_form, WORKER, WORKERS,
-        WORKERS_CREATE_FORM, WORKER_PROFILE,
+        WORKERS_CREATE_FORM, WORKERS_EXPORT, WORKER_PROFILE,
     },
     state::WebHtmxState,
 };
@@ -52,6 +55,7 @@ pub fn workers_routes(state: WebHtmxState) -> Router {
     Router::new()
         .route(WORKERS, get(get_workers))
         .route(WORKERS, post(filter_workers))
+        .route(WORKERS_EXPORT, get(export_workers))
         .route(WORKER, get(get_worker_details))
         .route(WORKER_PROFILE, post(post_worker_profile_form))
         .route(
@@ -64,6 +68,9 @@ pub fn workers_routes(state: WebHtmxState) -> Router {
 #[derive(Deserialize)]
 struct FilterWorkersFormData {
     filter: String,
+    // Blank for workers under any supervisor
+    #[serde(default)]
+    supervisor_id: String,
 }
 
 #[debug_handler]
@@ -87,6 +94,7 @@ async fn filter_workers(
         .filter_workers(FilterWorkersInput {
             worksite_id: worksite_id.clone(),
             filter: form_data.filter,
+            supervisor_id: Some(form_data.supervisor_id).filter(|id| !id.is_empty()),
         })
         .await
         .expect("Failed to get worker");
@@ -98,8 +106,14 @@ async fn filter_workers(
     })
 }
 
+#[derive(Deserialize)]
+struct WorkersQuery {
+    supervisor_id: Option<String>,
+}
+
 async fn get_workers(
     extract::Path(worksite_id): extract::Path<String>,
+    Query(query): Query<WorkersQuery>,
     flashes: IncomingFlashes,
     State(state): State<WebHtmxState>,
 ) -> impl IntoResponse {
@@ -113,15 +127,27 @@ async fn get_workers(
         .ok_or("Worksite not found")
         .unwrap();
 
-    let workers = state
-        .worksite_service
-        .get_workers(GetWorkersInput {
-            worksite_id: worksite_id.clone(),
-        })
-        .await
-        .expect("Failed to get worker");
+    let workers = match &query.supervisor_id {
+        Some(supervisor_id) => state
+            .worksite_service
+            .filter_workers(FilterWorkersInput {
+                worksite_id: worksite_id.clone(),
+                filter: "".into(),
+                supervisor_id: Some(supervisor_id.clone()),
+            })
+            .await
+            .expect("Failed to filter workers"),
+        None => state
+            .worksite_service
+            .get_workers(GetWorkersInput {
+                worksite_id: worksite_id.clone(),
+            })
+            .await
+            .expect("Failed to get worker"),
+    };
 
     let worksite_name = worksite.name.clone();
+    let supervisors = worksite.get_supervisors();
 
     Html(html! {
         <PageLayout
@@ -136,8 +162,43 @@ async fn get_workers(
                             hx_post=routes::workers(&worksite_id)
                             hx_trigger="input changed delay:500ms, filter"
                             hx_target="table"
+                            hx_include="[name='supervisor_id']"
                         />
                     </GridCell>
+                    <GridCell>
+                        <Select
+                            name="supervisor_id"
+                            hx_post=routes::workers(&worksite_id)
+                            hx_trigger="change"
+                            hx_target="table"
+                            hx_include="[name='filter']"
+                        >
+                            <SelectOption value="" selected=query.supervisor_id.is_none()>
+                                Any supervisor
+                            </SelectOption>
+                            {
+                                supervisors
+                                    .iter()
+                                    .map(|supervisor| {
+                                        let selected =
+                                            query.supervisor_id.as_ref() == Some(&supervisor.id);
+
+                                        async move {
+                                            html! {
+                                                <SelectOption value=supervisor.id.clone() selected=selected>
+                                                    {format!("Under {}", supervisor.full_name())}
+                                                </SelectOption>
+                                            }
+                                        }
+                                    })
+                                    .collect_fragment_async()
+                                    .await
+                            }
+                        </Select>
+                    </GridCell>
+                    <SecondaryButton tag="a" href=routes::workers_export(&worksite_id)>
+                        Export CSV
+                    </SecondaryButton>
                     <PrimaryButton
                         hx_get=workers_create_form(&worksite_id)
                         hx_target=modal_target()
@@ -159,6 +220,29 @@ async fn get_workers(
     })
 }
 
+// Downloads every worker, including their custom fields, as a CSV
+async fn export_workers(
+    extract::Path(worksite_id): extract::Path<String>,
+    State(state): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let csv = state
+        .worksite_service
+        .export_workers(ExportWorkersInput { worksite_id })
+        .await
+        .expect("Failed to export workers");
+
+    (
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"workers.csv\"",
+            ),
+        ],
+        csv,
+    )
+}
+
 async fn get_worker_details(
     extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
     State(state): State<WebHtmxState>,
@@ -176,10 +260,19 @@ async fn get_worker_details(
 
     let full_name = worker.full_name();
 
+    let custom_fields = state
+        .worksite_service
+        .get_custom_fields(GetCustomFieldsInput {
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get custom fields");
+
     let profile_form_data = WorkerProfileFormData {
         first_name: worker.first_name.clone(),
         last_name: worker.last_name.clone(),
         email: worker.email.clone(),
+        phone: worker.phone.clone(),
         street_address: worker
             .address
             .clone()
@@ -196,7 +289,22 @@ async fn get_worker_details(
             .clone()
             .map(|a| a.postal_code)
             .unwrap_or("".into()),
-    };
+        job_title: worker.employment.job_title.clone(),
+        department: worker.employment.department.clone(),
+        hire_date: worker
+            .employment
+            .hire_date
+            .map(|hire_date| hire_date.to_string())
+            .unwrap_or_default(),
+        pay_rate: worker.employment.pay_rate.clone(),
+        employment_type: worker
+            .employment
+            .employment_type
+            .map(|employment_type| employment_type.to_string())
+            .unwrap_or_default(),
+        custom: HashMap::new(),
+    }
+    .with_custom_values(&custom_fields, &worker.custom_values);
 
     Html(html! {
         <Flyout title=format!("Worker Detail: {}", &full_name)>
@@ -206,14 +314,51 @@ async fn get_worker_details(
                         route=routes::assigned_tags_form(&worksite_id, &worker_id)
                     />
                     <SectionDivider />
+                    <LoadingWorkerSection
+                        route=routes::worker_committee(&worksite_id, &worker_id)
+                    />
+                    <SectionDivider />
+                    <LoadingWorkerSection
+                        route=routes::worker_card(&worksite_id, &worker_id)
+                    />
+                    <SectionDivider />
+                    <LoadingWorkerSection
+                        route=routes::worker_eligibility(&worksite_id, &worker_id)
+                    />
+                    <SectionDivider />
+                    <LoadingWorkerSection
+                        route=routes::worker_status(&worksite_id, &worker_id)
+                    />
+                    <SectionDivider />
+                    <LoadingWorkerSection
+                        route=routes::worker_supervisor(&worksite_id, &worker_id)
+                    />
+                    <SectionDivider />
+                    <LoadingWorkerSection
+                        route=routes::worker_relationships(&worksite_id, &worker_id)
+                    />
+                    <SectionDivider />
                     <LoadingWorkerSection
                         route=routes::assessments(&worksite_id, &worker_id)
                     />
                     <SectionDivider />
+                    <LoadingWorkerSection
+                        route=routes::conversations(&worksite_id, &worker_id)
+                    />
+                    <SectionDivider />
+                    <LoadingWorkerSection
+                        route=routes::worker_tasks(&worksite_id, &worker_id)
+                    />
+                    <SectionDivider />
+                    <WorkerActivitySection
+                        route=routes::worker_activity(&worksite_id, &worker_id)
+                    />
+                    <SectionDivider />
                     <WorkerProfileSection
                         worker_id=worker_id.clone()
                         worksite_id=worksite_id.clone()
                         profile_form_data=profile_form_data
+                        custom_fields=custom_fields
                     />
                 </div>
             </div>
@@ -229,11 +374,20 @@ struct CreateFormQuery {
 async fn get_worker_create_form(
     extract::Path(wallchart_id): extract::Path<String>,
     Query(query): Query<CreateFormQuery>,
+    State(state): State<WebHtmxState>,
 ) -> impl IntoResponse {
+    let custom_fields = state
+        .worksite_service
+        .get_custom_fields(GetCustomFieldsInput {
+            worksite_id: wallchart_id.clone(),
+        })
+        .await
+        .expect("Failed to get custom fields");
+
     Html(html! {
         <PageLayout header="Add Worker">
             <ConditionalCreateFormModal disabled=query.content.is_some()>
-                <WorkerForm action=workers_create_form(&wallchart_id) />
+                <WorkerForm action=workers_create_form(&wallchart_id) custom_fields=custom_fields />
             </ConditionalCreateFormModal>
         </PageLayout>
     })
@@ -267,16 +421,15 @@ async fn post_worker(
     extract::Path(wallchart_id): extract::Path<String>,
     Form(form): Form<WorkerProfileFormData>,
 ) -> Response {
+    let custom_fields = worksite_service
+        .get_custom_fields(GetCustomFieldsInput {
+            worksite_id: wallchart_id.clone(),
+        })
+        .await
+        .expect("Failed to get custom fields");
+
     if let Err(e) = form.validate() {
-        let profile_form_data = WorkerProfileFormData {
-            first_name: form.first_name.clone(),
-            last_name: form.last_name.clone(),
-            email: form.email.clone(),
-            street_address: form.street_address.clone(),
-            city: form.city.clone(),
-            region: form.region.clone(),
-            postal_code: form.postal_code.clone(),
-        };
+        let profile_form_data = form.clone();
 
         return (
             StatusCode::BAD_REQUEST,
@@ -289,18 +442,47 @@ async fn post_worker(
                     action=workers_create_form(&wallchart_id)
                     form_data=profile_form_data
                     errors=e.errors().to_owned()
+                    custom_fields=custom_fields
                 />
             }),
         )
             .into_response();
     }
 
-    worksite_service
+    let custom_values = match form.custom_values(&custom_fields) {
+        Ok(custom_values) => custom_values,
+        Err(message) => {
+            let profile_form_data = form.clone();
+
+            return (
+                StatusCode::BAD_REQUEST,
+                Html(html! {
+                    <Alert
+                        class="mt-4"
+                        title="Oops! There was a problem with your submission."
+                    >
+                        {message}
+                    </Alert>
+                    <WorkerForm
+                        action=workers_create_form(&wallchart_id)
+                        form_data=profile_form_data
+                        custom_fields=custom_fields
+                    />
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let likely_duplicates = worksite_service
         .add_worker(AddWorkerInput {
             worksite_id: wallchart_id.clone(),
+            employment: form.employment(),
+            custom_values,
             first_name: form.first_name,
             last_name: form.last_name,
             email: form.email,
+            phone: form.phone,
             street_address: form.street_address,
             city: form.city,
             region: form.region,
@@ -309,9 +491,21 @@ async fn post_worker(
         .await
         .expect("Failed to add worker");
 
+    let flash = match likely_duplicates.is_empty() {
+        true => flash.success("Worker added successfully!"),
+        false => flash.warning(format!(
+            "Worker added, but they look a lot like {}. Check the duplicates page.",
+            likely_duplicates
+                .iter()
+                .map(|worker| worker.full_name())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )),
+    };
+
     (
         StatusCode::OK,
-        flash.success("Worker added successfully!"),
+        flash,
         [
             ("hx-redirect", workers(&wallchart_id)),
             ("hx-retarget", "body".into()),
@@ -328,13 +522,34 @@ async fn post_worker_profile_form(
     extract::Path((worksite_id, worker_id)): extract::Path<(String, String)>,
     Form(form): Form<WorkerProfileFormData>,
 ) -> impl IntoResponse {
+    let custom_fields = worksite_service
+        .get_custom_fields(GetCustomFieldsInput {
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get custom fields");
+
+    let custom_values = match form.custom_values(&custom_fields) {
+        Ok(custom_values) => custom_values,
+        Err(message) => {
+            return (
+                StatusCode::OK,
+                flash.error(message),
+                [("hx-redirect", "/wallchart"), ("hx-retarget", "body")],
+            )
+        }
+    };
+
     worksite_service
         .update_worker(UpdateWorkerInput {
             worker_id,
             worksite_id,
+            employment: form.employment(),
+            custom_values,
             first_name: form.first_name,
             last_name: form.last_name,
             email: form.email,
+            phone: form.phone,
             street_address: form.street_address,
             city: form.city,
             region: form.region,
@@ -362,6 +577,7 @@ fn WorkersTable(props: WorkersTableProps) -> String {
         <Table
             headings=vec![
                 TableHeading::title("Name"),
+                TableHeading::title("Job"),
                 TableHeading::title("Last Assessment"),
                 TableHeading::title("Tags"),
             ]
@@ -401,6 +617,15 @@ pub fn WorkerTableRow(props: WorkerTableRowProps) -> String {
                 {format!("{} {}", props.worker.first_name, props.worker.last_name)}
             </button>
         </TableData>
+        <TableData>
+            {
+                match (props.worker.employment.job_title.as_str(), props.worker.employment.department.as_str()) {
+                    ("", department) => department.to_string(),
+                    (job_title, "") => job_title.to_string(),
+                    (job_title, department) => format!("{}, {}", job_title, department),
+                }
+            }
+        </TableData>
         <TableData>
             {props.worker.last_assessment().map(|assessment| assessment.value).unwrap_or(0)}
         </TableData>
@@ -425,6 +650,9 @@ struct WorkerFormProps {
 
     #[builder(default=HashMap::new())]
     errors: HashMap<&'static str, ValidationErrorsKind>,
+
+    #[builder(default)]
+    custom_fields: Vec<CustomField>,
 }
 
 #[component]
@@ -440,6 +668,7 @@ fn WorkerForm(props: WorkerFormProps) -> String {
                 <WorkerProfileFieldset
                     form=props.form_data
                     errors=props.errors
+                    custom_fields=props.custom_fields
                 />
             </div>
             <div class="mt-6 flex items-center justify-end gap-x-6">
@@ -460,6 +689,7 @@ struct WorkerProfileSectionProps {
     worksite_id: String,
     worker_id: String,
     profile_form_data: WorkerProfileFormData,
+    custom_fields: Vec<CustomField>,
 }
 
 #[component]
@@ -474,7 +704,10 @@ fn WorkerProfileSection(props: WorkerProfileSectionProps) -> String {
                             title="👤 Profile"
                             subtitle="Update worker profile details below."
                         />
-                        <WorkerProfileFieldset form=props.profile_form_data />
+                        <WorkerProfileFieldset
+                            form=props.profile_form_data
+                            custom_fields=props.custom_fields
+                        />
                     </CardContent>
                     <CardFooter>
                         <PrimaryButton
@@ -489,6 +722,30 @@ fn WorkerProfileSection(props: WorkerProfileSectionProps) -> String {
     }
 }
 
+#[props]
+struct WorkerActivitySectionProps {
+    route: String,
+}
+
+#[component]
+fn WorkerActivitySection(props: WorkerActivitySectionProps) -> String {
+    html! {
+        <section>
+            <Card>
+                <CardContent padded=true>
+                    <SecondaryHeader
+                        title="🕒 Activity"
+                        subtitle="What has changed for this worker, newest first."
+                    />
+                    <div class="mt-4">
+                        <ActivityFeed route=props.route />
+                    </div>
+                </CardContent>
+            </Card>
+        </section>
+    }
+}
+
 #[props]
 struct LoadingWorkerSectionProps {
     route: String,
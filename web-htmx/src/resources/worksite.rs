@@ -1,30 +1,44 @@
 use axum::{
-    extract::{self, State},
-    response::{Html, IntoResponse, Redirect},
+    extract::{self, Query, State},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        Html, IntoResponse, Redirect,
+    },
     routing::{get, post},
     Form, Router,
 };
 use axum_flash::{Flash, IncomingFlashes};
 use axum_login::tower_sessions::Session;
 use axum_macros::debug_handler;
+use futures::stream::{self, StreamExt};
 use http::StatusCode;
 use rscx::{
     component, html, props, typed_builder::TypedBuilder, CollectFragment, CollectFragmentAsync,
 };
 use serde::Deserialize;
+use std::{collections::HashMap, convert::Infallible, sync::Arc};
+use tokio::sync::broadcast::{error::RecvError, Receiver};
 
-use auth_service::models::UserPermission;
+use auth_service::{get_user::GetUserInput, models::UserPermission};
+use task_service::service::TaskService;
 use web_client::server::{
     attrs::Attrs,
     button::{PrimaryButton, SecondaryButton},
-    card::Card,
-    form::{Button, GridCell, GridLayout, Label, TextInput},
+    card::{Card, CardFooter},
+    form::{Button, GridCell, GridLayout, Label, Select, SelectOption, TextInput},
     headers::SecondaryHeader,
     modal::{modal_target, Modal, ModalSize},
     notification::NotificationFlashes,
 };
 use worksite_service::{
-    create_worksite::CreateWorksiteInput, get_worksite::GetWorksiteInput, models::Worksite,
+    create_worksite::CreateWorksiteInput,
+    events::DomainEvent,
+    get_actions::GetActionsInput,
+    get_card_report::GetCardReportInput,
+    get_committee_report::GetCommitteeReportInput,
+    get_worksite::GetWorksiteInput,
+    models::{EmploymentStatus, ExclusionReason, ParticipationStatus, Worksite},
+    service::WorksiteService,
     update_worksite::UpdateWorksiteInput,
 };
 
@@ -36,6 +50,11 @@ use crate::{
         permission_required::PermissionRequired,
         simple_form::{SimpleForm, SimpleFormData},
     },
+    context::{provide_context, Context},
+    resources::{
+        actions::ParticipationBadge, activity::ActivityFeed, cards::CardProgress,
+        committee::CommitteeLists, tasks::count_overdue_tasks,
+    },
     routes,
     state::WebHtmxState,
 };
@@ -44,6 +63,7 @@ pub fn worksite_routes(state: WebHtmxState) -> Router {
     Router::new()
         .route(routes::WALLCHART, get(get_wallchart_page))
         .route(routes::WORKSITE, get(get_worksite))
+        .route(routes::WORKSITE_UPDATES, get(get_worksite_updates))
         .route(
             routes::WORKSITE_EDIT_FORM,
             get(get_worksite_edit_form).post(post_worksite_edit_form),
@@ -55,11 +75,34 @@ pub fn worksite_routes(state: WebHtmxState) -> Router {
 
 struct WorksitePresenter {
     worksite: Worksite, // TODO This should be the out model not domain model
+    // Worker ids to their number of overdue follow-ups
+    overdue_tasks: HashMap<String, usize>,
+    // Worker ids to how they took part in the action shown on the wallchart, if any
+    participation: HashMap<String, ParticipationStatus>,
 }
 
 impl WorksitePresenter {
-    fn new(worksite: Worksite) -> Self {
-        Self { worksite }
+    fn new(
+        worksite: Worksite,
+        overdue_tasks: HashMap<String, usize>,
+        action_id: Option<&String>,
+    ) -> Self {
+        let participation = action_id
+            .and_then(|action_id| worksite.get_action(action_id.clone()))
+            .map(|action| {
+                action
+                    .participation
+                    .into_iter()
+                    .map(|p| (p.worker_id, p.status))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            worksite,
+            overdue_tasks,
+            participation,
+        }
     }
 
     pub fn get_worksite_name(&self) -> String {
@@ -71,6 +114,8 @@ impl From<WorksitePresenter> for WallchartTableProps {
     fn from(presenter: WorksitePresenter) -> Self {
         let worksite_id = presenter.worksite.id.clone();
         let worksite = presenter.worksite;
+        let overdue_tasks = presenter.overdue_tasks;
+        let participation = presenter.participation;
         let locations = worksite.locations.clone();
 
         let locations = locations
@@ -79,6 +124,7 @@ impl From<WorksitePresenter> for WallchartTableProps {
                 let location_id = location.id.clone();
 
                 let add_shift_url = routes::shifts_create_form(&worksite_id, &location_id);
+                let org_chart_url = routes::location_org_chart(&worksite_id, &location_id);
 
                 let shifts = location
                     .shifts
@@ -105,9 +151,17 @@ impl From<WorksitePresenter> for WallchartTableProps {
 
                                 WorkerRowWorker {
                                     full_name: worker.full_name(),
+                                    committee_member: worker.committee_member,
                                     last_assessment: worker
                                         .last_assessment()
                                         .map(|a| a.value.to_string()),
+                                    overdue_tasks: overdue_tasks
+                                        .get(worker_id)
+                                        .copied()
+                                        .unwrap_or_default(),
+                                    participation: participation.get(worker_id).copied(),
+                                    exclusion_reason: worker.eligibility.exclusion_reason(),
+                                    status: worker.status,
                                     shift_assignment_url: assignment_url,
                                     tags: tags
                                         .into_iter()
@@ -133,6 +187,7 @@ impl From<WorksitePresenter> for WallchartTableProps {
                     id: location.id,
                     name: location.name,
                     add_shift_url,
+                    org_chart_url,
                     shifts,
                 }
             })
@@ -145,11 +200,38 @@ impl From<WorksitePresenter> for WallchartTableProps {
     }
 }
 
+#[derive(Deserialize, Debug, Clone)]
+struct WallchartQuery {
+    // A user id, to only show the workers in their turf
+    organizer: Option<String>,
+    // An action id, to highlight who took part in it
+    action: Option<String>,
+}
+
+impl WallchartQuery {
+    // Empty when nothing is filtered, otherwise e.g. "?organizer=1&action=2"
+    fn to_query_string(&self) -> String {
+        let params: Vec<String> = [("organizer", &self.organizer), ("action", &self.action)]
+            .into_iter()
+            .filter_map(|(name, value)| Some(format!("{}={}", name, value.as_ref()?)))
+            .collect();
+
+        match params.is_empty() {
+            true => "".into(),
+            false => format!("?{}", params.join("&")),
+        }
+    }
+}
+
 async fn get_worksite(
     flashes: IncomingFlashes,
     extract::Path(worksite_id): extract::Path<String>,
+    Query(query): Query<WallchartQuery>,
     State(WebHtmxState {
-        worksite_service, ..
+        worksite_service,
+        task_service,
+        auth_service,
+        ..
     }): State<WebHtmxState>,
 ) -> impl IntoResponse {
     let worksite = worksite_service
@@ -182,15 +264,157 @@ async fn get_worksite(
         }
     };
 
-    let presenter = WorksitePresenter::new(worksite);
+    let organizer = match &query.organizer {
+        Some(user_id) => auth_service
+            .get_user(GetUserInput {
+                user_id: user_id.clone(),
+            })
+            .await
+            .expect("Failed to get organizer"),
+        None => None,
+    };
+    let worksite = match &query.organizer {
+        Some(user_id) => worksite.for_turf(user_id),
+        None => worksite,
+    };
+    let updates_url = format!(
+        "{}{}",
+        routes::worksite_updates(&worksite_id),
+        query.to_query_string()
+    );
+    let wallchart_url = |query: WallchartQuery| {
+        format!(
+            "{}{}",
+            routes::worksite(&worksite_id),
+            query.to_query_string()
+        )
+    };
+
+    let actions = worksite_service
+        .get_actions(GetActionsInput {
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get actions");
+    let action = query
+        .action
+        .as_ref()
+        .and_then(|action_id| worksite.get_action(action_id.clone()));
+
+    let cards = worksite_service
+        .get_card_report(GetCardReportInput {
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get card report");
+
+    let committee = worksite_service
+        .get_committee_report(GetCommitteeReportInput {
+            worksite_id: worksite_id.clone(),
+        })
+        .await
+        .expect("Failed to get committee report");
+
+    let ctx: Context = crate::context::context().expect("Unable to retrieve htmx context.");
+    let current_user_id = ctx.current_user.map(|user| user.id).unwrap_or_default();
+
+    let overdue_tasks = count_overdue_tasks(&task_service, &worksite_id).await;
+    let presenter = WorksitePresenter::new(worksite, overdue_tasks, query.action.as_ref());
     let worksite_name = presenter.get_worksite_name();
     let view_model: WallchartTableProps = presenter.into();
 
+    let title = match &organizer {
+        Some(organizer) => format!("Wallchart: {} ({}'s turf)", worksite_name, organizer.name()),
+        None => format!("Wallchart: {}", worksite_name),
+    };
+    let title = match &action {
+        Some(action) => format!("{} – {}", title, action.description),
+        None => title,
+    };
+
     let html = html! {
         <PageLayout
             header=PageHeader::Toolbar {
-                title: format!("Wallchart: {}", worksite_name),
+                title,
                 buttons: html! {
+                    {
+                        match &query.organizer {
+                            Some(_) => html! {
+                                <SecondaryButton
+                                    tag="a"
+                                    href=wallchart_url(WallchartQuery {
+                                        organizer: None,
+                                        ..query.clone()
+                                    })
+                                >
+                                    Show Everyone
+                                </SecondaryButton>
+                            },
+                            None => html! {
+                                <SecondaryButton
+                                    tag="a"
+                                    href=wallchart_url(WallchartQuery {
+                                        organizer: Some(current_user_id.clone()),
+                                        ..query.clone()
+                                    })
+                                >
+                                    Show My Turf
+                                </SecondaryButton>
+                            },
+                        }
+                    }
+                    <div>
+                        <Select
+                            name="action"
+                            attrs=Attrs::with("onchange", "window.location = this.value".into())
+                        >
+                            <SelectOption
+                                value=wallchart_url(WallchartQuery {
+                                    action: None,
+                                    ..query.clone()
+                                })
+                            >
+                                No action overlay
+                            </SelectOption>
+                            {
+                                actions
+                                    .iter()
+                                    .map(|action| {
+                                        let value = wallchart_url(WallchartQuery {
+                                            action: Some(action.id.clone()),
+                                            ..query.clone()
+                                        });
+                                        let selected = query.action.as_ref() == Some(&action.id);
+                                        let label = format!("{} ({})", action.description, action.held_on);
+
+                                        async move {
+                                            html! {
+                                                <SelectOption value=value selected=selected>
+                                                    {label}
+                                                </SelectOption>
+                                            }
+                                        }
+                                    })
+                                    .collect_fragment_async()
+                                    .await
+                            }
+                        </Select>
+                    </div>
+                    {
+                        match &action {
+                            Some(action) => html! {
+                                <SecondaryButton
+                                    hx_get=routes::action_participation(&worksite_id, &action.id)
+                                    hx_target=modal_target()
+                                    hx_swap="beforeend"
+                                    hx_push_url=routes::page_modal_from(routes::action_participation(&worksite_id, &action.id))
+                                >
+                                    Record Participation
+                                </SecondaryButton>
+                            },
+                            None => "".into(),
+                        }
+                    }
                     <SecondaryButton
                         hx_get=routes::locations_create_form(&worksite_id)
                         hx_target=modal_target()
@@ -220,10 +444,36 @@ async fn get_worksite(
         >
             <NotificationFlashes flashes=flashes.clone() />
             <PageContent title="Manage your worksite and more">
+                <div
+                    hx-ext="sse"
+                    sse-connect=updates_url
+                    sse-swap=WALLCHART_EVENT
+                >
                     <WallchartTable
                         worksite_id=view_model.worksite_id.clone()
                         locations=view_model.locations.clone()
                     />
+                </div>
+            </PageContent>
+            <PageContent title="Authorization cards">
+                <Card>
+                    <CardProgress report=cards />
+                    <CardFooter>
+                        <SecondaryButton tag="a" href=routes::cards(&worksite_id)>
+                            Unsigned Workers
+                        </SecondaryButton>
+                    </CardFooter>
+                </Card>
+            </PageContent>
+            <PageContent title="Organizing committee">
+                <CommitteeLists worksite_id=worksite_id.clone() leaders=committee.leaders />
+            </PageContent>
+            <PageContent title="Recent activity">
+                <Card>
+                    <div class="px-6">
+                        <ActivityFeed route=routes::worksite_activity(&worksite_id) />
+                    </div>
+                </Card>
             </PageContent>
         </PageLayout>
     };
@@ -231,6 +481,170 @@ async fn get_worksite(
     (flashes, Html(html))
 }
 
+// Sent when locations or shifts come or go, swapping in the whole wallchart
+const WALLCHART_EVENT: &str = "wallchart";
+
+fn shift_event(shift_id: &String) -> String {
+    format!("shift-{}", shift_id)
+}
+
+struct RenderedWallchart {
+    wallchart: String,
+    // Shift ids with their rendered ShiftRow, in page order
+    shifts: Vec<(String, String)>,
+}
+
+async fn render_wallchart(
+    worksite_service: &WorksiteService,
+    task_service: &TaskService,
+    worksite_id: &str,
+    query: &WallchartQuery,
+) -> Option<RenderedWallchart> {
+    let worksite = worksite_service
+        .get_worksite(GetWorksiteInput {
+            id: worksite_id.to_string(),
+        })
+        .await
+        .ok()??;
+    let worksite = match &query.organizer {
+        Some(user_id) => worksite.for_turf(user_id),
+        None => worksite,
+    };
+    let overdue_tasks = count_overdue_tasks(task_service, worksite_id).await;
+    let view_model: WallchartTableProps =
+        WorksitePresenter::new(worksite, overdue_tasks, query.action.as_ref()).into();
+
+    let mut shifts = vec![];
+    for location in &view_model.locations {
+        for shift in &location.shifts {
+            let shift_row = html! {
+                <ShiftRow
+                    assign_worker_url=routes::shift_assignments_create_form(
+                        &view_model.worksite_id,
+                        &location.id,
+                        &shift.id,
+                    )
+                    shift_name=shift.name.clone()
+                    workers=shift.workers.clone()
+                />
+            };
+            shifts.push((shift.id.clone(), shift_row));
+        }
+    }
+
+    let wallchart = html! {
+        <WallchartTable
+            worksite_id=view_model.worksite_id
+            locations=view_model.locations
+        />
+    };
+
+    Some(RenderedWallchart { wallchart, shifts })
+}
+
+/**
+* Follows worksite events for one open wallchart. Each event re-renders the wallchart
+* and only the shifts that look different are sent to the browser.
+*/
+struct WallchartUpdates {
+    worksite_id: String,
+    query: WallchartQuery,
+    worksite_service: Arc<WorksiteService>,
+    task_service: Arc<TaskService>,
+    // Rendering happens after the request has finished, so it brings its own context
+    context: Context,
+    events: Receiver<DomainEvent>,
+    shifts: Vec<(String, String)>,
+}
+
+impl WallchartUpdates {
+    async fn next_events(&mut self) -> Option<Vec<Event>> {
+        loop {
+            match self.events.recv().await {
+                Ok(event) if event.worksite_id != self.worksite_id => continue,
+                // Missed events could be for this worksite, so check anyway
+                Ok(_) | Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => return None,
+            }
+
+            let rendered = provide_context(
+                self.context.clone(),
+                render_wallchart(
+                    &self.worksite_service,
+                    &self.task_service,
+                    &self.worksite_id,
+                    &self.query,
+                ),
+            )
+            .await?;
+
+            let same_shifts = rendered.shifts.len() == self.shifts.len()
+                && rendered
+                    .shifts
+                    .iter()
+                    .zip(self.shifts.iter())
+                    .all(|((id, _), (previous_id, _))| id == previous_id);
+
+            let events: Vec<Event> = if same_shifts {
+                rendered
+                    .shifts
+                    .iter()
+                    .zip(self.shifts.iter())
+                    .filter(|((_, html), (_, previous_html))| html != previous_html)
+                    .map(|((id, html), _)| Event::default().event(shift_event(id)).data(html))
+                    .collect()
+            } else {
+                vec![Event::default()
+                    .event(WALLCHART_EVENT)
+                    .data(rendered.wallchart)]
+            };
+
+            self.shifts = rendered.shifts;
+            if !events.is_empty() {
+                return Some(events);
+            }
+        }
+    }
+}
+
+async fn get_worksite_updates(
+    extract::Path(worksite_id): extract::Path<String>,
+    Query(query): Query<WallchartQuery>,
+    State(WebHtmxState {
+        worksite_service,
+        task_service,
+        worksite_events,
+        ..
+    }): State<WebHtmxState>,
+) -> impl IntoResponse {
+    let context: Context = crate::context::context().expect("Unable to retrieve htmx context.");
+
+    // Subscribe before rendering so no change slips in between
+    let events = worksite_events.subscribe();
+    let shifts = render_wallchart(&worksite_service, &task_service, &worksite_id, &query)
+        .await
+        .map(|rendered| rendered.shifts)
+        .unwrap_or_default();
+
+    let updates = WallchartUpdates {
+        worksite_id,
+        query,
+        worksite_service,
+        task_service,
+        context,
+        events,
+        shifts,
+    };
+
+    let events = stream::unfold(updates, |mut updates| async move {
+        let events = updates.next_events().await?;
+        Some((events, updates))
+    })
+    .flat_map(|events| stream::iter(events.into_iter().map(Ok::<Event, Infallible>)));
+
+    Sse::new(events).keep_alive(KeepAlive::default())
+}
+
 async fn get_wallchart_page() -> impl IntoResponse {
     let ctx: crate::context::Context =
         crate::context::context().expect("Unable to retrieve htmx context.");
@@ -371,10 +785,13 @@ struct LocationRowProps {
 fn LocationRow(props: LocationRowProps) -> String {
     html! {
         <GridLayout>
-            <GridCell span=5 class="bg-white py-2 pl-4 px-3 text-left text-xl font-bold text-gray-900">
+            <GridCell span=4 class="bg-white py-2 pl-4 px-3 text-left text-xl font-bold text-gray-900">
                 {props.location.name}
             </GridCell>
-            <GridCell span=1 class="bg-white py-2 pl-4 pr-3 text-right text-sm font-semibold text-gray-900 sm:pl-3">
+            <GridCell span=2 class="bg-white py-2 pl-4 pr-3 text-right text-sm font-semibold text-gray-900 sm:pl-3">
+                <SecondaryButton tag="a" href=props.location.org_chart_url.clone() class="mr-2">
+                    "Org Chart"
+                </SecondaryButton>
                 <SecondaryButton
                     hx_get=props.location.add_shift_url.clone()
                     hx_push_url=routes::page_modal_from(props.location.add_shift_url.clone())
@@ -392,7 +809,10 @@ fn LocationRow(props: LocationRowProps) -> String {
                     .iter()
                     .map(|shift| async {
                         html! {
-                            <li class="col-span-2 flex flex-col divide-y divide-gray-200 rounded-lg bg-white text-center shadow">
+                            <li
+                                class="col-span-2 flex flex-col divide-y divide-gray-200 rounded-lg bg-white text-center shadow"
+                                sse-swap=shift_event(&shift.id)
+                            >
                                 <ShiftRow
                                     assign_worker_url=routes::shift_assignments_create_form(
                                         &props.worksite_id,
@@ -430,6 +850,9 @@ struct LocationRowLocation {
     #[builder(setter(into))]
     add_shift_url: String,
 
+    #[builder(setter(into))]
+    org_chart_url: String,
+
     shifts: Vec<ShiftRowShift>,
 }
 
@@ -493,8 +916,18 @@ struct WorkerRowWorker {
     #[builder(setter(into))]
     full_name: String,
 
+    committee_member: bool,
+
     last_assessment: Option<String>,
 
+    overdue_tasks: usize,
+
+    participation: Option<ParticipationStatus>,
+
+    exclusion_reason: Option<ExclusionReason>,
+
+    status: EmploymentStatus,
+
     #[builder(setter(into))]
     shift_assignment_url: String,
 
@@ -515,8 +948,15 @@ struct WorkerRowProps {
 
 #[component]
 fn WorkerRow(props: WorkerRowProps) -> String {
+    let row_class = match (props.worker.exclusion_reason, props.worker.participation) {
+        (Some(_), _) => "border-t border-gray-300 bg-gray-50 opacity-60",
+        _ if props.worker.status.has_left() => "border-t border-gray-300 bg-gray-50 opacity-60",
+        (None, Some(ParticipationStatus::DidIt)) => "border-t border-gray-300 bg-green-50",
+        _ => "border-t border-gray-300",
+    };
+
     html! {
-        <tr class="border-t border-gray-300" data-loading-states>
+        <tr class=row_class data-loading-states>
             <td class="whitespace-nowrap py-4 pl-4 pr-3 text-sm font-medium text-gray-900 sm:pl-3">
                   <button
                       hx-get=props.worker.details_url.clone()
@@ -525,6 +965,60 @@ fn WorkerRow(props: WorkerRowProps) -> String {
                   >
                         {&props.worker.full_name}
                   </button>
+                  {
+                      if props.worker.committee_member {
+                          html! { <span title="Organizing committee" class="ml-1 cursor-pointer">"⭐"</span> }
+                      } else {
+                          String::from("")
+                      }
+                  }
+                  {
+                      if props.worker.overdue_tasks > 0 {
+                          html! {
+                              <span class="ml-2 rounded-md px-1.5 py-0.5 text-xs font-medium text-red-700 bg-red-50 ring-1 ring-inset ring-red-600/10">
+                                  {format!("{} overdue", props.worker.overdue_tasks)}
+                              </span>
+                          }
+                      } else {
+                          String::from("")
+                      }
+                  }
+                  {
+                      match props.worker.exclusion_reason {
+                          Some(reason) => html! {
+                              <span
+                                  title="Not in the bargaining unit"
+                                  class="ml-2 rounded-md px-1.5 py-0.5 text-xs font-medium text-gray-600 bg-gray-100 ring-1 ring-inset ring-gray-500/10"
+                              >
+                                  {format!("Excluded: {}", reason.label())}
+                              </span>
+                          },
+                          None => String::from(""),
+                      }
+                  }
+                  {
+                      match props.worker.status {
+                          EmploymentStatus::Active => String::from(""),
+                          status => html! {
+                              <span
+                                  title="Employment status"
+                                  class="ml-2 rounded-md px-1.5 py-0.5 text-xs font-medium text-gray-600 bg-gray-100 ring-1 ring-inset ring-gray-500/10"
+                              >
+                                  {status.label()}
+                              </span>
+                          },
+                      }
+                  }
+                  {
+                      match props.worker.participation {
+                          Some(status) => html! {
+                              <span class="ml-2">
+                                  <ParticipationBadge status=status />
+                              </span>
+                          },
+                          None => String::from(""),
+                      }
+                  }
             </td>
             <td class="whitespace-nowrap px-3 py-4 text-sm text-gray-500">
                 {props.worker.last_assessment}
@@ -595,3 +1089,169 @@ async fn get_new_worksite_modal() -> impl IntoResponse {
         </Modal>
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Arc, time::Duration};
+
+    use audit_service::service::AuditService;
+    use auth_service::{
+        models::{OidcRoleMapping, User},
+        ports::event_publisher::EventPublishers,
+        service::AuthService,
+    };
+    use axum::{
+        body::{Body, Bytes},
+        extract::{self, Query, State},
+        response::IntoResponse,
+    };
+    use broadcast_event_publisher::BroadcastEventPublisher;
+    use futures::{Stream, StreamExt};
+    use in_memory_audit_log_repository::InMemoryAuditLogRepository;
+    use in_memory_task_repository::InMemoryTaskRepository;
+    use in_memory_user_repository::InMemoryUserRepository;
+    use in_memory_worksite_repository::InMemoryWorksiteRepository;
+    use task_service::service::TaskService;
+    use worksite_service::{
+        add_assessment::AddAssessmentInput,
+        models::{Location, Shift, ShiftWorker, Worker, Worksite},
+        ports::worksite_repository::WorksiteRepository,
+        service::WorksiteService,
+    };
+
+    use super::{get_worksite_updates, WallchartQuery};
+    use crate::{
+        context::{provide_context, Context, LoggedInUser},
+        state::WebHtmxState,
+    };
+
+    fn worksite(id: &str, worker: &Worker) -> Worksite {
+        Worksite {
+            id: id.into(),
+            locations: vec![Location {
+                id: format!("location-{}", id),
+                name: "Floor".into(),
+                shifts: vec![Shift {
+                    id: format!("shift-{}", id),
+                    name: "Day".into(),
+                    workers: vec![ShiftWorker::new(worker.id.clone())],
+                }],
+            }],
+            ..Worksite::new("Warehouse".into()).add_worker(worker.clone())
+        }
+    }
+
+    async fn subscribe(
+        state: WebHtmxState,
+        worksite_id: &str,
+    ) -> impl Stream<Item = Result<Bytes, axum::Error>> {
+        let context = Context {
+            page_url: "".into(),
+            page_query_params: HashMap::new(),
+            worksite_id: worksite_id.into(),
+            worksite_name: "Warehouse".into(),
+            current_user: Some(LoggedInUser::new(User::new(
+                "organizer@example.com".into(),
+                "".into(),
+            ))),
+            is_partial_request: false,
+        };
+        let response = provide_context(
+            context,
+            get_worksite_updates(
+                extract::Path(worksite_id.into()),
+                Query(WallchartQuery {
+                    organizer: None,
+                    action: None,
+                }),
+                State(state),
+            ),
+        )
+        .await
+        .into_response();
+
+        Body::new(response.into_body()).into_data_stream()
+    }
+
+    // None when nothing is sent for a while
+    async fn next_update(
+        updates: &mut (impl Stream<Item = Result<Bytes, axum::Error>> + Unpin),
+    ) -> Option<String> {
+        let update = tokio::time::timeout(Duration::from_millis(500), updates.next())
+            .await
+            .ok()??
+            .unwrap();
+
+        Some(String::from_utf8(update.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn streams_updates_for_only_its_own_worksite() {
+        let jim = Worker::new("Jim".into(), "Halpert".into(), "jim@example.com".into());
+        let karen = Worker::new(
+            "Karen".into(),
+            "Filippelli".into(),
+            "karen@example.com".into(),
+        );
+        let repository =
+            InMemoryWorksiteRepository::with(vec![worksite("1", &jim), worksite("2", &karen)]);
+        let worksite_events = BroadcastEventPublisher::new();
+        let worksite_service = Arc::new(WorksiteService::new(
+            Arc::new(repository.clone()),
+            Arc::new(worksite_events.clone()),
+        ));
+        let state = WebHtmxState {
+            default_worksite_id: "1".into(),
+            default_worksite_name: "Warehouse".into(),
+            auth_service: Arc::new(AuthService::new(
+                Arc::new(InMemoryUserRepository::empty()),
+                Arc::new(EventPublishers(vec![])),
+                vec![],
+                None,
+                OidcRoleMapping::default(),
+            )),
+            worksite_service: worksite_service.clone(),
+            worksite_events,
+            audit_service: Arc::new(AuditService::new(Arc::new(
+                InMemoryAuditLogRepository::empty(),
+            ))),
+            task_service: Arc::new(TaskService::new(Arc::new(InMemoryTaskRepository::empty()))),
+            flash_config: axum_flash::Config::new(axum_flash::Key::generate()),
+        };
+
+        let mut updates = Box::pin(subscribe(state, "1").await);
+
+        // Changed without an event, so only re-rendering would show it
+        let worksite = repository.get_worksite("1".into()).await.unwrap().unwrap();
+        repository
+            .save(worksite.update_worker(jim.id.clone(), |worker| Worker {
+                first_name: "Jimothy".into(),
+                ..worker
+            }))
+            .await
+            .unwrap();
+
+        let assess = |worksite_id: &str, worker: &Worker| AddAssessmentInput {
+            worksite_id: worksite_id.into(),
+            worker_id: worker.id.clone(),
+            value: 1,
+            notes: "".into(),
+            assessor: "Michael Scott".into(),
+        };
+
+        worksite_service
+            .add_assessment(assess("2", &karen))
+            .await
+            .unwrap();
+        assert_eq!(next_update(&mut updates).await, None);
+
+        worksite_service
+            .add_assessment(assess("1", &jim))
+            .await
+            .unwrap();
+        let update = next_update(&mut updates).await.unwrap();
+        assert!(update.contains("event: shift-shift-1"), "{}", update);
+        assert!(update.contains("Jimothy"), "{}", update);
+        assert!(!update.contains("Karen"), "{}", update);
+    }
+}
@@ -38,6 +38,23 @@ pub fn login() -> String {
     LOGIN.into()
 }
 
+pub const LOGIN_OIDC: &str = "/login/oidc";
+pub fn login_oidc() -> String {
+    LOGIN_OIDC.into()
+}
+
+pub const LOGIN_OIDC_CALLBACK: &str = "/login/oidc/callback";
+
+pub const LOGIN_TWO_FACTOR: &str = "/login/two-factor";
+pub fn login_two_factor() -> String {
+    LOGIN_TWO_FACTOR.into()
+}
+
+pub const LOGIN_TWO_FACTOR_SETUP: &str = "/login/two-factor/setup";
+pub fn login_two_factor_setup() -> String {
+    LOGIN_TWO_FACTOR_SETUP.into()
+}
+
 pub const LOGOUT: &str = "/logout";
 pub fn logout() -> String {
     LOGOUT.into()
@@ -53,6 +70,16 @@ pub fn worksite(worksite_id: &String) -> String {
     format!("/worksites/{}", worksite_id)
 }
 
+pub const WORKSITE_UPDATES: &str = "/worksites/:worksite_id/updates";
+pub fn worksite_updates(worksite_id: &String) -> String {
+    format!("/worksites/{}/updates", worksite_id)
+}
+
+pub const WORKSITE_ACTIVITY: &str = "/worksites/:worksite_id/activity";
+pub fn worksite_activity(worksite_id: &String) -> String {
+    format!("/worksites/{}/activity", worksite_id)
+}
+
 pub const WORKSITE_EDIT_FORM: &str = "/worksites/:worksite_id/edit-form";
 pub fn worksite_edit_form(worksite_id: &String) -> String {
     format!("/worksites/{}/edit-form", worksite_id)
@@ -95,6 +122,14 @@ pub fn shifts_create_form(worksite_id: &String, location_id: &String) -> String
     )
 }
 
+pub const LOCATION_ORG_CHART: &str = "/worksites/:worksite_id/locations/:location_id/org-chart";
+pub fn location_org_chart(worksite_id: &String, location_id: &String) -> String {
+    format!(
+        "/worksites/{}/locations/{}/org-chart",
+        worksite_id, location_id
+    )
+}
+
 pub const SHIFT_ASSIGNMENTS_CREATE_FORM: &str =
     "/worksites/:worksite_id/locations/:location_id/shifts/:shift_id/workers/create-form";
 pub fn shift_assignments_create_form(
@@ -142,11 +177,45 @@ pub fn tag_edit_form(worksite_id: &String, tag_id: &String) -> String {
     format!("/worksites/{}/tags/{}/edit-form", worksite_id, tag_id)
 }
 
+pub const CUSTOM_FIELDS: &str = "/worksites/:worksite_id/custom-fields";
+pub fn custom_fields(worksite_id: &String) -> String {
+    format!("/worksites/{}/custom-fields", worksite_id)
+}
+
+pub const CUSTOM_FIELDS_CREATE_FORM: &str = "/worksites/:worksite_id/custom-fields/create-form";
+pub fn custom_fields_create_form(worksite_id: &String) -> String {
+    format!("/worksites/{}/custom-fields/create-form", worksite_id)
+}
+
+pub const CUSTOM_FIELD: &str = "/worksites/:worksite_id/custom-fields/:field_id";
+pub fn custom_field(worksite_id: &String, field_id: &String) -> String {
+    format!("/worksites/{}/custom-fields/{}", worksite_id, field_id)
+}
+
+pub const CUSTOM_FIELD_EDIT_FORM: &str =
+    "/worksites/:worksite_id/custom-fields/:field_id/edit-form";
+pub fn custom_field_edit_form(worksite_id: &String, field_id: &String) -> String {
+    format!(
+        "/worksites/{}/custom-fields/{}/edit-form",
+        worksite_id, field_id
+    )
+}
+
 pub const WORKERS: &str = "/worksites/:worksite_id/workers";
 pub fn workers(worksite_id: &String) -> String {
     format!("/worksites/{}/workers", worksite_id)
 }
 
+// The workers page narrowed to everyone under one supervisor
+pub fn supervisor_workers(worksite_id: &String, supervisor_id: &String) -> String {
+    format!("{}?supervisor_id={}", workers(worksite_id), supervisor_id)
+}
+
+pub const WORKERS_EXPORT: &str = "/worksites/:worksite_id/workers/export";
+pub fn workers_export(worksite_id: &String) -> String {
+    format!("/worksites/{}/workers/export", worksite_id)
+}
+
 pub const WORKERS_CREATE_FORM: &str = "/worksites/:worksite_id/workers/create-form";
 pub fn workers_create_form(worksite_id: &String) -> String {
     format!("/worksites/{}/workers/create-form", worksite_id)
@@ -165,14 +234,74 @@ pub fn worker_profile(worksite_id: &String, worker_id: &String) -> String {
     format!("/worksites/{}/workers/{}/profile", worksite_id, worker_id)
 }
 
+pub const WORKER_ACTIVITY: &str = "/worksites/:worksite_id/workers/:worker_id/activity";
+pub fn worker_activity(worksite_id: &String, worker_id: &String) -> String {
+    format!("/worksites/{}/workers/{}/activity", worksite_id, worker_id)
+}
+
 pub const USERS: &str = "/users";
 pub fn users() -> String {
     USERS.into()
 }
 
-pub const USERS_CREATE_FORM: &str = "/users/create-form";
-pub fn users_create_form() -> String {
-    USERS_CREATE_FORM.into()
+pub const AUDIT_LOG: &str = "/audit-log";
+pub fn audit_log() -> String {
+    AUDIT_LOG.into()
+}
+
+pub const AUDIT_LOG_ENTRIES: &str = "/audit-log/entries";
+pub fn audit_log_entries() -> String {
+    AUDIT_LOG_ENTRIES.into()
+}
+
+pub const PROFILE: &str = "/profile";
+pub fn profile() -> String {
+    PROFILE.into()
+}
+
+pub const PROFILE_PASSWORD: &str = "/profile/password";
+pub fn profile_password() -> String {
+    PROFILE_PASSWORD.into()
+}
+
+pub const PROFILE_TWO_FACTOR: &str = "/profile/two-factor";
+pub fn profile_two_factor() -> String {
+    PROFILE_TWO_FACTOR.into()
+}
+
+pub const PROFILE_TWO_FACTOR_DISABLE: &str = "/profile/two-factor/disable";
+pub fn profile_two_factor_disable() -> String {
+    PROFILE_TWO_FACTOR_DISABLE.into()
+}
+
+pub const PROFILE_API_TOKENS: &str = "/profile/api-tokens";
+pub fn profile_api_tokens() -> String {
+    PROFILE_API_TOKENS.into()
+}
+
+pub const PROFILE_API_TOKEN: &str = "/profile/api-tokens/:api_token_id";
+pub fn profile_api_token(api_token_id: &String) -> String {
+    format!("/profile/api-tokens/{}", api_token_id)
+}
+
+pub const INVITES: &str = "/users/invites";
+pub fn invites() -> String {
+    INVITES.into()
+}
+
+pub const INVITES_CREATE_FORM: &str = "/users/invites/create-form";
+pub fn invites_create_form() -> String {
+    INVITES_CREATE_FORM.into()
+}
+
+pub const INVITE: &str = "/users/invites/:user_id";
+pub fn invite(user_id: &String) -> String {
+    format!("/users/invites/{}", user_id)
+}
+
+pub const ACCEPT_INVITE: &str = "/accept-invite/:invite_token";
+pub fn accept_invite(invite_token: &String) -> String {
+    format!("/accept-invite/{}", invite_token)
 }
 
 pub const USER: &str = "/users/:user_id";
@@ -180,6 +309,11 @@ pub fn user(user_id: &String) -> String {
     format!("/users/{}", user_id)
 }
 
+pub const USER_UNLOCK: &str = "/users/:user_id/unlock";
+pub fn user_unlock(user_id: &String) -> String {
+    format!("/users/{}/unlock", user_id)
+}
+
 pub const USER_EDIT_FORM: &str = "/users/:user_id/edit-form";
 pub fn user_edit_form(user_id: &String) -> String {
     format!("/users/{}/edit-form", user_id)
@@ -217,6 +351,158 @@ pub fn assessment(worksite_id: &String, worker_id: &String, assessment_id: &Stri
     )
 }
 
+pub const CONVERSATIONS: &str = "/worksites/:worksite_id/workers/:worker_id/conversations";
+pub fn conversations(worksite_id: &String, worker_id: &String) -> String {
+    format!(
+        "/worksites/{}/workers/{}/conversations",
+        worksite_id, worker_id
+    )
+}
+
+pub const CONVERSATION: &str =
+    "/worksites/:worksite_id/workers/:worker_id/conversations/:conversation_id";
+pub fn conversation(worksite_id: &String, worker_id: &String, conversation_id: &String) -> String {
+    format!(
+        "/worksites/{}/workers/{}/conversations/{}",
+        worksite_id, worker_id, conversation_id
+    )
+}
+
+pub const TURF: &str = "/worksites/:worksite_id/turf";
+pub fn turf(worksite_id: &String) -> String {
+    format!("/worksites/{}/turf", worksite_id)
+}
+
+pub const TURF_ASSIGNMENT: &str = "/worksites/:worksite_id/turf/:assignment_id";
+pub fn turf_assignment(worksite_id: &String, assignment_id: &String) -> String {
+    format!("/worksites/{}/turf/{}", worksite_id, assignment_id)
+}
+
+// The wallchart filtered down to one organizer's turf
+pub fn organizer_wallchart(worksite_id: &String, user_id: &String) -> String {
+    format!("{}?organizer={}", worksite(worksite_id), user_id)
+}
+
+// The wallchart highlighting who took part in an action
+pub fn action_wallchart(worksite_id: &String, action_id: &String) -> String {
+    format!("{}?action={}", worksite(worksite_id), action_id)
+}
+
+pub const COMMITTEE: &str = "/worksites/:worksite_id/committee";
+pub fn committee(worksite_id: &String) -> String {
+    format!("/worksites/{}/committee", worksite_id)
+}
+
+pub const WORKER_COMMITTEE: &str = "/worksites/:worksite_id/workers/:worker_id/committee";
+pub fn worker_committee(worksite_id: &String, worker_id: &String) -> String {
+    format!("/worksites/{}/workers/{}/committee", worksite_id, worker_id)
+}
+
+pub const ACTIONS: &str = "/worksites/:worksite_id/actions";
+pub fn actions(worksite_id: &String) -> String {
+    format!("/worksites/{}/actions", worksite_id)
+}
+
+pub const ACTION: &str = "/worksites/:worksite_id/actions/:action_id";
+pub fn action(worksite_id: &String, action_id: &String) -> String {
+    format!("/worksites/{}/actions/{}", worksite_id, action_id)
+}
+
+pub const ACTION_PARTICIPATION: &str = "/worksites/:worksite_id/actions/:action_id/participation";
+pub fn action_participation(worksite_id: &String, action_id: &String) -> String {
+    format!(
+        "/worksites/{}/actions/{}/participation",
+        worksite_id, action_id
+    )
+}
+
+pub const CARDS: &str = "/worksites/:worksite_id/cards";
+pub fn cards(worksite_id: &String) -> String {
+    format!("/worksites/{}/cards", worksite_id)
+}
+
+pub const WORKER_CARD: &str = "/worksites/:worksite_id/workers/:worker_id/card";
+pub fn worker_card(worksite_id: &String, worker_id: &String) -> String {
+    format!("/worksites/{}/workers/{}/card", worksite_id, worker_id)
+}
+
+pub const WORKER_ELIGIBILITY: &str = "/worksites/:worksite_id/workers/:worker_id/eligibility";
+pub fn worker_eligibility(worksite_id: &String, worker_id: &String) -> String {
+    format!(
+        "/worksites/{}/workers/{}/eligibility",
+        worksite_id, worker_id
+    )
+}
+
+pub const WORKER_STATUS: &str = "/worksites/:worksite_id/workers/:worker_id/status";
+pub fn worker_status(worksite_id: &String, worker_id: &String) -> String {
+    format!("/worksites/{}/workers/{}/status", worksite_id, worker_id)
+}
+
+pub const TURNOVER: &str = "/worksites/:worksite_id/turnover";
+pub fn turnover(worksite_id: &String) -> String {
+    format!("/worksites/{}/turnover", worksite_id)
+}
+
+pub const ROSTER: &str = "/worksites/:worksite_id/roster";
+pub fn roster(worksite_id: &String) -> String {
+    format!("/worksites/{}/roster", worksite_id)
+}
+
+pub const DUPLICATES: &str = "/worksites/:worksite_id/duplicates";
+pub fn duplicates(worksite_id: &String) -> String {
+    format!("/worksites/{}/duplicates", worksite_id)
+}
+
+pub const WORKER_SUPERVISOR: &str = "/worksites/:worksite_id/workers/:worker_id/supervisor";
+pub fn worker_supervisor(worksite_id: &String, worker_id: &String) -> String {
+    format!(
+        "/worksites/{}/workers/{}/supervisor",
+        worksite_id, worker_id
+    )
+}
+
+pub const WORKER_RELATIONSHIPS: &str = "/worksites/:worksite_id/workers/:worker_id/relationships";
+pub fn worker_relationships(worksite_id: &String, worker_id: &String) -> String {
+    format!(
+        "/worksites/{}/workers/{}/relationships",
+        worksite_id, worker_id
+    )
+}
+
+pub const WORKER_RELATIONSHIP: &str =
+    "/worksites/:worksite_id/workers/:worker_id/relationships/:relationship_id";
+pub fn worker_relationship(
+    worksite_id: &String,
+    worker_id: &String,
+    relationship_id: &String,
+) -> String {
+    format!(
+        "/worksites/{}/workers/{}/relationships/{}",
+        worksite_id, worker_id, relationship_id
+    )
+}
+
+pub const MY_TASKS: &str = "/tasks";
+pub fn my_tasks() -> String {
+    MY_TASKS.into()
+}
+
+pub const WORKER_TASKS: &str = "/worksites/:worksite_id/workers/:worker_id/tasks";
+pub fn worker_tasks(worksite_id: &String, worker_id: &String) -> String {
+    format!("/worksites/{}/workers/{}/tasks", worksite_id, worker_id)
+}
+
+pub const TASK: &str = "/tasks/:task_id";
+pub fn task(task_id: &String) -> String {
+    format!("/tasks/{}", task_id)
+}
+
+pub const TASK_STATUS: &str = "/tasks/:task_id/status";
+pub fn task_status(task_id: &String) -> String {
+    format!("/tasks/{}/status", task_id)
+}
+
 pub const SELECTED_WORKSITE: &str = "/selected-worksite";
 pub fn selected_worksite() -> String {
     SELECTED_WORKSITE.into()
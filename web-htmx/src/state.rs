@@ -1,6 +1,9 @@
+use audit_service::service::AuditService;
 use auth_service::service::AuthService;
 use axum::extract::FromRef;
+use broadcast_event_publisher::BroadcastEventPublisher;
 use std::sync::Arc;
+use task_service::service::TaskService;
 use worksite_service::service::WorksiteService;
 
 #[derive(Clone)]
@@ -9,6 +12,9 @@ pub struct WebHtmxState {
     pub default_worksite_name: String,
     pub auth_service: Arc<AuthService>,
     pub worksite_service: Arc<WorksiteService>,
+    pub worksite_events: BroadcastEventPublisher,
+    pub audit_service: Arc<AuditService>,
+    pub task_service: Arc<TaskService>,
     pub flash_config: axum_flash::Config,
 }
 
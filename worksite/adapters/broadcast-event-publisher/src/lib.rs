@@ -0,0 +1,272 @@
+use async_trait::async_trait;
+use tokio::sync::broadcast;
+
+use worksite_service::events::DomainEvent;
+use worksite_service::ports::event_publisher::EventPublisher;
+
+// Slow subscribers that fall further behind than this skip ahead, see broadcast::error::RecvError::Lagged
+const EVENTS_CAPACITY: usize = 100;
+
+/**
+* Hands every event to whoever is subscribed in this process, e.g. open wallcharts.
+* Events from other instances of the app are never seen here.
+*/
+#[derive(Clone, Debug)]
+pub struct BroadcastEventPublisher {
+    sender: broadcast::Sender<DomainEvent>,
+}
+
+impl BroadcastEventPublisher {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(EVENTS_CAPACITY);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<DomainEvent> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for BroadcastEventPublisher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl EventPublisher for BroadcastEventPublisher {
+    async fn publish(&self, event: DomainEvent) {
+        // Sending only fails when nobody is listening
+        let _ = self.sender.send(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::NaiveDate;
+    use in_memory_worksite_repository::InMemoryWorksiteRepository;
+    use worksite_service::{
+        add_action::AddActionInput,
+        add_assessment::AddAssessmentInput,
+        add_conversation::AddConversationInput,
+        assign_tags::AssignTagsInput,
+        events::WorksiteEvent,
+        get_conversations::GetConversationsInput,
+        models::{ConversationChannel, ParticipationStatus, Tag, Worker, Worksite},
+        record_participation::RecordParticipationInput,
+        remove_conversation::RemoveConversationInput,
+        service::WorksiteService,
+        update_conversation::UpdateConversationInput,
+    };
+
+    use super::*;
+
+    fn setup() -> (WorksiteService, BroadcastEventPublisher, Worksite, Worker) {
+        let worker = Worker::new("Jane".into(), "Doe".into(), "jane@example.com".into());
+        let worksite = Worksite::new("Warehouse".into())
+            .add_worker(worker.clone())
+            .add_tag(Tag {
+                id: "tag-1".into(),
+                name: "Leader".into(),
+                icon: "⭐".into(),
+            });
+
+        let publisher = BroadcastEventPublisher::new();
+        let worksite_service = WorksiteService::new(
+            Arc::new(InMemoryWorksiteRepository::with(vec![worksite.clone()])),
+            Arc::new(publisher.clone()),
+        );
+
+        (worksite_service, publisher, worksite, worker)
+    }
+
+    #[tokio::test]
+    async fn publishes_assessments() {
+        let (worksite_service, publisher, worksite, worker) = setup();
+        let mut events = publisher.subscribe();
+
+        worksite_service
+            .add_assessment(AddAssessmentInput {
+                worksite_id: worksite.id.clone(),
+                worker_id: worker.id.clone(),
+                value: 2,
+                notes: "".into(),
+                assessor: "Organizer".into(),
+            })
+            .await
+            .unwrap();
+
+        let event = events.recv().await.unwrap();
+        assert_eq!(event.worksite_id, worksite.id);
+        match event.event {
+            WorksiteEvent::WorkerAssessed {
+                worker_id,
+                assessment,
+            } => {
+                assert_eq!(worker_id, worker.id);
+                assert_eq!(assessment.value, 2);
+            }
+            other => panic!("Unexpected event {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn publishes_only_changed_tags() {
+        let (worksite_service, publisher, worksite, worker) = setup();
+        let mut events = publisher.subscribe();
+
+        for tags in [vec!["tag-1".to_string()], vec!["tag-1".to_string()], vec![]] {
+            worksite_service
+                .assign_tags(AssignTagsInput {
+                    worksite_id: worksite.id.clone(),
+                    worker_id: worker.id.clone(),
+                    tags,
+                })
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            events.recv().await.unwrap().event,
+            WorksiteEvent::TagAssigned {
+                worker_id: worker.id.clone(),
+                tag_id: "tag-1".into(),
+            }
+        );
+        assert_eq!(
+            events.recv().await.unwrap().event,
+            WorksiteEvent::TagUnassigned {
+                worker_id: worker.id.clone(),
+                tag_id: "tag-1".into(),
+            }
+        );
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn publishes_conversation_changes() {
+        let (worksite_service, publisher, worksite, worker) = setup();
+        let mut events = publisher.subscribe();
+
+        worksite_service
+            .add_conversation(AddConversationInput {
+                worksite_id: worksite.id.clone(),
+                worker_id: worker.id.clone(),
+                held_on: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+                organizer: "Organizer".into(),
+                channel: ConversationChannel::Phone,
+                issues: vec!["Scheduling".into()],
+                notes: "".into(),
+                next_step: "Meet after shift".into(),
+            })
+            .await
+            .unwrap();
+        let conversation = worksite_service
+            .get_conversations(GetConversationsInput {
+                worksite_id: worksite.id.clone(),
+                worker_id: worker.id.clone(),
+            })
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+        worksite_service
+            .update_conversation(UpdateConversationInput {
+                worksite_id: worksite.id.clone(),
+                worker_id: worker.id.clone(),
+                conversation_id: conversation.id.clone(),
+                held_on: conversation.held_on,
+                organizer: conversation.organizer.clone(),
+                channel: ConversationChannel::InPerson,
+                issues: conversation.issues.clone(),
+                notes: conversation.notes.clone(),
+                next_step: conversation.next_step.clone(),
+            })
+            .await
+            .unwrap();
+        worksite_service
+            .remove_conversation(RemoveConversationInput {
+                worksite_id: worksite.id.clone(),
+                worker_id: worker.id.clone(),
+                conversation_id: conversation.id.clone(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            events.recv().await.unwrap().event,
+            WorksiteEvent::ConversationLogged {
+                worker_id: worker.id.clone(),
+                conversation: conversation.clone(),
+            }
+        );
+        match events.recv().await.unwrap().event {
+            WorksiteEvent::ConversationUpdated {
+                previous,
+                conversation: updated,
+                ..
+            } => {
+                assert_eq!(previous, conversation);
+                assert_eq!(updated.channel, ConversationChannel::InPerson);
+            }
+            other => panic!("Unexpected event {:?}", other),
+        }
+        match events.recv().await.unwrap().event {
+            WorksiteEvent::ConversationRemoved {
+                conversation: removed,
+                ..
+            } => assert_eq!(removed.id, conversation.id),
+            other => panic!("Unexpected event {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn publishes_only_changed_participation() {
+        let (worksite_service, publisher, worksite, worker) = setup();
+        let action = worksite_service
+            .add_action(AddActionInput {
+                worksite_id: worksite.id.clone(),
+                held_on: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+                description: "Sign the petition".into(),
+            })
+            .await
+            .unwrap();
+        let mut events = publisher.subscribe();
+
+        for status in [
+            Some(ParticipationStatus::Yes),
+            Some(ParticipationStatus::Yes),
+            None,
+        ] {
+            worksite_service
+                .record_participation(RecordParticipationInput {
+                    worksite_id: worksite.id.clone(),
+                    action_id: action.id.clone(),
+                    participation: vec![
+                        (worker.id.clone(), status),
+                        ("not-a-worker".into(), status),
+                    ],
+                })
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            events.recv().await.unwrap().event,
+            WorksiteEvent::ParticipationRecorded {
+                action_id: action.id.clone(),
+                changes: vec![(worker.id.clone(), Some(ParticipationStatus::Yes))],
+            }
+        );
+        assert_eq!(
+            events.recv().await.unwrap().event,
+            WorksiteEvent::ParticipationRecorded {
+                action_id: action.id.clone(),
+                changes: vec![(worker.id.clone(), None)],
+            }
+        );
+        assert!(events.try_recv().is_err());
+    }
+}
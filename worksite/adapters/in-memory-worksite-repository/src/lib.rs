@@ -44,3 +44,737 @@ impl WorksiteRepository for InMemoryWorksiteRepository {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::{NaiveDate, Utc};
+    use worksite_service::{
+        add_action::AddActionInput,
+        add_relationship::{AddRelationshipFailure, AddRelationshipInput},
+        assign_leader::{AssignLeaderFailure, AssignLeaderInput},
+        assign_turf::AssignTurfInput,
+        change_status::ChangeStatusInput,
+        compare_roster::CompareRosterInput,
+        get_action_report::GetActionReportInput,
+        get_card_report::GetCardReportInput,
+        get_committee_report::GetCommitteeReportInput,
+        get_leaver_alerts::GetLeaverAlertsInput,
+        get_turf_coverage::GetTurfCoverageInput,
+        get_turnover_report::{GetTurnoverReportInput, MonthlyTurnover},
+        get_worksite::GetWorksiteInput,
+        models::{
+            Assessment, AuthorizationCard, CardThreshold, Eligibility, EmploymentStatus,
+            ExclusionReason, Location, ParticipationCount, ParticipationStatus, RelationshipKind,
+            RosterChange, Shift, Turf, Worker, Worksite,
+        },
+        ports::event_publisher::EventPublishers,
+        reconcile_roster::ReconcileRosterInput,
+        record_authorization_card::RecordAuthorizationCardInput,
+        record_participation::RecordParticipationInput,
+        service::WorksiteService,
+        set_committee_member::SetCommitteeMemberInput,
+        set_supervisor::{SetSupervisorFailure, SetSupervisorInput},
+        update_card_thresholds::{UpdateCardThresholdsFailure, UpdateCardThresholdsInput},
+    };
+
+    use super::InMemoryWorksiteRepository;
+
+    fn worker(first_name: &str, last_name: &str) -> Worker {
+        Worker::new(
+            first_name.into(),
+            last_name.into(),
+            format!("{}@example.com", first_name.to_lowercase()),
+        )
+    }
+
+    fn setup(worksite: Worksite) -> WorksiteService {
+        WorksiteService::new(
+            Arc::new(InMemoryWorksiteRepository::with(vec![worksite])),
+            Arc::new(EventPublishers(vec![])),
+        )
+    }
+
+    #[tokio::test]
+    async fn turf_coverage_counts_eligible_workers_in_any_organizers_turf() {
+        let pam = worker("Pam", "Beesly");
+        let jim = worker("Jim", "Halpert");
+        let dwight = worker("Dwight", "Schrute");
+        let kevin = worker("Kevin", "Malone");
+        let mut michael = worker("Michael", "Scott");
+        michael.eligibility = Eligibility::Excluded(ExclusionReason::Manager);
+
+        let day_shift = Shift::new("Day".into())
+            .assign_worker(pam.id.clone())
+            .assign_worker(michael.id.clone());
+        let night_shift = Shift::new("Night".into()).assign_worker(jim.id.clone());
+        let office = Location::new("Office".into())
+            .add_shift(day_shift.clone())
+            .add_shift(night_shift);
+        let worksite = Worksite::new("Scranton".into())
+            .add_location(office)
+            .add_worker(pam)
+            .add_worker(jim.clone())
+            .add_worker(dwight)
+            .add_worker(kevin.clone())
+            .add_worker(michael);
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+
+        for turf in [Turf::Shift(day_shift.id), Turf::Worker(kevin.id)] {
+            worksite_service
+                .assign_turf(AssignTurfInput {
+                    worksite_id: worksite_id.clone(),
+                    user_id: "organizer-1".into(),
+                    turf,
+                })
+                .await
+                .unwrap();
+        }
+
+        let coverage = worksite_service
+            .get_turf_coverage(GetTurfCoverageInput {
+                worksite_id: worksite_id.clone(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(coverage.total_workers, 4);
+        assert_eq!(coverage.covered_workers, 2);
+        assert_eq!(coverage.percent_covered(), 50);
+        let uncovered: Vec<String> = coverage.uncovered.iter().map(|w| w.full_name()).collect();
+        assert_eq!(uncovered, vec!["Jim Halpert", "Dwight Schrute"]);
+    }
+
+    #[tokio::test]
+    async fn turf_coverage_of_an_empty_worksite_is_zero() {
+        let worksite = Worksite::new("Empty".into());
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+
+        let coverage = worksite_service
+            .get_turf_coverage(GetTurfCoverageInput { worksite_id })
+            .await
+            .unwrap();
+
+        assert_eq!(coverage.total_workers, 0);
+        assert_eq!(coverage.covered_workers, 0);
+        assert_eq!(coverage.percent_covered(), 0);
+    }
+
+    async fn set_committee_member(
+        worksite_service: &WorksiteService,
+        worksite_id: &str,
+        worker_id: &str,
+        committee_member: bool,
+    ) {
+        worksite_service
+            .set_committee_member(SetCommitteeMemberInput {
+                worksite_id: worksite_id.into(),
+                worker_id: worker_id.into(),
+                committee_member,
+            })
+            .await
+            .unwrap();
+    }
+
+    async fn assign_leader(
+        worksite_service: &WorksiteService,
+        worksite_id: &str,
+        worker_id: &str,
+        leader_id: &str,
+    ) -> Result<(), AssignLeaderFailure> {
+        worksite_service
+            .assign_leader(AssignLeaderInput {
+                worksite_id: worksite_id.into(),
+                worker_id: worker_id.into(),
+                leader_id: Some(leader_id.into()),
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn committee_leaders_are_over_ratio_past_ten_coworkers() {
+        let pam = worker("Pam", "Beesly");
+        let jim = worker("Jim", "Halpert");
+        let coworkers: Vec<Worker> = (0..21)
+            .map(|i| worker("Coworker", &format!("{:02}", i)))
+            .collect();
+        let mut worksite = Worksite::new("Scranton".into())
+            .add_worker(pam.clone())
+            .add_worker(jim.clone());
+        for coworker in &coworkers {
+            worksite = worksite.add_worker(coworker.clone());
+        }
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+
+        set_committee_member(&worksite_service, &worksite_id, &pam.id, true).await;
+        set_committee_member(&worksite_service, &worksite_id, &jim.id, true).await;
+        for (i, coworker) in coworkers.iter().enumerate() {
+            let leader = if i < 10 { &pam } else { &jim };
+            assign_leader(&worksite_service, &worksite_id, &coworker.id, &leader.id)
+                .await
+                .unwrap();
+        }
+
+        let report = worksite_service
+            .get_committee_report(GetCommitteeReportInput {
+                worksite_id: worksite_id.clone(),
+            })
+            .await
+            .unwrap();
+
+        let ratios: Vec<(String, usize, bool)> = report
+            .leaders
+            .iter()
+            .map(|l| (l.leader.full_name(), l.workers.len(), l.is_over_ratio()))
+            .collect();
+        assert_eq!(
+            ratios,
+            vec![
+                ("Pam Beesly".to_string(), 10, false),
+                ("Jim Halpert".to_string(), 11, true),
+            ]
+        );
+        assert!(report.workers_without_leader.is_empty());
+    }
+
+    #[tokio::test]
+    async fn workers_lose_their_leader_when_the_leader_leaves_the_committee() {
+        let pam = worker("Pam", "Beesly");
+        let jim = worker("Jim", "Halpert");
+        let mut michael = worker("Michael", "Scott");
+        michael.eligibility = Eligibility::Excluded(ExclusionReason::Manager);
+        let worksite = Worksite::new("Scranton".into())
+            .add_worker(pam.clone())
+            .add_worker(jim.clone())
+            .add_worker(michael.clone());
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+
+        assert_eq!(
+            assign_leader(&worksite_service, &worksite_id, &jim.id, &pam.id).await,
+            Err(AssignLeaderFailure::NotOnCommittee)
+        );
+        set_committee_member(&worksite_service, &worksite_id, &pam.id, true).await;
+        assign_leader(&worksite_service, &worksite_id, &jim.id, &pam.id)
+            .await
+            .unwrap();
+        set_committee_member(&worksite_service, &worksite_id, &pam.id, false).await;
+
+        let report = worksite_service
+            .get_committee_report(GetCommitteeReportInput {
+                worksite_id: worksite_id.clone(),
+            })
+            .await
+            .unwrap();
+
+        assert!(report.leaders.is_empty());
+        let without_leader: Vec<String> = report
+            .workers_without_leader
+            .iter()
+            .map(|w| w.full_name())
+            .collect();
+        assert_eq!(without_leader, vec!["Pam Beesly", "Jim Halpert"]);
+    }
+
+    #[tokio::test]
+    async fn committee_report_of_an_empty_worksite_is_empty() {
+        let worksite = Worksite::new("Empty".into());
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+
+        let report = worksite_service
+            .get_committee_report(GetCommitteeReportInput { worksite_id })
+            .await
+            .unwrap();
+
+        assert!(report.leaders.is_empty());
+        assert!(report.workers_without_leader.is_empty());
+    }
+
+    async fn add_action(worksite_service: &WorksiteService, worksite_id: &str) -> String {
+        worksite_service
+            .add_action(AddActionInput {
+                worksite_id: worksite_id.into(),
+                held_on: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+                description: "Petition delivery".into(),
+            })
+            .await
+            .unwrap()
+            .id
+    }
+
+    #[tokio::test]
+    async fn action_report_counts_eligible_workers_who_did_it() {
+        let pam = worker("Pam", "Beesly");
+        let jim = worker("Jim", "Halpert");
+        let dwight = worker("Dwight", "Schrute");
+        let mut michael = worker("Michael", "Scott");
+        michael.eligibility = Eligibility::Excluded(ExclusionReason::Manager);
+
+        let day_shift = Shift::new("Day".into())
+            .assign_worker(pam.id.clone())
+            .assign_worker(jim.id.clone())
+            .assign_worker(michael.id.clone());
+        let night_shift = Shift::new("Night".into()).assign_worker(dwight.id.clone());
+        let office = Location::new("Office".into())
+            .add_shift(day_shift)
+            .add_shift(night_shift);
+        let worksite = Worksite::new("Scranton".into())
+            .add_location(office)
+            .add_worker(pam.clone())
+            .add_worker(jim.clone())
+            .add_worker(dwight.clone())
+            .add_worker(michael.clone());
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+
+        let action_id = add_action(&worksite_service, &worksite_id).await;
+        worksite_service
+            .record_participation(RecordParticipationInput {
+                worksite_id: worksite_id.clone(),
+                action_id: action_id.clone(),
+                participation: vec![
+                    (pam.id, Some(ParticipationStatus::DidIt)),
+                    (jim.id, Some(ParticipationStatus::Yes)),
+                    (dwight.id, Some(ParticipationStatus::Asked)),
+                    (michael.id, Some(ParticipationStatus::DidIt)),
+                ],
+            })
+            .await
+            .unwrap();
+
+        let report = worksite_service
+            .get_action_report(GetActionReportInput {
+                worksite_id: worksite_id.clone(),
+                action_id,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            report.overall,
+            ParticipationCount {
+                participated: 1,
+                total: 3,
+            }
+        );
+        assert_eq!(report.overall.percent(), 33);
+        let by_shift: Vec<(String, usize)> = report
+            .by_shift
+            .iter()
+            .map(|row| (row.name.clone(), row.count.percent()))
+            .collect();
+        assert_eq!(
+            by_shift,
+            vec![
+                ("Office - Day".to_string(), 50),
+                ("Office - Night".to_string(), 0),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn action_report_of_an_empty_worksite_is_zero_percent() {
+        let worksite = Worksite::new("Empty".into());
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+
+        let action_id = add_action(&worksite_service, &worksite_id).await;
+        let report = worksite_service
+            .get_action_report(GetActionReportInput {
+                worksite_id,
+                action_id,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(report.overall.total, 0);
+        assert_eq!(report.overall.percent(), 0);
+    }
+
+    #[tokio::test]
+    async fn card_report_counts_eligible_signers_against_each_threshold() {
+        let workers: Vec<Worker> = (0..7)
+            .map(|i| worker("Worker", &format!("{:02}", i)))
+            .collect();
+        let mut michael = worker("Michael", "Scott");
+        michael.eligibility = Eligibility::Excluded(ExclusionReason::Manager);
+        let mut worksite = Worksite::new("Scranton".into()).add_worker(michael.clone());
+        for worker in &workers {
+            worksite = worksite.add_worker(worker.clone());
+        }
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+
+        for worker_id in workers.iter().take(3).map(|w| &w.id).chain([&michael.id]) {
+            worksite_service
+                .record_authorization_card(RecordAuthorizationCardInput {
+                    worksite_id: worksite_id.clone(),
+                    worker_id: worker_id.clone(),
+                    card: Some(AuthorizationCard {
+                        signed_on: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+                        witnessed_by: "Pam".into(),
+                        on_file: true,
+                    }),
+                })
+                .await
+                .unwrap();
+        }
+
+        let report = worksite_service
+            .get_card_report(GetCardReportInput {
+                worksite_id: worksite_id.clone(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!((report.signed, report.total), (3, 7));
+        assert_eq!(report.percent_signed(), 42);
+        let thresholds: Vec<(u8, bool, usize)> = report
+            .thresholds
+            .iter()
+            .map(|t| (t.threshold.percent, t.met, t.cards_needed))
+            .collect();
+        assert_eq!(thresholds, vec![(30, true, 0), (70, false, 2)]);
+    }
+
+    #[tokio::test]
+    async fn card_report_of_an_empty_worksite_meets_no_thresholds() {
+        let worksite = Worksite::new("Empty".into());
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+
+        let report = worksite_service
+            .get_card_report(GetCardReportInput { worksite_id })
+            .await
+            .unwrap();
+
+        assert_eq!(report.total, 0);
+        assert_eq!(report.percent_signed(), 0);
+        assert!(report
+            .thresholds
+            .iter()
+            .all(|t| !t.met && t.cards_needed == 0));
+    }
+
+    #[tokio::test]
+    async fn card_thresholds_must_be_between_one_and_a_hundred_percent() {
+        let worksite = Worksite::new("Scranton".into());
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+
+        for percent in [0, 101] {
+            let result = worksite_service
+                .update_card_thresholds(UpdateCardThresholdsInput {
+                    worksite_id: worksite_id.clone(),
+                    thresholds: vec![CardThreshold::new("Majority", percent)],
+                })
+                .await;
+
+            assert_eq!(result, Err(UpdateCardThresholdsFailure::InvalidPercent));
+        }
+    }
+
+    #[tokio::test]
+    async fn supervisors_cant_be_anyone_who_reports_to_the_worker() {
+        let michael = worker("Michael", "Scott");
+        let dwight = worker("Dwight", "Schrute");
+        let jim = worker("Jim", "Halpert");
+        let worksite = Worksite::new("Scranton".into())
+            .add_worker(michael.clone())
+            .add_worker(dwight.clone())
+            .add_worker(jim.clone());
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+
+        let set_supervisor = |worker: &Worker, supervisor: &Worker| {
+            worksite_service.set_supervisor(SetSupervisorInput {
+                worksite_id: worksite_id.clone(),
+                worker_id: worker.id.clone(),
+                supervisor_id: Some(supervisor.id.clone()),
+            })
+        };
+
+        assert_eq!(
+            set_supervisor(&michael, &michael).await,
+            Err(SetSupervisorFailure::CircularChain)
+        );
+        assert_eq!(set_supervisor(&dwight, &michael).await, Ok(()));
+        assert_eq!(set_supervisor(&jim, &dwight).await, Ok(()));
+        // Jim reports to Michael through Dwight
+        assert_eq!(
+            set_supervisor(&michael, &jim).await,
+            Err(SetSupervisorFailure::CircularChain)
+        );
+        assert_eq!(
+            set_supervisor(&dwight, &jim).await,
+            Err(SetSupervisorFailure::CircularChain)
+        );
+        // Moving Jim under Michael directly is still fine
+        assert_eq!(set_supervisor(&jim, &michael).await, Ok(()));
+    }
+
+    #[tokio::test]
+    async fn relationships_cant_be_added_twice_either_way_round() {
+        let pam = worker("Pam", "Beesly");
+        let jim = worker("Jim", "Halpert");
+        let worksite = Worksite::new("Scranton".into())
+            .add_worker(pam.clone())
+            .add_worker(jim.clone());
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+
+        let add = |from: &Worker, to: &Worker| {
+            worksite_service.add_relationship(AddRelationshipInput {
+                worksite_id: worksite_id.clone(),
+                from_worker_id: from.id.clone(),
+                to_worker_id: to.id.clone(),
+                kind: RelationshipKind::Carpool,
+                directional: false,
+            })
+        };
+
+        assert!(add(&pam, &jim).await.is_ok());
+        assert_eq!(
+            add(&jim, &pam).await.map(|_| ()),
+            Err(AddRelationshipFailure::AlreadyRelated)
+        );
+        assert_eq!(
+            add(&pam, &pam).await.map(|_| ()),
+            Err(AddRelationshipFailure::SameWorker)
+        );
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn hired_on(mut worker: Worker, hire_date: NaiveDate) -> Worker {
+        worker.employment.hire_date = Some(hire_date);
+        worker
+    }
+
+    fn assessed(worker: Worker, value: u8) -> Worker {
+        worker.add_assessment(Assessment {
+            id: format!("assessment-{}", worker.id),
+            value,
+            notes: "".into(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            assessor: "organizer@example.com".into(),
+        })
+    }
+
+    async fn change_status(
+        worksite_service: &WorksiteService,
+        worksite_id: &str,
+        worker: &Worker,
+        status: EmploymentStatus,
+        changed_on: NaiveDate,
+    ) {
+        worksite_service
+            .change_status(ChangeStatusInput {
+                worksite_id: worksite_id.into(),
+                worker_id: worker.id.clone(),
+                status,
+                changed_on,
+                note: "".into(),
+            })
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn turnover_counts_each_change_in_the_month_it_happened() {
+        let pam = hired_on(worker("Pam", "Beesly"), date(2023, 12, 15));
+        let jim = hired_on(worker("Jim", "Halpert"), date(2024, 1, 31));
+        let dwight = worker("Dwight", "Schrute");
+        let worksite = Worksite::new("Scranton".into())
+            .add_worker(pam.clone())
+            .add_worker(jim.clone())
+            .add_worker(dwight.clone());
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+
+        // Changes on the first and last days of a month, including a leap day
+        for (worker, status, changed_on) in [
+            (&dwight, EmploymentStatus::Terminated, date(2024, 1, 31)),
+            (&jim, EmploymentStatus::Transferred, date(2024, 2, 29)),
+            (&pam, EmploymentStatus::Quit, date(2024, 3, 1)),
+            (&dwight, EmploymentStatus::Active, date(2024, 3, 15)),
+        ] {
+            change_status(&worksite_service, &worksite_id, worker, status, changed_on).await;
+        }
+
+        let report = worksite_service
+            .get_turnover_report(GetTurnoverReportInput {
+                worksite_id: worksite_id.clone(),
+            })
+            .await
+            .unwrap();
+
+        let month =
+            |month: NaiveDate, headcount, hired, terminated, quit, transferred| MonthlyTurnover {
+                month,
+                headcount,
+                hired,
+                terminated,
+                quit,
+                transferred,
+            };
+        assert_eq!(
+            report.months,
+            vec![
+                month(date(2024, 3, 1), 1, 1, 0, 1, 0),
+                month(date(2024, 2, 1), 2, 0, 0, 0, 1),
+                month(date(2024, 1, 1), 2, 1, 1, 0, 0),
+                month(date(2023, 12, 1), 1, 1, 0, 0, 0),
+            ]
+        );
+        let percents: Vec<usize> = report
+            .months
+            .iter()
+            .map(|month| month.turnover_percent())
+            .collect();
+        assert_eq!(percents, vec![100, 50, 50, 0]);
+        assert_eq!((report.total_hired(), report.total_left()), (3, 3));
+    }
+
+    #[tokio::test]
+    async fn turnover_is_empty_without_any_hires_or_departures() {
+        let worksite = Worksite::new("Scranton".into()).add_worker(worker("Pam", "Beesly"));
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+
+        let report = worksite_service
+            .get_turnover_report(GetTurnoverReportInput { worksite_id })
+            .await
+            .unwrap();
+
+        assert!(report.months.is_empty());
+        assert_eq!(report.total_left(), 0);
+    }
+
+    #[tokio::test]
+    async fn leaver_alerts_are_raised_for_committee_members_and_strong_supporters() {
+        let mut pam = worker("Pam", "Beesly");
+        pam.committee_member = true;
+        let jim = assessed(worker("Jim", "Halpert"), 2);
+        let dwight = assessed(worker("Dwight", "Schrute"), 4);
+        let angela = assessed(worker("Angela", "Martin"), 1);
+        let worksite = Worksite::new("Scranton".into())
+            .add_worker(pam.clone())
+            .add_worker(jim.clone())
+            .add_worker(dwight.clone())
+            .add_worker(angela.clone());
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+
+        for (worker, status, changed_on) in [
+            (&pam, EmploymentStatus::Quit, date(2024, 1, 10)),
+            (&jim, EmploymentStatus::Transferred, date(2024, 2, 10)),
+            (&dwight, EmploymentStatus::Terminated, date(2024, 3, 10)),
+            (&angela, EmploymentStatus::OnLeave, date(2024, 3, 10)),
+        ] {
+            change_status(&worksite_service, &worksite_id, worker, status, changed_on).await;
+        }
+
+        let alerts: Vec<(String, EmploymentStatus)> = worksite_service
+            .get_leaver_alerts(GetLeaverAlertsInput { worksite_id })
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|alert| (alert.worker.first_name, alert.change.status))
+            .collect();
+
+        assert_eq!(
+            alerts,
+            vec![
+                ("Jim".to_string(), EmploymentStatus::Transferred),
+                ("Pam".to_string(), EmploymentStatus::Quit),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn roster_only_applies_the_chosen_changes() {
+        let pam = worker("Pam", "Beesly");
+        let jim = worker("Jim", "Halpert");
+        let kevin = worker("Kevin", "Malone");
+        let day_shift = Shift::new("Day".into())
+            .assign_worker(pam.id.clone())
+            .assign_worker(jim.id.clone())
+            .assign_worker(kevin.id.clone());
+        let office = Location::new("Office".into())
+            .add_shift(day_shift)
+            .add_shift(Shift::new("Night".into()));
+        let worksite = Worksite::new("Scranton".into())
+            .add_location(office)
+            .add_worker(pam)
+            .add_worker(jim.clone())
+            .add_worker(kevin.clone());
+        let worksite_id = worksite.id.clone();
+        let worksite_service = setup(worksite);
+        let csv_input = [
+            "location,shift1,shift2,shift3,first_name,last_name,email",
+            "Office,Day,,,Pam,Beesly,pam@example.com",
+            "Office,Night,,,Jim,Halpert,jim@example.com",
+            "Office,Night,,,Erin,Hannon,erin@example.com",
+        ]
+        .join("\n");
+        let compare = || {
+            worksite_service.compare_roster(CompareRosterInput {
+                worksite_id: worksite_id.clone(),
+                csv_input: csv_input.clone(),
+            })
+        };
+
+        // Everything but Jim's move to nights
+        let keys: Vec<String> = compare()
+            .await
+            .unwrap()
+            .into_iter()
+            .filter(|change| !matches!(change, RosterChange::Move { .. }))
+            .map(|change| change.key())
+            .collect();
+        assert_eq!(keys.len(), 2);
+        worksite_service
+            .reconcile_roster(ReconcileRosterInput {
+                worksite_id: worksite_id.clone(),
+                csv_input: csv_input.clone(),
+                keys,
+                departed_as: EmploymentStatus::Terminated,
+                changed_on: date(2024, 3, 1),
+            })
+            .await
+            .unwrap();
+
+        let remaining: Vec<String> = compare()
+            .await
+            .unwrap()
+            .iter()
+            .map(|change| change.key())
+            .collect();
+        assert_eq!(remaining, vec![format!("move:{}", jim.id)]);
+        let worksite = worksite_service
+            .get_worksite(GetWorksiteInput {
+                id: worksite_id.clone(),
+            })
+            .await
+            .unwrap()
+            .unwrap();
+        let kevin = worksite.get_worker(kevin.id).unwrap();
+        assert_eq!(kevin.status, EmploymentStatus::Terminated);
+        assert_eq!(kevin.left_on(), Some(date(2024, 3, 1)));
+        let erin = worksite
+            .workers
+            .iter()
+            .find(|worker| worker.email == "erin@example.com")
+            .unwrap();
+        assert_eq!(worksite.get_placements(&erin.id).len(), 1);
+    }
+}
@@ -1,11 +1,15 @@
 use async_trait::async_trait;
-use chrono::{serde::ts_seconds, DateTime, Utc};
+use chrono::{serde::ts_seconds, DateTime, NaiveDate, Utc};
 use futures::TryStreamExt;
 use mongodb::bson::doc;
 use serde::{Deserialize, Serialize};
 use worksite_service::{
     models::{
-        Address, Assessment, AssignedTag, Location, Shift, ShiftWorker, Tag, Worker, Worksite,
+        Action, Address, Assessment, AssignedTag, AuthorizationCard, CardThreshold, Conversation,
+        ConversationChannel, CustomField, CustomFieldType, CustomFieldValue, CustomValue,
+        Eligibility, Employment, EmploymentStatus, EmploymentType, ExclusionReason, Location,
+        Participation, ParticipationStatus, Relationship, RelationshipKind, Shift, ShiftWorker,
+        StatusChange, Tag, Turf, TurfAssignment, Worker, Worksite,
     },
     ports::worksite_repository::{RepositoryFailure, WorksiteRepository},
 };
@@ -17,6 +21,25 @@ struct WorksiteRecord {
     pub locations: Vec<LocationRecord>,
     pub tags: Vec<TagRecord>,
     pub workers: Vec<WorkerRecord>,
+    // Missing from worksites saved before organizers had turf
+    #[serde(default)]
+    pub turf_assignments: Vec<TurfAssignmentRecord>,
+    #[serde(default)]
+    pub actions: Vec<ActionRecord>,
+    #[serde(default = "default_card_thresholds")]
+    pub card_thresholds: Vec<CardThresholdRecord>,
+    #[serde(default)]
+    pub custom_fields: Vec<CustomFieldRecord>,
+    #[serde(default)]
+    pub relationships: Vec<RelationshipRecord>,
+}
+
+// Worksites saved before thresholds were configurable get the same ones as new worksites
+fn default_card_thresholds() -> Vec<CardThresholdRecord> {
+    CardThreshold::defaults()
+        .iter()
+        .map(to_card_threshold_record)
+        .collect()
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -25,10 +48,36 @@ pub struct WorkerRecord {
     pub first_name: String,
     pub last_name: String,
     pub assessments: Vec<AssessmentRecord>,
+    // Missing from workers saved before conversations were logged
+    #[serde(default)]
+    pub conversations: Vec<ConversationRecord>,
+    #[serde(default)]
+    pub committee_member: bool,
+    #[serde(default)]
+    pub leader_id: Option<String>,
+    #[serde(default)]
+    pub authorization_card: Option<AuthorizationCardRecord>,
+    // Supervisor, Manager, Confidential or Other, and missing for workers in the unit
+    #[serde(default)]
+    pub exclusion_reason: Option<String>,
     // Tag ids
     pub tags: Vec<String>,
     pub email: String,
+    // Missing from workers saved before employment details were tracked
+    #[serde(default)]
+    pub phone: String,
     pub address: Option<AddressRecord>,
+    #[serde(default)]
+    pub employment: EmploymentRecord,
+    // Active, OnLeave, Terminated, Quit or Transferred
+    #[serde(default = "default_status")]
+    pub status: String,
+    #[serde(default)]
+    pub status_changes: Vec<StatusChangeRecord>,
+    #[serde(default)]
+    pub supervisor_id: Option<String>,
+    #[serde(default)]
+    pub custom_values: Vec<CustomValueRecord>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -39,6 +88,60 @@ pub struct AddressRecord {
     pub postal_code: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomFieldRecord {
+    pub id: String,
+    pub name: String,
+    // Text, Number, Date, SingleSelect, MultiSelect or Boolean
+    pub field_type: String,
+    pub options: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomValueRecord {
+    pub field_id: String,
+    // The field type, so the values can be read back without the field
+    pub kind: String,
+    // Dates are YYYY-MM-DD and yes/no is "true" or "false". Only multi-selects have more than one.
+    pub values: Vec<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct EmploymentRecord {
+    pub job_title: String,
+    pub department: String,
+    pub hire_date: Option<NaiveDate>,
+    pub pay_rate: String,
+    // FullTime, PartTime or Temp
+    pub employment_type: Option<String>,
+}
+
+// Workers saved before statuses were tracked were all still there
+fn default_status() -> String {
+    EmploymentStatus::Active.to_string()
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StatusChangeRecord {
+    pub previous: String,
+    pub status: String,
+    pub changed_on: NaiveDate,
+    pub note: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuthorizationCardRecord {
+    pub signed_on: NaiveDate,
+    pub witnessed_by: String,
+    pub on_file: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CardThresholdRecord {
+    pub name: String,
+    pub percent: u8,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AssessmentRecord {
     pub id: String,
@@ -51,6 +154,59 @@ pub struct AssessmentRecord {
     pub assessor: String,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConversationRecord {
+    pub id: String,
+    pub held_on: NaiveDate,
+    pub organizer: String,
+    pub channel: String,
+    pub issues: Vec<String>,
+    pub notes: String,
+    pub next_step: String,
+    #[serde(with = "ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TurfAssignmentRecord {
+    pub id: String,
+    pub user_id: String,
+    // Location, Shift or Worker
+    pub kind: String,
+    pub turf_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ActionRecord {
+    pub id: String,
+    pub held_on: NaiveDate,
+    pub description: String,
+    pub participation: Vec<ParticipationRecord>,
+    #[serde(with = "ts_seconds")]
+    pub created_at: DateTime<Utc>,
+    #[serde(with = "ts_seconds")]
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ParticipationRecord {
+    pub worker_id: String,
+    // Asked, Yes, No or DidIt
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RelationshipRecord {
+    pub id: String,
+    pub from_worker_id: String,
+    pub to_worker_id: String,
+    // Carpool, Friend, Family, BreakTime, Mentor or Other
+    pub kind: String,
+    pub directional: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TagRecord {
     pub id: String,
@@ -81,10 +237,86 @@ impl WorksiteRecord {
             locations: self.locations.iter().map(|l| l.to_location()).collect(),
             tags: self.tags.iter().map(|t| t.to_tag()).collect(),
             workers: self.workers.iter().map(|w| w.to_worker()).collect(),
+            turf_assignments: self
+                .turf_assignments
+                .iter()
+                .filter_map(|a| a.to_turf_assignment())
+                .collect(),
+            actions: self.actions.iter().map(|a| a.to_action()).collect(),
+            card_thresholds: self
+                .card_thresholds
+                .iter()
+                .map(|t| CardThreshold::new(t.name.clone(), t.percent))
+                .collect(),
+            custom_fields: self
+                .custom_fields
+                .iter()
+                .filter_map(|f| f.to_custom_field())
+                .collect(),
+            relationships: self
+                .relationships
+                .iter()
+                .filter_map(|r| r.to_relationship())
+                .collect(),
         }
     }
 }
 
+impl StatusChangeRecord {
+    pub fn to_status_change(&self) -> Option<StatusChange> {
+        Some(StatusChange {
+            previous: EmploymentStatus::new(&self.previous)?,
+            status: EmploymentStatus::new(&self.status)?,
+            changed_on: self.changed_on,
+            note: self.note.clone(),
+        })
+    }
+}
+
+impl RelationshipRecord {
+    pub fn to_relationship(&self) -> Option<Relationship> {
+        Some(Relationship {
+            id: self.id.clone(),
+            from_worker_id: self.from_worker_id.clone(),
+            to_worker_id: self.to_worker_id.clone(),
+            kind: RelationshipKind::new(&self.kind)?,
+            directional: self.directional,
+        })
+    }
+}
+
+impl CustomFieldRecord {
+    pub fn to_custom_field(&self) -> Option<CustomField> {
+        Some(CustomField {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            field_type: CustomFieldType::new(&self.field_type)?,
+            options: self.options.clone(),
+        })
+    }
+}
+
+impl CustomValueRecord {
+    pub fn to_custom_value(&self) -> Option<CustomValue> {
+        let first = self.values.first().cloned();
+        let value = match CustomFieldType::new(&self.kind)? {
+            CustomFieldType::Text => CustomFieldValue::Text(first?),
+            CustomFieldType::Number => CustomFieldValue::Number(first?),
+            CustomFieldType::Date => {
+                CustomFieldValue::Date(NaiveDate::parse_from_str(&first?, "%Y-%m-%d").ok()?)
+            }
+            CustomFieldType::SingleSelect => CustomFieldValue::SingleSelect(first?),
+            CustomFieldType::MultiSelect => CustomFieldValue::MultiSelect(self.values.clone()),
+            CustomFieldType::Boolean => CustomFieldValue::Boolean(first? == "true"),
+        };
+
+        Some(CustomValue {
+            field_id: self.field_id.clone(),
+            value,
+        })
+    }
+}
+
 impl WorkerRecord {
     pub fn to_worker(&self) -> Worker {
         worksite_service::models::Worker {
@@ -92,13 +324,58 @@ impl WorkerRecord {
             first_name: self.first_name.clone(),
             last_name: self.last_name.clone(),
             assessments: self.assessments.iter().map(|a| a.to_assessment()).collect(),
+            conversations: self
+                .conversations
+                .iter()
+                .map(|c| c.to_conversation())
+                .collect(),
+            committee_member: self.committee_member,
+            leader_id: self.leader_id.clone(),
+            authorization_card: self.authorization_card.as_ref().map(|c| AuthorizationCard {
+                signed_on: c.signed_on,
+                witnessed_by: c.witnessed_by.clone(),
+                on_file: c.on_file,
+            }),
+            // Only ever written from an ExclusionReason
+            eligibility: match self.exclusion_reason.as_ref() {
+                Some(reason) => Eligibility::Excluded(
+                    ExclusionReason::new(reason).unwrap_or(ExclusionReason::Other),
+                ),
+                None => Eligibility::Eligible,
+            },
             tags: self
                 .tags
                 .iter()
                 .map(|t| AssignedTag::new(t.clone()))
                 .collect(),
             email: self.email.clone(),
+            phone: self.phone.clone(),
             address: self.address.as_ref().map(|a| a.to_address()),
+            employment: self.employment.to_employment(),
+            status: EmploymentStatus::new(&self.status).unwrap_or(EmploymentStatus::Active),
+            status_changes: self
+                .status_changes
+                .iter()
+                .filter_map(|c| c.to_status_change())
+                .collect(),
+            supervisor_id: self.supervisor_id.clone(),
+            custom_values: self
+                .custom_values
+                .iter()
+                .filter_map(|v| v.to_custom_value())
+                .collect(),
+        }
+    }
+}
+
+impl EmploymentRecord {
+    pub fn to_employment(&self) -> Employment {
+        Employment {
+            job_title: self.job_title.clone(),
+            department: self.department.clone(),
+            hire_date: self.hire_date,
+            pay_rate: self.pay_rate.clone(),
+            employment_type: self.employment_type.as_ref().and_then(EmploymentType::new),
         }
     }
 }
@@ -127,6 +404,58 @@ impl AssessmentRecord {
     }
 }
 
+impl ConversationRecord {
+    pub fn to_conversation(&self) -> Conversation {
+        Conversation {
+            id: self.id.clone(),
+            held_on: self.held_on,
+            organizer: self.organizer.clone(),
+            // Only ever written from a ConversationChannel
+            channel: ConversationChannel::new(&self.channel)
+                .unwrap_or(ConversationChannel::InPerson),
+            issues: self.issues.clone(),
+            notes: self.notes.clone(),
+            next_step: self.next_step.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+impl TurfAssignmentRecord {
+    // None for kinds of turf this version doesn't know about
+    pub fn to_turf_assignment(&self) -> Option<TurfAssignment> {
+        Some(TurfAssignment {
+            id: self.id.clone(),
+            user_id: self.user_id.clone(),
+            turf: Turf::new(&self.kind, self.turf_id.clone())?,
+        })
+    }
+}
+
+impl ActionRecord {
+    pub fn to_action(&self) -> Action {
+        Action {
+            id: self.id.clone(),
+            held_on: self.held_on,
+            description: self.description.clone(),
+            // Only ever written from a ParticipationStatus
+            participation: self
+                .participation
+                .iter()
+                .filter_map(|p| {
+                    Some(Participation {
+                        worker_id: p.worker_id.clone(),
+                        status: ParticipationStatus::new(&p.status)?,
+                    })
+                })
+                .collect(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
 impl TagRecord {
     pub fn to_tag(&self) -> Tag {
         Tag {
@@ -176,6 +505,90 @@ fn to_worksite_record(worksite: &Worksite) -> WorksiteRecord {
             .iter()
             .map(to_worker_record)
             .collect(),
+        turf_assignments: worksite
+            .turf_assignments
+            .iter()
+            .map(to_turf_assignment_record)
+            .collect(),
+        actions: worksite.actions.iter().map(to_action_record).collect(),
+        card_thresholds: worksite
+            .card_thresholds
+            .iter()
+            .map(to_card_threshold_record)
+            .collect(),
+        custom_fields: worksite
+            .custom_fields
+            .iter()
+            .map(to_custom_field_record)
+            .collect(),
+        relationships: worksite
+            .relationships
+            .iter()
+            .map(to_relationship_record)
+            .collect(),
+    }
+}
+
+fn to_relationship_record(relationship: &Relationship) -> RelationshipRecord {
+    RelationshipRecord {
+        id: relationship.id.clone(),
+        from_worker_id: relationship.from_worker_id.clone(),
+        to_worker_id: relationship.to_worker_id.clone(),
+        kind: relationship.kind.to_string(),
+        directional: relationship.directional,
+    }
+}
+
+fn to_custom_field_record(field: &CustomField) -> CustomFieldRecord {
+    CustomFieldRecord {
+        id: field.id.clone(),
+        name: field.name.clone(),
+        field_type: field.field_type.to_string(),
+        options: field.options.clone(),
+    }
+}
+
+fn to_custom_value_record(custom_value: &CustomValue) -> CustomValueRecord {
+    let (kind, values) = match &custom_value.value {
+        CustomFieldValue::Text(text) => (CustomFieldType::Text, vec![text.clone()]),
+        CustomFieldValue::Number(number) => (CustomFieldType::Number, vec![number.clone()]),
+        CustomFieldValue::Date(date) => (CustomFieldType::Date, vec![date.to_string()]),
+        CustomFieldValue::SingleSelect(option) => {
+            (CustomFieldType::SingleSelect, vec![option.clone()])
+        }
+        CustomFieldValue::MultiSelect(options) => (CustomFieldType::MultiSelect, options.clone()),
+        CustomFieldValue::Boolean(answer) => (CustomFieldType::Boolean, vec![answer.to_string()]),
+    };
+
+    CustomValueRecord {
+        field_id: custom_value.field_id.clone(),
+        kind: kind.to_string(),
+        values,
+    }
+}
+
+fn to_card_threshold_record(threshold: &CardThreshold) -> CardThresholdRecord {
+    CardThresholdRecord {
+        name: threshold.name.clone(),
+        percent: threshold.percent,
+    }
+}
+
+fn to_action_record(action: &Action) -> ActionRecord {
+    ActionRecord {
+        id: action.id.clone(),
+        held_on: action.held_on,
+        description: action.description.clone(),
+        participation: action
+            .participation
+            .iter()
+            .map(|p| ParticipationRecord {
+                worker_id: p.worker_id.clone(),
+                status: p.status.to_string(),
+            })
+            .collect(),
+        created_at: action.created_at,
+        updated_at: action.updated_at,
     }
 }
 
@@ -189,9 +602,59 @@ fn to_worker_record(worker: &Worker) -> WorkerRecord {
             .iter()
             .map(to_assessment_record)
             .collect(),
+        conversations: worker
+            .conversations
+            .iter()
+            .map(to_conversation_record)
+            .collect(),
+        committee_member: worker.committee_member,
+        leader_id: worker.leader_id.clone(),
+        authorization_card: worker
+            .authorization_card
+            .as_ref()
+            .map(|c| AuthorizationCardRecord {
+                signed_on: c.signed_on,
+                witnessed_by: c.witnessed_by.clone(),
+                on_file: c.on_file,
+            }),
+        exclusion_reason: worker
+            .eligibility
+            .exclusion_reason()
+            .map(|reason| reason.to_string()),
         tags: worker.tags.iter().map(|t| t.0.clone()).collect(),
         email: worker.email.clone(),
+        phone: worker.phone.clone(),
         address: worker.address.as_ref().map(to_address_record),
+        employment: to_employment_record(&worker.employment),
+        status: worker.status.to_string(),
+        status_changes: worker
+            .status_changes
+            .iter()
+            .map(|c| StatusChangeRecord {
+                previous: c.previous.to_string(),
+                status: c.status.to_string(),
+                changed_on: c.changed_on,
+                note: c.note.clone(),
+            })
+            .collect(),
+        supervisor_id: worker.supervisor_id.clone(),
+        custom_values: worker
+            .custom_values
+            .iter()
+            .map(to_custom_value_record)
+            .collect(),
+    }
+}
+
+fn to_employment_record(employment: &Employment) -> EmploymentRecord {
+    EmploymentRecord {
+        job_title: employment.job_title.clone(),
+        department: employment.department.clone(),
+        hire_date: employment.hire_date,
+        pay_rate: employment.pay_rate.clone(),
+        employment_type: employment
+            .employment_type
+            .map(|employment_type| employment_type.to_string()),
     }
 }
 
@@ -215,6 +678,29 @@ fn to_assessment_record(assessment: &Assessment) -> AssessmentRecord {
     }
 }
 
+fn to_conversation_record(conversation: &Conversation) -> ConversationRecord {
+    ConversationRecord {
+        id: conversation.id.clone(),
+        held_on: conversation.held_on,
+        organizer: conversation.organizer.clone(),
+        channel: conversation.channel.to_string(),
+        issues: conversation.issues.clone(),
+        notes: conversation.notes.clone(),
+        next_step: conversation.next_step.clone(),
+        created_at: conversation.created_at,
+        updated_at: conversation.updated_at,
+    }
+}
+
+fn to_turf_assignment_record(assignment: &TurfAssignment) -> TurfAssignmentRecord {
+    TurfAssignmentRecord {
+        id: assignment.id.clone(),
+        user_id: assignment.user_id.clone(),
+        kind: assignment.turf.kind().into(),
+        turf_id: assignment.turf.id().clone(),
+    }
+}
+
 fn to_tag_record(tag: &Tag) -> TagRecord {
     TagRecord {
         id: tag.id.clone(),
@@ -304,15 +790,20 @@ impl WorksiteRepository for MongoWorksiteRepository {
 
 #[cfg(test)]
 mod tests {
-    use chrono::{TimeZone, Utc};
-    
+    use chrono::{NaiveDate, TimeZone, Utc};
+
     use mongo_testcontainer::Mongo;
     use mongodb::Client;
     use pretty_assertions::assert_eq;
     use testcontainers::clients;
     use worksite_service::{
         models::{
-            Address, Assessment, AssignedTag, Location, Shift, ShiftWorker, Tag, Worker, Worksite,
+            Action, Address, Assessment, AssignedTag, AuthorizationCard, CardThreshold,
+            Conversation, ConversationChannel, CustomField, CustomFieldType, CustomFieldValue,
+            CustomValue, Eligibility, Employment, EmploymentStatus, EmploymentType,
+            ExclusionReason, Location, Participation, ParticipationStatus, Relationship,
+            RelationshipKind, Shift, ShiftWorker, StatusChange, Tag, Turf, TurfAssignment, Worker,
+            Worksite,
         },
         ports::worksite_repository::WorksiteRepository,
     };
@@ -383,6 +874,7 @@ mod tests {
                     first_name: "Jim".into(),
                     last_name: "Halpert".into(),
                     email: "jim.halpert@skynet.org".into(),
+                    phone: "".into(),
                     assessments: vec![Assessment {
                         id: "1".into(),
                         value: 1,
@@ -391,18 +883,52 @@ mod tests {
                         created_at: Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap(),
                         updated_at: Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap(),
                     }],
+                    conversations: vec![Conversation {
+                        id: "1".into(),
+                        held_on: NaiveDate::from_ymd_opt(2021, 1, 4).unwrap(),
+                        organizer: "Victoria Hall".into(),
+                        channel: ConversationChannel::Phone,
+                        issues: vec!["Scheduling".into(), "Pay".into()],
+                        notes: "Wants to see the new schedule before deciding.".into(),
+                        next_step: "Bring the schedule on Friday".into(),
+                        created_at: Utc.with_ymd_and_hms(2021, 1, 4, 0, 0, 0).unwrap(),
+                        updated_at: Utc.with_ymd_and_hms(2021, 1, 4, 0, 0, 0).unwrap(),
+                    }],
+                    committee_member: true,
+                    leader_id: None,
+                    authorization_card: Some(AuthorizationCard {
+                        signed_on: NaiveDate::from_ymd_opt(2023, 9, 14).unwrap(),
+                        witnessed_by: "Victoria Hall".into(),
+                        on_file: false,
+                    }),
+                    eligibility: Eligibility::Eligible,
                     tags: vec![
                         AssignedTag::new("1".into()),
                         AssignedTag::new("2".into()),
                         AssignedTag::new("3".into()),
                     ],
                     address: Some(Address::default()),
+                    employment: Employment {
+                        job_title: "Sales Representative".into(),
+                        department: "Sales".into(),
+                        hire_date: NaiveDate::from_ymd_opt(2005, 3, 24),
+                        pay_rate: "Commission".into(),
+                        employment_type: Some(EmploymentType::FullTime),
+                    },
+                    status: EmploymentStatus::Active,
+                    status_changes: vec![],
+                    supervisor_id: None,
+                    custom_values: vec![CustomValue {
+                        field_id: "1".into(),
+                        value: CustomFieldValue::MultiSelect(vec!["English".into()]),
+                    }],
                 },
                 Worker {
                     id: "2".into(),
                     first_name: "Pam".into(),
                     last_name: "Beesly".into(),
                     email: "pam.beesly@skynet.org".into(),
+                    phone: "".into(),
                     assessments: vec![Assessment {
                         id: "2".into(),
                         value: 2,
@@ -411,18 +937,29 @@ mod tests {
                         created_at: Utc.with_ymd_and_hms(2022, 10, 12, 0, 0, 0).unwrap(),
                         updated_at: Utc.with_ymd_and_hms(2022, 10, 12, 0, 0, 0).unwrap(),
                     }],
+                    conversations: vec![],
+                    committee_member: false,
+                    leader_id: Some("1".into()),
+                    authorization_card: None,
+                    eligibility: Eligibility::Eligible,
                     tags: vec![
                         AssignedTag::new("1".into()),
                         AssignedTag::new("2".into()),
                         AssignedTag::new("3".into()),
                     ],
                     address: Some(Address::default()),
+                    employment: Employment::default(),
+                    status: EmploymentStatus::Active,
+                    status_changes: vec![],
+                    supervisor_id: None,
+                    custom_values: vec![],
                 },
                 Worker {
                     id: "3".into(),
                     first_name: "Dwight".into(),
                     last_name: "Schrute".into(),
                     email: "dwight.schrute@skynet.org".into(),
+                    phone: "".into(),
                     assessments: vec![
                         Assessment {
                             id: "3".into(),
@@ -441,14 +978,25 @@ mod tests {
                             updated_at: Utc.with_ymd_and_hms(2022, 6, 2, 0, 0, 0).unwrap(),
                         },
                     ],
+                    conversations: vec![],
+                    committee_member: false,
+                    leader_id: None,
+                    authorization_card: None,
+                    eligibility: Eligibility::Eligible,
                     tags: vec![AssignedTag::new("3".into())],
                     address: Some(Address::default()),
+                    employment: Employment::default(),
+                    status: EmploymentStatus::Active,
+                    status_changes: vec![],
+                    supervisor_id: None,
+                    custom_values: vec![],
                 },
                 Worker {
                     id: "4".into(),
                     first_name: "Darryl".into(),
                     last_name: "Philbin".into(),
                     email: "darryl.philbin@skynet.org".into(),
+                    phone: "".into(),
                     assessments: vec![Assessment {
                         id: "4".into(),
                         value: 1,
@@ -457,14 +1005,25 @@ mod tests {
                         created_at: Utc.with_ymd_and_hms(2023, 7, 4, 0, 0, 0).unwrap(),
                         updated_at: Utc.with_ymd_and_hms(2023, 7, 4, 0, 0, 0).unwrap(),
                     }],
+                    conversations: vec![],
+                    committee_member: false,
+                    leader_id: None,
+                    authorization_card: None,
+                    eligibility: Eligibility::Excluded(ExclusionReason::Supervisor),
                     tags: vec![AssignedTag::new("2".into()), AssignedTag::new("3".into())],
                     address: Some(Address::default()),
+                    employment: Employment::default(),
+                    status: EmploymentStatus::Active,
+                    status_changes: vec![],
+                    supervisor_id: None,
+                    custom_values: vec![],
                 },
                 Worker {
                     id: "5".into(),
                     first_name: "Nate".into(),
                     last_name: "Nickerson".into(),
                     email: "nate.nickerson@skynet.org".into(),
+                    phone: "".into(),
                     assessments: vec![Assessment {
                         id: "5".into(),
                         value: 3,
@@ -473,14 +1032,25 @@ mod tests {
                         created_at: Utc.with_ymd_and_hms(2023, 2, 6, 0, 0, 0).unwrap(),
                         updated_at: Utc.with_ymd_and_hms(2023, 2, 6, 0, 0, 0).unwrap(),
                     }],
+                    conversations: vec![],
+                    committee_member: false,
+                    leader_id: None,
+                    authorization_card: None,
+                    eligibility: Eligibility::Eligible,
                     tags: vec![AssignedTag::new("1".into())],
                     address: Some(Address::default()),
+                    employment: Employment::default(),
+                    status: EmploymentStatus::Active,
+                    status_changes: vec![],
+                    supervisor_id: Some("4".into()),
+                    custom_values: vec![],
                 },
                 Worker {
                     id: "6".into(),
                     first_name: "Roy".into(),
                     last_name: "Anderson".into(),
                     email: "roy.anderson@skynet.org".into(),
+                    phone: "".into(),
                     assessments: vec![Assessment {
                         id: "3".into(),
                         value: 3,
@@ -489,14 +1059,30 @@ mod tests {
                         created_at: Utc.with_ymd_and_hms(2023, 4, 9, 0, 0, 0).unwrap(),
                         updated_at: Utc.with_ymd_and_hms(2023, 4, 9, 0, 0, 0).unwrap(),
                     }],
+                    conversations: vec![],
+                    committee_member: false,
+                    leader_id: None,
+                    authorization_card: None,
+                    eligibility: Eligibility::Eligible,
                     tags: vec![AssignedTag::new("2".into()), AssignedTag::new("3".into())],
                     address: Some(Address::default()),
+                    employment: Employment::default(),
+                    status: EmploymentStatus::Quit,
+                    status_changes: vec![StatusChange {
+                        previous: EmploymentStatus::Active,
+                        status: EmploymentStatus::Quit,
+                        changed_on: NaiveDate::from_ymd_opt(2023, 6, 2).unwrap(),
+                        note: "Took a job at the other warehouse".into(),
+                    }],
+                    supervisor_id: Some("4".into()),
+                    custom_values: vec![],
                 },
                 Worker {
                     id: "7".into(),
                     first_name: "Val".into(),
                     last_name: "Johnson".into(),
                     email: "val.johnson@skynet.org".into(),
+                    phone: "".into(),
                     assessments: vec![Assessment {
                         id: "7".into(),
                         value: 2,
@@ -505,14 +1091,70 @@ mod tests {
                         created_at: Utc.with_ymd_and_hms(2023, 10, 18, 0, 0, 0).unwrap(),
                         updated_at: Utc.with_ymd_and_hms(2023, 10, 18, 0, 0, 0).unwrap(),
                     }],
+                    conversations: vec![],
+                    committee_member: false,
+                    leader_id: None,
+                    authorization_card: None,
+                    eligibility: Eligibility::Eligible,
                     tags: vec![
                         AssignedTag::new("1".into()),
                         AssignedTag::new("2".into()),
                         AssignedTag::new("3".into()),
                     ],
                     address: Some(Address::default()),
+                    employment: Employment::default(),
+                    status: EmploymentStatus::Active,
+                    status_changes: vec![],
+                    supervisor_id: Some("4".into()),
+                    custom_values: vec![],
+                },
+            ],
+            turf_assignments: vec![
+                TurfAssignment {
+                    id: "1".into(),
+                    user_id: "user-1".into(),
+                    turf: Turf::Location("1".into()),
                 },
+                TurfAssignment {
+                    id: "2".into(),
+                    user_id: "user-2".into(),
+                    turf: Turf::Worker("6".into()),
+                },
+            ],
+            actions: vec![Action {
+                id: "1".into(),
+                held_on: NaiveDate::from_ymd_opt(2023, 11, 1).unwrap(),
+                description: "Wear union buttons".into(),
+                participation: vec![
+                    Participation {
+                        worker_id: "1".into(),
+                        status: ParticipationStatus::DidIt,
+                    },
+                    Participation {
+                        worker_id: "2".into(),
+                        status: ParticipationStatus::No,
+                    },
+                ],
+                created_at: Utc.with_ymd_and_hms(2023, 10, 20, 0, 0, 0).unwrap(),
+                updated_at: Utc.with_ymd_and_hms(2023, 11, 2, 0, 0, 0).unwrap(),
+            }],
+            card_thresholds: vec![
+                CardThreshold::new("File for an election", 30),
+                CardThreshold::new("Supermajority", 65),
             ],
+            custom_fields: vec![CustomField {
+                id: "1".into(),
+                name: "Languages spoken".into(),
+                field_type: CustomFieldType::MultiSelect,
+                options: vec!["English".into(), "Spanish".into()],
+            }],
+            relationships: vec![Relationship {
+                id: "1".into(),
+                from_worker_id: "1".into(),
+                to_worker_id: "2".into(),
+                kind: RelationshipKind::Mentor,
+                directional: true,
+            }],
         }
     }
 
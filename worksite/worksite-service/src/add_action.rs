@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::Action,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct AddAction {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AddActionInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub held_on: NaiveDate,
+    pub description: String,
+}
+
+// Change the return type, if needed
+pub type AddActionOutput = Result<Action, AddActionFailure>;
+
+impl AddAction {
+    pub async fn add_action(&self, input: AddActionInput) -> AddActionOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| AddActionFailure::Unknown(e.to_string()))?
+            .ok_or(AddActionFailure::NotFound)?;
+
+        let action = Action::new(input.held_on, input.description);
+        let updated_worksite = worksite.add_action(action.clone());
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| AddActionFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::ActionAdded {
+                    action: action.clone(),
+                },
+            ))
+            .await;
+
+        Ok(action)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum AddActionFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -4,13 +4,15 @@ use chrono::Utc;
 use thiserror::Error;
 
 use crate::{
+    events::{DomainEvent, WorksiteEvent},
     models::{Assessment, Worker},
-    ports::worksite_repository::WorksiteRepository,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
 };
 
 #[derive(Clone)]
 pub struct AddAssessment {
     pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -35,21 +37,37 @@ impl AddAssessment {
             .map_err(|e| AddAssessmentFailure::Unknown(e.to_string()))?
             .ok_or(AddAssessmentFailure::NotFound)?;
 
-        let updated_worksite = worksite.update_worker(input.worker_id, |worker| -> Worker {
-            worker.add_assessment(Assessment {
-                id: uuid::Uuid::new_v4().to_string(),
-                value: input.value,
-                notes: input.notes,
-                assessor: input.assessor,
-                created_at: Utc::now(),
-                updated_at: Utc::now(),
-            })
-        });
+        let assessment = Assessment {
+            id: uuid::Uuid::new_v4().to_string(),
+            value: input.value,
+            notes: input.notes,
+            assessor: input.assessor,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        let assessed = worksite.get_worker(input.worker_id.clone()).is_some();
+
+        let updated_worksite = worksite
+            .update_worker(input.worker_id.clone(), |worker| -> Worker {
+                worker.add_assessment(assessment.clone())
+            });
         self.worksite_repository
-            .save(updated_worksite)
+            .save(updated_worksite.clone())
             .await
             .map_err(|e| AddAssessmentFailure::Unknown(e.to_string()))?;
 
+        if assessed {
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    updated_worksite.id,
+                    WorksiteEvent::WorkerAssessed {
+                        worker_id: input.worker_id,
+                        assessment,
+                    },
+                ))
+                .await;
+        }
+
         Ok(())
     }
 }
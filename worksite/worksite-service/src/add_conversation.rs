@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, Utc};
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::{Conversation, ConversationChannel, Worker},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct AddConversation {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AddConversationInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub worker_id: String,
+    pub held_on: NaiveDate,
+    pub organizer: String,
+    pub channel: ConversationChannel,
+    pub issues: Vec<String>,
+    pub notes: String,
+    pub next_step: String,
+}
+
+// Change the return type, if needed
+pub type AddConversationOutput = Result<(), AddConversationFailure>;
+
+impl AddConversation {
+    pub async fn add_conversation(&self, input: AddConversationInput) -> AddConversationOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| AddConversationFailure::Unknown(e.to_string()))?
+            .ok_or(AddConversationFailure::NotFound)?;
+
+        let conversation = Conversation {
+            id: uuid::Uuid::new_v4().to_string(),
+            held_on: input.held_on,
+            organizer: input.organizer,
+            channel: input.channel,
+            issues: input.issues,
+            notes: input.notes,
+            next_step: input.next_step,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        let logged = worksite.get_worker(input.worker_id.clone()).is_some();
+
+        let updated_worksite = worksite
+            .update_worker(input.worker_id.clone(), |worker| -> Worker {
+                worker.add_conversation(conversation.clone())
+            });
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| AddConversationFailure::Unknown(e.to_string()))?;
+
+        if logged {
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    updated_worksite.id,
+                    WorksiteEvent::ConversationLogged {
+                        worker_id: input.worker_id,
+                        conversation,
+                    },
+                ))
+                .await;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum AddConversationFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
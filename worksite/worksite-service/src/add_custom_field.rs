@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::{CustomField, CustomFieldType},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct AddCustomField {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AddCustomFieldInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub name: String,
+    pub field_type: CustomFieldType,
+    // Only kept for select fields
+    pub options: Vec<String>,
+}
+
+// Change the return type, if needed
+pub type AddCustomFieldOutput = Result<CustomField, AddCustomFieldFailure>;
+
+impl AddCustomField {
+    pub async fn add_custom_field(&self, input: AddCustomFieldInput) -> AddCustomFieldOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| AddCustomFieldFailure::Unknown(e.to_string()))?
+            .ok_or(AddCustomFieldFailure::NotFound)?;
+
+        let name = input.name.trim().to_string();
+        if name.is_empty() {
+            return Err(AddCustomFieldFailure::MissingName);
+        }
+        if worksite
+            .custom_fields
+            .iter()
+            .any(|f| f.name.eq_ignore_ascii_case(&name))
+        {
+            return Err(AddCustomFieldFailure::DuplicateName);
+        }
+
+        let options = match input.field_type.has_options() {
+            true => clean_options(input.options),
+            false => vec![],
+        };
+        if input.field_type.has_options() && options.is_empty() {
+            return Err(AddCustomFieldFailure::MissingOptions);
+        }
+
+        let field = CustomField::new(name, input.field_type, options);
+        let updated_worksite = worksite.add_custom_field(field.clone());
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| AddCustomFieldFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::CustomFieldAdded {
+                    field: field.clone(),
+                },
+            ))
+            .await;
+
+        Ok(field)
+    }
+}
+
+// Trimmed, without blanks or repeats, in the order they were entered
+pub fn clean_options(options: Vec<String>) -> Vec<String> {
+    let mut cleaned: Vec<String> = vec![];
+    for option in options {
+        let option = option.trim().to_string();
+        if !option.is_empty() && !cleaned.contains(&option) {
+            cleaned.push(option);
+        }
+    }
+
+    cleaned
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum AddCustomFieldFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Field name can not be empty")]
+    MissingName,
+    #[error("A field with that name already exists")]
+    DuplicateName,
+    #[error("Select fields need at least one option")]
+    MissingOptions,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -2,11 +2,16 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
-use crate::ports::worksite_repository::WorksiteRepository;
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::Location,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
 
 #[derive(Clone)]
 pub struct AddLocation {
     pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -28,13 +33,21 @@ impl AddLocation {
             .map_err(|e| AddLocationFailure::Unknown(e.to_string()))?
             .ok_or(AddLocationFailure::NotFound)?;
 
-        let updated_worksite = worksite.add_new_location(input.location_name);
+        let location = Location::new(input.location_name);
+        let updated_worksite = worksite.add_location(location.clone());
 
         self.worksite_repository
             .save(updated_worksite.clone())
             .await
             .map_err(|e| AddLocationFailure::Unknown(e.to_string()))?;
 
+        self.event_publisher
+            .publish(DomainEvent::new(
+                input.worksite_id,
+                WorksiteEvent::LocationAdded { location },
+            ))
+            .await;
+
         Ok(())
     }
 }
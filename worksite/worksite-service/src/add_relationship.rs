@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::{Relationship, RelationshipKind},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct AddRelationship {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AddRelationshipInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub from_worker_id: String,
+    pub to_worker_id: String,
+    pub kind: RelationshipKind,
+    // True when only the from worker sways the to worker
+    pub directional: bool,
+}
+
+// Change the return type, if needed
+pub type AddRelationshipOutput = Result<Relationship, AddRelationshipFailure>;
+
+impl AddRelationship {
+    pub async fn add_relationship(&self, input: AddRelationshipInput) -> AddRelationshipOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| AddRelationshipFailure::Unknown(e.to_string()))?
+            .ok_or(AddRelationshipFailure::NotFound)?;
+
+        if input.from_worker_id == input.to_worker_id {
+            return Err(AddRelationshipFailure::SameWorker);
+        }
+        for worker_id in [&input.from_worker_id, &input.to_worker_id] {
+            worksite
+                .get_worker(worker_id.clone())
+                .ok_or(AddRelationshipFailure::WorkerNotFound)?;
+        }
+
+        let relationship = Relationship::new(
+            input.from_worker_id,
+            input.to_worker_id,
+            input.kind,
+            input.directional,
+        );
+        if worksite
+            .relationships
+            .iter()
+            .any(|existing| existing.duplicates(&relationship))
+        {
+            return Err(AddRelationshipFailure::AlreadyRelated);
+        }
+
+        let updated_worksite = worksite.add_relationship(relationship.clone());
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| AddRelationshipFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::RelationshipAdded {
+                    relationship: relationship.clone(),
+                },
+            ))
+            .await;
+
+        Ok(relationship)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum AddRelationshipFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Worker does not exist")]
+    WorkerNotFound,
+    #[error("A worker can't have a relationship with themselves")]
+    SameWorker,
+    #[error("These workers already have that relationship")]
+    AlreadyRelated,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -2,11 +2,15 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
-use crate::ports::worksite_repository::WorksiteRepository;
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
 
 #[derive(Clone)]
 pub struct AddShift {
     pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -29,7 +33,7 @@ impl AddShift {
             .ok_or(AddShiftFailure::NotFound)?;
 
         let updated_worksite = worksite
-            .add_shift(input.location_id, input.shift_name)
+            .add_shift(input.location_id.clone(), input.shift_name)
             .ok_or(AddShiftFailure::LocationNotFound)?;
 
         self.worksite_repository
@@ -37,6 +41,24 @@ impl AddShift {
             .await
             .map_err(|e| AddShiftFailure::Unknown(e.to_string()))?;
 
+        // The new shift is the last one at its location
+        let shift = updated_worksite
+            .locations
+            .iter()
+            .find(|l| l.id == input.location_id)
+            .and_then(|l| l.shifts.last());
+        if let Some(shift) = shift {
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    input.worksite_id,
+                    WorksiteEvent::ShiftAdded {
+                        location_id: input.location_id,
+                        shift: shift.clone(),
+                    },
+                ))
+                .await;
+        }
+
         Ok(())
     }
 }
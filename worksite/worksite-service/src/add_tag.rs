@@ -2,11 +2,16 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
-use crate::{models::Tag, ports::worksite_repository::WorksiteRepository};
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::Tag,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
 
 #[derive(Clone)]
 pub struct AddTag {
     pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -30,17 +35,25 @@ impl AddTag {
             .ok_or(AddTagFailure::NotFound)?;
 
         // TODO! Implement uuid generation as a port
-        let worksite = worksite.add_tag(Tag {
+        let tag = Tag {
             id: uuid::Uuid::new_v4().to_string(),
             name: input.name.clone(),
             icon: input.icon.clone(),
-        });
+        };
+        let worksite = worksite.add_tag(tag.clone());
 
         self.worksite_repository
-            .save(worksite)
+            .save(worksite.clone())
             .await
             .map_err(|e| AddTagFailure::Unknown(e.to_string()))?;
 
+        self.event_publisher
+            .publish(DomainEvent::new(
+                worksite.id,
+                WorksiteEvent::TagAdded { tag },
+            ))
+            .await;
+
         Ok(())
     }
 }
@@ -3,13 +3,15 @@ use std::sync::Arc;
 use thiserror::Error;
 
 use crate::{
-    models::{Address, Worker},
-    ports::worksite_repository::WorksiteRepository,
+    events::{DomainEvent, WorksiteEvent},
+    models::{Address, CustomValue, Eligibility, Employment, EmploymentStatus, Worker},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
 };
 
 #[derive(Clone)]
 pub struct AddWorker {
     pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -18,14 +20,17 @@ pub struct AddWorkerInput {
     pub first_name: String,
     pub last_name: String,
     pub email: String,
+    pub phone: String,
     pub street_address: String,
     pub city: String,
     pub region: String,
     pub postal_code: String,
+    pub employment: Employment,
+    pub custom_values: Vec<CustomValue>,
 }
 
-// Change the return type, if needed
-pub type AddWorkerOutput = Result<(), AddWorkerFailure>;
+// Existing workers who look like the one just added, so whoever added them can check
+pub type AddWorkerOutput = Result<Vec<Worker>, AddWorkerFailure>;
 
 impl AddWorker {
     pub async fn add_worker(&self, input: AddWorkerInput) -> AddWorkerOutput {
@@ -36,30 +41,53 @@ impl AddWorker {
             .map_err(|e| AddWorkerFailure::Unknown(e.to_string()))?
             .ok_or(AddWorkerFailure::NotFound)?;
 
+        if let Some(field) = worksite.find_invalid_custom_value(&input.custom_values) {
+            return Err(AddWorkerFailure::InvalidCustomValue(field));
+        }
+
         // TODO! Implement uuid generation as a port
         let worker = Worker {
             id: uuid::Uuid::new_v4().to_string(),
             first_name: input.first_name,
             last_name: input.last_name,
             assessments: Vec::new(),
+            conversations: Vec::new(),
+            committee_member: false,
+            leader_id: None,
+            authorization_card: None,
+            eligibility: Eligibility::Eligible,
             tags: Vec::new(),
             email: input.email,
+            phone: input.phone,
             address: Some(Address {
                 street_address: input.street_address,
                 city: input.city,
                 region: input.region,
                 postal_code: input.postal_code,
             }),
+            employment: input.employment,
+            status: EmploymentStatus::Active,
+            status_changes: vec![],
+            supervisor_id: None,
+            custom_values: input.custom_values,
         };
 
+        let likely_duplicates = worksite.find_likely_duplicates(&worker);
         let updated_worksite = worksite.add_worker(worker.clone());
 
         self.worksite_repository
-            .save(updated_worksite)
+            .save(updated_worksite.clone())
             .await
             .map_err(|e| AddWorkerFailure::Unknown(e.to_string()))?;
 
-        Ok(())
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::WorkerAdded { worker },
+            ))
+            .await;
+
+        Ok(likely_duplicates)
     }
 }
 
@@ -67,6 +95,8 @@ impl AddWorker {
 pub enum AddWorkerFailure {
     #[error("Worksite does not exist")]
     NotFound,
+    #[error("The value for {0} doesn't fit the field")]
+    InvalidCustomValue(String),
     #[error("Something went wrong")]
     Unknown(String),
 }
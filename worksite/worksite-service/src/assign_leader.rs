@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::Worker,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct AssignLeader {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AssignLeaderInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub worker_id: String,
+    // None takes the worker off their leader's list
+    pub leader_id: Option<String>,
+}
+
+// Change the return type, if needed
+pub type AssignLeaderOutput = Result<(), AssignLeaderFailure>;
+
+impl AssignLeader {
+    pub async fn assign_leader(&self, input: AssignLeaderInput) -> AssignLeaderOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| AssignLeaderFailure::Unknown(e.to_string()))?
+            .ok_or(AssignLeaderFailure::NotFound)?;
+
+        let worker = worksite
+            .get_worker(input.worker_id.clone())
+            .ok_or(AssignLeaderFailure::WorkerNotFound)?;
+        if worker.committee_member {
+            return Err(AssignLeaderFailure::CommitteeMember);
+        }
+        if let Some(leader_id) = &input.leader_id {
+            let is_leader = worksite
+                .get_worker(leader_id.clone())
+                .is_some_and(|leader| leader.committee_member);
+            if !is_leader {
+                return Err(AssignLeaderFailure::NotOnCommittee);
+            }
+        }
+        if worker.leader_id == input.leader_id {
+            return Ok(());
+        }
+
+        let updated_worksite = worksite.update_worker(input.worker_id.clone(), |worker| Worker {
+            leader_id: input.leader_id.clone(),
+            ..worker
+        });
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| AssignLeaderFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::LeaderAssigned {
+                    worker_id: input.worker_id,
+                    previous_leader_id: worker.leader_id,
+                    leader_id: input.leader_id,
+                },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum AssignLeaderFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Worker does not exist")]
+    WorkerNotFound,
+    #[error("Committee members don't need a leader")]
+    CommitteeMember,
+    #[error("Leader is not on the organizing committee")]
+    NotOnCommittee,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -1,11 +1,16 @@
 use std::sync::Arc;
 
-use crate::{models::Worker, ports::worksite_repository::WorksiteRepository};
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::Worker,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
 use thiserror::Error;
 
 #[derive(Clone)]
 pub struct AssignTags {
     pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -27,15 +32,47 @@ impl AssignTags {
             .map_err(|e| AssignTagsFailure::Unknown(e.to_string()))?
             .ok_or(AssignTagsFailure::NotFound)?;
 
-        let updated_worksite = worksite.update_worker(input.worker_id, |worker| -> Worker {
-            worker.assign_tags(input.tags)
-        });
+        let previous_tags: Vec<String> = worksite
+            .get_worker(input.worker_id.clone())
+            .map(|worker| worker.tags.into_iter().map(|tag| tag.0).collect())
+            .unwrap_or_default();
+
+        let updated_worksite = worksite
+            .update_worker(input.worker_id.clone(), |worker| -> Worker {
+                worker.assign_tags(input.tags.clone())
+            });
 
         self.worksite_repository
-            .save(updated_worksite)
+            .save(updated_worksite.clone())
             .await
             .map_err(|e| AssignTagsFailure::Unknown(e.to_string()))?;
 
+        let tags: Vec<String> = updated_worksite
+            .get_worker(input.worker_id.clone())
+            .map(|worker| worker.tags.into_iter().map(|tag| tag.0).collect())
+            .unwrap_or_default();
+
+        // One event per tag that actually changed
+        let assigned = tags
+            .iter()
+            .filter(|tag_id| !previous_tags.contains(tag_id))
+            .map(|tag_id| WorksiteEvent::TagAssigned {
+                worker_id: input.worker_id.clone(),
+                tag_id: tag_id.clone(),
+            });
+        let unassigned = previous_tags
+            .iter()
+            .filter(|tag_id| !tags.contains(tag_id))
+            .map(|tag_id| WorksiteEvent::TagUnassigned {
+                worker_id: input.worker_id.clone(),
+                tag_id: tag_id.clone(),
+            });
+        for event in assigned.chain(unassigned) {
+            self.event_publisher
+                .publish(DomainEvent::new(updated_worksite.id.clone(), event))
+                .await;
+        }
+
         Ok(())
     }
 }
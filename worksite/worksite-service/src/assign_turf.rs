@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::{Turf, TurfAssignment},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct AssignTurf {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AssignTurfInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub user_id: String,
+    pub turf: Turf,
+}
+
+// Change the return type, if needed
+pub type AssignTurfOutput = Result<(), AssignTurfFailure>;
+
+impl AssignTurf {
+    pub async fn assign_turf(&self, input: AssignTurfInput) -> AssignTurfOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| AssignTurfFailure::Unknown(e.to_string()))?
+            .ok_or(AssignTurfFailure::NotFound)?;
+
+        if !worksite.has_turf(&input.turf) {
+            return Err(AssignTurfFailure::TurfNotFound);
+        }
+
+        // Assigning the same turf twice changes nothing
+        let already_assigned = worksite
+            .get_turf_assignments_for_user(&input.user_id)
+            .iter()
+            .any(|a| a.turf == input.turf);
+        if already_assigned {
+            return Ok(());
+        }
+
+        let assignment = TurfAssignment::new(input.user_id, input.turf);
+        let updated_worksite = worksite.assign_turf(assignment.clone());
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| AssignTurfFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::TurfAssigned { assignment },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum AssignTurfFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Location, shift or worker does not exist")]
+    TurfNotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
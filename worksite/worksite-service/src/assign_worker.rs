@@ -2,14 +2,17 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
-// Example repo dependency
-use crate::ports::worksite_repository::WorksiteRepository;
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
 
 #[derive(Clone)]
 pub struct AssignWorker {
     // Put infra dependencies in this struct
     // Below is an example of a repo dependency
     worksite_repository: Arc<dyn WorksiteRepository>,
+    event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -24,9 +27,13 @@ pub struct AssignWorkerInput {
 pub type AssignWorkerOutput = Result<(), AssignWorkerFailure>;
 
 impl AssignWorker {
-    pub fn new(worksite_repository: Arc<dyn WorksiteRepository>) -> Self {
+    pub fn new(
+        worksite_repository: Arc<dyn WorksiteRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
         Self {
             worksite_repository,
+            event_publisher,
         }
     }
 
@@ -48,13 +55,25 @@ impl AssignWorker {
             ..
         } = input;
 
-        let worksite = worksite.assign_worker(worker.id, shift_id, location_id);
+        let worksite =
+            worksite.assign_worker(worker.id.clone(), shift_id.clone(), location_id.clone());
 
         self.worksite_repository
-            .save(worksite)
+            .save(worksite.clone())
             .await
             .map_err(|e| AssignWorkerFailure::Unknown(e.to_string()))?;
 
+        self.event_publisher
+            .publish(DomainEvent::new(
+                worksite.id,
+                WorksiteEvent::WorkerAssignedToShift {
+                    location_id,
+                    shift_id,
+                    worker_id: worker.id,
+                },
+            ))
+            .await;
+
         Ok(())
     }
 }
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::{EmploymentStatus, StatusChange},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct ChangeStatus {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ChangeStatusInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub worker_id: String,
+    pub status: EmploymentStatus,
+    pub changed_on: NaiveDate,
+    pub note: String,
+}
+
+// Change the return type, if needed
+pub type ChangeStatusOutput = Result<(), ChangeStatusFailure>;
+
+impl ChangeStatus {
+    pub async fn change_status(&self, input: ChangeStatusInput) -> ChangeStatusOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| ChangeStatusFailure::Unknown(e.to_string()))?
+            .ok_or(ChangeStatusFailure::NotFound)?;
+
+        let worker = worksite
+            .get_worker(input.worker_id.clone())
+            .ok_or(ChangeStatusFailure::WorkerNotFound)?;
+        if worker.status == input.status {
+            return Ok(());
+        }
+        if !worker.can_change_status_on(input.changed_on) {
+            return Err(ChangeStatusFailure::OutOfOrder);
+        }
+
+        let change = StatusChange {
+            previous: worker.status,
+            status: input.status,
+            changed_on: input.changed_on,
+            note: input.note.trim().to_string(),
+        };
+        let updated_worksite = worksite.update_worker(input.worker_id.clone(), |worker| {
+            worker.change_status(change.clone())
+        });
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| ChangeStatusFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::StatusChanged {
+                    worker_id: input.worker_id,
+                    change,
+                },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ChangeStatusFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Worker does not exist")]
+    WorkerNotFound,
+    #[error("A status change can't be dated before the one it follows")]
+    OutOfOrder,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
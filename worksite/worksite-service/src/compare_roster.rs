@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::models::{Placement, RosterChange, RosterEntry};
+use crate::ports::worksite_repository::WorksiteRepository;
+
+#[derive(Clone)]
+pub struct CompareRoster {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct CompareRosterInput {
+    // Put input fields here
+    pub worksite_id: String,
+    // The employer's list, as CSV content with a header row
+    pub csv_input: String,
+}
+
+/**
+* A row of an employer's list. The columns match the CSV upload without the worksite, so the
+* same file can be used for both, and any extra columns are ignored.
+*/
+#[derive(Clone, Debug, Deserialize)]
+struct RosterRecord {
+    location: Option<String>,
+    shift1: Option<String>,
+    shift2: Option<String>,
+    shift3: Option<String>,
+    first_name: String,
+    last_name: String,
+    email: Option<String>,
+}
+
+// Change the return type, if needed
+pub type CompareRosterOutput = Result<Vec<RosterChange>, CompareRosterFailure>;
+
+/**
+* Reads an employer's list into one entry per person. Someone listed at several locations gets
+* a row for each, so rows for the same email or name are combined.
+*/
+pub fn parse_roster(csv_input: &str) -> Result<Vec<RosterEntry>, String> {
+    let mut rdr = csv::Reader::from_reader(csv_input.as_bytes());
+    let records: Result<Vec<RosterRecord>, csv::Error> = rdr.deserialize().collect();
+    let records = records.map_err(|e| e.to_string())?;
+
+    let mut entries: Vec<RosterEntry> = vec![];
+    for record in records {
+        let location = record.location.unwrap_or_default().trim().to_string();
+        let placements: Vec<Placement> = [record.shift1, record.shift2, record.shift3]
+            .into_iter()
+            .flatten()
+            .map(|shift| shift.trim().to_string())
+            .filter(|shift| !location.is_empty() && !shift.is_empty())
+            .map(|shift| Placement {
+                location: location.clone(),
+                shift,
+            })
+            .collect();
+
+        let entry = RosterEntry {
+            first_name: record.first_name.trim().to_string(),
+            last_name: record.last_name.trim().to_string(),
+            email: record.email.unwrap_or_default().trim().to_string(),
+            placements,
+        };
+        if entry.first_name.is_empty() && entry.last_name.is_empty() {
+            return Err("Every row needs a first or last name".into());
+        }
+
+        match entries.iter_mut().find(|existing| {
+            match (existing.email.is_empty(), entry.email.is_empty()) {
+                (false, false) => existing.email.eq_ignore_ascii_case(&entry.email),
+                _ => existing.normalized_name() == entry.normalized_name(),
+            }
+        }) {
+            Some(existing) => existing.placements.extend(entry.placements),
+            None => entries.push(entry),
+        }
+    }
+
+    for entry in entries.iter_mut() {
+        entry.placements.sort();
+        entry.placements.dedup();
+    }
+
+    Ok(entries)
+}
+
+impl CompareRoster {
+    pub async fn compare_roster(&self, input: CompareRosterInput) -> CompareRosterOutput {
+        let roster = parse_roster(&input.csv_input).map_err(CompareRosterFailure::ParseFailure)?;
+
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| CompareRosterFailure::Unknown(e.to_string()))?
+            .ok_or(CompareRosterFailure::NotFound)?;
+
+        Ok(worksite.diff_roster(&roster))
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum CompareRosterFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Failed to read the list: {0}")]
+    ParseFailure(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
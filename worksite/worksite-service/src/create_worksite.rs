@@ -2,11 +2,16 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
-use crate::{models::Worksite, ports::worksite_repository::WorksiteRepository};
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::{CardThreshold, Worksite},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
 
 #[derive(Clone)]
 pub struct CreateWorksite {
     pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -26,6 +31,11 @@ impl CreateWorksite {
             locations: vec![],
             tags: vec![],
             workers: vec![],
+            turf_assignments: vec![],
+            actions: vec![],
+            card_thresholds: CardThreshold::defaults(),
+            custom_fields: vec![],
+            relationships: vec![],
         };
 
         self.worksite_repository
@@ -33,6 +43,15 @@ impl CreateWorksite {
             .await
             .map_err(|e| CreateWorksiteFailure::Unknown(e.to_string()))?;
 
+        self.event_publisher
+            .publish(DomainEvent::new(
+                worksite.id.clone(),
+                WorksiteEvent::WorksiteCreated {
+                    name: worksite.name.clone(),
+                },
+            ))
+            .await;
+
         Ok(worksite)
     }
 }
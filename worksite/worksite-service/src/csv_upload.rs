@@ -1,19 +1,22 @@
 use std::{collections::HashMap, sync::Arc};
 
+use chrono::NaiveDate;
 use serde::Deserialize;
 use thiserror::Error;
 
 use crate::{
+    events::{DomainEvent, WorksiteEvent},
     models::{
-        Email, FirstName, LastName, Location, LocationName, Shift, ShiftName, Worker, Worksite,
-        WorksiteName,
+        Email, Employment, EmploymentType, FirstName, LastName, Location, LocationName, Shift,
+        ShiftName, Worker, Worksite, WorksiteName,
     },
-    ports::worksite_repository::WorksiteRepository,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
 };
 
 #[derive(Clone)]
 pub struct CsvUpload {
     pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -36,6 +39,57 @@ pub struct WorkerRecord {
     first_name: String,
     last_name: String,
     email: String,
+    // The employment columns are optional, so older CSVs still upload
+    phone: Option<String>,
+    job_title: Option<String>,
+    department: Option<String>,
+    // YYYY-MM-DD
+    hire_date: Option<String>,
+    pay_rate: Option<String>,
+    // e.g. "Full time", "Part time" or "Temp"
+    employment_type: Option<String>,
+}
+
+impl WorkerRecord {
+    fn employment(&self) -> Result<Employment, CsvUploadFailure> {
+        let hire_date = match self.hire_date.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(hire_date) => Some(NaiveDate::parse_from_str(hire_date, "%Y-%m-%d").map_err(
+                |_| {
+                    CsvUploadFailure::ParseFailure(format!(
+                        "Invalid hire date for {} {}: {}",
+                        self.first_name, self.last_name, hire_date
+                    ))
+                },
+            )?),
+        };
+
+        let employment_type = match self.employment_type.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(employment_type) => Some(
+                EmploymentType::ALL
+                    .into_iter()
+                    .find(|t| {
+                        t.label().eq_ignore_ascii_case(employment_type)
+                            || t.to_string().eq_ignore_ascii_case(employment_type)
+                    })
+                    .ok_or_else(|| {
+                        CsvUploadFailure::ParseFailure(format!(
+                            "Invalid employment type for {} {}: {}",
+                            self.first_name, self.last_name, employment_type
+                        ))
+                    })?,
+            ),
+        };
+
+        Ok(Employment {
+            job_title: self.job_title.clone().unwrap_or_default(),
+            department: self.department.clone().unwrap_or_default(),
+            hire_date,
+            pay_rate: self.pay_rate.clone().unwrap_or_default(),
+            employment_type,
+        })
+    }
 }
 
 // Change the return type, if needed
@@ -75,11 +129,15 @@ impl CsvUpload {
                 .cloned()
                 .unwrap_or_else(|| Worksite::new(record.worksite.clone()));
 
-            let worker = Worker::new(
-                record.first_name.clone(),
-                record.last_name.clone(),
-                record.email.clone(),
-            );
+            let worker = Worker {
+                phone: record.phone.clone().unwrap_or_default(),
+                employment: record.employment()?,
+                ..Worker::new(
+                    record.first_name.clone(),
+                    record.last_name.clone(),
+                    record.email.clone(),
+                )
+            };
 
             let worksite = worksite.add_worker(worker.clone());
 
@@ -244,6 +302,15 @@ impl CsvUpload {
                 .save(worksite.clone())
                 .await
                 .map_err(|e| CsvUploadFailure::Unknown(e.to_string()))?;
+
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    worksite.id.clone(),
+                    WorksiteEvent::WorkersImported {
+                        worker_ids: worksite.workers.iter().map(|w| w.id.clone()).collect(),
+                    },
+                ))
+                .await;
         }
 
         Ok(worksites
@@ -0,0 +1,198 @@
+use chrono::{DateTime, Utc};
+
+use crate::models::{
+    Action, Assessment, AuthorizationCard, CardThreshold, Conversation, CustomField, Eligibility,
+    Location, ParticipationStatus, Relationship, Shift, StatusChange, Tag, TurfAssignment, Worker,
+};
+
+/**
+* What happened to a worksite, published by the commands after they save.
+* Subscribers such as live wallcharts, audit logging and notifications react to these instead
+* of each command knowing about them.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct DomainEvent {
+    pub worksite_id: String,
+    pub occurred_at: DateTime<Utc>,
+    pub event: WorksiteEvent,
+}
+
+impl DomainEvent {
+    pub fn new(worksite_id: String, event: WorksiteEvent) -> Self {
+        Self {
+            worksite_id,
+            occurred_at: Utc::now(),
+            event,
+        }
+    }
+}
+
+// Changes carry the entity before and after, so subscribers like the audit log can show what changed
+#[derive(Clone, Debug, PartialEq)]
+pub enum WorksiteEvent {
+    WorksiteCreated {
+        name: String,
+    },
+    WorksiteRenamed {
+        previous_name: String,
+        name: String,
+    },
+    LocationAdded {
+        location: Location,
+    },
+    ShiftAdded {
+        location_id: String,
+        shift: Shift,
+    },
+    WorkerAdded {
+        worker: Worker,
+    },
+    WorkerUpdated {
+        previous: Box<Worker>,
+        worker: Box<Worker>,
+    },
+    // A csv upload, which also creates locations and shifts
+    WorkersImported {
+        worker_ids: Vec<String>,
+    },
+    WorkerAssignedToShift {
+        location_id: String,
+        shift_id: String,
+        worker_id: String,
+    },
+    WorkerRemovedFromShift {
+        shift_id: String,
+        worker_id: String,
+    },
+    TagAdded {
+        tag: Tag,
+    },
+    TagUpdated {
+        previous: Tag,
+        tag: Tag,
+    },
+    TagRemoved {
+        tag: Tag,
+    },
+    TagAssigned {
+        worker_id: String,
+        tag_id: String,
+    },
+    TagUnassigned {
+        worker_id: String,
+        tag_id: String,
+    },
+    WorkerAssessed {
+        worker_id: String,
+        assessment: Assessment,
+    },
+    AssessmentUpdated {
+        worker_id: String,
+        previous: Assessment,
+        assessment: Assessment,
+    },
+    AssessmentRemoved {
+        worker_id: String,
+        assessment: Assessment,
+    },
+    ConversationLogged {
+        worker_id: String,
+        conversation: Conversation,
+    },
+    ConversationUpdated {
+        worker_id: String,
+        previous: Conversation,
+        conversation: Conversation,
+    },
+    ConversationRemoved {
+        worker_id: String,
+        conversation: Conversation,
+    },
+    TurfAssigned {
+        assignment: TurfAssignment,
+    },
+    TurfUnassigned {
+        assignment: TurfAssignment,
+    },
+    CommitteeMemberAdded {
+        worker_id: String,
+    },
+    CommitteeMemberRemoved {
+        worker_id: String,
+    },
+    // Either leader is None when the worker had or now has no leader
+    LeaderAssigned {
+        worker_id: String,
+        previous_leader_id: Option<String>,
+        leader_id: Option<String>,
+    },
+    // Either supervisor is None when the worker had or now has no supervisor
+    SupervisorChanged {
+        worker_id: String,
+        previous_supervisor_id: Option<String>,
+        supervisor_id: Option<String>,
+    },
+    ActionAdded {
+        action: Action,
+    },
+    ActionUpdated {
+        previous: Action,
+        action: Action,
+    },
+    ActionRemoved {
+        action: Action,
+    },
+    // Only the workers whose participation changed, with None where it was cleared
+    ParticipationRecorded {
+        action_id: String,
+        changes: Vec<(String, Option<ParticipationStatus>)>,
+    },
+    // Either card is None when the worker had or now has no card
+    AuthorizationCardRecorded {
+        worker_id: String,
+        previous: Option<AuthorizationCard>,
+        card: Option<AuthorizationCard>,
+    },
+    CardThresholdsUpdated {
+        previous: Vec<CardThreshold>,
+        thresholds: Vec<CardThreshold>,
+    },
+    EligibilityChanged {
+        worker_id: String,
+        previous: Eligibility,
+        eligibility: Eligibility,
+    },
+    CustomFieldAdded {
+        field: CustomField,
+    },
+    CustomFieldUpdated {
+        previous: CustomField,
+        field: CustomField,
+    },
+    // Also clears the field's values from every worker
+    CustomFieldRemoved {
+        field: CustomField,
+    },
+    StatusChanged {
+        worker_id: String,
+        change: StatusChange,
+    },
+    RelationshipAdded {
+        relationship: Relationship,
+    },
+    RelationshipRemoved {
+        relationship: Relationship,
+    },
+    // The changes picked from comparing an employer's list, applied together
+    RosterReconciled {
+        hired: Vec<String>,
+        returned: Vec<String>,
+        moved: Vec<String>,
+        departed: Vec<String>,
+    },
+    // The duplicate is gone, with its records folded into the worker
+    WorkersMerged {
+        duplicate: Box<Worker>,
+        worker: Box<Worker>,
+    },
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::ports::worksite_repository::WorksiteRepository;
+
+#[derive(Clone)]
+pub struct ExportWorkers {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ExportWorkersInput {
+    // Put input fields here
+    pub worksite_id: String,
+}
+
+// The CSV content, with a header row
+pub type ExportWorkersOutput = Result<String, ExportWorkersFailure>;
+
+/**
+* Every worker in the worksite as a CSV, one row each, with a column per custom field after the
+* built in ones. The built in columns use the same names as the CSV upload.
+*/
+impl ExportWorkers {
+    pub async fn export_workers(&self, input: ExportWorkersInput) -> ExportWorkersOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| ExportWorkersFailure::Unknown(e.to_string()))?
+            .ok_or(ExportWorkersFailure::NotFound)?;
+
+        let mut writer = csv::Writer::from_writer(vec![]);
+
+        let mut header: Vec<String> = [
+            "first_name",
+            "last_name",
+            "email",
+            "phone",
+            "street_address",
+            "city",
+            "region",
+            "postal_code",
+            "job_title",
+            "department",
+            "hire_date",
+            "pay_rate",
+            "employment_type",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        header.extend(worksite.custom_fields.iter().map(|f| f.name.clone()));
+        writer
+            .write_record(&header)
+            .map_err(|e| ExportWorkersFailure::Unknown(e.to_string()))?;
+
+        let mut workers = worksite.workers.clone();
+        workers.sort_by(|a, b| (&a.last_name, &a.first_name).cmp(&(&b.last_name, &b.first_name)));
+
+        for worker in workers {
+            let address = worker.address.clone().unwrap_or_default();
+            let employment = &worker.employment;
+
+            let mut record = vec![
+                worker.first_name.clone(),
+                worker.last_name.clone(),
+                worker.email.clone(),
+                worker.phone.clone(),
+                address.street_address,
+                address.city,
+                address.region,
+                address.postal_code,
+                employment.job_title.clone(),
+                employment.department.clone(),
+                employment
+                    .hire_date
+                    .map(|hire_date| hire_date.to_string())
+                    .unwrap_or_default(),
+                employment.pay_rate.clone(),
+                employment
+                    .employment_type
+                    .map(|employment_type| employment_type.label().to_string())
+                    .unwrap_or_default(),
+            ];
+            record.extend(worksite.custom_fields.iter().map(|field| {
+                worker
+                    .get_custom_value(&field.id)
+                    .map(|value| value.to_string())
+                    .unwrap_or_default()
+            }));
+
+            writer
+                .write_record(&record)
+                .map_err(|e| ExportWorkersFailure::Unknown(e.to_string()))?;
+        }
+
+        let bytes = writer
+            .into_inner()
+            .map_err(|e| ExportWorkersFailure::Unknown(e.to_string()))?;
+
+        String::from_utf8(bytes).map_err(|e| ExportWorkersFailure::Unknown(e.to_string()))
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ExportWorkersFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -13,6 +13,8 @@ pub struct FilterWorkers {
 pub struct FilterWorkersInput {
     pub worksite_id: String,
     pub filter: String,
+    // Narrows the results to everyone under this supervisor, directly or not
+    pub supervisor_id: Option<String>,
 }
 
 // Change the return type, if needed
@@ -27,9 +29,17 @@ impl FilterWorkers {
             .map_err(|e| FilterWorkersFailure::Unknown(e.to_string()))?
             .ok_or(FilterWorkersFailure::NotFound)?;
 
+        let filter = input.filter.to_lowercase();
         let filtered_workers:Vec<Worker> = 
             worksite.workers.iter()                     
-            .filter(|worker| worker.matches_filter(&input.filter.to_lowercase())).cloned()
+            .filter(|worker| {
+                worker.matches_filter(&filter) || worksite.matches_custom_filter(worker, &filter)
+            })
+            .filter(|worker| match &input.supervisor_id {
+                Some(supervisor_id) => worksite.reports_to(worker, supervisor_id),
+                None => true,
+            })
+            .cloned()
             .collect();
         Ok(filtered_workers)
     }
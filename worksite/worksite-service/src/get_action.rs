@@ -0,0 +1,41 @@
+use std::sync::Arc;
+use thiserror::Error;
+
+use crate::models::Action;
+use crate::ports::worksite_repository::WorksiteRepository;
+
+#[derive(Clone)]
+pub struct GetAction {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetActionInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub action_id: String,
+}
+
+// Change the return type, if needed
+pub type GetActionOutput = Result<Option<Action>, GetActionFailure>;
+
+impl GetAction {
+    pub async fn get_action(&self, input: GetActionInput) -> GetActionOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetActionFailure::Unknown(e.to_string()))?
+            .ok_or(GetActionFailure::NotFound)?;
+
+        Ok(worksite.get_action(input.action_id))
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetActionFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+use thiserror::Error;
+
+use crate::models::{Action, ParticipationCount};
+use crate::ports::worksite_repository::WorksiteRepository;
+
+#[derive(Clone)]
+pub struct GetActionReport {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetActionReportInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub action_id: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActionReportRow {
+    pub name: String,
+    pub count: ParticipationCount,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActionReport {
+    pub action: Action,
+    pub overall: ParticipationCount,
+    pub by_location: Vec<ActionReportRow>,
+    pub by_shift: Vec<ActionReportRow>,
+}
+
+// Change the return type, if needed
+pub type GetActionReportOutput = Result<ActionReport, GetActionReportFailure>;
+
+impl GetActionReport {
+    pub async fn get_action_report(&self, input: GetActionReportInput) -> GetActionReportOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetActionReportFailure::Unknown(e.to_string()))?
+            .ok_or(GetActionReportFailure::NotFound)?;
+
+        let action = worksite
+            .get_action(input.action_id)
+            .ok_or(GetActionReportFailure::ActionNotFound)?;
+
+        let overall = action.count_participants(&worksite.workers);
+
+        let by_location = worksite
+            .locations
+            .iter()
+            .map(|location| ActionReportRow {
+                name: location.name.clone(),
+                count: action.count_participants(&worksite.get_workers_for_location(&location.id)),
+            })
+            .collect();
+
+        let by_shift = worksite
+            .locations
+            .iter()
+            .flat_map(|location| {
+                location.shifts.iter().map(|shift| ActionReportRow {
+                    name: format!("{} - {}", location.name, shift.name),
+                    count: action
+                        .count_participants(&worksite.get_workers_for_shift(shift.id.clone())),
+                })
+            })
+            .collect();
+
+        Ok(ActionReport {
+            action,
+            overall,
+            by_location,
+            by_shift,
+        })
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetActionReportFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Action does not exist")]
+    ActionNotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
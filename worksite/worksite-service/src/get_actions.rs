@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use thiserror::Error;
+
+use crate::models::Action;
+use crate::ports::worksite_repository::WorksiteRepository;
+
+#[derive(Clone)]
+pub struct GetActions {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetActionsInput {
+    // Put input fields here
+    pub worksite_id: String,
+}
+
+// Change the return type, if needed
+pub type GetActionsOutput = Result<Vec<Action>, GetActionsFailure>;
+
+impl GetActions {
+    pub async fn get_actions(&self, input: GetActionsInput) -> GetActionsOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetActionsFailure::Unknown(e.to_string()))?
+            .ok_or(GetActionsFailure::NotFound)?;
+
+        // Most recent first
+        let mut actions = worksite.actions;
+        actions.sort_by(|a, b| {
+            b.held_on
+                .cmp(&a.held_on)
+                .then(b.created_at.cmp(&a.created_at))
+        });
+
+        Ok(actions)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetActionsFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
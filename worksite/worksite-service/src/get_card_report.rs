@@ -0,0 +1,155 @@
+use std::sync::Arc;
+use thiserror::Error;
+
+use crate::models::{CardThreshold, Worker};
+use crate::ports::worksite_repository::WorksiteRepository;
+
+#[derive(Clone)]
+pub struct GetCardReport {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetCardReportInput {
+    // Put input fields here
+    pub worksite_id: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThresholdProgress {
+    pub threshold: CardThreshold,
+    pub met: bool,
+    pub cards_needed: usize,
+}
+
+// Workers who haven't signed, grouped under a location or shift
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnsignedWorkers {
+    pub name: String,
+    pub workers: Vec<Worker>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CardReport {
+    pub signed: usize,
+    pub total: usize,
+    pub thresholds: Vec<ThresholdProgress>,
+    pub unsigned_by_location: Vec<UnsignedWorkers>,
+    pub unsigned_by_shift: Vec<UnsignedWorkers>,
+}
+
+impl CardReport {
+    pub fn percent_signed(&self) -> usize {
+        match self.total {
+            0 => 0,
+            total => self.signed * 100 / total,
+        }
+    }
+}
+
+// Change the return type, if needed
+pub type GetCardReportOutput = Result<CardReport, GetCardReportFailure>;
+
+fn by_name(a: &Worker, b: &Worker) -> std::cmp::Ordering {
+    a.last_name
+        .cmp(&b.last_name)
+        .then(a.first_name.cmp(&b.first_name))
+}
+
+impl GetCardReport {
+    pub async fn get_card_report(&self, input: GetCardReportInput) -> GetCardReportOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetCardReportFailure::Unknown(e.to_string()))?
+            .ok_or(GetCardReportFailure::NotFound)?;
+
+        let eligible = worksite.get_eligible_workers();
+        let total = eligible.len();
+        let signed = eligible
+            .iter()
+            .filter(|worker| worker.has_signed_card())
+            .count();
+
+        let thresholds = worksite
+            .card_thresholds
+            .iter()
+            .map(|threshold| ThresholdProgress {
+                threshold: threshold.clone(),
+                met: threshold.is_met(signed, total),
+                cards_needed: threshold.cards_needed(signed, total),
+            })
+            .collect();
+
+        let unsigned = |workers: Vec<Worker>| {
+            let mut workers: Vec<Worker> = workers
+                .into_iter()
+                .filter(|worker| worker.is_eligible() && !worker.has_signed_card())
+                .collect();
+            workers.sort_by(by_name);
+            workers
+        };
+
+        let mut unsigned_by_location: Vec<UnsignedWorkers> = worksite
+            .locations
+            .iter()
+            .map(|location| UnsignedWorkers {
+                name: location.name.clone(),
+                workers: unsigned(worksite.get_workers_for_location(&location.id)),
+            })
+            .filter(|group| !group.workers.is_empty())
+            .collect();
+
+        let mut unsigned_by_shift: Vec<UnsignedWorkers> = worksite
+            .locations
+            .iter()
+            .flat_map(|location| {
+                location.shifts.iter().map(|shift| UnsignedWorkers {
+                    name: format!("{} - {}", location.name, shift.name),
+                    workers: unsigned(worksite.get_workers_for_shift(shift.id.clone())),
+                })
+            })
+            .filter(|group| !group.workers.is_empty())
+            .collect();
+
+        // Anyone not on a shift would otherwise be missing from both lists
+        let without_shift = unsigned(
+            eligible
+                .iter()
+                .filter(|worker| {
+                    !worksite
+                        .locations
+                        .iter()
+                        .flat_map(|location| location.shifts.iter())
+                        .any(|shift| shift.contains_worker(worker))
+                })
+                .cloned()
+                .collect(),
+        );
+        if !without_shift.is_empty() {
+            let group = UnsignedWorkers {
+                name: "No shift".into(),
+                workers: without_shift,
+            };
+            unsigned_by_location.push(group.clone());
+            unsigned_by_shift.push(group);
+        }
+
+        Ok(CardReport {
+            signed,
+            total,
+            thresholds,
+            unsigned_by_location,
+            unsigned_by_shift,
+        })
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetCardReportFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+use thiserror::Error;
+
+use crate::models::{Worker, COMMITTEE_RATIO};
+use crate::ports::worksite_repository::WorksiteRepository;
+
+#[derive(Clone)]
+pub struct GetCommitteeReport {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetCommitteeReportInput {
+    // Put input fields here
+    pub worksite_id: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommitteeLeader {
+    pub leader: Worker,
+    pub workers: Vec<Worker>,
+}
+
+impl CommitteeLeader {
+    pub fn is_over_ratio(&self) -> bool {
+        self.workers.len() > COMMITTEE_RATIO
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommitteeReport {
+    pub leaders: Vec<CommitteeLeader>,
+    pub workers_without_leader: Vec<Worker>,
+}
+
+// Change the return type, if needed
+pub type GetCommitteeReportOutput = Result<CommitteeReport, GetCommitteeReportFailure>;
+
+fn by_name(a: &Worker, b: &Worker) -> std::cmp::Ordering {
+    a.last_name
+        .cmp(&b.last_name)
+        .then(a.first_name.cmp(&b.first_name))
+}
+
+impl GetCommitteeReport {
+    pub async fn get_committee_report(
+        &self,
+        input: GetCommitteeReportInput,
+    ) -> GetCommitteeReportOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetCommitteeReportFailure::Unknown(e.to_string()))?
+            .ok_or(GetCommitteeReportFailure::NotFound)?;
+
+        let mut committee = worksite.get_committee();
+        committee.sort_by(by_name);
+
+        let leaders = committee
+            .into_iter()
+            .map(|leader| {
+                let mut workers = worksite.get_workers_for_leader(&leader.id);
+                workers.sort_by(by_name);
+                CommitteeLeader { leader, workers }
+            })
+            .collect();
+
+        let mut workers_without_leader = worksite.get_workers_without_leader();
+        workers_without_leader.sort_by(by_name);
+
+        Ok(CommitteeReport {
+            leaders,
+            workers_without_leader,
+        })
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetCommitteeReportFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
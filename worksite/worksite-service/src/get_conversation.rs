@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{models::Conversation, ports::worksite_repository::WorksiteRepository};
+
+#[derive(Clone)]
+pub struct GetConversation {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetConversationInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub worker_id: String,
+    pub conversation_id: String,
+}
+
+// Change the return type, if needed
+pub type GetConversationOutput = Result<Option<Conversation>, GetConversationFailure>;
+
+impl GetConversation {
+    pub async fn get_conversation(&self, input: GetConversationInput) -> GetConversationOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetConversationFailure::Unknown(e.to_string()))?
+            .ok_or(GetConversationFailure::NotFound)?;
+
+        Ok(worksite.get_conversation_for_worker(input.worker_id, input.conversation_id))
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetConversationFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
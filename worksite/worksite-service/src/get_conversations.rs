@@ -0,0 +1,49 @@
+use std::sync::Arc;
+use thiserror::Error;
+
+use crate::models::Conversation;
+use crate::ports::worksite_repository::WorksiteRepository;
+
+#[derive(Clone)]
+pub struct GetConversations {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetConversationsInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub worker_id: String,
+}
+
+// Change the return type, if needed
+pub type GetConversationsOutput = Result<Vec<Conversation>, GetConversationsFailure>;
+
+impl GetConversations {
+    pub async fn get_conversations(&self, input: GetConversationsInput) -> GetConversationsOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetConversationsFailure::Unknown(e.to_string()))?
+            .ok_or(GetConversationsFailure::NotFound)?;
+
+        // Most recent first. Conversations can be logged after the fact, so go by the date held
+        let mut conversations = worksite.get_conversations_for_worker(input.worker_id);
+        conversations.sort_by(|a, b| {
+            b.held_on
+                .cmp(&a.held_on)
+                .then(b.created_at.cmp(&a.created_at))
+        });
+
+        Ok(conversations)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetConversationsFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{models::CustomField, ports::worksite_repository::WorksiteRepository};
+
+#[derive(Clone)]
+pub struct GetCustomField {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetCustomFieldInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub field_id: String,
+}
+
+// Change the return type, if needed
+pub type GetCustomFieldOutput = Result<Option<CustomField>, GetCustomFieldFailure>;
+
+impl GetCustomField {
+    pub async fn get_custom_field(&self, input: GetCustomFieldInput) -> GetCustomFieldOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetCustomFieldFailure::Unknown(e.to_string()))?
+            .ok_or(GetCustomFieldFailure::NotFound)?;
+
+        Ok(worksite.get_custom_field(&input.field_id))
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetCustomFieldFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{models::CustomField, ports::worksite_repository::WorksiteRepository};
+
+#[derive(Clone)]
+pub struct GetCustomFields {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetCustomFieldsInput {
+    // Put input fields here
+    pub worksite_id: String,
+}
+
+// Change the return type, if needed
+pub type GetCustomFieldsOutput = Result<Vec<CustomField>, GetCustomFieldsFailure>;
+
+impl GetCustomFields {
+    pub async fn get_custom_fields(&self, input: GetCustomFieldsInput) -> GetCustomFieldsOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetCustomFieldsFailure::Unknown(e.to_string()))?
+            .ok_or(GetCustomFieldsFailure::NotFound)?;
+
+        Ok(worksite.custom_fields)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetCustomFieldsFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{models::Influence, ports::worksite_repository::WorksiteRepository};
+
+#[derive(Clone)]
+pub struct GetInfluencers {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetInfluencersInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub worker_id: String,
+}
+
+// Change the return type, if needed
+pub type GetInfluencersOutput = Result<Vec<Influence>, GetInfluencersFailure>;
+
+impl GetInfluencers {
+    pub async fn get_influencers(&self, input: GetInfluencersInput) -> GetInfluencersOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetInfluencersFailure::Unknown(e.to_string()))?
+            .ok_or(GetInfluencersFailure::NotFound)?;
+
+        worksite
+            .get_worker(input.worker_id.clone())
+            .ok_or(GetInfluencersFailure::WorkerNotFound)?;
+
+        Ok(worksite.get_influencers(&input.worker_id))
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetInfluencersFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Worker does not exist")]
+    WorkerNotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,65 @@
+use std::{cmp::Reverse, sync::Arc};
+
+use thiserror::Error;
+
+use crate::models::{StatusChange, Worker};
+use crate::ports::worksite_repository::WorksiteRepository;
+
+#[derive(Clone)]
+pub struct GetLeaverAlerts {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetLeaverAlertsInput {
+    // Put input fields here
+    pub worksite_id: String,
+}
+
+// A committee member or strong supporter who left, with the change that took them out
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeaverAlert {
+    pub worker: Worker,
+    pub change: StatusChange,
+}
+
+// Change the return type, if needed
+pub type GetLeaverAlertsOutput = Result<Vec<LeaverAlert>, GetLeaverAlertsFailure>;
+
+impl GetLeaverAlerts {
+    pub async fn get_leaver_alerts(&self, input: GetLeaverAlertsInput) -> GetLeaverAlertsOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetLeaverAlertsFailure::Unknown(e.to_string()))?
+            .ok_or(GetLeaverAlertsFailure::NotFound)?;
+
+        let mut alerts: Vec<LeaverAlert> = worksite
+            .workers
+            .iter()
+            .filter(|worker| worker.is_highly_rated())
+            .flat_map(|worker| {
+                worker
+                    .status_changes
+                    .iter()
+                    .filter(|change| change.status.has_left() && !change.previous.has_left())
+                    .map(|change| LeaverAlert {
+                        worker: worker.clone(),
+                        change: change.clone(),
+                    })
+            })
+            .collect();
+        alerts.sort_by_key(|alert| Reverse(alert.change.changed_on));
+
+        Ok(alerts)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetLeaverAlertsFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    models::{DuplicateReason, LikelyDuplicate},
+    ports::worksite_repository::WorksiteRepository,
+};
+
+#[derive(Clone)]
+pub struct GetLikelyDuplicates {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetLikelyDuplicatesInput {
+    // Put input fields here
+    pub worksite_id: String,
+}
+
+// Change the return type, if needed
+pub type GetLikelyDuplicatesOutput = Result<Vec<LikelyDuplicate>, GetLikelyDuplicatesFailure>;
+
+impl GetLikelyDuplicates {
+    pub async fn get_likely_duplicates(
+        &self,
+        input: GetLikelyDuplicatesInput,
+    ) -> GetLikelyDuplicatesOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetLikelyDuplicatesFailure::Unknown(e.to_string()))?
+            .ok_or(GetLikelyDuplicatesFailure::NotFound)?;
+
+        let mut duplicates = worksite.get_likely_duplicates();
+        // Matching emails are the surest sign, so they go first
+        duplicates.sort_by_key(|duplicate| duplicate.reason != DuplicateReason::SameEmail);
+
+        Ok(duplicates)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetLikelyDuplicatesFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{models::OrgChartNode, ports::worksite_repository::WorksiteRepository};
+
+#[derive(Clone)]
+pub struct GetOrgChart {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetOrgChartInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub location_id: String,
+}
+
+// Change the return type, if needed
+pub type GetOrgChartOutput = Result<Vec<OrgChartNode>, GetOrgChartFailure>;
+
+impl GetOrgChart {
+    pub async fn get_org_chart(&self, input: GetOrgChartInput) -> GetOrgChartOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetOrgChartFailure::Unknown(e.to_string()))?
+            .ok_or(GetOrgChartFailure::NotFound)?;
+
+        if !worksite
+            .locations
+            .iter()
+            .any(|location| location.id == input.location_id)
+        {
+            return Err(GetOrgChartFailure::LocationNotFound);
+        }
+
+        Ok(worksite.get_org_chart(&input.location_id))
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetOrgChartFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Location does not exist")]
+    LocationNotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use thiserror::Error;
+
+use crate::models::TurfAssignment;
+use crate::ports::worksite_repository::WorksiteRepository;
+
+#[derive(Clone)]
+pub struct GetTurfAssignments {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetTurfAssignmentsInput {
+    // Put input fields here
+    pub worksite_id: String,
+    // Only this organizer's assignments, when set
+    pub user_id: Option<String>,
+}
+
+// Change the return type, if needed
+pub type GetTurfAssignmentsOutput = Result<Vec<TurfAssignment>, GetTurfAssignmentsFailure>;
+
+impl GetTurfAssignments {
+    pub async fn get_turf_assignments(
+        &self,
+        input: GetTurfAssignmentsInput,
+    ) -> GetTurfAssignmentsOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetTurfAssignmentsFailure::Unknown(e.to_string()))?
+            .ok_or(GetTurfAssignmentsFailure::NotFound)?;
+
+        Ok(match input.user_id {
+            Some(user_id) => worksite.get_turf_assignments_for_user(&user_id),
+            None => worksite.turf_assignments,
+        })
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetTurfAssignmentsFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+use thiserror::Error;
+
+use crate::models::Worker;
+use crate::ports::worksite_repository::WorksiteRepository;
+
+#[derive(Clone)]
+pub struct GetTurfCoverage {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetTurfCoverageInput {
+    // Put input fields here
+    pub worksite_id: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TurfCoverage {
+    pub total_workers: usize,
+    pub covered_workers: usize,
+    // Workers nobody has in their turf, by last name
+    pub uncovered: Vec<Worker>,
+}
+
+impl TurfCoverage {
+    pub fn percent_covered(&self) -> usize {
+        match self.total_workers {
+            0 => 0,
+            total => self.covered_workers * 100 / total,
+        }
+    }
+}
+
+// Change the return type, if needed
+pub type GetTurfCoverageOutput = Result<TurfCoverage, GetTurfCoverageFailure>;
+
+impl GetTurfCoverage {
+    pub async fn get_turf_coverage(&self, input: GetTurfCoverageInput) -> GetTurfCoverageOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetTurfCoverageFailure::Unknown(e.to_string()))?
+            .ok_or(GetTurfCoverageFailure::NotFound)?;
+
+        let mut uncovered = worksite.get_workers_without_organizer();
+        uncovered.sort_by(|a, b| {
+            a.last_name
+                .cmp(&b.last_name)
+                .then(a.first_name.cmp(&b.first_name))
+        });
+
+        let total_workers = worksite.get_eligible_workers().len();
+
+        Ok(TurfCoverage {
+            total_workers,
+            covered_workers: total_workers - uncovered.len(),
+            uncovered,
+        })
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetTurfCoverageFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use chrono::{Datelike, Months, NaiveDate};
+use thiserror::Error;
+
+use crate::models::{EmploymentStatus, Worker};
+use crate::ports::worksite_repository::WorksiteRepository;
+
+#[derive(Clone)]
+pub struct GetTurnoverReport {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+}
+
+#[derive(Clone, Debug)]
+pub struct GetTurnoverReportInput {
+    // Put input fields here
+    pub worksite_id: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonthlyTurnover {
+    // The first day of the month
+    pub month: NaiveDate,
+    // Workers on staff as the month started
+    pub headcount: usize,
+    pub hired: usize,
+    pub terminated: usize,
+    pub quit: usize,
+    pub transferred: usize,
+}
+
+impl MonthlyTurnover {
+    pub fn left(&self) -> usize {
+        self.terminated + self.quit + self.transferred
+    }
+
+    pub fn turnover_percent(&self) -> usize {
+        match self.headcount {
+            0 => 0,
+            headcount => self.left() * 100 / headcount,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TurnoverReport {
+    // Every month from the first hire or departure on record to the last, newest first
+    pub months: Vec<MonthlyTurnover>,
+}
+
+impl TurnoverReport {
+    pub fn total_left(&self) -> usize {
+        self.months.iter().map(|month| month.left()).sum()
+    }
+
+    pub fn total_hired(&self) -> usize {
+        self.months.iter().map(|month| month.hired).sum()
+    }
+}
+
+// Change the return type, if needed
+pub type GetTurnoverReportOutput = Result<TurnoverReport, GetTurnoverReportFailure>;
+
+fn month_of(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+fn is_on_staff(worker: &Worker, date: NaiveDate) -> bool {
+    worker
+        .employment
+        .hire_date
+        .map_or(true, |hired| hired < date)
+        && !worker.status_on(date).has_left()
+}
+
+impl GetTurnoverReport {
+    pub async fn get_turnover_report(
+        &self,
+        input: GetTurnoverReportInput,
+    ) -> GetTurnoverReportOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| GetTurnoverReportFailure::Unknown(e.to_string()))?
+            .ok_or(GetTurnoverReportFailure::NotFound)?;
+
+        // (month, status) for each departure and (month, Active) for each hire or return
+        let mut events: Vec<(NaiveDate, EmploymentStatus)> = vec![];
+        for worker in &worksite.workers {
+            if let Some(hire_date) = worker.employment.hire_date {
+                events.push((month_of(hire_date), EmploymentStatus::Active));
+            }
+            for change in &worker.status_changes {
+                if change.status.has_left() != change.previous.has_left() {
+                    events.push((month_of(change.changed_on), change.status));
+                }
+            }
+        }
+
+        let (first, last) = match (
+            events.iter().map(|(month, _)| *month).min(),
+            events.iter().map(|(month, _)| *month).max(),
+        ) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Ok(TurnoverReport { months: vec![] }),
+        };
+
+        let mut months = vec![];
+        let mut month = first;
+        while month <= last {
+            let count = |status: EmploymentStatus| {
+                events
+                    .iter()
+                    .filter(|(event_month, event_status)| {
+                        *event_month == month && *event_status == status
+                    })
+                    .count()
+            };
+
+            months.push(MonthlyTurnover {
+                month,
+                headcount: worksite
+                    .workers
+                    .iter()
+                    .filter(|worker| is_on_staff(worker, month))
+                    .count(),
+                hired: count(EmploymentStatus::Active),
+                terminated: count(EmploymentStatus::Terminated),
+                quit: count(EmploymentStatus::Quit),
+                transferred: count(EmploymentStatus::Transferred),
+            });
+
+            month = match month.checked_add_months(Months::new(1)) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+        months.reverse();
+
+        Ok(TurnoverReport { months })
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GetTurnoverReportFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -27,4 +27,46 @@ pub mod remove_assessment;
 pub mod csv_upload;
 pub mod get_worksites;
 pub mod create_worksite;
-pub mod update_worksite;
\ No newline at end of file
+pub mod update_worksite;
+pub mod events;
+pub mod add_conversation;
+pub mod get_conversations;
+pub mod get_conversation;
+pub mod update_conversation;
+pub mod remove_conversation;
+pub mod assign_turf;
+pub mod unassign_turf;
+pub mod get_turf_assignments;
+pub mod get_turf_coverage;
+pub mod set_committee_member;
+pub mod assign_leader;
+pub mod get_committee_report;
+pub mod add_action;
+pub mod update_action;
+pub mod remove_action;
+pub mod get_actions;
+pub mod get_action;
+pub mod record_participation;
+pub mod get_action_report;
+pub mod record_authorization_card;
+pub mod update_card_thresholds;
+pub mod get_card_report;
+pub mod set_eligibility;
+pub mod add_custom_field;
+pub mod update_custom_field;
+pub mod remove_custom_field;
+pub mod get_custom_fields;
+pub mod get_custom_field;
+pub mod export_workers;
+pub mod set_supervisor;
+pub mod get_org_chart;
+pub mod add_relationship;
+pub mod remove_relationship;
+pub mod get_influencers;
+pub mod change_status;
+pub mod get_turnover_report;
+pub mod get_leaver_alerts;
+pub mod compare_roster;
+pub mod reconcile_roster;
+pub mod get_likely_duplicates;
+pub mod merge_workers;
\ No newline at end of file
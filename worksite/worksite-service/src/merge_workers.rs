@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::Worker,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct MergeWorkers {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MergeWorkersInput {
+    // Put input fields here
+    pub worksite_id: String,
+    // The worker to keep
+    pub worker_id: String,
+    // The worker folded into them and removed
+    pub duplicate_id: String,
+}
+
+// The worker, with the duplicate's records merged in
+pub type MergeWorkersOutput = Result<Worker, MergeWorkersFailure>;
+
+impl MergeWorkers {
+    pub async fn merge_workers(&self, input: MergeWorkersInput) -> MergeWorkersOutput {
+        if input.worker_id == input.duplicate_id {
+            return Err(MergeWorkersFailure::SameWorker);
+        }
+
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| MergeWorkersFailure::Unknown(e.to_string()))?
+            .ok_or(MergeWorkersFailure::NotFound)?;
+
+        worksite
+            .get_worker(input.worker_id.clone())
+            .ok_or(MergeWorkersFailure::WorkerNotFound)?;
+        let duplicate = worksite
+            .get_worker(input.duplicate_id.clone())
+            .ok_or(MergeWorkersFailure::WorkerNotFound)?;
+
+        let updated_worksite = worksite.merge_workers(&input.worker_id, &input.duplicate_id);
+        let worker = updated_worksite
+            .get_worker(input.worker_id)
+            .ok_or(MergeWorkersFailure::WorkerNotFound)?;
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| MergeWorkersFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::WorkersMerged {
+                    duplicate: Box::new(duplicate),
+                    worker: Box::new(worker.clone()),
+                },
+            ))
+            .await;
+
+        Ok(worker)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum MergeWorkersFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Worker does not exist")]
+    WorkerNotFound,
+    #[error("A worker can't be merged into themselves")]
+    SameWorker,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -1,7 +1,17 @@
-use chrono::{DateTime, Utc};
+use std::fmt::Display;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
 
 pub type WorksiteName = String;
 pub type WorksiteId = String;
+
+// How many coworkers one committee member can keep in touch with
+pub const COMMITTEE_RATIO: usize = 10;
+// How many relationships away someone can be and still count as an influence
+pub const INFLUENCE_DEPTH: usize = 3;
+// Workers assessed at or under this are strong enough supporters to raise an alert if they leave
+pub const LEADER_ASSESSMENT: u8 = 2;
 pub type WorkerId = String;
 pub type LocationId = String;
 pub type ShiftId = String;
@@ -13,6 +23,11 @@ pub struct Worksite {
     pub locations: Vec<Location>,
     pub tags: Vec<Tag>,
     pub workers: Vec<Worker>,
+    pub turf_assignments: Vec<TurfAssignment>,
+    pub actions: Vec<Action>,
+    pub card_thresholds: Vec<CardThreshold>,
+    pub custom_fields: Vec<CustomField>,
+    pub relationships: Vec<Relationship>,
 }
 
 impl Worksite {
@@ -23,6 +38,11 @@ impl Worksite {
             locations: vec![],
             tags: vec![],
             workers: vec![],
+            turf_assignments: vec![],
+            actions: vec![],
+            card_thresholds: CardThreshold::defaults(),
+            custom_fields: vec![],
+            relationships: vec![],
         }
     }
 
@@ -30,6 +50,15 @@ impl Worksite {
         self.workers.iter().find(|w| w.id == worker_id).cloned()
     }
 
+    // Only workers in the bargaining unit count towards its numbers
+    pub fn get_eligible_workers(&self) -> Vec<Worker> {
+        self.workers
+            .iter()
+            .filter(|worker| worker.is_eligible())
+            .cloned()
+            .collect()
+    }
+
     pub fn get_workers_for_shift(&self, shift_id: String) -> Vec<Worker> {
         let shift = self.get_shift(shift_id);
         let shift = match shift {
@@ -76,6 +105,292 @@ impl Worksite {
         worker.get_assessment(assessment_id)
     }
 
+    pub fn get_conversations_for_worker(&self, worker_id: String) -> Vec<Conversation> {
+        match self.get_worker(worker_id) {
+            Some(worker) => worker.conversations,
+            None => vec![],
+        }
+    }
+
+    pub fn get_conversation_for_worker(
+        &self,
+        worker_id: String,
+        conversation_id: String,
+    ) -> Option<Conversation> {
+        let worker = self.get_worker(worker_id)?;
+        worker.get_conversation(conversation_id)
+    }
+
+    pub fn get_turf_assignment(&self, assignment_id: String) -> Option<TurfAssignment> {
+        self.turf_assignments
+            .iter()
+            .find(|a| a.id == assignment_id)
+            .cloned()
+    }
+
+    pub fn get_turf_assignments_for_user(&self, user_id: &String) -> Vec<TurfAssignment> {
+        self.turf_assignments
+            .iter()
+            .filter(|a| &a.user_id == user_id)
+            .cloned()
+            .collect()
+    }
+
+    // Whether the location, shift or worker is still part of this worksite
+    pub fn has_turf(&self, turf: &Turf) -> bool {
+        match turf {
+            Turf::Location(location_id) => self.locations.iter().any(|l| &l.id == location_id),
+            Turf::Shift(shift_id) => self.get_shift(shift_id.clone()).is_some(),
+            Turf::Worker(worker_id) => self.get_worker(worker_id.clone()).is_some(),
+        }
+    }
+
+    pub fn turf_covers(&self, turf: &Turf, worker: &Worker) -> bool {
+        match turf {
+            Turf::Location(location_id) => self
+                .locations
+                .iter()
+                .filter(|l| &l.id == location_id)
+                .flat_map(|l| l.shifts.iter())
+                .any(|shift| shift.contains_worker(worker)),
+            Turf::Shift(shift_id) => self
+                .get_shift(shift_id.clone())
+                .is_some_and(|shift| shift.contains_worker(worker)),
+            Turf::Worker(worker_id) => &worker.id == worker_id,
+        }
+    }
+
+    // User ids of the organizers whose turf includes the worker
+    pub fn get_organizers_for_worker(&self, worker: &Worker) -> Vec<String> {
+        let mut user_ids: Vec<String> = self
+            .turf_assignments
+            .iter()
+            .filter(|a| self.turf_covers(&a.turf, worker))
+            .map(|a| a.user_id.clone())
+            .collect();
+        user_ids.sort();
+        user_ids.dedup();
+        user_ids
+    }
+
+    pub fn get_workers_without_organizer(&self) -> Vec<Worker> {
+        self.workers
+            .iter()
+            .filter(|worker| worker.is_eligible())
+            .filter(|worker| self.get_organizers_for_worker(worker).is_empty())
+            .cloned()
+            .collect()
+    }
+
+    /**
+     * The worksite as one organizer sees it: only the workers in their turf, and only the
+     * locations and shifts those workers are on or that were assigned to them directly.
+     */
+    pub fn for_turf(&self, user_id: &String) -> Worksite {
+        let assignments = self.get_turf_assignments_for_user(user_id);
+        let mut turf_worksite = self.clone();
+
+        turf_worksite.workers.retain(|worker| {
+            assignments
+                .iter()
+                .any(|a| self.turf_covers(&a.turf, worker))
+        });
+        let is_in_turf =
+            |worker_id: &String| turf_worksite.workers.iter().any(|w| &w.id == worker_id);
+        let is_assigned = |turf: Turf| assignments.iter().any(|a| a.turf == turf);
+
+        let locations = turf_worksite
+            .locations
+            .iter()
+            .filter_map(|location| {
+                let shifts: Vec<Shift> = location
+                    .shifts
+                    .iter()
+                    .map(|shift| Shift {
+                        workers: shift
+                            .workers
+                            .iter()
+                            .filter(|w| is_in_turf(&w.0))
+                            .cloned()
+                            .collect(),
+                        ..shift.clone()
+                    })
+                    .filter(|shift| {
+                        !shift.workers.is_empty()
+                            || is_assigned(Turf::Shift(shift.id.clone()))
+                            || is_assigned(Turf::Location(location.id.clone()))
+                    })
+                    .collect();
+
+                if shifts.is_empty() && !is_assigned(Turf::Location(location.id.clone())) {
+                    return None;
+                }
+
+                Some(Location {
+                    shifts,
+                    ..location.clone()
+                })
+            })
+            .collect();
+        turf_worksite.locations = locations;
+
+        turf_worksite
+    }
+
+    pub fn get_action(&self, action_id: String) -> Option<Action> {
+        self.actions.iter().find(|a| a.id == action_id).cloned()
+    }
+
+    pub fn get_workers_for_location(&self, location_id: &String) -> Vec<Worker> {
+        self.workers
+            .iter()
+            .filter(|worker| {
+                self.locations
+                    .iter()
+                    .filter(|location| &location.id == location_id)
+                    .flat_map(|location| location.shifts.iter())
+                    .any(|shift| shift.contains_worker(worker))
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_workers_without_card(&self) -> Vec<Worker> {
+        self.workers
+            .iter()
+            .filter(|worker| worker.is_eligible() && !worker.has_signed_card())
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_committee(&self) -> Vec<Worker> {
+        self.workers
+            .iter()
+            .filter(|worker| worker.committee_member)
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_workers_for_leader(&self, leader_id: &String) -> Vec<Worker> {
+        self.workers
+            .iter()
+            .filter(|worker| worker.leader_id.as_ref() == Some(leader_id))
+            .cloned()
+            .collect()
+    }
+
+    // Committee members lead themselves, so only the rest need a leader
+    pub fn get_workers_without_leader(&self) -> Vec<Worker> {
+        self.workers
+            .iter()
+            .filter(|worker| worker.is_eligible() && !worker.committee_member)
+            .filter(|worker| match &worker.leader_id {
+                Some(leader_id) => !self
+                    .get_worker(leader_id.clone())
+                    .is_some_and(|leader| leader.committee_member),
+                None => true,
+            })
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_direct_reports(&self, supervisor_id: &String) -> Vec<Worker> {
+        self.workers
+            .iter()
+            .filter(|worker| worker.supervisor_id.as_ref() == Some(supervisor_id))
+            .cloned()
+            .collect()
+    }
+
+    // Anyone with at least one direct report
+    pub fn get_supervisors(&self) -> Vec<Worker> {
+        self.workers
+            .iter()
+            .filter(|worker| !self.get_direct_reports(&worker.id).is_empty())
+            .cloned()
+            .collect()
+    }
+
+    // The worker's supervisor, then their supervisor, and so on up to the top
+    pub fn get_management_chain(&self, worker: &Worker) -> Vec<Worker> {
+        let mut chain: Vec<Worker> = vec![];
+        let mut next_id = worker.supervisor_id.clone();
+
+        while let Some(supervisor_id) = next_id {
+            if supervisor_id == worker.id || chain.iter().any(|w| w.id == supervisor_id) {
+                break;
+            }
+            match self.get_worker(supervisor_id) {
+                Some(supervisor) => {
+                    next_id = supervisor.supervisor_id.clone();
+                    chain.push(supervisor);
+                }
+                None => break,
+            }
+        }
+
+        chain
+    }
+
+    // True for direct reports and anyone further down the chain
+    pub fn reports_to(&self, worker: &Worker, supervisor_id: &String) -> bool {
+        self.get_management_chain(worker)
+            .iter()
+            .any(|supervisor| &supervisor.id == supervisor_id)
+    }
+
+    /**
+     * Builds the management tree for everyone working at a location. Supervisors who work
+     * elsewhere, like managers outside the unit, are pulled in so every chain reaches the top.
+     */
+    pub fn get_org_chart(&self, location_id: &String) -> Vec<OrgChartNode> {
+        let mut members: Vec<Worker> = vec![];
+        for worker in self.get_workers_for_location(location_id) {
+            let chain = self.get_management_chain(&worker);
+            for member in std::iter::once(worker).chain(chain) {
+                if !members.iter().any(|m| m.id == member.id) {
+                    members.push(member);
+                }
+            }
+        }
+
+        let mut placed: Vec<WorkerId> = vec![];
+        members
+            .iter()
+            .filter(|member| match &member.supervisor_id {
+                Some(supervisor_id) => !members.iter().any(|m| &m.id == supervisor_id),
+                None => true,
+            })
+            .map(|top| org_chart_node(top.clone(), &members, &mut placed))
+            .collect()
+    }
+
+    /**
+     * Adds or removes a worker from the committee. Anyone on a departing member's list is
+     * left without a leader.
+     */
+    pub fn set_committee_member(&self, worker_id: String, committee_member: bool) -> Worksite {
+        let mut updated_worksite = self.update_worker(worker_id.clone(), |worker| Worker {
+            committee_member,
+            leader_id: if committee_member {
+                None
+            } else {
+                worker.leader_id
+            },
+            ..worker
+        });
+
+        if !committee_member {
+            updated_worksite.workers.iter_mut().for_each(|worker| {
+                if worker.leader_id.as_ref() == Some(&worker_id) {
+                    worker.leader_id = None;
+                }
+            });
+        }
+
+        updated_worksite
+    }
+
     fn get_shift(&self, shift_id: String) -> Option<Shift> {
         self.locations
             .iter()
@@ -236,168 +551,1294 @@ impl Worksite {
         updated_worksite.tags.retain(|tag| tag.id != tag_id);
         updated_worksite
     }
-}
 
-pub type LocationName = String;
+    pub fn get_custom_field(&self, field_id: &String) -> Option<CustomField> {
+        self.custom_fields
+            .iter()
+            .find(|f| &f.id == field_id)
+            .cloned()
+    }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Location {
-    pub id: String,
-    pub name: LocationName,
-    pub shifts: Vec<Shift>,
-}
+    pub fn add_custom_field(&self, field: CustomField) -> Worksite {
+        let mut updated_worksite = self.clone();
 
-impl Location {
-    pub fn new(name: LocationName) -> Self {
-        Self {
-            id: uuid::Uuid::new_v4().to_string(),
-            name,
-            shifts: vec![],
-        }
+        updated_worksite.custom_fields.push(field);
+        updated_worksite
     }
 
-    pub fn add_shift(&self, shift: Shift) -> Location {
-        let mut updated_location = self.clone();
+    pub fn update_custom_field(
+        &self,
+        field_id: &String,
+        update_fn: impl FnOnce(CustomField) -> CustomField,
+    ) -> Worksite {
+        let mut updated_worksite = self.clone();
 
-        if updated_location.shifts.iter().any(|s| s.id == shift.id) {
-            return updated_location;
+        if let Some(field) = updated_worksite
+            .custom_fields
+            .iter_mut()
+            .find(|f| &f.id == field_id)
+        {
+            *field = update_fn(field.clone());
         }
 
-        updated_location.shifts.push(shift);
-        updated_location
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ShiftWorker(pub String);
-
-impl ShiftWorker {
-    pub fn new(id: String) -> Self {
-        Self(id)
+        updated_worksite
     }
-}
 
-pub type ShiftName = String;
+    // Also clears the field's values from every worker
+    pub fn remove_custom_field(&self, field_id: &String) -> Worksite {
+        let mut updated_worksite = self.clone();
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Shift {
-    pub id: String,
-    pub name: ShiftName,
-    pub workers: Vec<ShiftWorker>,
-}
+        updated_worksite.custom_fields.retain(|f| &f.id != field_id);
+        updated_worksite.workers.iter_mut().for_each(|worker| {
+            worker.custom_values.retain(|v| &v.field_id != field_id);
+        });
 
-impl Shift {
-    pub fn new(name: ShiftName) -> Self {
-        Self {
-            id: uuid::Uuid::new_v4().to_string(),
-            name,
-            workers: vec![],
-        }
+        updated_worksite
     }
 
-    pub fn assign_worker(&self, worker_id: String) -> Shift {
-        let mut updated_shift = self.clone();
-        updated_shift.workers.push(ShiftWorker(worker_id));
-
-        updated_shift
+    // The name of the first field a value doesn't fit, or its id if the field doesn't exist
+    pub fn find_invalid_custom_value(&self, values: &[CustomValue]) -> Option<String> {
+        values
+            .iter()
+            .find_map(|v| match self.get_custom_field(&v.field_id) {
+                Some(field) if field.accepts(&v.value) => None,
+                Some(field) => Some(field.name),
+                None => Some(v.field_id.clone()),
+            })
     }
-    pub fn contains_worker(&self, worker: &Worker) -> bool {
-        self.workers.iter().any(|w| w.0 == worker.id)
+
+    // Searches the worker's custom values, and the names of yes/no fields they answered yes to
+    pub fn matches_custom_filter(&self, worker: &Worker, filter: &String) -> bool {
+        worker.custom_values.iter().any(|custom_value| {
+            match (
+                self.get_custom_field(&custom_value.field_id),
+                &custom_value.value,
+            ) {
+                (None, _) => false,
+                (Some(field), CustomFieldValue::Boolean(true)) => {
+                    field.name.to_lowercase().contains(filter)
+                }
+                (Some(_), CustomFieldValue::Boolean(false)) => false,
+                (Some(_), value) => value.to_string().to_lowercase().contains(filter),
+            }
+        })
     }
-    pub fn remove_worker(&self, worker: &Worker) -> Shift {
-        let mut updated_shift = self.clone();
-        updated_shift.workers.retain(|w| w.0 != worker.id);
 
-        updated_shift
+    pub fn add_action(&self, action: Action) -> Worksite {
+        let mut updated_worksite = self.clone();
+
+        updated_worksite.actions.push(action);
+        updated_worksite
     }
-}
 
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
-pub struct Address {
-    pub street_address: String,
-    pub city: String,
-    pub region: String,
-    pub postal_code: String,
-}
+    pub fn update_action(
+        &self,
+        action_id: String,
+        update_fn: impl FnOnce(Action) -> Action,
+    ) -> Worksite {
+        let mut updated_worksite = self.clone();
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Worker {
-    pub id: String,
-    pub first_name: String,
-    pub last_name: String,
-    pub assessments: Vec<Assessment>,
-    pub tags: Vec<AssignedTag>,
-    pub email: String,
-    pub address: Option<Address>,
-}
+        match self.get_action(action_id.clone()) {
+            Some(action) => {
+                let updated_action = update_fn(action);
 
-pub type FirstName = String;
-pub type LastName = String;
-pub type Email = String;
+                updated_worksite.actions.iter_mut().for_each(|action| {
+                    if action.id == action_id {
+                        *action = updated_action.clone();
+                    }
+                });
 
-impl Worker {
-    pub fn new(first_name: FirstName, last_name: LastName, email: Email) -> Self {
-        Self {
-            id: uuid::Uuid::new_v4().to_string(),
-            first_name,
-            last_name,
-            assessments: vec![],
-            tags: vec![],
-            email,
-            address: None,
+                updated_worksite
+            }
+            None => updated_worksite,
         }
     }
 
-    pub fn full_name(&self) -> String {
-        format!("{} {}", self.first_name, self.last_name)
+    pub fn remove_action(&self, action_id: String) -> Worksite {
+        let mut updated_worksite = self.clone();
+
+        updated_worksite
+            .actions
+            .retain(|action| action.id != action_id);
+        updated_worksite
     }
-    pub fn has_tag(&self, tag: &Tag) -> bool {
-        self.tags.iter().any(|t| t.0 == tag.id)
+
+    pub fn assign_turf(&self, assignment: TurfAssignment) -> Worksite {
+        let mut updated_worksite = self.clone();
+
+        updated_worksite.turf_assignments.push(assignment);
+        updated_worksite
     }
-    pub fn assign_tags(&self, tags: Vec<String>) -> Worker {
-        let mut updated_worker = self.clone();
-        updated_worker.tags = tags.into_iter().map(AssignedTag::new).collect();
 
-        updated_worker
+    pub fn unassign_turf(&self, assignment_id: String) -> Worksite {
+        let mut updated_worksite = self.clone();
+
+        updated_worksite
+            .turf_assignments
+            .retain(|assignment| assignment.id != assignment_id);
+        updated_worksite
+    }
+
+    pub fn get_relationship(&self, relationship_id: &String) -> Option<Relationship> {
+        self.relationships
+            .iter()
+            .find(|r| &r.id == relationship_id)
+            .cloned()
+    }
+
+    pub fn get_relationships_for_worker(&self, worker_id: &String) -> Vec<Relationship> {
+        self.relationships
+            .iter()
+            .filter(|r| r.involves(worker_id))
+            .cloned()
+            .collect()
+    }
+
+    pub fn add_relationship(&self, relationship: Relationship) -> Worksite {
+        let mut updated_worksite = self.clone();
+
+        updated_worksite.relationships.push(relationship);
+        updated_worksite
+    }
+
+    pub fn remove_relationship(&self, relationship_id: &String) -> Worksite {
+        let mut updated_worksite = self.clone();
+
+        updated_worksite
+            .relationships
+            .retain(|r| &r.id != relationship_id);
+        updated_worksite
+    }
+
+    /**
+     * Walks relationships outwards from a worker to find who could sway them, up to
+     * INFLUENCE_DEPTH steps away. Closer people and stronger supporters (lower assessment
+     * values) score higher, so the top of the list is who to bring into the conversation.
+     */
+    pub fn get_influencers(&self, worker_id: &String) -> Vec<Influence> {
+        let mut influences: Vec<Influence> = vec![];
+        let mut seen: Vec<WorkerId> = vec![worker_id.clone()];
+        // Each worker reached so far, with who they're connected through
+        let mut frontier: Vec<(WorkerId, Vec<Worker>)> = vec![(worker_id.clone(), vec![])];
+
+        for distance in 1..=INFLUENCE_DEPTH {
+            let mut next_frontier = vec![];
+
+            for (influenced_id, through) in &frontier {
+                for relationship in &self.relationships {
+                    let influencer_id = match relationship.influencer_of(influenced_id) {
+                        Some(influencer_id) if !seen.contains(influencer_id) => influencer_id,
+                        _ => continue,
+                    };
+                    let influencer = match self.get_worker(influencer_id.clone()) {
+                        Some(influencer) => influencer,
+                        None => continue,
+                    };
+                    seen.push(influencer.id.clone());
+
+                    let mut next_through = through.clone();
+                    if influenced_id != worker_id {
+                        if let Some(influenced) = self.get_worker(influenced_id.clone()) {
+                            next_through.insert(0, influenced);
+                        }
+                    }
+
+                    influences.push(Influence {
+                        score: support_weight(&influencer) * (INFLUENCE_DEPTH + 1 - distance),
+                        worker: influencer.clone(),
+                        distance,
+                        through: next_through.clone(),
+                    });
+                    next_frontier.push((influencer.id, next_through));
+                }
+            }
+
+            frontier = next_frontier;
+        }
+
+        influences.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then(a.distance.cmp(&b.distance))
+                .then(a.worker.last_name.cmp(&b.worker.last_name))
+        });
+        influences
+    }
+
+    // Every shift the worker is on, in order
+    pub fn get_placements(&self, worker_id: &String) -> Vec<Placement> {
+        let mut placements: Vec<Placement> = self
+            .locations
+            .iter()
+            .flat_map(|location| {
+                location
+                    .shifts
+                    .iter()
+                    .filter(|shift| shift.workers.iter().any(|w| &w.0 == worker_id))
+                    .map(|shift| Placement {
+                        location: location.name.clone(),
+                        shift: shift.name.clone(),
+                    })
+            })
+            .collect();
+        placements.sort();
+
+        placements
+    }
+
+    /**
+     * Takes the worker off every shift and puts them on the given ones instead, adding any
+     * location or shift that doesn't exist yet.
+     */
+    pub fn place_worker(&self, worker_id: &String, placements: &[Placement]) -> Worksite {
+        let mut updated_worksite = self.clone();
+
+        updated_worksite.locations.iter_mut().for_each(|location| {
+            location.shifts.iter_mut().for_each(|shift| {
+                shift.workers.retain(|w| &w.0 != worker_id);
+            })
+        });
+
+        for placement in placements {
+            if updated_worksite
+                .get_location_by_name(placement.location.clone())
+                .is_none()
+            {
+                updated_worksite = updated_worksite.add_new_location(placement.location.clone());
+            }
+            let location = updated_worksite
+                .locations
+                .iter_mut()
+                .find(|location| location.name == placement.location)
+                .expect("Location was just added");
+
+            if !location
+                .shifts
+                .iter()
+                .any(|shift| shift.name == placement.shift)
+            {
+                location.shifts.push(Shift::new(placement.shift.clone()));
+            }
+            location.shifts.iter_mut().for_each(|shift| {
+                if shift.name == placement.shift {
+                    *shift = shift.assign_worker(worker_id.clone());
+                }
+            });
+        }
+
+        updated_worksite
+    }
+
+    /**
+     * Compares an employer's list against the workers here. People are matched by email first,
+     * then by name for anyone left over. Workers outside the unit, like managers, often aren't
+     * on the list at all, so only current unit members can show up as departures.
+     */
+    pub fn diff_roster(&self, roster: &[RosterEntry]) -> Vec<RosterChange> {
+        let mut matched: Vec<(RosterEntry, Worker)> = vec![];
+        let mut matched_ids: Vec<WorkerId> = vec![];
+        let mut unmatched: Vec<RosterEntry> = vec![];
+
+        for entry in roster.iter().filter(|entry| !entry.email.trim().is_empty()) {
+            let email = normalized(&entry.email);
+            match self
+                .workers
+                .iter()
+                .find(|w| !matched_ids.contains(&w.id) && normalized(&w.email) == email)
+            {
+                Some(worker) => {
+                    matched_ids.push(worker.id.clone());
+                    matched.push((entry.clone(), worker.clone()));
+                }
+                None => unmatched.push(entry.clone()),
+            }
+        }
+
+        let mut hires = vec![];
+        let by_name = roster
+            .iter()
+            .filter(|entry| entry.email.trim().is_empty())
+            .chain(unmatched.iter());
+        for entry in by_name {
+            match self.workers.iter().find(|w| {
+                !matched_ids.contains(&w.id)
+                    && normalized(&w.full_name()) == entry.normalized_name()
+            }) {
+                Some(worker) => {
+                    matched_ids.push(worker.id.clone());
+                    matched.push((entry.clone(), worker.clone()));
+                }
+                None => hires.push(RosterChange::Hire(entry.clone())),
+            }
+        }
+
+        let mut changes = hires;
+        for (entry, worker) in matched {
+            let from = self.get_placements(&worker.id);
+
+            if worker.status.has_left() {
+                changes.push(RosterChange::Return {
+                    worker,
+                    placements: entry.placements,
+                });
+            } else if !entry.placements.is_empty() && from != entry.placements {
+                changes.push(RosterChange::Move {
+                    worker,
+                    from,
+                    to: entry.placements,
+                });
+            }
+        }
+        changes.extend(
+            self.workers
+                .iter()
+                .filter(|w| !matched_ids.contains(&w.id) && w.is_eligible())
+                .cloned()
+                .map(RosterChange::Departure),
+        );
+
+        changes
+    }
+
+    pub fn find_likely_duplicates(&self, worker: &Worker) -> Vec<Worker> {
+        self.workers
+            .iter()
+            .filter(|other| worker.duplicate_reason(other).is_some())
+            .cloned()
+            .collect()
+    }
+
+    // Every pair of workers who look like the same person, once each
+    pub fn get_likely_duplicates(&self) -> Vec<LikelyDuplicate> {
+        let mut duplicates = vec![];
+        for (i, worker) in self.workers.iter().enumerate() {
+            for other in &self.workers[i + 1..] {
+                if let Some(reason) = worker.duplicate_reason(other) {
+                    duplicates.push(LikelyDuplicate {
+                        worker: worker.clone(),
+                        duplicate: other.clone(),
+                        reason,
+                    });
+                }
+            }
+        }
+
+        duplicates
+    }
+
+    /**
+     * Folds the duplicate into the worker and removes it. Anything that pointed at the
+     * duplicate, like shifts, leaders, relationships and action participation, points at the
+     * worker afterwards.
+     */
+    pub fn merge_workers(&self, worker_id: &String, duplicate_id: &String) -> Worksite {
+        let duplicate = match self.get_worker(duplicate_id.clone()) {
+            Some(duplicate) => duplicate,
+            None => return self.clone(),
+        };
+
+        let mut updated_worksite =
+            self.update_worker(worker_id.clone(), |worker| worker.merge(&duplicate));
+        updated_worksite.workers.retain(|w| &w.id != duplicate_id);
+
+        let rewrite = |id: &mut WorkerId| {
+            if id == duplicate_id {
+                *id = worker_id.clone();
+            }
+        };
+
+        updated_worksite.locations.iter_mut().for_each(|location| {
+            location.shifts.iter_mut().for_each(|shift| {
+                shift.workers.iter_mut().for_each(|w| rewrite(&mut w.0));
+                let mut seen: Vec<WorkerId> = vec![];
+                shift.workers.retain(|w| {
+                    let first = !seen.contains(&w.0);
+                    seen.push(w.0.clone());
+                    first
+                });
+            })
+        });
+
+        updated_worksite.workers.iter_mut().for_each(|w| {
+            w.leader_id.iter_mut().for_each(rewrite);
+            w.supervisor_id.iter_mut().for_each(rewrite);
+            if w.leader_id.as_ref() == Some(&w.id) {
+                w.leader_id = None;
+            }
+            if w.supervisor_id.as_ref() == Some(&w.id) {
+                w.supervisor_id = None;
+            }
+        });
+
+        let mut relationships: Vec<Relationship> = vec![];
+        for mut relationship in updated_worksite.relationships.clone() {
+            rewrite(&mut relationship.from_worker_id);
+            rewrite(&mut relationship.to_worker_id);
+            if relationship.from_worker_id != relationship.to_worker_id
+                && !relationships.iter().any(|r| r.duplicates(&relationship))
+            {
+                relationships.push(relationship);
+            }
+        }
+        updated_worksite.relationships = relationships;
+
+        // When both took part in an action, the worker's own record wins
+        updated_worksite.actions.iter_mut().for_each(|action| {
+            if action
+                .participation
+                .iter()
+                .any(|p| &p.worker_id == worker_id)
+            {
+                action
+                    .participation
+                    .retain(|p| &p.worker_id != duplicate_id);
+            }
+            action
+                .participation
+                .iter_mut()
+                .for_each(|p| rewrite(&mut p.worker_id));
+        });
+
+        updated_worksite
+            .turf_assignments
+            .iter_mut()
+            .for_each(|assignment| {
+                if let Turf::Worker(id) = &mut assignment.turf {
+                    rewrite(id);
+                }
+            });
+
+        updated_worksite
+    }
+}
+
+// Case and surrounding whitespace don't matter when matching people up
+fn normalized(value: &str) -> String {
+    value.trim().to_lowercase()
+}
+
+// Mail for jim+union@example.com still reaches jim@example.com
+fn normalized_email(email: &str) -> String {
+    let email = normalized(email);
+    match email.split_once('@') {
+        Some((mailbox, domain)) => match mailbox.split_once('+') {
+            Some((mailbox, _)) => format!("{}@{}", mailbox, domain),
+            None => email.clone(),
+        },
+        None => email,
+    }
+}
+
+// How many single letter changes it takes to turn one name into the other
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+// Names a typo apart, allowing more slips in longer names
+fn similar_names(a: &str, b: &str) -> bool {
+    let (a, b) = (normalized(a), normalized(b));
+    let allowed = match a.chars().count().min(b.chars().count()) {
+        0 => return false,
+        1..=5 => 1,
+        _ => 2,
+    };
+
+    edit_distance(&a, &b) <= allowed
+}
+
+// First names also match when one is short for the other, like Dan and Daniel
+fn similar_first_names(a: &str, b: &str) -> bool {
+    let (a, b) = (normalized(a), normalized(b));
+
+    similar_names(&a, &b)
+        || (!a.is_empty() && !b.is_empty() && (a.starts_with(&b) || b.starts_with(&a)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum DuplicateReason {
+    SameEmail,
+    SimilarName,
+}
+
+impl DuplicateReason {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::SameEmail => "Same email",
+            Self::SimilarName => "Similar name",
+        }
+    }
+}
+
+// Two workers who look like the same person, most likely from manual entry and an import
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LikelyDuplicate {
+    pub worker: Worker,
+    pub duplicate: Worker,
+    pub reason: DuplicateReason,
+}
+
+// A shift at a location, by name, as employer lists give them
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Placement {
+    pub location: LocationName,
+    pub shift: ShiftName,
+}
+
+impl Display for Placement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}", self.shift, self.location)
+    }
+}
+
+// A person on an employer's list, with every shift they're listed under
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RosterEntry {
+    pub first_name: FirstName,
+    pub last_name: LastName,
+    pub email: Email,
+    pub placements: Vec<Placement>,
+}
+
+impl RosterEntry {
+    pub fn full_name(&self) -> String {
+        format!("{} {}", self.first_name, self.last_name)
+    }
+    pub fn normalized_name(&self) -> String {
+        normalized(&self.full_name())
+    }
+}
+
+// One difference between an employer's list and the worksite
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RosterChange {
+    // On the list, but not at the worksite
+    Hire(RosterEntry),
+    // Back on the list after leaving
+    Return {
+        worker: Worker,
+        placements: Vec<Placement>,
+    },
+    // Listed under different shifts than they're on
+    Move {
+        worker: Worker,
+        from: Vec<Placement>,
+        to: Vec<Placement>,
+    },
+    // At the worksite, but gone from the list
+    Departure(Worker),
+}
+
+impl RosterChange {
+    // Picks the change out again when the same list is compared a second time
+    pub fn key(&self) -> String {
+        match self {
+            Self::Hire(entry) if entry.email.trim().is_empty() => {
+                format!("hire:{}", entry.normalized_name())
+            }
+            Self::Hire(entry) => format!("hire:{}", normalized(&entry.email)),
+            Self::Return { worker, .. } => format!("return:{}", worker.id),
+            Self::Move { worker, .. } => format!("move:{}", worker.id),
+            Self::Departure(worker) => format!("departure:{}", worker.id),
+        }
+    }
+    pub fn full_name(&self) -> String {
+        match self {
+            Self::Hire(entry) => entry.full_name(),
+            Self::Return { worker, .. } | Self::Move { worker, .. } | Self::Departure(worker) => {
+                worker.full_name()
+            }
+        }
+    }
+}
+
+// Assessments run from 1 for the strongest supporters to 5, and unassessed workers count least
+fn support_weight(worker: &Worker) -> usize {
+    match worker.last_assessment() {
+        Some(assessment) => 6_usize.saturating_sub(assessment.value as usize).max(1),
+        None => 1,
+    }
+}
+
+/**
+* Someone who could sway a worker, found by walking relationships. Through lists anyone in
+* between, starting from the influencer's side.
+*/
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Influence {
+    pub worker: Worker,
+    pub distance: usize,
+    pub through: Vec<Worker>,
+    pub score: usize,
+}
+
+pub type LocationName = String;
+
+fn org_chart_node(worker: Worker, members: &[Worker], placed: &mut Vec<WorkerId>) -> OrgChartNode {
+    placed.push(worker.id.clone());
+
+    let mut reports = vec![];
+    for member in members {
+        if member.supervisor_id.as_ref() == Some(&worker.id) && !placed.contains(&member.id) {
+            reports.push(org_chart_node(member.clone(), members, placed));
+        }
+    }
+
+    OrgChartNode { worker, reports }
+}
+
+// A person on an org chart, along with everyone who reports to them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrgChartNode {
+    pub worker: Worker,
+    pub reports: Vec<OrgChartNode>,
+}
+
+impl OrgChartNode {
+    // Everyone below this person on the chart
+    pub fn all_reports(&self) -> Vec<Worker> {
+        self.reports
+            .iter()
+            .flat_map(|report| std::iter::once(report.worker.clone()).chain(report.all_reports()))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Location {
+    pub id: String,
+    pub name: LocationName,
+    pub shifts: Vec<Shift>,
+}
+
+impl Location {
+    pub fn new(name: LocationName) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name,
+            shifts: vec![],
+        }
+    }
+
+    pub fn add_shift(&self, shift: Shift) -> Location {
+        let mut updated_location = self.clone();
+
+        if updated_location.shifts.iter().any(|s| s.id == shift.id) {
+            return updated_location;
+        }
+
+        updated_location.shifts.push(shift);
+        updated_location
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ShiftWorker(pub String);
+
+impl ShiftWorker {
+    pub fn new(id: String) -> Self {
+        Self(id)
+    }
+}
+
+pub type ShiftName = String;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Shift {
+    pub id: String,
+    pub name: ShiftName,
+    pub workers: Vec<ShiftWorker>,
+}
+
+impl Shift {
+    pub fn new(name: ShiftName) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name,
+            workers: vec![],
+        }
+    }
+
+    pub fn assign_worker(&self, worker_id: String) -> Shift {
+        let mut updated_shift = self.clone();
+        updated_shift.workers.push(ShiftWorker(worker_id));
+
+        updated_shift
+    }
+    pub fn contains_worker(&self, worker: &Worker) -> bool {
+        self.workers.iter().any(|w| w.0 == worker.id)
+    }
+    pub fn remove_worker(&self, worker: &Worker) -> Shift {
+        let mut updated_shift = self.clone();
+        updated_shift.workers.retain(|w| w.0 != worker.id);
+
+        updated_shift
+    }
+}
+
+/**
+* Whether a worker is in the bargaining unit. Excluded people stay on the wallchart, since
+* organizers still need to know who they are, but aren't counted.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Eligibility {
+    Eligible,
+    Excluded(ExclusionReason),
+}
+
+impl Eligibility {
+    pub fn exclusion_reason(&self) -> Option<ExclusionReason> {
+        match self {
+            Self::Eligible => None,
+            Self::Excluded(reason) => Some(*reason),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ExclusionReason {
+    Supervisor,
+    Manager,
+    Confidential,
+    Other,
+}
+
+impl ExclusionReason {
+    pub const ALL: [ExclusionReason; 4] = [
+        Self::Supervisor,
+        Self::Manager,
+        Self::Confidential,
+        Self::Other,
+    ];
+
+    pub fn new<T: AsRef<str>>(reason: T) -> Option<Self> {
+        match reason.as_ref() {
+            "Supervisor" => Some(Self::Supervisor),
+            "Manager" => Some(Self::Manager),
+            "Confidential" => Some(Self::Confidential),
+            "Other" => Some(Self::Other),
+            _ => None,
+        }
+    }
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Supervisor => "Supervisor",
+            Self::Manager => "Manager",
+            Self::Confidential => "Confidential employee",
+            Self::Other => "Other",
+        }
+    }
+}
+
+impl Display for ExclusionReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            Self::Supervisor => "Supervisor",
+            Self::Manager => "Manager",
+            Self::Confidential => "Confidential",
+            Self::Other => "Other",
+        };
+        write!(f, "{}", reason)
+    }
+}
+
+/**
+* Where a worker stands with the employer. Workers who leave stay on the worksite, unlike
+* deleted ones, so turnover can be tracked.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum EmploymentStatus {
+    Active,
+    OnLeave,
+    Terminated,
+    Quit,
+    Transferred,
+}
+
+impl EmploymentStatus {
+    pub const ALL: [EmploymentStatus; 5] = [
+        Self::Active,
+        Self::OnLeave,
+        Self::Terminated,
+        Self::Quit,
+        Self::Transferred,
+    ];
+
+    pub fn new<T: AsRef<str>>(status: T) -> Option<Self> {
+        match status.as_ref() {
+            "Active" => Some(Self::Active),
+            "OnLeave" => Some(Self::OnLeave),
+            "Terminated" => Some(Self::Terminated),
+            "Quit" => Some(Self::Quit),
+            "Transferred" => Some(Self::Transferred),
+            _ => None,
+        }
+    }
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Active => "Active",
+            Self::OnLeave => "On leave",
+            Self::Terminated => "Terminated",
+            Self::Quit => "Quit",
+            Self::Transferred => "Transferred",
+        }
+    }
+    pub fn has_left(&self) -> bool {
+        matches!(self, Self::Terminated | Self::Quit | Self::Transferred)
+    }
+}
+
+impl Display for EmploymentStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            Self::Active => "Active",
+            Self::OnLeave => "OnLeave",
+            Self::Terminated => "Terminated",
+            Self::Quit => "Quit",
+            Self::Transferred => "Transferred",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StatusChange {
+    pub previous: EmploymentStatus,
+    pub status: EmploymentStatus,
+    pub changed_on: NaiveDate,
+    pub note: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AuthorizationCard {
+    pub signed_on: NaiveDate,
+    pub witnessed_by: String,
+    // Whether the organizers have the signed paper card, not just a photo or e-signature
+    pub on_file: bool,
+}
+
+/**
+* A share of the bargaining unit with signed cards to aim for, e.g. 30% to be able to
+* file for an election.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CardThreshold {
+    pub name: String,
+    pub percent: u8,
+}
+
+impl CardThreshold {
+    pub fn new<T: Into<String>>(name: T, percent: u8) -> Self {
+        Self {
+            name: name.into(),
+            percent,
+        }
+    }
+
+    // What new worksites start with
+    pub fn defaults() -> Vec<CardThreshold> {
+        vec![
+            Self::new("File for an election", 30),
+            Self::new("Supermajority", 70),
+        ]
+    }
+
+    pub fn is_met(&self, signed: usize, total: usize) -> bool {
+        total > 0 && signed * 100 >= self.percent as usize * total
+    }
+
+    pub fn cards_needed(&self, signed: usize, total: usize) -> usize {
+        let required = (self.percent as usize * total).div_ceil(100);
+        required.saturating_sub(signed)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Address {
+    pub street_address: String,
+    pub city: String,
+    pub region: String,
+    pub postal_code: String,
+}
+
+/**
+* What a worker does and how they're employed, which organizers use to map the workplace and
+* find who shares the same issues.
+*/
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Employment {
+    pub job_title: String,
+    pub department: String,
+    pub hire_date: Option<NaiveDate>,
+    // Free text, since some workplaces use an hourly rate and others a classification or grade
+    pub pay_rate: String,
+    pub employment_type: Option<EmploymentType>,
+}
+
+impl Employment {
+    pub fn matches_filter(&self, filter: &String) -> bool {
+        self.job_title.to_lowercase().contains(filter)
+            || self.department.to_lowercase().contains(filter)
+            || (self.pay_rate.to_lowercase().contains(filter) && filter.len() > 1)
+            || self.employment_type.is_some_and(|employment_type| {
+                employment_type.label().to_lowercase().contains(filter)
+            })
+            || self.hire_date.is_some_and(|hire_date| {
+                hire_date
+                    .format("%B %Y")
+                    .to_string()
+                    .to_lowercase()
+                    .contains(filter)
+                    && filter.len() > 1
+            })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum EmploymentType {
+    FullTime,
+    PartTime,
+    Temp,
+}
+
+impl EmploymentType {
+    pub const ALL: [EmploymentType; 3] = [Self::FullTime, Self::PartTime, Self::Temp];
+
+    pub fn new<T: AsRef<str>>(employment_type: T) -> Option<Self> {
+        match employment_type.as_ref() {
+            "FullTime" => Some(Self::FullTime),
+            "PartTime" => Some(Self::PartTime),
+            "Temp" => Some(Self::Temp),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::FullTime => "Full time",
+            Self::PartTime => "Part time",
+            Self::Temp => "Temp",
+        }
+    }
+}
+
+impl Display for EmploymentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let employment_type = match self {
+            Self::FullTime => "FullTime",
+            Self::PartTime => "PartTime",
+            Self::Temp => "Temp",
+        };
+        write!(f, "{}", employment_type)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Worker {
+    pub id: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub assessments: Vec<Assessment>,
+    pub conversations: Vec<Conversation>,
+    // Worker-leaders on the organizing committee each have a list of coworkers to talk to
+    pub committee_member: bool,
+    pub leader_id: Option<WorkerId>,
+    // None until the worker signs a union authorization card
+    pub authorization_card: Option<AuthorizationCard>,
+    pub eligibility: Eligibility,
+    pub tags: Vec<AssignedTag>,
+    pub email: String,
+    pub phone: String,
+    pub address: Option<Address>,
+    pub employment: Employment,
+    pub status: EmploymentStatus,
+    // Every change of status, oldest first
+    pub status_changes: Vec<StatusChange>,
+    // Whoever the worker answers to at work, who may be a manager outside the unit
+    pub supervisor_id: Option<WorkerId>,
+    // Answers to the worksite's custom fields, leaving out any that are blank
+    pub custom_values: Vec<CustomValue>,
+}
+
+pub type FirstName = String;
+pub type LastName = String;
+pub type Email = String;
+
+impl Worker {
+    pub fn new(first_name: FirstName, last_name: LastName, email: Email) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            first_name,
+            last_name,
+            assessments: vec![],
+            conversations: vec![],
+            committee_member: false,
+            leader_id: None,
+            authorization_card: None,
+            eligibility: Eligibility::Eligible,
+            tags: vec![],
+            email,
+            phone: "".into(),
+            address: None,
+            employment: Employment::default(),
+            status: EmploymentStatus::Active,
+            status_changes: vec![],
+            supervisor_id: None,
+            custom_values: vec![],
+        }
+    }
+
+    pub fn full_name(&self) -> String {
+        format!("{} {}", self.first_name, self.last_name)
+    }
+    pub fn has_signed_card(&self) -> bool {
+        self.authorization_card.is_some()
+    }
+    // Only current workers in the unit count, so anyone who has left drops out of the numbers
+    pub fn is_eligible(&self) -> bool {
+        self.eligibility == Eligibility::Eligible && !self.status.has_left()
+    }
+    pub fn is_highly_rated(&self) -> bool {
+        self.committee_member
+            || self
+                .last_assessment()
+                .is_some_and(|assessment| assessment.value <= LEADER_ASSESSMENT)
+    }
+    // The worker's status at the start of the given day
+    pub fn status_on(&self, date: NaiveDate) -> EmploymentStatus {
+        self.status_changes
+            .iter()
+            .rev()
+            .find(|change| change.changed_on < date)
+            .map(|change| change.status)
+            .or(self.status_changes.first().map(|change| change.previous))
+            .unwrap_or(self.status)
+    }
+    pub fn duplicate_reason(&self, other: &Worker) -> Option<DuplicateReason> {
+        if self.id == other.id {
+            return None;
+        }
+
+        let email = normalized_email(&self.email);
+        if !email.is_empty() && email == normalized_email(&other.email) {
+            return Some(DuplicateReason::SameEmail);
+        }
+        if similar_names(&self.last_name, &other.last_name)
+            && similar_first_names(&self.first_name, &other.first_name)
+        {
+            return Some(DuplicateReason::SimilarName);
+        }
+
+        None
+    }
+    /**
+     * Combines a duplicate's records into this worker. History like assessments and
+     * conversations is kept from both, and anything this worker is missing is filled in from
+     * the duplicate. Status changes from both are replayed in date order, so the latest one
+     * decides the employment status, and an exclusion on either keeps the worker excluded.
+     */
+    pub fn merge(&self, duplicate: &Worker) -> Worker {
+        let mut merged = self.clone();
+        let or_theirs = |mine: &String, theirs: &String| match mine.trim().is_empty() {
+            true => theirs.clone(),
+            false => mine.clone(),
+        };
+
+        merged.assessments.extend(duplicate.assessments.clone());
+        merged
+            .assessments
+            .sort_by_key(|assessment| assessment.created_at);
+        merged.conversations.extend(duplicate.conversations.clone());
+        merged
+            .conversations
+            .sort_by_key(|conversation| (conversation.held_on, conversation.created_at));
+        merged
+            .status_changes
+            .extend(duplicate.status_changes.clone());
+        merged
+            .status_changes
+            .sort_by_key(|change| change.changed_on);
+        merged.status = merged
+            .status_changes
+            .last()
+            .map(|change| change.status)
+            .unwrap_or(self.status);
+        for tag in &duplicate.tags {
+            if !merged.tags.contains(tag) {
+                merged.tags.push(tag.clone());
+            }
+        }
+        for value in &duplicate.custom_values {
+            if merged.get_custom_value(&value.field_id).is_none() {
+                merged.custom_values.push(value.clone());
+            }
+        }
+
+        merged.committee_member = self.committee_member || duplicate.committee_member;
+        merged.leader_id = self.leader_id.clone().or(duplicate.leader_id.clone());
+        merged.supervisor_id = self
+            .supervisor_id
+            .clone()
+            .or(duplicate.supervisor_id.clone());
+        if self.eligibility == Eligibility::Eligible {
+            merged.eligibility = duplicate.eligibility;
+        }
+        merged.authorization_card = self
+            .authorization_card
+            .clone()
+            .or(duplicate.authorization_card.clone());
+        merged.email = or_theirs(&self.email, &duplicate.email);
+        merged.phone = or_theirs(&self.phone, &duplicate.phone);
+        merged.address = match &self.address {
+            Some(address) if !address.street_address.trim().is_empty() => Some(address.clone()),
+            _ => duplicate.address.clone().or(self.address.clone()),
+        };
+        merged.employment = Employment {
+            job_title: or_theirs(&self.employment.job_title, &duplicate.employment.job_title),
+            department: or_theirs(
+                &self.employment.department,
+                &duplicate.employment.department,
+            ),
+            hire_date: self.employment.hire_date.or(duplicate.employment.hire_date),
+            pay_rate: or_theirs(&self.employment.pay_rate, &duplicate.employment.pay_rate),
+            employment_type: self
+                .employment
+                .employment_type
+                .or(duplicate.employment.employment_type),
+        };
+
+        merged
+    }
+    // Status changes have to stay in date order
+    pub fn can_change_status_on(&self, date: NaiveDate) -> bool {
+        self.status_changes
+            .last()
+            .map_or(true, |last| last.changed_on <= date)
+    }
+    pub fn change_status(&self, change: StatusChange) -> Worker {
+        let mut updated_worker = self.clone();
+        updated_worker.status = change.status;
+        updated_worker.status_changes.push(change);
+
+        updated_worker
+    }
+    // The date the worker was last marked as gone, if they've left
+    pub fn left_on(&self) -> Option<NaiveDate> {
+        if !self.status.has_left() {
+            return None;
+        }
+        self.status_changes.last().map(|change| change.changed_on)
+    }
+    pub fn get_custom_value(&self, field_id: &String) -> Option<&CustomFieldValue> {
+        self.custom_values
+            .iter()
+            .find(|v| &v.field_id == field_id)
+            .map(|v| &v.value)
+    }
+    pub fn has_tag(&self, tag: &Tag) -> bool {
+        self.tags.iter().any(|t| t.0 == tag.id)
+    }
+    pub fn assign_tags(&self, tags: Vec<String>) -> Worker {
+        let mut updated_worker = self.clone();
+        updated_worker.tags = tags.into_iter().map(AssignedTag::new).collect();
+
+        updated_worker
     }
     pub fn get_assessment(&self, assessment_id: String) -> Option<Assessment> {
         self.assessments
             .iter()
-            .find(|a| a.id == assessment_id)
+            .find(|a| a.id == assessment_id)
+            .cloned()
+    }
+    pub fn last_assessment(&self) -> Option<Assessment> {
+        self.assessments.last().cloned()
+    }
+    pub fn add_assessment(&self, assessment: Assessment) -> Worker {
+        let mut updated_worker = self.clone();
+        updated_worker.assessments.push(assessment);
+
+        updated_worker
+    }
+    pub fn update_assessment(
+        &self,
+        assessment_id: String,
+        update_fn: impl FnOnce(Assessment) -> Assessment,
+    ) -> Worker {
+        let mut updated_worker = self.clone();
+
+        let assessment = self
+            .assessments
+            .iter()
+            .find(|a| a.id == assessment_id)
+            .cloned();
+
+        match assessment {
+            Some(assessment) => {
+                let updated_assessment = update_fn(assessment);
+
+                updated_worker
+                    .assessments
+                    .iter_mut()
+                    .for_each(|assessment| {
+                        if assessment.id == assessment_id {
+                            *assessment = updated_assessment.clone();
+                        }
+                    });
+
+                updated_worker
+            }
+            None => updated_worker,
+        }
+    }
+    pub fn remove_assessment(&self, assessment_id: String) -> Worker {
+        let mut updated_worker = self.clone();
+
+        updated_worker
+            .assessments
+            .retain(|assessment| assessment.id != assessment_id);
+
+        updated_worker
+    }
+    pub fn get_conversation(&self, conversation_id: String) -> Option<Conversation> {
+        self.conversations
+            .iter()
+            .find(|c| c.id == conversation_id)
             .cloned()
     }
-    pub fn last_assessment(&self) -> Option<Assessment> {
-        self.assessments.last().cloned()
-    }
-    pub fn add_assessment(&self, assessment: Assessment) -> Worker {
+    pub fn add_conversation(&self, conversation: Conversation) -> Worker {
         let mut updated_worker = self.clone();
-        updated_worker.assessments.push(assessment);
+        updated_worker.conversations.push(conversation);
 
         updated_worker
     }
-    pub fn update_assessment(
+    pub fn update_conversation(
         &self,
-        assessment_id: String,
-        update_fn: impl FnOnce(Assessment) -> Assessment,
+        conversation_id: String,
+        update_fn: impl FnOnce(Conversation) -> Conversation,
     ) -> Worker {
         let mut updated_worker = self.clone();
 
-        let assessment = self
-            .assessments
-            .iter()
-            .find(|a| a.id == assessment_id)
-            .cloned();
-
-        match assessment {
-            Some(assessment) => {
-                let updated_assessment = update_fn(assessment);
+        match self.get_conversation(conversation_id.clone()) {
+            Some(conversation) => {
+                let updated_conversation = update_fn(conversation);
 
                 updated_worker
-                    .assessments
+                    .conversations
                     .iter_mut()
-                    .for_each(|assessment| {
-                        if assessment.id == assessment_id {
-                            *assessment = updated_assessment.clone();
+                    .for_each(|conversation| {
+                        if conversation.id == conversation_id {
+                            *conversation = updated_conversation.clone();
                         }
                     });
 
@@ -406,12 +1847,12 @@ impl Worker {
             None => updated_worker,
         }
     }
-    pub fn remove_assessment(&self, assessment_id: String) -> Worker {
+    pub fn remove_conversation(&self, conversation_id: String) -> Worker {
         let mut updated_worker = self.clone();
 
         updated_worker
-            .assessments
-            .retain(|assessment| assessment.id != assessment_id);
+            .conversations
+            .retain(|conversation| conversation.id != conversation_id);
 
         updated_worker
     }
@@ -419,6 +1860,7 @@ impl Worker {
         self.first_name.to_lowercase().contains(filter) | 
         self.last_name.to_lowercase().contains(filter) | 
         self.email.to_lowercase().contains(filter) |
+        (self.phone.to_lowercase().contains(filter) && (filter.len() > 1)) |
         match &self.address {
             Some(address) => address.city.to_lowercase().contains(filter) |
                 (address.postal_code.to_lowercase().contains(filter) && (filter.len() > 1)) |
@@ -426,6 +1868,7 @@ impl Worker {
                 (address.street_address.to_lowercase().contains(filter) && (filter.len() > 1)),
             None    => false,
         } | 
+        self.employment.matches_filter(filter) |
         self.assessments.iter().any(|assessment| assessment.assessor.to_lowercase().contains(filter) |
             (assessment.created_at.format("%B %Y").to_string().to_lowercase().contains(filter) && (filter.len() > 1)) |
             (assessment.updated_at.format("%B %Y").to_string().to_lowercase().contains(filter) && (filter.len() > 1)) |
@@ -433,7 +1876,7 @@ impl Worker {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Assessment {
     pub id: String,
     pub value: u8,
@@ -443,14 +1886,553 @@ pub struct Assessment {
     pub assessor: String,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/**
+* A conversation an organizer had with a worker. Unlike an assessment it carries no rating,
+* so every contact can be logged.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Conversation {
+    pub id: String,
+    pub held_on: NaiveDate,
+    pub organizer: String,
+    pub channel: ConversationChannel,
+    pub issues: Vec<String>,
+    pub notes: String,
+    pub next_step: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ConversationChannel {
+    InPerson,
+    Phone,
+    Text,
+}
+
+impl ConversationChannel {
+    pub const ALL: [ConversationChannel; 3] = [Self::InPerson, Self::Phone, Self::Text];
+
+    pub fn new<T: AsRef<str>>(channel: T) -> Option<Self> {
+        match channel.as_ref() {
+            "InPerson" => Some(Self::InPerson),
+            "Phone" => Some(Self::Phone),
+            "Text" => Some(Self::Text),
+            _ => None,
+        }
+    }
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::InPerson => "In person",
+            Self::Phone => "Phone",
+            Self::Text => "Text",
+        }
+    }
+}
+
+impl Display for ConversationChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let channel = match self {
+            Self::InPerson => "InPerson",
+            Self::Phone => "Phone",
+            Self::Text => "Text",
+        };
+        write!(f, "{}", channel)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Tag {
     pub id: String,
     pub name: String,
     pub icon: String,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/**
+* Something a campaign tracks about every worker that the app doesn't have a field for, like
+* languages spoken. Defined per worksite, with the values kept on each worker.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CustomField {
+    pub id: String,
+    pub name: String,
+    pub field_type: CustomFieldType,
+    // The choices for select fields, empty for the other types
+    pub options: Vec<String>,
+}
+
+impl CustomField {
+    pub fn new<T: Into<String>>(
+        name: T,
+        field_type: CustomFieldType,
+        options: Vec<String>,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.into(),
+            field_type,
+            options,
+        }
+    }
+
+    /**
+     * Turns what was entered for this field into a value. Blank inputs are None, and anything that
+     * doesn't fit the field's type is an error to show back to the user.
+     */
+    pub fn parse_value(&self, inputs: Vec<String>) -> Result<Option<CustomFieldValue>, String> {
+        let inputs: Vec<String> = inputs
+            .into_iter()
+            .map(|input| input.trim().to_string())
+            .filter(|input| !input.is_empty())
+            .collect();
+        let input = match inputs.first() {
+            Some(input) => input.clone(),
+            None => return Ok(None),
+        };
+
+        let value = match self.field_type {
+            CustomFieldType::Text => CustomFieldValue::Text(input),
+            CustomFieldType::Number => match input.parse::<f64>() {
+                Ok(_) => CustomFieldValue::Number(input),
+                Err(_) => return Err(format!("{} must be a number", self.name)),
+            },
+            CustomFieldType::Date => match NaiveDate::parse_from_str(&input, "%Y-%m-%d") {
+                Ok(date) => CustomFieldValue::Date(date),
+                Err(_) => return Err(format!("{} must be a date", self.name)),
+            },
+            CustomFieldType::Boolean => match input.as_str() {
+                "true" => CustomFieldValue::Boolean(true),
+                "false" => CustomFieldValue::Boolean(false),
+                _ => return Err(format!("{} must be yes or no", self.name)),
+            },
+            CustomFieldType::SingleSelect => CustomFieldValue::SingleSelect(input),
+            CustomFieldType::MultiSelect => CustomFieldValue::MultiSelect(inputs),
+        };
+
+        match self.accepts(&value) {
+            true => Ok(Some(value)),
+            false => Err(format!("{} has an option that doesn't exist", self.name)),
+        }
+    }
+
+    pub fn accepts(&self, value: &CustomFieldValue) -> bool {
+        match (self.field_type, value) {
+            (CustomFieldType::Text, CustomFieldValue::Text(_))
+            | (CustomFieldType::Date, CustomFieldValue::Date(_))
+            | (CustomFieldType::Boolean, CustomFieldValue::Boolean(_)) => true,
+            (CustomFieldType::Number, CustomFieldValue::Number(number)) => {
+                number.parse::<f64>().is_ok()
+            }
+            (CustomFieldType::SingleSelect, CustomFieldValue::SingleSelect(option)) => {
+                self.options.contains(option)
+            }
+            (CustomFieldType::MultiSelect, CustomFieldValue::MultiSelect(options)) => {
+                options.iter().all(|option| self.options.contains(option))
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CustomFieldType {
+    Text,
+    Number,
+    Date,
+    SingleSelect,
+    MultiSelect,
+    Boolean,
+}
+
+impl CustomFieldType {
+    pub const ALL: [CustomFieldType; 6] = [
+        Self::Text,
+        Self::Number,
+        Self::Date,
+        Self::SingleSelect,
+        Self::MultiSelect,
+        Self::Boolean,
+    ];
+
+    pub fn new<T: AsRef<str>>(field_type: T) -> Option<Self> {
+        match field_type.as_ref() {
+            "Text" => Some(Self::Text),
+            "Number" => Some(Self::Number),
+            "Date" => Some(Self::Date),
+            "SingleSelect" => Some(Self::SingleSelect),
+            "MultiSelect" => Some(Self::MultiSelect),
+            "Boolean" => Some(Self::Boolean),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Text => "Text",
+            Self::Number => "Number",
+            Self::Date => "Date",
+            Self::SingleSelect => "Pick one",
+            Self::MultiSelect => "Pick any",
+            Self::Boolean => "Yes or no",
+        }
+    }
+
+    pub fn has_options(&self) -> bool {
+        matches!(self, Self::SingleSelect | Self::MultiSelect)
+    }
+}
+
+impl Display for CustomFieldType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let field_type = match self {
+            Self::Text => "Text",
+            Self::Number => "Number",
+            Self::Date => "Date",
+            Self::SingleSelect => "SingleSelect",
+            Self::MultiSelect => "MultiSelect",
+            Self::Boolean => "Boolean",
+        };
+        write!(f, "{}", field_type)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CustomValue {
+    pub field_id: String,
+    pub value: CustomFieldValue,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum CustomFieldValue {
+    Text(String),
+    // Kept as entered, once it has parsed as a number, so values like "2.50" don't change
+    Number(String),
+    Date(NaiveDate),
+    SingleSelect(String),
+    MultiSelect(Vec<String>),
+    Boolean(bool),
+}
+
+// How a value reads on a page or in an export
+impl Display for CustomFieldValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Text(text) | Self::Number(text) | Self::SingleSelect(text) => {
+                write!(f, "{}", text)
+            }
+            Self::Date(date) => write!(f, "{}", date),
+            Self::MultiSelect(options) => write!(f, "{}", options.join(", ")),
+            Self::Boolean(true) => write!(f, "Yes"),
+            Self::Boolean(false) => write!(f, "No"),
+        }
+    }
+}
+
+/**
+* A collective action, like signing a petition or wearing a button, used to test how many
+* workers will act together. Workers who were never asked have no participation.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Action {
+    pub id: String,
+    pub held_on: NaiveDate,
+    pub description: String,
+    pub participation: Vec<Participation>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Action {
+    pub fn new(held_on: NaiveDate, description: String) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            held_on,
+            description,
+            participation: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn get_participation(&self, worker_id: &String) -> Option<ParticipationStatus> {
+        self.participation
+            .iter()
+            .find(|p| &p.worker_id == worker_id)
+            .map(|p| p.status)
+    }
+
+    // None forgets the worker was ever asked
+    pub fn set_participation(
+        &self,
+        worker_id: String,
+        status: Option<ParticipationStatus>,
+    ) -> Action {
+        let mut updated_action = self.clone();
+
+        updated_action
+            .participation
+            .retain(|p| p.worker_id != worker_id);
+        if let Some(status) = status {
+            updated_action
+                .participation
+                .push(Participation { worker_id, status });
+        }
+
+        updated_action
+    }
+
+    // Of the given workers in the bargaining unit, how many followed through
+    pub fn count_participants(&self, workers: &[Worker]) -> ParticipationCount {
+        let eligible: Vec<&Worker> = workers.iter().filter(|w| w.is_eligible()).collect();
+
+        ParticipationCount {
+            participated: eligible
+                .iter()
+                .filter(|worker| {
+                    self.get_participation(&worker.id) == Some(ParticipationStatus::DidIt)
+                })
+                .count(),
+            total: eligible.len(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Participation {
+    pub worker_id: WorkerId,
+    pub status: ParticipationStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ParticipationStatus {
+    Asked,
+    Yes,
+    No,
+    DidIt,
+}
+
+impl ParticipationStatus {
+    pub const ALL: [ParticipationStatus; 4] = [Self::Asked, Self::Yes, Self::No, Self::DidIt];
+
+    pub fn new<T: AsRef<str>>(status: T) -> Option<Self> {
+        match status.as_ref() {
+            "Asked" => Some(Self::Asked),
+            "Yes" => Some(Self::Yes),
+            "No" => Some(Self::No),
+            "DidIt" => Some(Self::DidIt),
+            _ => None,
+        }
+    }
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Asked => "Asked",
+            Self::Yes => "Said yes",
+            Self::No => "Said no",
+            Self::DidIt => "Did it",
+        }
+    }
+}
+
+impl Display for ParticipationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            Self::Asked => "Asked",
+            Self::Yes => "Yes",
+            Self::No => "No",
+            Self::DidIt => "DidIt",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParticipationCount {
+    pub participated: usize,
+    pub total: usize,
+}
+
+impl ParticipationCount {
+    pub fn percent(&self) -> usize {
+        match self.total {
+            0 => 0,
+            total => self.participated * 100 / total,
+        }
+    }
+}
+
+/**
+* Part of a worksite an organizer (an auth-service user) is responsible for. A location
+* covers everyone on its shifts and a shift covers everyone on it.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TurfAssignment {
+    pub id: String,
+    pub user_id: String,
+    pub turf: Turf,
+}
+
+impl TurfAssignment {
+    pub fn new(user_id: String, turf: Turf) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            user_id,
+            turf,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "id")]
+pub enum Turf {
+    Location(LocationId),
+    Shift(ShiftId),
+    Worker(WorkerId),
+}
+
+impl Turf {
+    pub fn new<T: AsRef<str>>(kind: T, id: String) -> Option<Self> {
+        match kind.as_ref() {
+            "Location" => Some(Self::Location(id)),
+            "Shift" => Some(Self::Shift(id)),
+            "Worker" => Some(Self::Worker(id)),
+            _ => None,
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Location(_) => "Location",
+            Self::Shift(_) => "Shift",
+            Self::Worker(_) => "Worker",
+        }
+    }
+
+    pub fn id(&self) -> &String {
+        match self {
+            Self::Location(id) | Self::Shift(id) | Self::Worker(id) => id,
+        }
+    }
+}
+
+/**
+* A social tie between two workers. Most go both ways, but a directional one means the first
+* worker sways the second and not the other way around.
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Relationship {
+    pub id: String,
+    pub from_worker_id: WorkerId,
+    pub to_worker_id: WorkerId,
+    pub kind: RelationshipKind,
+    pub directional: bool,
+}
+
+impl Relationship {
+    pub fn new(
+        from_worker_id: WorkerId,
+        to_worker_id: WorkerId,
+        kind: RelationshipKind,
+        directional: bool,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            from_worker_id,
+            to_worker_id,
+            kind,
+            directional,
+        }
+    }
+
+    pub fn involves(&self, worker_id: &String) -> bool {
+        &self.from_worker_id == worker_id || &self.to_worker_id == worker_id
+    }
+
+    // Whoever is on the other end from the given worker
+    pub fn other_worker_id(&self, worker_id: &String) -> &WorkerId {
+        if &self.from_worker_id == worker_id {
+            &self.to_worker_id
+        } else {
+            &self.from_worker_id
+        }
+    }
+
+    // Who this relationship lets sway the given worker, if anyone
+    pub fn influencer_of(&self, worker_id: &String) -> Option<&WorkerId> {
+        if &self.to_worker_id == worker_id {
+            Some(&self.from_worker_id)
+        } else if &self.from_worker_id == worker_id && !self.directional {
+            Some(&self.to_worker_id)
+        } else {
+            None
+        }
+    }
+
+    // Same two workers and kind, whichever way round
+    pub fn duplicates(&self, other: &Relationship) -> bool {
+        self.kind == other.kind
+            && self.involves(&other.from_worker_id)
+            && self.involves(&other.to_worker_id)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum RelationshipKind {
+    Carpool,
+    Friend,
+    Family,
+    BreakTime,
+    Mentor,
+    Other,
+}
+
+impl RelationshipKind {
+    pub const ALL: [RelationshipKind; 6] = [
+        Self::Carpool,
+        Self::Friend,
+        Self::Family,
+        Self::BreakTime,
+        Self::Mentor,
+        Self::Other,
+    ];
+
+    pub fn new<T: AsRef<str>>(kind: T) -> Option<Self> {
+        match kind.as_ref() {
+            "Carpool" => Some(Self::Carpool),
+            "Friend" => Some(Self::Friend),
+            "Family" => Some(Self::Family),
+            "BreakTime" => Some(Self::BreakTime),
+            "Mentor" => Some(Self::Mentor),
+            "Other" => Some(Self::Other),
+            _ => None,
+        }
+    }
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Carpool => "Carpool",
+            Self::Friend => "Friends",
+            Self::Family => "Family",
+            Self::BreakTime => "Same break time",
+            Self::Mentor => "Mentor",
+            Self::Other => "Other",
+        }
+    }
+}
+
+impl Display for RelationshipKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            Self::Carpool => "Carpool",
+            Self::Friend => "Friend",
+            Self::Family => "Family",
+            Self::BreakTime => "BreakTime",
+            Self::Mentor => "Mentor",
+            Self::Other => "Other",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct AssignedTag(pub String);
 
 impl AssignedTag {
@@ -458,3 +2440,465 @@ impl AssignedTag {
         Self(id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn worker(first_name: &str, last_name: &str) -> Worker {
+        Worker::new(
+            first_name.into(),
+            last_name.into(),
+            format!("{}@example.com", first_name.to_lowercase()),
+        )
+    }
+
+    #[test]
+    fn turf_worksite_shows_only_the_organizers_workers() {
+        let pam = worker("Pam", "Beesly");
+        let jim = worker("Jim", "Halpert");
+        let day_shift = Shift::new("Day".into()).assign_worker(pam.id.clone());
+        let night_shift = Shift::new("Night".into()).assign_worker(jim.id.clone());
+        let office = Location::new("Office".into())
+            .add_shift(day_shift.clone())
+            .add_shift(night_shift);
+        let warehouse = Location::new("Warehouse".into());
+        let worksite = Worksite::new("Scranton".into())
+            .add_location(office)
+            .add_location(warehouse)
+            .add_worker(pam.clone())
+            .add_worker(jim)
+            .assign_turf(TurfAssignment::new(
+                "organizer-1".into(),
+                Turf::Shift(day_shift.id.clone()),
+            ));
+
+        let turf_worksite = worksite.for_turf(&"organizer-1".to_string());
+
+        assert_eq!(turf_worksite.workers, vec![pam]);
+        assert_eq!(turf_worksite.locations.len(), 1);
+        assert_eq!(turf_worksite.locations[0].shifts.len(), 1);
+        assert_eq!(turf_worksite.locations[0].shifts[0].id, day_shift.id);
+        assert!(worksite
+            .for_turf(&"organizer-2".to_string())
+            .workers
+            .is_empty());
+    }
+
+    #[test]
+    fn card_thresholds_are_met_at_exactly_their_percent() {
+        let threshold = CardThreshold::new("File for an election", 30);
+
+        assert!(threshold.is_met(3, 10));
+        assert!(!threshold.is_met(2, 10));
+        assert_eq!(threshold.cards_needed(2, 10), 1);
+        // 30% of one worker still takes a whole card
+        assert_eq!(threshold.cards_needed(0, 1), 1);
+    }
+
+    fn supervised_by(worker: Worker, supervisor: &Worker) -> Worker {
+        Worker {
+            supervisor_id: Some(supervisor.id.clone()),
+            ..worker
+        }
+    }
+
+    // Each person followed by their reports in brackets
+    fn outline(nodes: &[OrgChartNode]) -> String {
+        nodes
+            .iter()
+            .map(|node| match node.reports.is_empty() {
+                true => node.worker.first_name.clone(),
+                false => format!("{} ({})", node.worker.first_name, outline(&node.reports)),
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    #[test]
+    fn org_chart_pulls_in_supervisors_from_other_locations() {
+        let michael = worker("Michael", "Scott");
+        let dwight = supervised_by(worker("Dwight", "Schrute"), &michael);
+        let jim = supervised_by(worker("Jim", "Halpert"), &dwight);
+        let pam = supervised_by(worker("Pam", "Beesly"), &dwight);
+        let kevin = worker("Kevin", "Malone");
+        let darryl = supervised_by(worker("Darryl", "Philbin"), &michael);
+
+        let day_shift = [&jim, &pam, &dwight, &kevin]
+            .iter()
+            .fold(Shift::new("Day".into()), |shift, worker| {
+                shift.assign_worker(worker.id.clone())
+            });
+        let office = Location::new("Office".into()).add_shift(day_shift);
+        let warehouse = Location::new("Warehouse".into())
+            .add_shift(Shift::new("Day".into()).assign_worker(darryl.id.clone()));
+        let office_id = office.id.clone();
+        let worksite = [&michael, &dwight, &jim, &pam, &kevin, &darryl]
+            .iter()
+            .fold(
+                Worksite::new("Scranton".into())
+                    .add_location(office)
+                    .add_location(warehouse),
+                |worksite, worker| worksite.add_worker((*worker).clone()),
+            );
+
+        let chart = worksite.get_org_chart(&office_id);
+
+        assert_eq!(outline(&chart), "Michael (Dwight (Jim, Pam)), Kevin");
+        let everyone_under_michael: Vec<String> = chart[0]
+            .all_reports()
+            .into_iter()
+            .map(|worker| worker.first_name)
+            .collect();
+        assert_eq!(everyone_under_michael, vec!["Dwight", "Jim", "Pam"]);
+        assert!(worksite.reports_to(&jim, &michael.id));
+        assert!(!worksite.reports_to(&michael, &jim.id));
+        assert!(!worksite.reports_to(&darryl, &dwight.id));
+    }
+
+    fn assessed(worker: Worker, value: u8) -> Worker {
+        worker.add_assessment(Assessment {
+            id: format!("assessment-{}", worker.id),
+            value,
+            notes: "".into(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            assessor: "organizer@example.com".into(),
+        })
+    }
+
+    fn related(worksite: Worksite, from: &Worker, to: &Worker, directional: bool) -> Worksite {
+        worksite.add_relationship(Relationship::new(
+            from.id.clone(),
+            to.id.clone(),
+            RelationshipKind::Friend,
+            directional,
+        ))
+    }
+
+    #[test]
+    fn influencers_are_found_up_to_three_relationships_away() {
+        let pam = worker("Pam", "Beesly");
+        let jim = worker("Jim", "Halpert");
+        let dwight = worker("Dwight", "Schrute");
+        let angela = worker("Angela", "Martin");
+        let oscar = worker("Oscar", "Martinez");
+        let mut worksite = Worksite::new("Scranton".into());
+        for worker in [&pam, &jim, &dwight, &angela, &oscar] {
+            worksite = worksite.add_worker(worker.clone());
+        }
+        let worksite = related(worksite, &pam, &jim, false);
+        let worksite = related(worksite, &jim, &dwight, false);
+        let worksite = related(worksite, &dwight, &angela, false);
+        let worksite = related(worksite, &angela, &oscar, false);
+
+        let influences: Vec<(String, usize, Vec<String>, usize)> = worksite
+            .get_influencers(&pam.id)
+            .into_iter()
+            .map(|influence| {
+                (
+                    influence.worker.first_name,
+                    influence.distance,
+                    influence
+                        .through
+                        .into_iter()
+                        .map(|w| w.first_name)
+                        .collect(),
+                    influence.score,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            influences,
+            vec![
+                ("Jim".to_string(), 1, vec![], 3),
+                ("Dwight".to_string(), 2, vec!["Jim".to_string()], 2),
+                (
+                    "Angela".to_string(),
+                    3,
+                    vec!["Dwight".to_string(), "Jim".to_string()],
+                    1
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn directional_relationships_only_sway_one_way() {
+        let michael = worker("Michael", "Scott");
+        let ryan = worker("Ryan", "Howard");
+        let worksite = Worksite::new("Scranton".into())
+            .add_worker(michael.clone())
+            .add_worker(ryan.clone());
+        let worksite = related(worksite, &michael, &ryan, true);
+
+        let influencers: Vec<String> = worksite
+            .get_influencers(&ryan.id)
+            .into_iter()
+            .map(|influence| influence.worker.id)
+            .collect();
+
+        assert_eq!(influencers, vec![michael.id.clone()]);
+        assert!(worksite.get_influencers(&michael.id).is_empty());
+    }
+
+    #[test]
+    fn influencers_in_a_cycle_are_each_listed_once_at_their_shortest_distance() {
+        let pam = worker("Pam", "Beesly");
+        let jim = worker("Jim", "Halpert");
+        let dwight = worker("Dwight", "Schrute");
+        let worksite = Worksite::new("Scranton".into())
+            .add_worker(pam.clone())
+            .add_worker(jim.clone())
+            .add_worker(dwight.clone());
+        let worksite = related(worksite, &pam, &jim, false);
+        let worksite = related(worksite, &jim, &dwight, false);
+        let worksite = related(worksite, &dwight, &pam, false);
+
+        let influences: Vec<(String, usize)> = worksite
+            .get_influencers(&pam.id)
+            .into_iter()
+            .map(|influence| (influence.worker.first_name, influence.distance))
+            .collect();
+
+        assert_eq!(
+            influences,
+            vec![("Jim".to_string(), 1), ("Dwight".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn stronger_supporters_outrank_closer_influencers() {
+        let pam = worker("Pam", "Beesly");
+        let jim = assessed(worker("Jim", "Halpert"), 5);
+        let dwight = assessed(worker("Dwight", "Schrute"), 1);
+        let worksite = Worksite::new("Scranton".into())
+            .add_worker(pam.clone())
+            .add_worker(jim.clone())
+            .add_worker(dwight.clone());
+        let worksite = related(worksite, &jim, &pam, false);
+        let worksite = related(worksite, &dwight, &jim, false);
+
+        let influences: Vec<(String, usize)> = worksite
+            .get_influencers(&pam.id)
+            .into_iter()
+            .map(|influence| (influence.worker.first_name, influence.score))
+            .collect();
+
+        assert_eq!(
+            influences,
+            vec![("Dwight".to_string(), 10), ("Jim".to_string(), 3)]
+        );
+    }
+
+    fn placement(shift: &str) -> Placement {
+        Placement {
+            location: "Office".into(),
+            shift: shift.into(),
+        }
+    }
+
+    fn roster_entry(first_name: &str, last_name: &str, email: &str, shift: &str) -> RosterEntry {
+        RosterEntry {
+            first_name: first_name.into(),
+            last_name: last_name.into(),
+            email: email.into(),
+            placements: vec![placement(shift)],
+        }
+    }
+
+    #[test]
+    fn roster_matches_people_by_email_then_by_name() {
+        let pam = worker("Pam", "Beesly");
+        let jim = worker("Jim", "Halpert");
+        let dwight = worker("Dwight", "Schrute");
+        let angela = worker("Angela", "Martin");
+        let kevin = worker("Kevin", "Malone");
+        let mut michael = worker("Michael", "Scott");
+        michael.eligibility = Eligibility::Excluded(ExclusionReason::Manager);
+        let mut toby = worker("Toby", "Flenderson");
+        toby.status = EmploymentStatus::Quit;
+
+        let day_shift = [&pam, &jim, &dwight, &angela, &kevin]
+            .iter()
+            .fold(Shift::new("Day".into()), |shift, worker| {
+                shift.assign_worker(worker.id.clone())
+            });
+        let office = Location::new("Office".into())
+            .add_shift(day_shift)
+            .add_shift(Shift::new("Night".into()));
+        let worksite = [pam, jim, dwight, angela, kevin, michael, toby]
+            .into_iter()
+            .fold(
+                Worksite::new("Scranton".into()).add_location(office),
+                |worksite, worker| worksite.add_worker(worker),
+            );
+
+        let changes = worksite.diff_roster(&[
+            roster_entry("Pam", "Beesly", "pam@example.com", "Day"),
+            roster_entry("Jim", "Halpert", "jim@example.com", "Night"),
+            // Renamed, but the email still matches
+            roster_entry("Dwight", "Schrute-Fart", " DWIGHT@example.com ", "Day"),
+            // A new email, but the same name
+            roster_entry("angela", "MARTIN", "angela.martin@example.com", "Day"),
+            roster_entry("Toby", "Flenderson", "toby@example.com", "Day"),
+            roster_entry("Erin", "Hannon", "erin@example.com", "Night"),
+        ]);
+
+        let mut described: Vec<String> = changes
+            .iter()
+            .map(|change| {
+                let kind = match change {
+                    RosterChange::Hire(_) => "hire",
+                    RosterChange::Return { .. } => "return",
+                    RosterChange::Move { .. } => "move",
+                    RosterChange::Departure(_) => "departure",
+                };
+                format!("{} {}", kind, change.full_name())
+            })
+            .collect();
+        described.sort();
+        assert_eq!(
+            described,
+            vec![
+                "departure Kevin Malone",
+                "hire Erin Hannon",
+                "move Jim Halpert",
+                "return Toby Flenderson",
+            ]
+        );
+        let moves: Vec<(Vec<Placement>, Vec<Placement>)> = changes
+            .into_iter()
+            .filter_map(|change| match change {
+                RosterChange::Move { from, to, .. } => Some((from, to)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            moves,
+            vec![(vec![placement("Day")], vec![placement("Night")])]
+        );
+    }
+
+    fn status_change(
+        previous: EmploymentStatus,
+        status: EmploymentStatus,
+        changed_on: NaiveDate,
+    ) -> StatusChange {
+        StatusChange {
+            previous,
+            status,
+            changed_on,
+            note: "".into(),
+        }
+    }
+
+    #[test]
+    fn edit_distance_counts_single_letter_changes() {
+        assert_eq!(edit_distance("pam", "pam"), 0);
+        assert_eq!(edit_distance("pam", "pan"), 1);
+        assert_eq!(edit_distance("halpert", "halbert"), 1);
+        assert_eq!(edit_distance("schrute", "shrute"), 1);
+        assert_eq!(edit_distance("kevin", "kevni"), 2);
+        assert_eq!(edit_distance("", "jim"), 3);
+    }
+
+    #[test]
+    fn short_names_allow_one_typo_and_longer_names_two() {
+        assert!(similar_names("Malone", " malone "));
+        assert!(similar_names("Scott", "Scot"));
+        assert!(!similar_names("Scott", "Sco"));
+        assert!(similar_names("Halpert", "Hilbert"));
+        assert!(!similar_names("Halpert", "Hilbart"));
+        assert!(!similar_names("", ""));
+    }
+
+    #[test]
+    fn first_names_match_when_one_is_short_for_the_other() {
+        assert!(similar_first_names("Dan", "Daniel"));
+        assert!(similar_first_names("daniel", "DAN"));
+        assert!(!similar_first_names("Dan", "Jan-Michael"));
+        assert!(!similar_first_names("", "Daniel"));
+    }
+
+    #[test]
+    fn duplicates_are_found_by_email_or_similar_name() {
+        let pam = worker("Pam", "Beesly");
+
+        assert_eq!(
+            pam.duplicate_reason(&Worker {
+                email: " PAM+union@Example.com".into(),
+                ..worker("Pamela", "Halpert")
+            }),
+            Some(DuplicateReason::SameEmail)
+        );
+        assert_eq!(
+            pam.duplicate_reason(&worker("Pamela", "Beesley")),
+            Some(DuplicateReason::SimilarName)
+        );
+        assert_eq!(pam.duplicate_reason(&worker("Jim", "Halpert")), None);
+        assert_eq!(pam.duplicate_reason(&worker("Kevin", "Beesly")), None);
+        assert_eq!(pam.duplicate_reason(&pam), None);
+    }
+
+    #[test]
+    fn merging_replays_status_changes_from_both_in_date_order() {
+        let day = |day: u32| NaiveDate::from_ymd_opt(2024, 3, day).unwrap();
+        let jim = worker("Jim", "Halpert").change_status(status_change(
+            EmploymentStatus::Active,
+            EmploymentStatus::OnLeave,
+            day(1),
+        ));
+        let james = worker("James", "Halpert")
+            .change_status(status_change(
+                EmploymentStatus::Active,
+                EmploymentStatus::Transferred,
+                day(5),
+            ))
+            .change_status(status_change(
+                EmploymentStatus::Transferred,
+                EmploymentStatus::Active,
+                day(3),
+            ));
+
+        let merged = jim.merge(&james);
+
+        assert_eq!(
+            merged
+                .status_changes
+                .iter()
+                .map(|change| change.changed_on)
+                .collect::<Vec<_>>(),
+            vec![day(1), day(3), day(5)]
+        );
+        assert_eq!(merged.status, EmploymentStatus::Transferred);
+        assert_eq!(
+            jim.merge(&worker("Jim", "Halpert")).status,
+            EmploymentStatus::OnLeave
+        );
+    }
+
+    #[test]
+    fn merging_keeps_an_exclusion_from_either_worker() {
+        let mut michael = worker("Michael", "Scott");
+        michael.eligibility = Eligibility::Excluded(ExclusionReason::Manager);
+        let mut mike = worker("Mike", "Scott");
+        mike.eligibility = Eligibility::Excluded(ExclusionReason::Supervisor);
+        let eligible = worker("Michael", "Scott");
+
+        assert_eq!(
+            eligible.merge(&michael).eligibility,
+            Eligibility::Excluded(ExclusionReason::Manager)
+        );
+        assert_eq!(
+            michael.merge(&eligible).eligibility,
+            Eligibility::Excluded(ExclusionReason::Manager)
+        );
+        assert_eq!(
+            michael.merge(&mike).eligibility,
+            Eligibility::Excluded(ExclusionReason::Manager)
+        );
+        assert_eq!(eligible.merge(&eligible).eligibility, Eligibility::Eligible);
+    }
+}
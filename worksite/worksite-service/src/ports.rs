@@ -1 +1,2 @@
+pub mod event_publisher;
 pub mod worksite_repository;
@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+use crate::{
+    compare_roster::parse_roster,
+    events::{DomainEvent, WorksiteEvent},
+    models::{Employment, EmploymentStatus, RosterChange, StatusChange, Worker},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct ReconcileRoster {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ReconcileRosterInput {
+    // Put input fields here
+    pub worksite_id: String,
+    // The same list that was compared
+    pub csv_input: String,
+    // Keys of the changes to apply, from RosterChange::key
+    pub keys: Vec<String>,
+    // The list doesn't say why someone is missing from it, so this is up to whoever applies it
+    pub departed_as: EmploymentStatus,
+    // The date of the list, used for hires, returns and departures
+    pub changed_on: NaiveDate,
+}
+
+// Change the return type, if needed
+pub type ReconcileRosterOutput = Result<(), ReconcileRosterFailure>;
+
+/**
+* Applies the picked changes from comparing an employer's list, saving them all at once so a
+* failure part way through doesn't leave the worksite half reconciled. The list is compared
+* again rather than trusting the earlier preview, and picked changes that no longer show up
+* are skipped.
+*/
+impl ReconcileRoster {
+    pub async fn reconcile_roster(&self, input: ReconcileRosterInput) -> ReconcileRosterOutput {
+        if !input.departed_as.has_left() {
+            return Err(ReconcileRosterFailure::InvalidDeparture);
+        }
+
+        let roster =
+            parse_roster(&input.csv_input).map_err(ReconcileRosterFailure::ParseFailure)?;
+
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| ReconcileRosterFailure::Unknown(e.to_string()))?
+            .ok_or(ReconcileRosterFailure::NotFound)?;
+
+        let changes: Vec<RosterChange> = worksite
+            .diff_roster(&roster)
+            .into_iter()
+            .filter(|change| input.keys.contains(&change.key()))
+            .collect();
+        if changes.is_empty() {
+            return Err(ReconcileRosterFailure::NothingSelected);
+        }
+
+        let (mut hired, mut returned, mut moved, mut departed) = (vec![], vec![], vec![], vec![]);
+        let mut updated_worksite = worksite;
+        for change in changes {
+            match change {
+                RosterChange::Hire(entry) => {
+                    let worker = Worker {
+                        employment: Employment {
+                            hire_date: Some(input.changed_on),
+                            ..Employment::default()
+                        },
+                        ..Worker::new(entry.first_name, entry.last_name, entry.email)
+                    };
+                    updated_worksite = updated_worksite
+                        .add_worker(worker.clone())
+                        .place_worker(&worker.id, &entry.placements);
+                    hired.push(worker.id);
+                }
+                RosterChange::Return { worker, placements } => {
+                    if !worker.can_change_status_on(input.changed_on) {
+                        return Err(ReconcileRosterFailure::OutOfOrder(worker.full_name()));
+                    }
+                    let change = StatusChange {
+                        previous: worker.status,
+                        status: EmploymentStatus::Active,
+                        changed_on: input.changed_on,
+                        note: "Back on the employer's list".into(),
+                    };
+                    updated_worksite = updated_worksite
+                        .update_worker(worker.id.clone(), |worker| worker.change_status(change));
+                    if !placements.is_empty() {
+                        updated_worksite = updated_worksite.place_worker(&worker.id, &placements);
+                    }
+                    returned.push(worker.id);
+                }
+                RosterChange::Move { worker, to, .. } => {
+                    updated_worksite = updated_worksite.place_worker(&worker.id, &to);
+                    moved.push(worker.id);
+                }
+                RosterChange::Departure(worker) => {
+                    if !worker.can_change_status_on(input.changed_on) {
+                        return Err(ReconcileRosterFailure::OutOfOrder(worker.full_name()));
+                    }
+                    let change = StatusChange {
+                        previous: worker.status,
+                        status: input.departed_as,
+                        changed_on: input.changed_on,
+                        note: "Missing from the employer's list".into(),
+                    };
+                    updated_worksite = updated_worksite
+                        .update_worker(worker.id.clone(), |worker| worker.change_status(change));
+                    departed.push(worker.id);
+                }
+            }
+        }
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| ReconcileRosterFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::RosterReconciled {
+                    hired,
+                    returned,
+                    moved,
+                    departed,
+                },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ReconcileRosterFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Failed to read the list: {0}")]
+    ParseFailure(String),
+    #[error("Pick at least one change to apply")]
+    NothingSelected,
+    #[error("Departures have to be recorded as terminated, quit or transferred")]
+    InvalidDeparture,
+    #[error("{0} already has a status change after the date of the list")]
+    OutOfOrder(String),
+    #[error("Something went wrong")]
+    Unknown(String),
+}
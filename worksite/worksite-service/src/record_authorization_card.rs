@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::{AuthorizationCard, Worker},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct RecordAuthorizationCard {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RecordAuthorizationCardInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub worker_id: String,
+    // None removes a card recorded by mistake
+    pub card: Option<AuthorizationCard>,
+}
+
+// Change the return type, if needed
+pub type RecordAuthorizationCardOutput = Result<(), RecordAuthorizationCardFailure>;
+
+impl RecordAuthorizationCard {
+    pub async fn record_authorization_card(
+        &self,
+        input: RecordAuthorizationCardInput,
+    ) -> RecordAuthorizationCardOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| RecordAuthorizationCardFailure::Unknown(e.to_string()))?
+            .ok_or(RecordAuthorizationCardFailure::NotFound)?;
+
+        let worker = worksite
+            .get_worker(input.worker_id.clone())
+            .ok_or(RecordAuthorizationCardFailure::WorkerNotFound)?;
+        if worker.authorization_card == input.card {
+            return Ok(());
+        }
+
+        let updated_worksite = worksite.update_worker(input.worker_id.clone(), |worker| Worker {
+            authorization_card: input.card.clone(),
+            ..worker
+        });
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| RecordAuthorizationCardFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::AuthorizationCardRecorded {
+                    worker_id: input.worker_id,
+                    previous: worker.authorization_card,
+                    card: input.card,
+                },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RecordAuthorizationCardFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Worker does not exist")]
+    WorkerNotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
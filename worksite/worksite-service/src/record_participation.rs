@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::{ParticipationStatus, WorkerId},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct RecordParticipation {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RecordParticipationInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub action_id: String,
+    // A status of None clears the worker's participation
+    pub participation: Vec<(WorkerId, Option<ParticipationStatus>)>,
+}
+
+// Change the return type, if needed
+pub type RecordParticipationOutput = Result<(), RecordParticipationFailure>;
+
+impl RecordParticipation {
+    pub async fn record_participation(
+        &self,
+        input: RecordParticipationInput,
+    ) -> RecordParticipationOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| RecordParticipationFailure::Unknown(e.to_string()))?
+            .ok_or(RecordParticipationFailure::NotFound)?;
+
+        let action = worksite
+            .get_action(input.action_id.clone())
+            .ok_or(RecordParticipationFailure::ActionNotFound)?;
+
+        // Only workers on this worksite whose status actually changed
+        let changes: Vec<(WorkerId, Option<ParticipationStatus>)> = input
+            .participation
+            .into_iter()
+            .filter(|(worker_id, status)| {
+                worksite.get_worker(worker_id.clone()).is_some()
+                    && &action.get_participation(worker_id) != status
+            })
+            .collect();
+
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let updated_worksite = worksite.update_action(input.action_id.clone(), |action| {
+            let mut updated_action = changes.iter().fold(action, |action, (worker_id, status)| {
+                action.set_participation(worker_id.clone(), *status)
+            });
+            updated_action.updated_at = Utc::now();
+            updated_action
+        });
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| RecordParticipationFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::ParticipationRecorded {
+                    action_id: input.action_id,
+                    changes,
+                },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RecordParticipationFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Action does not exist")]
+    ActionNotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
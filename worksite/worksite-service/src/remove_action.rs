@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct RemoveAction {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RemoveActionInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub action_id: String,
+}
+
+// Change the return type, if needed
+pub type RemoveActionOutput = Result<(), RemoveActionFailure>;
+
+impl RemoveAction {
+    pub async fn remove_action(&self, input: RemoveActionInput) -> RemoveActionOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| RemoveActionFailure::Unknown(e.to_string()))?
+            .ok_or(RemoveActionFailure::NotFound)?;
+
+        let action = worksite.get_action(input.action_id.clone());
+        let updated_worksite = worksite.remove_action(input.action_id);
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| RemoveActionFailure::Unknown(e.to_string()))?;
+
+        if let Some(action) = action {
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    updated_worksite.id,
+                    WorksiteEvent::ActionRemoved { action },
+                ))
+                .await;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RemoveActionFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
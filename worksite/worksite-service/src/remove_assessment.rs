@@ -2,11 +2,16 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
-use crate::{models::Worker, ports::worksite_repository::WorksiteRepository};
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::Worker,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
 
 #[derive(Clone)]
 pub struct RemoveAssessment {
     pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -29,15 +34,31 @@ impl RemoveAssessment {
             .map_err(|e| RemoveAssessmentFailure::Unknown(e.to_string()))?
             .ok_or(RemoveAssessmentFailure::NotFound)?;
 
-        let updated_worksite = worksite.update_worker(input.worker_id, |worker| -> Worker {
-            worker.remove_assessment(input.assessment_id)
-        });
+        let assessment = worksite
+            .get_assessment_for_worker(input.worker_id.clone(), input.assessment_id.clone());
+
+        let updated_worksite = worksite
+            .update_worker(input.worker_id.clone(), |worker| -> Worker {
+                worker.remove_assessment(input.assessment_id.clone())
+            });
 
         self.worksite_repository
-            .save(updated_worksite)
+            .save(updated_worksite.clone())
             .await
             .map_err(|e| RemoveAssessmentFailure::Unknown(e.to_string()))?;
 
+        if let Some(assessment) = assessment {
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    updated_worksite.id,
+                    WorksiteEvent::AssessmentRemoved {
+                        worker_id: input.worker_id,
+                        assessment,
+                    },
+                ))
+                .await;
+        }
+
         Ok(())
     }
 }
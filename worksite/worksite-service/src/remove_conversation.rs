@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::Worker,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct RemoveConversation {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RemoveConversationInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub worker_id: String,
+    pub conversation_id: String,
+}
+
+// Change the return type, if needed
+pub type RemoveConversationOutput = Result<(), RemoveConversationFailure>;
+
+impl RemoveConversation {
+    pub async fn remove_conversation(
+        &self,
+        input: RemoveConversationInput,
+    ) -> RemoveConversationOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| RemoveConversationFailure::Unknown(e.to_string()))?
+            .ok_or(RemoveConversationFailure::NotFound)?;
+
+        let conversation = worksite
+            .get_conversation_for_worker(input.worker_id.clone(), input.conversation_id.clone());
+
+        let updated_worksite = worksite
+            .update_worker(input.worker_id.clone(), |worker| -> Worker {
+                worker.remove_conversation(input.conversation_id.clone())
+            });
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| RemoveConversationFailure::Unknown(e.to_string()))?;
+
+        if let Some(conversation) = conversation {
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    updated_worksite.id,
+                    WorksiteEvent::ConversationRemoved {
+                        worker_id: input.worker_id,
+                        conversation,
+                    },
+                ))
+                .await;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RemoveConversationFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
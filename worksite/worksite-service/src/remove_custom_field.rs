@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct RemoveCustomField {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RemoveCustomFieldInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub field_id: String,
+}
+
+// Change the return type, if needed
+pub type RemoveCustomFieldOutput = Result<(), RemoveCustomFieldFailure>;
+
+impl RemoveCustomField {
+    pub async fn remove_custom_field(
+        &self,
+        input: RemoveCustomFieldInput,
+    ) -> RemoveCustomFieldOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| RemoveCustomFieldFailure::Unknown(e.to_string()))?
+            .ok_or(RemoveCustomFieldFailure::NotFound)?;
+
+        let field = worksite
+            .get_custom_field(&input.field_id)
+            .ok_or(RemoveCustomFieldFailure::FieldNotFound)?;
+        let updated_worksite = worksite.remove_custom_field(&field.id);
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| RemoveCustomFieldFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::CustomFieldRemoved { field },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RemoveCustomFieldFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Field does not exist")]
+    FieldNotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
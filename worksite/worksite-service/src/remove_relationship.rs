@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct RemoveRelationship {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RemoveRelationshipInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub relationship_id: String,
+}
+
+// Change the return type, if needed
+pub type RemoveRelationshipOutput = Result<(), RemoveRelationshipFailure>;
+
+impl RemoveRelationship {
+    pub async fn remove_relationship(
+        &self,
+        input: RemoveRelationshipInput,
+    ) -> RemoveRelationshipOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| RemoveRelationshipFailure::Unknown(e.to_string()))?
+            .ok_or(RemoveRelationshipFailure::NotFound)?;
+
+        let relationship = worksite
+            .get_relationship(&input.relationship_id)
+            .ok_or(RemoveRelationshipFailure::RelationshipNotFound)?;
+        let updated_worksite = worksite.remove_relationship(&input.relationship_id);
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| RemoveRelationshipFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::RelationshipRemoved { relationship },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum RemoveRelationshipFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Relationship does not exist")]
+    RelationshipNotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
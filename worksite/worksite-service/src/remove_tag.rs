@@ -2,11 +2,15 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
-use crate::ports::worksite_repository::WorksiteRepository;
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
 
 #[derive(Clone)]
 pub struct RemoveTag {
     pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -28,13 +32,23 @@ impl RemoveTag {
             .map_err(|e| RemoveTagFailure::Unknown(e.to_string()))?
             .ok_or(RemoveTagFailure::NotFound)?;
 
+        let tag = worksite.get_tag(input.tag_id.clone());
         let worksite = worksite.remove_tag(input.tag_id);
 
         self.worksite_repository
-            .save(worksite)
+            .save(worksite.clone())
             .await
             .map_err(|e| RemoveTagFailure::Unknown(e.to_string()))?;
 
+        if let Some(tag) = tag {
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    worksite.id,
+                    WorksiteEvent::TagRemoved { tag },
+                ))
+                .await;
+        }
+
         Ok(())
     }
 }
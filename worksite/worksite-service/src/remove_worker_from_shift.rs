@@ -2,12 +2,16 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
-use crate::models::Worksite;
-use crate::ports::worksite_repository::WorksiteRepository;
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::Worksite,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
 
 #[derive(Clone)]
 pub struct RemoveWorkerFromShift {
     pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -33,13 +37,23 @@ impl RemoveWorkerFromShift {
             .get_worker(input.worker_id)
             .ok_or(RemoveWorkerFromShiftFailure::WorkerNotFound)?;
 
-        let updated_worksite = worksite.remove_worker(input.shift_id, worker);
+        let updated_worksite = worksite.remove_worker(input.shift_id.clone(), worker.clone());
 
         self.worksite_repository
             .save(updated_worksite.clone())
             .await
             .map_err(|e| RemoveWorkerFromShiftFailure::Unknown(e.to_string()))?;
 
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id.clone(),
+                WorksiteEvent::WorkerRemovedFromShift {
+                    shift_id: input.shift_id,
+                    worker_id: worker.id,
+                },
+            ))
+            .await;
+
         Ok(updated_worksite)
     }
 }
@@ -9,6 +9,55 @@ use crate::{
     assign_tags::{AssignTags, AssignTagsInput, AssignTagsOutput},
     assign_worker::{AssignWorker, AssignWorkerInput, AssignWorkerOutput},
     //##PLOP INSERT COMMAND IMPORTS HOOK##
+    merge_workers::{MergeWorkers, MergeWorkersInput, MergeWorkersOutput},
+    get_likely_duplicates::{
+        GetLikelyDuplicates, GetLikelyDuplicatesInput, GetLikelyDuplicatesOutput,
+    },
+    reconcile_roster::{ReconcileRoster, ReconcileRosterInput, ReconcileRosterOutput},
+    compare_roster::{CompareRoster, CompareRosterInput, CompareRosterOutput},
+    get_leaver_alerts::{GetLeaverAlerts, GetLeaverAlertsInput, GetLeaverAlertsOutput},
+    get_turnover_report::{GetTurnoverReport, GetTurnoverReportInput, GetTurnoverReportOutput},
+    change_status::{ChangeStatus, ChangeStatusInput, ChangeStatusOutput},
+    get_influencers::{GetInfluencers, GetInfluencersInput, GetInfluencersOutput},
+    remove_relationship::{RemoveRelationship, RemoveRelationshipInput, RemoveRelationshipOutput},
+    add_relationship::{AddRelationship, AddRelationshipInput, AddRelationshipOutput},
+    get_org_chart::{GetOrgChart, GetOrgChartInput, GetOrgChartOutput},
+    set_supervisor::{SetSupervisor, SetSupervisorInput, SetSupervisorOutput},
+    export_workers::{ExportWorkers, ExportWorkersInput, ExportWorkersOutput},
+    get_custom_field::{GetCustomField, GetCustomFieldInput, GetCustomFieldOutput},
+    get_custom_fields::{GetCustomFields, GetCustomFieldsInput, GetCustomFieldsOutput},
+    remove_custom_field::{RemoveCustomField, RemoveCustomFieldInput, RemoveCustomFieldOutput},
+    update_custom_field::{UpdateCustomField, UpdateCustomFieldInput, UpdateCustomFieldOutput},
+    add_custom_field::{AddCustomField, AddCustomFieldInput, AddCustomFieldOutput},
+    set_eligibility::{SetEligibility, SetEligibilityInput, SetEligibilityOutput},
+    get_card_report::{GetCardReport, GetCardReportInput, GetCardReportOutput},
+    update_card_thresholds::{
+        UpdateCardThresholds, UpdateCardThresholdsInput, UpdateCardThresholdsOutput,
+    },
+    record_authorization_card::{
+        RecordAuthorizationCard, RecordAuthorizationCardInput, RecordAuthorizationCardOutput,
+    },
+    get_action_report::{GetActionReport, GetActionReportInput, GetActionReportOutput},
+    record_participation::{
+        RecordParticipation, RecordParticipationInput, RecordParticipationOutput,
+    },
+    get_action::{GetAction, GetActionInput, GetActionOutput},
+    get_actions::{GetActions, GetActionsInput, GetActionsOutput},
+    remove_action::{RemoveAction, RemoveActionInput, RemoveActionOutput},
+    update_action::{UpdateAction, UpdateActionInput, UpdateActionOutput},
+    add_action::{AddAction, AddActionInput, AddActionOutput},
+    get_committee_report::{GetCommitteeReport, GetCommitteeReportInput, GetCommitteeReportOutput},
+    assign_leader::{AssignLeader, AssignLeaderInput, AssignLeaderOutput},
+    set_committee_member::{SetCommitteeMember, SetCommitteeMemberInput, SetCommitteeMemberOutput},
+    get_turf_coverage::{GetTurfCoverage, GetTurfCoverageInput, GetTurfCoverageOutput},
+    get_turf_assignments::{GetTurfAssignments, GetTurfAssignmentsInput, GetTurfAssignmentsOutput},
+    unassign_turf::{UnassignTurf, UnassignTurfInput, UnassignTurfOutput},
+    assign_turf::{AssignTurf, AssignTurfInput, AssignTurfOutput},
+    remove_conversation::{RemoveConversation, RemoveConversationInput, RemoveConversationOutput},
+    update_conversation::{UpdateConversation, UpdateConversationInput, UpdateConversationOutput},
+    get_conversation::{GetConversation, GetConversationInput, GetConversationOutput},
+    get_conversations::{GetConversations, GetConversationsInput, GetConversationsOutput},
+    add_conversation::{AddConversation, AddConversationInput, AddConversationOutput},
     update_worksite::{
       UpdateWorksite, UpdateWorksiteInput, UpdateWorksiteOutput, 
     },
@@ -26,7 +75,7 @@ use crate::{
     get_worksite::{GetWorksite, GetWorksiteFailure, GetWorksiteInput},
     get_worksites::{GetWorksites, GetWorksitesOutput},
     models::Worksite,
-    ports::worksite_repository::WorksiteRepository,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
     remove_assessment::{RemoveAssessment, RemoveAssessmentInput, RemoveAssessmentOutput},
     remove_tag::{RemoveTag, RemoveTagInput, RemoveTagOutput},
     remove_worker_from_shift::{
@@ -40,6 +89,47 @@ use crate::{
 #[derive(Clone)]
 pub struct WorksiteService {
     //##PLOP INSERT COMMAND HOOK##
+    pub merge_workers: MergeWorkers,
+    pub get_likely_duplicates: GetLikelyDuplicates,
+    pub reconcile_roster: ReconcileRoster,
+    pub compare_roster: CompareRoster,
+    pub get_leaver_alerts: GetLeaverAlerts,
+    pub get_turnover_report: GetTurnoverReport,
+    pub change_status: ChangeStatus,
+    pub get_influencers: GetInfluencers,
+    pub remove_relationship: RemoveRelationship,
+    pub add_relationship: AddRelationship,
+    pub get_org_chart: GetOrgChart,
+    pub set_supervisor: SetSupervisor,
+    pub export_workers: ExportWorkers,
+    pub get_custom_field: GetCustomField,
+    pub get_custom_fields: GetCustomFields,
+    pub remove_custom_field: RemoveCustomField,
+    pub update_custom_field: UpdateCustomField,
+    pub add_custom_field: AddCustomField,
+    pub set_eligibility: SetEligibility,
+    pub get_card_report: GetCardReport,
+    pub update_card_thresholds: UpdateCardThresholds,
+    pub record_authorization_card: RecordAuthorizationCard,
+    pub get_action_report: GetActionReport,
+    pub record_participation: RecordParticipation,
+    pub get_action: GetAction,
+    pub get_actions: GetActions,
+    pub remove_action: RemoveAction,
+    pub update_action: UpdateAction,
+    pub add_action: AddAction,
+    pub get_committee_report: GetCommitteeReport,
+    pub assign_leader: AssignLeader,
+    pub set_committee_member: SetCommitteeMember,
+    pub get_turf_coverage: GetTurfCoverage,
+    pub get_turf_assignments: GetTurfAssignments,
+    pub unassign_turf: UnassignTurf,
+    pub assign_turf: AssignTurf,
+    pub remove_conversation: RemoveConversation,
+    pub update_conversation: UpdateConversation,
+    pub get_conversation: GetConversation,
+    pub get_conversations: GetConversations,
+    pub add_conversation: AddConversation,
     pub update_worksite: UpdateWorksite,
     pub create_worksite: CreateWorksite,
     pub csv_upload: CsvUpload,
@@ -68,24 +158,218 @@ pub struct WorksiteService {
 }
 
 impl WorksiteService {
-    pub fn new(worksite_repository: Arc<dyn WorksiteRepository>) -> Self {
+    pub fn new(
+        worksite_repository: Arc<dyn WorksiteRepository>,
+        event_publisher: Arc<dyn EventPublisher>,
+    ) -> Self {
         Self {
             //##PLOP INSERT COMMAND INSTANTIATION HOOK##
+            merge_workers: MergeWorkers {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            get_likely_duplicates: GetLikelyDuplicates {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            reconcile_roster: ReconcileRoster {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            compare_roster: CompareRoster {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            get_leaver_alerts: GetLeaverAlerts {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            get_turnover_report: GetTurnoverReport {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            change_status: ChangeStatus {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            get_influencers: GetInfluencers {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            remove_relationship: RemoveRelationship {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            add_relationship: AddRelationship {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            get_org_chart: GetOrgChart {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            set_supervisor: SetSupervisor {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            export_workers: ExportWorkers {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            get_custom_field: GetCustomField {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            get_custom_fields: GetCustomFields {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            remove_custom_field: RemoveCustomField {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            update_custom_field: UpdateCustomField {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            add_custom_field: AddCustomField {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            set_eligibility: SetEligibility {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            get_card_report: GetCardReport {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            update_card_thresholds: UpdateCardThresholds {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            record_authorization_card: RecordAuthorizationCard {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            get_action_report: GetActionReport {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            record_participation: RecordParticipation {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            get_action: GetAction {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            get_actions: GetActions {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            remove_action: RemoveAction {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            update_action: UpdateAction {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            add_action: AddAction {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            get_committee_report: GetCommitteeReport {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            assign_leader: AssignLeader {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            set_committee_member: SetCommitteeMember {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            get_turf_coverage: GetTurfCoverage {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            get_turf_assignments: GetTurfAssignments {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            unassign_turf: UnassignTurf {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            assign_turf: AssignTurf {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            remove_conversation: RemoveConversation {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            update_conversation: UpdateConversation {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
+            get_conversation: GetConversation {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            get_conversations: GetConversations {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+            },
+            add_conversation: AddConversation {
+                // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
+                worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
+            },
             update_worksite: UpdateWorksite {
               // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
               worksite_repository: worksite_repository.clone(),
+              event_publisher: event_publisher.clone(),
             },
             create_worksite: CreateWorksite {
               // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
               worksite_repository: worksite_repository.clone(),
+              event_publisher: event_publisher.clone(),
             },
             csv_upload: CsvUpload {
                 // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
                 worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
             },
             remove_assessment: RemoveAssessment {
                 // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
                 worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
             },
             get_assessment: GetAssessment {
                 // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
@@ -94,10 +378,12 @@ impl WorksiteService {
             update_assessment: UpdateAssessment {
                 // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
                 worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
             },
             add_assessment: AddAssessment {
                 // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
                 worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
             },
             get_assessments: GetAssessments {
                 // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
@@ -106,6 +392,7 @@ impl WorksiteService {
             remove_tag: RemoveTag {
                 // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
                 worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
             },
             get_tag: GetTag {
                 // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
@@ -114,14 +401,17 @@ impl WorksiteService {
             update_tag: UpdateTag {
                 // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
                 worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
             },
             add_tag: AddTag {
                 // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
                 worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
             },
             assign_tags: AssignTags {
                 // Add any dependencies for the command here. They should be passed into this function and supplied by main.rs.
                 worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
             },
             filter_workers: FilterWorkers {
                 worksite_repository: worksite_repository.clone(),
@@ -131,23 +421,27 @@ impl WorksiteService {
             },
             add_worker: AddWorker {
                 worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
             },
             get_workers: GetWorkers {
                 worksite_repository: worksite_repository.clone(),
             },
             add_shift: AddShift {
                 worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
             },
             add_location: AddLocation {
                 worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
             },
             update_worker: UpdateWorker {
                 worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
             },
             get_worker: GetWorker {
                 worksite_repository: worksite_repository.clone(),
             },
-            assign_worker: AssignWorker::new(worksite_repository.clone()),
+            assign_worker: AssignWorker::new(worksite_repository.clone(), event_publisher.clone()),
             get_worksite: GetWorksite {
                 worksite_repository: worksite_repository.clone(),
             },
@@ -156,10 +450,220 @@ impl WorksiteService {
             },
             remove_worker_from_shift: RemoveWorkerFromShift {
                 worksite_repository: worksite_repository.clone(),
+                event_publisher: event_publisher.clone(),
             },
         }
     }
     //##PLOP INSERT DELEGATE HOOK##
+    pub async fn merge_workers(&self, input: MergeWorkersInput) -> MergeWorkersOutput {
+        self.merge_workers.merge_workers(input).await
+    }
+
+    pub async fn get_likely_duplicates(
+        &self,
+        input: GetLikelyDuplicatesInput,
+    ) -> GetLikelyDuplicatesOutput {
+        self.get_likely_duplicates
+            .get_likely_duplicates(input)
+            .await
+    }
+
+    pub async fn reconcile_roster(&self, input: ReconcileRosterInput) -> ReconcileRosterOutput {
+        self.reconcile_roster.reconcile_roster(input).await
+    }
+
+    pub async fn compare_roster(&self, input: CompareRosterInput) -> CompareRosterOutput {
+        self.compare_roster.compare_roster(input).await
+    }
+
+    pub async fn get_leaver_alerts(&self, input: GetLeaverAlertsInput) -> GetLeaverAlertsOutput {
+        self.get_leaver_alerts.get_leaver_alerts(input).await
+    }
+
+    pub async fn get_turnover_report(
+        &self,
+        input: GetTurnoverReportInput,
+    ) -> GetTurnoverReportOutput {
+        self.get_turnover_report.get_turnover_report(input).await
+    }
+
+    pub async fn change_status(&self, input: ChangeStatusInput) -> ChangeStatusOutput {
+        self.change_status.change_status(input).await
+    }
+
+    pub async fn get_influencers(&self, input: GetInfluencersInput) -> GetInfluencersOutput {
+        self.get_influencers.get_influencers(input).await
+    }
+
+    pub async fn remove_relationship(
+        &self,
+        input: RemoveRelationshipInput,
+    ) -> RemoveRelationshipOutput {
+        self.remove_relationship.remove_relationship(input).await
+    }
+
+    pub async fn add_relationship(&self, input: AddRelationshipInput) -> AddRelationshipOutput {
+        self.add_relationship.add_relationship(input).await
+    }
+
+    pub async fn get_org_chart(&self, input: GetOrgChartInput) -> GetOrgChartOutput {
+        self.get_org_chart.get_org_chart(input).await
+    }
+
+    pub async fn set_supervisor(&self, input: SetSupervisorInput) -> SetSupervisorOutput {
+        self.set_supervisor.set_supervisor(input).await
+    }
+
+    pub async fn export_workers(&self, input: ExportWorkersInput) -> ExportWorkersOutput {
+        self.export_workers.export_workers(input).await
+    }
+
+    pub async fn get_custom_field(&self, input: GetCustomFieldInput) -> GetCustomFieldOutput {
+        self.get_custom_field.get_custom_field(input).await
+    }
+
+    pub async fn get_custom_fields(&self, input: GetCustomFieldsInput) -> GetCustomFieldsOutput {
+        self.get_custom_fields.get_custom_fields(input).await
+    }
+
+    pub async fn remove_custom_field(
+        &self,
+        input: RemoveCustomFieldInput,
+    ) -> RemoveCustomFieldOutput {
+        self.remove_custom_field.remove_custom_field(input).await
+    }
+
+    pub async fn update_custom_field(
+        &self,
+        input: UpdateCustomFieldInput,
+    ) -> UpdateCustomFieldOutput {
+        self.update_custom_field.update_custom_field(input).await
+    }
+
+    pub async fn add_custom_field(&self, input: AddCustomFieldInput) -> AddCustomFieldOutput {
+        self.add_custom_field.add_custom_field(input).await
+    }
+
+    pub async fn set_eligibility(&self, input: SetEligibilityInput) -> SetEligibilityOutput {
+        self.set_eligibility.set_eligibility(input).await
+    }
+
+    pub async fn get_card_report(&self, input: GetCardReportInput) -> GetCardReportOutput {
+        self.get_card_report.get_card_report(input).await
+    }
+
+    pub async fn update_card_thresholds(
+        &self,
+        input: UpdateCardThresholdsInput,
+    ) -> UpdateCardThresholdsOutput {
+        self.update_card_thresholds
+            .update_card_thresholds(input)
+            .await
+    }
+
+    pub async fn record_authorization_card(
+        &self,
+        input: RecordAuthorizationCardInput,
+    ) -> RecordAuthorizationCardOutput {
+        self.record_authorization_card
+            .record_authorization_card(input)
+            .await
+    }
+
+    pub async fn get_action_report(&self, input: GetActionReportInput) -> GetActionReportOutput {
+        self.get_action_report.get_action_report(input).await
+    }
+
+    pub async fn record_participation(
+        &self,
+        input: RecordParticipationInput,
+    ) -> RecordParticipationOutput {
+        self.record_participation.record_participation(input).await
+    }
+
+    pub async fn get_action(&self, input: GetActionInput) -> GetActionOutput {
+        self.get_action.get_action(input).await
+    }
+
+    pub async fn get_actions(&self, input: GetActionsInput) -> GetActionsOutput {
+        self.get_actions.get_actions(input).await
+    }
+
+    pub async fn remove_action(&self, input: RemoveActionInput) -> RemoveActionOutput {
+        self.remove_action.remove_action(input).await
+    }
+
+    pub async fn update_action(&self, input: UpdateActionInput) -> UpdateActionOutput {
+        self.update_action.update_action(input).await
+    }
+
+    pub async fn add_action(&self, input: AddActionInput) -> AddActionOutput {
+        self.add_action.add_action(input).await
+    }
+
+    pub async fn get_committee_report(
+        &self,
+        input: GetCommitteeReportInput,
+    ) -> GetCommitteeReportOutput {
+        self.get_committee_report.get_committee_report(input).await
+    }
+
+    pub async fn assign_leader(&self, input: AssignLeaderInput) -> AssignLeaderOutput {
+        self.assign_leader.assign_leader(input).await
+    }
+
+    pub async fn set_committee_member(
+        &self,
+        input: SetCommitteeMemberInput,
+    ) -> SetCommitteeMemberOutput {
+        self.set_committee_member.set_committee_member(input).await
+    }
+
+    pub async fn get_turf_coverage(&self, input: GetTurfCoverageInput) -> GetTurfCoverageOutput {
+        self.get_turf_coverage.get_turf_coverage(input).await
+    }
+
+    pub async fn get_turf_assignments(
+        &self,
+        input: GetTurfAssignmentsInput,
+    ) -> GetTurfAssignmentsOutput {
+        self.get_turf_assignments.get_turf_assignments(input).await
+    }
+
+    pub async fn unassign_turf(&self, input: UnassignTurfInput) -> UnassignTurfOutput {
+        self.unassign_turf.unassign_turf(input).await
+    }
+
+    pub async fn assign_turf(&self, input: AssignTurfInput) -> AssignTurfOutput {
+        self.assign_turf.assign_turf(input).await
+    }
+
+    pub async fn remove_conversation(
+        &self,
+        input: RemoveConversationInput,
+    ) -> RemoveConversationOutput {
+        self.remove_conversation.remove_conversation(input).await
+    }
+
+    pub async fn update_conversation(
+        &self,
+        input: UpdateConversationInput,
+    ) -> UpdateConversationOutput {
+        self.update_conversation.update_conversation(input).await
+    }
+
+    pub async fn get_conversation(&self, input: GetConversationInput) -> GetConversationOutput {
+        self.get_conversation.get_conversation(input).await
+    }
+
+    pub async fn get_conversations(&self, input: GetConversationsInput) -> GetConversationsOutput {
+        self.get_conversations.get_conversations(input).await
+    }
+
+    pub async fn add_conversation(&self, input: AddConversationInput) -> AddConversationOutput {
+        self.add_conversation.add_conversation(input).await
+    }
+
     pub async fn update_worksite(
         &self,
         input: UpdateWorksiteInput,
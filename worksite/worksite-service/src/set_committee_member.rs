@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct SetCommitteeMember {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SetCommitteeMemberInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub worker_id: String,
+    pub committee_member: bool,
+}
+
+// Change the return type, if needed
+pub type SetCommitteeMemberOutput = Result<(), SetCommitteeMemberFailure>;
+
+impl SetCommitteeMember {
+    pub async fn set_committee_member(
+        &self,
+        input: SetCommitteeMemberInput,
+    ) -> SetCommitteeMemberOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| SetCommitteeMemberFailure::Unknown(e.to_string()))?
+            .ok_or(SetCommitteeMemberFailure::NotFound)?;
+
+        let changed = worksite
+            .get_worker(input.worker_id.clone())
+            .is_some_and(|worker| worker.committee_member != input.committee_member);
+        if !changed {
+            return Ok(());
+        }
+
+        let updated_worksite =
+            worksite.set_committee_member(input.worker_id.clone(), input.committee_member);
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| SetCommitteeMemberFailure::Unknown(e.to_string()))?;
+
+        let event = if input.committee_member {
+            WorksiteEvent::CommitteeMemberAdded {
+                worker_id: input.worker_id,
+            }
+        } else {
+            WorksiteEvent::CommitteeMemberRemoved {
+                worker_id: input.worker_id,
+            }
+        };
+        self.event_publisher
+            .publish(DomainEvent::new(updated_worksite.id, event))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SetCommitteeMemberFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
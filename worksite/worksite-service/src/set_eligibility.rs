@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::{Eligibility, Worker},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct SetEligibility {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SetEligibilityInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub worker_id: String,
+    pub eligibility: Eligibility,
+}
+
+// Change the return type, if needed
+pub type SetEligibilityOutput = Result<(), SetEligibilityFailure>;
+
+impl SetEligibility {
+    pub async fn set_eligibility(&self, input: SetEligibilityInput) -> SetEligibilityOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| SetEligibilityFailure::Unknown(e.to_string()))?
+            .ok_or(SetEligibilityFailure::NotFound)?;
+
+        let worker = worksite
+            .get_worker(input.worker_id.clone())
+            .ok_or(SetEligibilityFailure::WorkerNotFound)?;
+        if worker.eligibility == input.eligibility {
+            return Ok(());
+        }
+
+        let updated_worksite = worksite.update_worker(input.worker_id.clone(), |worker| Worker {
+            eligibility: input.eligibility,
+            ..worker
+        });
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| SetEligibilityFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::EligibilityChanged {
+                    worker_id: input.worker_id,
+                    previous: worker.eligibility,
+                    eligibility: input.eligibility,
+                },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SetEligibilityFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Worker does not exist")]
+    WorkerNotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
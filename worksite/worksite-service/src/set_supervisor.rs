@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::Worker,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct SetSupervisor {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SetSupervisorInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub worker_id: String,
+    // None leaves the worker without a supervisor
+    pub supervisor_id: Option<String>,
+}
+
+// Change the return type, if needed
+pub type SetSupervisorOutput = Result<(), SetSupervisorFailure>;
+
+impl SetSupervisor {
+    pub async fn set_supervisor(&self, input: SetSupervisorInput) -> SetSupervisorOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| SetSupervisorFailure::Unknown(e.to_string()))?
+            .ok_or(SetSupervisorFailure::NotFound)?;
+
+        let worker = worksite
+            .get_worker(input.worker_id.clone())
+            .ok_or(SetSupervisorFailure::WorkerNotFound)?;
+        if let Some(supervisor_id) = &input.supervisor_id {
+            let supervisor = worksite
+                .get_worker(supervisor_id.clone())
+                .ok_or(SetSupervisorFailure::SupervisorNotFound)?;
+            if supervisor.id == worker.id || worksite.reports_to(&supervisor, &worker.id) {
+                return Err(SetSupervisorFailure::CircularChain);
+            }
+        }
+        if worker.supervisor_id == input.supervisor_id {
+            return Ok(());
+        }
+
+        let updated_worksite = worksite.update_worker(input.worker_id.clone(), |worker| Worker {
+            supervisor_id: input.supervisor_id.clone(),
+            ..worker
+        });
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| SetSupervisorFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::SupervisorChanged {
+                    worker_id: input.worker_id,
+                    previous_supervisor_id: worker.supervisor_id,
+                    supervisor_id: input.supervisor_id,
+                },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SetSupervisorFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Worker does not exist")]
+    WorkerNotFound,
+    #[error("Supervisor does not exist")]
+    SupervisorNotFound,
+    #[error("A worker can't be supervised by someone who reports to them")]
+    CircularChain,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
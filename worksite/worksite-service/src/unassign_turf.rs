@@ -0,0 +1,62 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct UnassignTurf {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UnassignTurfInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub assignment_id: String,
+}
+
+// Change the return type, if needed
+pub type UnassignTurfOutput = Result<(), UnassignTurfFailure>;
+
+impl UnassignTurf {
+    pub async fn unassign_turf(&self, input: UnassignTurfInput) -> UnassignTurfOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| UnassignTurfFailure::Unknown(e.to_string()))?
+            .ok_or(UnassignTurfFailure::NotFound)?;
+
+        let assignment = worksite.get_turf_assignment(input.assignment_id.clone());
+        let updated_worksite = worksite.unassign_turf(input.assignment_id);
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| UnassignTurfFailure::Unknown(e.to_string()))?;
+
+        if let Some(assignment) = assignment {
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    updated_worksite.id,
+                    WorksiteEvent::TurfUnassigned { assignment },
+                ))
+                .await;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum UnassignTurfFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
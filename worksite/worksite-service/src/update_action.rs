@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, Utc};
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::Action,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct UpdateAction {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateActionInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub action_id: String,
+    pub held_on: NaiveDate,
+    pub description: String,
+}
+
+// Change the return type, if needed
+pub type UpdateActionOutput = Result<(), UpdateActionFailure>;
+
+impl UpdateAction {
+    pub async fn update_action(&self, input: UpdateActionInput) -> UpdateActionOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| UpdateActionFailure::Unknown(e.to_string()))?
+            .ok_or(UpdateActionFailure::NotFound)?;
+
+        let previous = worksite
+            .get_action(input.action_id.clone())
+            .ok_or(UpdateActionFailure::ActionNotFound)?;
+
+        let updated_worksite = worksite.update_action(input.action_id.clone(), |action| Action {
+            held_on: input.held_on,
+            description: input.description,
+            updated_at: Utc::now(),
+            ..action
+        });
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| UpdateActionFailure::Unknown(e.to_string()))?;
+
+        if let Some(action) = updated_worksite.get_action(input.action_id) {
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    updated_worksite.id,
+                    WorksiteEvent::ActionUpdated { previous, action },
+                ))
+                .await;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum UpdateActionFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Action does not exist")]
+    ActionNotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -4,13 +4,15 @@ use chrono::Utc;
 use thiserror::Error;
 
 use crate::{
+    events::{DomainEvent, WorksiteEvent},
     models::{Assessment, Worker},
-    ports::worksite_repository::WorksiteRepository,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
 };
 
 #[derive(Clone)]
 pub struct UpdateAssessment {
     pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -36,24 +38,44 @@ impl UpdateAssessment {
             .map_err(|e| UpdateAssessmentFailure::Unknown(e.to_string()))?
             .ok_or(UpdateAssessmentFailure::NotFound)?;
 
-        let updated_worksite = worksite.update_worker(input.worker_id, |worker| -> Worker {
-            worker.update_assessment(input.assessment_id, |assessment| -> Assessment {
-                Assessment {
-                    id: assessment.id,
-                    value: input.value,
-                    notes: input.notes,
-                    created_at: assessment.created_at,
-                    updated_at: Utc::now(),
-                    assessor: input.assessor,
-                }
-            })
-        });
+        let previous = worksite
+            .get_assessment_for_worker(input.worker_id.clone(), input.assessment_id.clone());
+
+        let updated_worksite =
+            worksite.update_worker(input.worker_id.clone(), |worker| -> Worker {
+                worker.update_assessment(input.assessment_id.clone(), |assessment| -> Assessment {
+                    Assessment {
+                        id: assessment.id,
+                        value: input.value,
+                        notes: input.notes,
+                        created_at: assessment.created_at,
+                        updated_at: Utc::now(),
+                        assessor: input.assessor,
+                    }
+                })
+            });
 
         self.worksite_repository
-            .save(updated_worksite)
+            .save(updated_worksite.clone())
             .await
             .map_err(|e| UpdateAssessmentFailure::Unknown(e.to_string()))?;
 
+        let assessment = updated_worksite
+            .get_assessment_for_worker(input.worker_id.clone(), input.assessment_id.clone());
+
+        if let (Some(previous), Some(assessment)) = (previous, assessment) {
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    updated_worksite.id,
+                    WorksiteEvent::AssessmentUpdated {
+                        worker_id: input.worker_id,
+                        previous,
+                        assessment,
+                    },
+                ))
+                .await;
+        }
+
         Ok(())
     }
 }
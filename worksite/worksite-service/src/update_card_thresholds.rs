@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::{CardThreshold, Worksite},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct UpdateCardThresholds {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateCardThresholdsInput {
+    // Put input fields here
+    pub worksite_id: String,
+    // Replaces every threshold the worksite had
+    pub thresholds: Vec<CardThreshold>,
+}
+
+// Change the return type, if needed
+pub type UpdateCardThresholdsOutput = Result<(), UpdateCardThresholdsFailure>;
+
+impl UpdateCardThresholds {
+    pub async fn update_card_thresholds(
+        &self,
+        input: UpdateCardThresholdsInput,
+    ) -> UpdateCardThresholdsOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| UpdateCardThresholdsFailure::Unknown(e.to_string()))?
+            .ok_or(UpdateCardThresholdsFailure::NotFound)?;
+
+        if input
+            .thresholds
+            .iter()
+            .any(|t| t.percent == 0 || t.percent > 100)
+        {
+            return Err(UpdateCardThresholdsFailure::InvalidPercent);
+        }
+        if input.thresholds.iter().any(|t| t.name.trim().is_empty()) {
+            return Err(UpdateCardThresholdsFailure::MissingName);
+        }
+
+        let mut thresholds = input.thresholds;
+        thresholds.sort_by_key(|t| t.percent);
+        if worksite.card_thresholds == thresholds {
+            return Ok(());
+        }
+
+        let previous = worksite.card_thresholds.clone();
+        let updated_worksite = Worksite {
+            card_thresholds: thresholds.clone(),
+            ..worksite
+        };
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| UpdateCardThresholdsFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::CardThresholdsUpdated {
+                    previous,
+                    thresholds,
+                },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum UpdateCardThresholdsFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Thresholds must be between 1% and 100%")]
+    InvalidPercent,
+    #[error("Every threshold needs a name")]
+    MissingName,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
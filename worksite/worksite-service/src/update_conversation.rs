@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, Utc};
+use thiserror::Error;
+
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::{Conversation, ConversationChannel, Worker},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct UpdateConversation {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateConversationInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub worker_id: String,
+    pub conversation_id: String,
+    pub held_on: NaiveDate,
+    pub organizer: String,
+    pub channel: ConversationChannel,
+    pub issues: Vec<String>,
+    pub notes: String,
+    pub next_step: String,
+}
+
+// Change the return type, if needed
+pub type UpdateConversationOutput = Result<(), UpdateConversationFailure>;
+
+impl UpdateConversation {
+    pub async fn update_conversation(
+        &self,
+        input: UpdateConversationInput,
+    ) -> UpdateConversationOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| UpdateConversationFailure::Unknown(e.to_string()))?
+            .ok_or(UpdateConversationFailure::NotFound)?;
+
+        let previous = worksite
+            .get_conversation_for_worker(input.worker_id.clone(), input.conversation_id.clone());
+
+        let updated_worksite =
+            worksite.update_worker(input.worker_id.clone(), |worker| -> Worker {
+                worker.update_conversation(
+                    input.conversation_id.clone(),
+                    |conversation| -> Conversation {
+                        Conversation {
+                            id: conversation.id,
+                            held_on: input.held_on,
+                            organizer: input.organizer,
+                            channel: input.channel,
+                            issues: input.issues,
+                            notes: input.notes,
+                            next_step: input.next_step,
+                            created_at: conversation.created_at,
+                            updated_at: Utc::now(),
+                        }
+                    },
+                )
+            });
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| UpdateConversationFailure::Unknown(e.to_string()))?;
+
+        let conversation = updated_worksite
+            .get_conversation_for_worker(input.worker_id.clone(), input.conversation_id.clone());
+
+        if let (Some(previous), Some(conversation)) = (previous, conversation) {
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    updated_worksite.id,
+                    WorksiteEvent::ConversationUpdated {
+                        worker_id: input.worker_id,
+                        previous,
+                        conversation,
+                    },
+                ))
+                .await;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum UpdateConversationFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::{
+    add_custom_field::clean_options,
+    events::{DomainEvent, WorksiteEvent},
+    models::{CustomField, CustomFieldValue},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
+
+#[derive(Clone)]
+pub struct UpdateCustomField {
+    pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+}
+
+#[derive(Clone, Debug)]
+pub struct UpdateCustomFieldInput {
+    // Put input fields here
+    pub worksite_id: String,
+    pub field_id: String,
+    pub name: String,
+    pub options: Vec<String>,
+}
+
+// Change the return type, if needed
+pub type UpdateCustomFieldOutput = Result<(), UpdateCustomFieldFailure>;
+
+/**
+* Renames a field or changes its options. The type can't change, since the values workers already
+* have wouldn't fit. Values for options that were taken away are cleared.
+*/
+impl UpdateCustomField {
+    pub async fn update_custom_field(
+        &self,
+        input: UpdateCustomFieldInput,
+    ) -> UpdateCustomFieldOutput {
+        let worksite = self
+            .worksite_repository
+            .get_worksite(input.worksite_id)
+            .await
+            .map_err(|e| UpdateCustomFieldFailure::Unknown(e.to_string()))?
+            .ok_or(UpdateCustomFieldFailure::NotFound)?;
+
+        let previous = worksite
+            .get_custom_field(&input.field_id)
+            .ok_or(UpdateCustomFieldFailure::FieldNotFound)?;
+
+        let name = input.name.trim().to_string();
+        if name.is_empty() {
+            return Err(UpdateCustomFieldFailure::MissingName);
+        }
+        if worksite
+            .custom_fields
+            .iter()
+            .any(|f| f.id != previous.id && f.name.eq_ignore_ascii_case(&name))
+        {
+            return Err(UpdateCustomFieldFailure::DuplicateName);
+        }
+
+        let options = match previous.field_type.has_options() {
+            true => clean_options(input.options),
+            false => vec![],
+        };
+        if previous.field_type.has_options() && options.is_empty() {
+            return Err(UpdateCustomFieldFailure::MissingOptions);
+        }
+
+        let field = CustomField {
+            name,
+            options,
+            ..previous.clone()
+        };
+        if field == previous {
+            return Ok(());
+        }
+
+        let mut updated_worksite = worksite.update_custom_field(&field.id, |_| field.clone());
+        updated_worksite.workers.iter_mut().for_each(|worker| {
+            worker.custom_values = worker
+                .custom_values
+                .clone()
+                .into_iter()
+                .filter_map(|mut custom_value| {
+                    if custom_value.field_id != field.id {
+                        return Some(custom_value);
+                    }
+                    if let CustomFieldValue::MultiSelect(chosen) = &mut custom_value.value {
+                        chosen.retain(|option| field.options.contains(option));
+                        if chosen.is_empty() {
+                            return None;
+                        }
+                    }
+                    field.accepts(&custom_value.value).then_some(custom_value)
+                })
+                .collect();
+        });
+
+        self.worksite_repository
+            .save(updated_worksite.clone())
+            .await
+            .map_err(|e| UpdateCustomFieldFailure::Unknown(e.to_string()))?;
+
+        self.event_publisher
+            .publish(DomainEvent::new(
+                updated_worksite.id,
+                WorksiteEvent::CustomFieldUpdated { previous, field },
+            ))
+            .await;
+
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum UpdateCustomFieldFailure {
+    #[error("Worksite does not exist")]
+    NotFound,
+    #[error("Field does not exist")]
+    FieldNotFound,
+    #[error("Field name can not be empty")]
+    MissingName,
+    #[error("A field with that name already exists")]
+    DuplicateName,
+    #[error("Select fields need at least one option")]
+    MissingOptions,
+    #[error("Something went wrong")]
+    Unknown(String),
+}
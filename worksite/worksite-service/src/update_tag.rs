@@ -2,11 +2,16 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
-use crate::{models::Tag, ports::worksite_repository::WorksiteRepository};
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::Tag,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
 
 #[derive(Clone)]
 pub struct UpdateTag {
     pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -30,7 +35,8 @@ impl UpdateTag {
             .map_err(|e| UpdateTagFailure::Unknown(e.to_string()))?
             .ok_or(UpdateTagFailure::NotFound)?;
 
-        let worksite = worksite.update_tag(input.tag_id, |tag| -> Tag {
+        let previous = worksite.get_tag(input.tag_id.clone());
+        let worksite = worksite.update_tag(input.tag_id.clone(), |tag| -> Tag {
             Tag {
                 id: tag.id,
                 name: input.name,
@@ -39,10 +45,21 @@ impl UpdateTag {
         });
 
         self.worksite_repository
-            .save(worksite)
+            .save(worksite.clone())
             .await
             .map_err(|e| UpdateTagFailure::Unknown(e.to_string()))?;
 
+        let tag = worksite.get_tag(input.tag_id);
+
+        if let (Some(previous), Some(tag)) = (previous, tag) {
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    worksite.id,
+                    WorksiteEvent::TagUpdated { previous, tag },
+                ))
+                .await;
+        }
+
         Ok(())
     }
 }
@@ -1,6 +1,7 @@
 use crate::{
-    models::{Address, Worker},
-    ports::worksite_repository::WorksiteRepository,
+    events::{DomainEvent, WorksiteEvent},
+    models::{Address, CustomValue, Employment, Worker},
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
 };
 
 use std::sync::Arc;
@@ -9,6 +10,7 @@ use thiserror::Error;
 #[derive(Clone)]
 pub struct UpdateWorker {
     pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -18,18 +20,19 @@ pub struct UpdateWorkerInput {
     pub first_name: String,
     pub last_name: String,
     pub email: String,
+    pub phone: String,
     pub street_address: String,
     pub city: String,
     pub region: String,
     pub postal_code: String,
+    pub employment: Employment,
+    pub custom_values: Vec<CustomValue>,
 }
 
 pub type UpdateWorkerOutput = Result<(), UpdateWorkerFailure>;
 
 impl UpdateWorker {
     pub async fn update_worker(&self, input: UpdateWorkerInput) -> UpdateWorkerOutput {
-        let _worksite_id = input.worksite_id.clone();
-
         let worksite = self
             .worksite_repository
             .get_worksite(input.worksite_id)
@@ -37,26 +40,49 @@ impl UpdateWorker {
             .map_err(|e| UpdateWorkerFailure::Unknown(e.to_string()))?
             .ok_or(UpdateWorkerFailure::NotFound)?;
 
-        let updated_worksite = worksite.update_worker(input.worker_id, |worker| -> Worker {
-            Worker {
-                first_name: input.first_name,
-                last_name: input.last_name,
-                email: input.email,
-                address: Some(Address {
-                    street_address: input.street_address,
-                    city: input.city,
-                    region: input.region,
-                    postal_code: input.postal_code,
-                }),
-                ..worker
-            }
-        });
+        if let Some(field) = worksite.find_invalid_custom_value(&input.custom_values) {
+            return Err(UpdateWorkerFailure::InvalidCustomValue(field));
+        }
+
+        let previous = worksite.get_worker(input.worker_id.clone());
+        let updated_worksite =
+            worksite.update_worker(input.worker_id.clone(), |worker| -> Worker {
+                Worker {
+                    first_name: input.first_name,
+                    last_name: input.last_name,
+                    email: input.email,
+                    phone: input.phone,
+                    address: Some(Address {
+                        street_address: input.street_address,
+                        city: input.city,
+                        region: input.region,
+                        postal_code: input.postal_code,
+                    }),
+                    employment: input.employment,
+                    custom_values: input.custom_values,
+                    ..worker
+                }
+            });
 
         self.worksite_repository
-            .save(updated_worksite)
+            .save(updated_worksite.clone())
             .await
             .map_err(|e| UpdateWorkerFailure::Unknown(e.to_string()))?;
 
+        let worker = updated_worksite.get_worker(input.worker_id);
+
+        if let (Some(previous), Some(worker)) = (previous, worker) {
+            self.event_publisher
+                .publish(DomainEvent::new(
+                    updated_worksite.id,
+                    WorksiteEvent::WorkerUpdated {
+                        previous: Box::new(previous),
+                        worker: Box::new(worker),
+                    },
+                ))
+                .await;
+        }
+
         Ok(())
     }
 }
@@ -67,4 +93,6 @@ pub enum UpdateWorkerFailure {
     Unknown(String),
     #[error("Worksite does not exist")]
     NotFound,
+    #[error("The value for {0} doesn't fit the field")]
+    InvalidCustomValue(String),
 }
@@ -2,11 +2,16 @@ use std::sync::Arc;
 
 use thiserror::Error;
 
-use crate::{models::Worksite, ports::worksite_repository::WorksiteRepository};
+use crate::{
+    events::{DomainEvent, WorksiteEvent},
+    models::Worksite,
+    ports::{event_publisher::EventPublisher, worksite_repository::WorksiteRepository},
+};
 
 #[derive(Clone)]
 pub struct UpdateWorksite {
     pub worksite_repository: Arc<dyn WorksiteRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
 }
 
 #[derive(Clone, Debug)]
@@ -27,16 +32,27 @@ impl UpdateWorksite {
             .map_err(|e| UpdateWorksiteFailure::Unknown(e.to_string()))?
             .ok_or(UpdateWorksiteFailure::NotFound)?;
 
+        let previous_name = worksite.name.clone();
         let worksite = Worksite {
             name: input.worksite_name,
             ..worksite
         };
 
         self.worksite_repository
-            .save(worksite)
+            .save(worksite.clone())
             .await
             .map_err(|e| UpdateWorksiteFailure::Unknown(e.to_string()))?;
 
+        self.event_publisher
+            .publish(DomainEvent::new(
+                worksite.id,
+                WorksiteEvent::WorksiteRenamed {
+                    previous_name,
+                    name: worksite.name,
+                },
+            ))
+            .await;
+
         Ok(())
     }
 }